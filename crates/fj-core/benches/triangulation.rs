@@ -0,0 +1,96 @@
+//! Benchmarks for approximation and triangulation
+//!
+//! Each benchmark approximates and triangulates a representative model at a
+//! fixed tolerance. Run them with `cargo bench -p fj-core`.
+
+// `criterion_group!` generates a public function without documentation.
+#![allow(missing_docs)]
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use fj_core::{
+    algorithms::{approx::Tolerance, triangulate::Triangulate},
+    fixtures,
+    objects::{Region, Sketch, Solid},
+    operations::{
+        build::{BuildRegion, BuildSketch, BuildSolid},
+        insert::Insert,
+        sweep::SweepSketch,
+        update::UpdateSketch,
+    },
+    services::Services,
+};
+use fj_math::{Point, Scalar};
+
+fn spacer(c: &mut Criterion) {
+    let mut services = Services::new();
+    let spacer = fixtures::spacer(&mut services);
+    let tolerance = Tolerance::from(0.01);
+
+    c.bench_function("spacer", |b| {
+        b.iter(|| (&spacer, tolerance).triangulate());
+    });
+}
+
+fn circle_approximation(c: &mut Criterion) {
+    // The spacer consists of circles and cylinders, so most of the work of
+    // approximating it goes into approximating those. Smaller tolerances
+    // result in more points per circle.
+    let mut services = Services::new();
+    let spacer = fixtures::spacer(&mut services);
+
+    let mut group = c.benchmark_group("circle_approximation");
+    for tolerance in [0.01, 0.001, 0.0001] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(tolerance),
+            &Tolerance::from(tolerance),
+            |b, &tolerance| {
+                b.iter(|| (&spacer, tolerance).triangulate());
+            },
+        );
+    }
+    group.finish();
+}
+
+fn faceted_sphere(c: &mut Criterion) {
+    let mut services = Services::new();
+    let sphere = Solid::faceted_sphere(1., 64, &mut services);
+    let tolerance = Tolerance::from(0.01);
+
+    c.bench_function("faceted_sphere", |b| {
+        b.iter(|| (&sphere, tolerance).triangulate());
+    });
+}
+
+fn polygon_prism(c: &mut Criterion) {
+    // A prism with a high number of sides, as a stand-in for a revolved
+    // profile, until there is a revolve operation.
+    let num_sides = 256;
+
+    let mut services = Services::new();
+    let points = (0..num_sides).map(|i| {
+        let angle = Scalar::TAU / num_sides as f64 * i as f64;
+        let (sin, cos) = angle.sin_cos();
+        Point::from([cos, sin])
+    });
+    let region = Region::polygon(points.collect::<Vec<_>>(), &mut services)
+        .insert(&mut services);
+    let surface = services.objects.surfaces.xy_plane();
+    let prism = Sketch::empty()
+        .add_region(region)
+        .sweep_sketch(surface, [0., 0., 1.], &mut services)
+        .expect("Polygon encloses an area");
+    let tolerance = Tolerance::from(0.01);
+
+    c.bench_function("polygon_prism", |b| {
+        b.iter(|| (&prism, tolerance).triangulate());
+    });
+}
+
+criterion_group!(
+    benches,
+    spacer,
+    circle_approximation,
+    faceted_sphere,
+    polygon_prism
+);
+criterion_main!(benches);
@@ -2,7 +2,7 @@
 
 use std::collections::BTreeMap;
 
-use fj_math::Point;
+use fj_math::{Point, Scalar};
 
 use crate::{
     geometry::{CurveBoundary, GlobalPath, SurfacePath},
@@ -10,7 +10,10 @@ use crate::{
     storage::{Handle, HandleWrapper},
 };
 
-use super::{Approx, ApproxPoint, Tolerance};
+use super::{
+    strategy::{ApproxStrategy, PathApproxCache},
+    Approx, ApproxError, ApproxPoint, Tolerance,
+};
 
 impl Approx
     for (
@@ -20,7 +23,7 @@ impl Approx
         CurveBoundary<Point<1>>,
     )
 {
-    type Approximation = CurveApprox;
+    type Approximation = Result<CurveApprox, ApproxError>;
     type Cache = CurveApproxCache;
 
     fn approx_with_cache(
@@ -29,14 +32,20 @@ impl Approx
         cache: &mut Self::Cache,
     ) -> Self::Approximation {
         let (curve, surface_path, surface, boundary) = self;
+        let tolerance = tolerance.into();
 
-        match cache.get(curve, boundary) {
-            Some(approx) => approx,
+        match cache.get(curve, boundary, tolerance) {
+            Some(approx) => Ok(approx),
             None => {
-                let approx =
-                    approx_curve(&surface_path, surface, boundary, tolerance);
-
-                cache.insert(curve.clone(), boundary, approx)
+                let approx = approx_curve(
+                    &surface_path,
+                    surface,
+                    boundary,
+                    tolerance,
+                    &mut cache.path,
+                )?;
+
+                Ok(cache.insert(curve.clone(), boundary, tolerance, approx))
             }
         }
     }
@@ -47,7 +56,8 @@ fn approx_curve(
     surface: &Surface,
     boundary: CurveBoundary<Point<1>>,
     tolerance: impl Into<Tolerance>,
-) -> CurveApprox {
+    cache: &mut PathApproxCache,
+) -> Result<CurveApprox, ApproxError> {
     // There are different cases of varying complexity. Circles are the hard
     // part here, as they need to be approximated, while lines don't need to be.
     //
@@ -56,13 +66,17 @@ fn approx_curve(
     // in a more abstract way.
     let points = match (path, surface.geometry().u) {
         (SurfacePath::Circle(_), GlobalPath::Circle(_)) => {
-            todo!(
-                "Approximating a circle on a curved surface not supported yet."
-            )
+            return Err(ApproxError::CircleOnCurvedSurface);
         }
-        (SurfacePath::Circle(_), GlobalPath::Line(_)) => {
+        (SurfacePath::Spiral(_), GlobalPath::Circle(_)) => {
+            return Err(ApproxError::SpiralOnCurvedSurface);
+        }
+        (
+            SurfacePath::Circle(_) | SurfacePath::Spiral(_),
+            GlobalPath::Line(_),
+        ) => {
             (path, boundary)
-                .approx_with_cache(tolerance, &mut ())
+                .approx_with_cache(tolerance, cache)
                 .into_iter()
                 .map(|(point_curve, point_surface)| {
                     // We're throwing away `point_surface` here, which is a bit
@@ -94,7 +108,7 @@ fn approx_curve(
                 }));
 
             let approx_u = (surface.geometry().u, range_u)
-                .approx_with_cache(tolerance, &mut ());
+                .approx_with_cache(tolerance, cache);
 
             let mut points = Vec::new();
             for (u, _) in approx_u {
@@ -115,7 +129,7 @@ fn approx_curve(
             ApproxPoint::new(point_curve, point_global)
         })
         .collect();
-    CurveApprox { points }
+    Ok(CurveApprox { points })
 }
 
 /// Approximation of [`Curve`], within a specific boundary
@@ -126,6 +140,70 @@ pub struct CurveApprox {
 }
 
 impl CurveApprox {
+    /// Compute the tolerance that the approximation actually achieves
+    ///
+    /// This is the largest distance between the curve and the polyline that
+    /// approximates it. The tolerance that the approximation was computed with
+    /// is an upper bound for it.
+    ///
+    /// `path`, `surface`, and `boundary` must be the ones that the
+    /// approximation was computed from. The distance is determined by sampling
+    /// the curve between each pair of neighboring points of the polyline.
+    pub fn achieved_tolerance(
+        &self,
+        path: &SurfacePath,
+        surface: &Surface,
+        boundary: CurveBoundary<Point<1>>,
+    ) -> Scalar {
+        const NUM_SAMPLES_PER_SEGMENT: u32 = 8;
+
+        let point_global = |point_curve: Point<1>| {
+            surface.geometry().point_from_surface_coords(
+                path.point_from_path_coords(point_curve),
+            )
+        };
+
+        // The approximation doesn't include the boundary, so we need to add
+        // it back to get the full polyline.
+        let [start, end] = boundary.inner;
+        let points = [start]
+            .into_iter()
+            .chain(self.points.iter().map(|point| point.local_form))
+            .chain([end])
+            .collect::<Vec<_>>();
+
+        let mut max_distance = Scalar::ZERO;
+
+        for segment in points.windows(2) {
+            let [a, b] = [segment[0], segment[1]];
+            let [a_global, b_global] = [a, b].map(point_global);
+            let chord = b_global - a_global;
+
+            for i in 1..NUM_SAMPLES_PER_SEGMENT {
+                let t = a
+                    + (b - a)
+                        * (f64::from(i) / f64::from(NUM_SAMPLES_PER_SEGMENT));
+                let point = point_global(t);
+
+                // Distance from the point to the chord, which is the closest
+                // point on the chord's line, clamped to the chord.
+                let to_point = point - a_global;
+                let s = if chord.magnitude() > Scalar::ZERO {
+                    (to_point.dot(&chord) / chord.dot(&chord))
+                        .max(Scalar::ZERO)
+                        .min(Scalar::ONE)
+                } else {
+                    Scalar::ZERO
+                };
+                let distance = (to_point - chord * s).magnitude();
+
+                max_distance = max_distance.max(distance);
+            }
+        }
+
+        max_distance
+    }
+
     fn reverse(mut self) -> Self {
         self.points.reverse();
         self
@@ -133,24 +211,44 @@ impl CurveApprox {
 }
 
 /// Cache for curve approximations
+///
+/// Approximations are cached per tolerance, so approximating the same curve
+/// with different tolerances (for example, a coarse one for display and a fine
+/// one for export) yields a different result for each.
 #[derive(Default)]
 pub struct CurveApproxCache {
-    inner:
-        BTreeMap<(HandleWrapper<Curve>, CurveBoundary<Point<1>>), CurveApprox>,
+    inner: BTreeMap<CurveApproxCacheKey, CurveApprox>,
+    path: PathApproxCache,
 }
 
+type CurveApproxCacheKey =
+    (HandleWrapper<Curve>, CurveBoundary<Point<1>>, Tolerance);
+
 impl CurveApproxCache {
+    /// Create a cache that approximates curves using the provided strategy
+    pub fn with_strategy(strategy: impl ApproxStrategy + 'static) -> Self {
+        Self {
+            inner: BTreeMap::new(),
+            path: PathApproxCache::with_strategy(strategy),
+        }
+    }
+
     fn get(
         &self,
         handle: &Handle<Curve>,
         boundary: CurveBoundary<Point<1>>,
+        tolerance: Tolerance,
     ) -> Option<CurveApprox> {
         let handle = HandleWrapper::from(handle.clone());
 
-        if let Some(approx) = self.inner.get(&(handle.clone(), boundary)) {
+        if let Some(approx) =
+            self.inner.get(&(handle.clone(), boundary, tolerance))
+        {
             return Some(approx.clone());
         }
-        if let Some(approx) = self.inner.get(&(handle, boundary.reverse())) {
+        if let Some(approx) =
+            self.inner.get(&(handle, boundary.reverse(), tolerance))
+        {
             return Some(approx.clone().reverse());
         }
 
@@ -161,11 +259,12 @@ impl CurveApproxCache {
         &mut self,
         handle: Handle<Curve>,
         boundary: CurveBoundary<Point<1>>,
+        tolerance: Tolerance,
         approx: CurveApprox,
     ) -> CurveApprox {
         let handle = HandleWrapper::from(handle);
         self.inner
-            .insert((handle, boundary), approx.clone())
+            .insert((handle, boundary, tolerance), approx.clone())
             .unwrap_or(approx)
     }
 }
@@ -174,18 +273,21 @@ impl CurveApproxCache {
 mod tests {
     use std::{f64::consts::TAU, ops::Deref};
 
+    use fj_math::Scalar;
     use pretty_assertions::assert_eq;
 
     use crate::{
-        algorithms::approx::{Approx, ApproxPoint},
+        algorithms::approx::{Approx, ApproxError, ApproxPoint},
         geometry::{CurveBoundary, GlobalPath, SurfaceGeometry, SurfacePath},
         objects::{Curve, Surface},
         operations::insert::Insert,
         services::Services,
     };
 
+    use super::CurveApproxCache;
+
     #[test]
-    fn approx_line_on_flat_surface() {
+    fn approx_line_on_flat_surface() -> anyhow::Result<()> {
         let mut services = Services::new();
 
         let curve = Curve::new().insert(&mut services);
@@ -195,14 +297,17 @@ mod tests {
         let surface = services.objects.surfaces.xz_plane();
 
         let tolerance = 1.;
-        let approx =
-            (&curve, surface_path, surface.deref(), boundary).approx(tolerance);
+        let approx = (&curve, surface_path, surface.deref(), boundary)
+            .approx(tolerance)?;
 
         assert_eq!(approx.points, vec![]);
+
+        Ok(())
     }
 
     #[test]
-    fn approx_line_on_curved_surface_but_not_along_curve() {
+    fn approx_line_on_curved_surface_but_not_along_curve() -> anyhow::Result<()>
+    {
         let mut services = Services::new();
 
         let curve = Curve::new().insert(&mut services);
@@ -216,13 +321,15 @@ mod tests {
 
         let tolerance = 1.;
         let approx =
-            (&curve, surface_path, &surface, boundary).approx(tolerance);
+            (&curve, surface_path, &surface, boundary).approx(tolerance)?;
 
         assert_eq!(approx.points, vec![]);
+
+        Ok(())
     }
 
     #[test]
-    fn approx_line_on_curved_surface_along_curve() {
+    fn approx_line_on_curved_surface_along_curve() -> anyhow::Result<()> {
         let mut services = Services::new();
 
         let global_path = GlobalPath::circle_from_radius(1.);
@@ -239,7 +346,7 @@ mod tests {
 
         let tolerance = 1.;
         let approx =
-            (&curve, surface_path, &surface, boundary).approx(tolerance);
+            (&curve, surface_path, &surface, boundary).approx(tolerance)?;
 
         let expected_approx = (global_path, boundary)
             .approx(tolerance)
@@ -253,10 +360,12 @@ mod tests {
             })
             .collect::<Vec<_>>();
         assert_eq!(approx.points, expected_approx);
+
+        Ok(())
     }
 
     #[test]
-    fn approx_circle_on_flat_surface() {
+    fn approx_circle_on_flat_surface() -> anyhow::Result<()> {
         let mut services = Services::new();
 
         let curve = Curve::new().insert(&mut services);
@@ -266,8 +375,8 @@ mod tests {
         let surface = services.objects.surfaces.xz_plane();
 
         let tolerance = 1.;
-        let approx =
-            (&curve, surface_path, surface.deref(), boundary).approx(tolerance);
+        let approx = (&curve, surface_path, surface.deref(), boundary)
+            .approx(tolerance)?;
 
         let expected_approx = (&surface_path, boundary)
             .approx(tolerance)
@@ -281,5 +390,74 @@ mod tests {
             })
             .collect::<Vec<_>>();
         assert_eq!(approx.points, expected_approx);
+
+        Ok(())
+    }
+
+    #[test]
+    fn approx_circle_with_different_tolerances() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let curve = Curve::new().insert(&mut services);
+        let surface_path =
+            SurfacePath::circle_from_center_and_radius([0., 0.], 1.);
+        let boundary = CurveBoundary::from([[0.], [TAU]]);
+        let surface = services.objects.surfaces.xz_plane();
+
+        let mut cache = CurveApproxCache::default();
+        let [coarse, fine] = [1., 0.01].map(|tolerance| {
+            (&curve, surface_path, surface.deref(), boundary)
+                .approx_with_cache(tolerance, &mut cache)
+        });
+
+        assert!(coarse?.points.len() < fine?.points.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn approx_circle_on_curved_surface() {
+        let mut services = Services::new();
+
+        let curve = Curve::new().insert(&mut services);
+        let surface_path =
+            SurfacePath::circle_from_center_and_radius([0., 0.], 1.);
+        let boundary = CurveBoundary::from([[0.], [TAU]]);
+        let surface = Surface::new(SurfaceGeometry {
+            u: GlobalPath::circle_from_radius(1.),
+            v: [0., 0., 1.].into(),
+        });
+
+        let tolerance = 1.;
+        let approx =
+            (&curve, surface_path, &surface, boundary).approx(tolerance);
+
+        assert_eq!(approx.err(), Some(ApproxError::CircleOnCurvedSurface));
+    }
+
+    #[test]
+    fn achieved_tolerance() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let curve = Curve::new().insert(&mut services);
+        let surface_path =
+            SurfacePath::circle_from_center_and_radius([0., 0.], 1.);
+        let boundary = CurveBoundary::from([[0.], [TAU]]);
+        let surface = services.objects.surfaces.xz_plane();
+
+        let [coarse, fine] = [0.1, 0.001].map(|tolerance| {
+            (&curve, surface_path, surface.deref(), boundary)
+                .approx(tolerance)
+                .map(|approx| {
+                    approx.achieved_tolerance(&surface_path, &surface, boundary)
+                })
+        });
+        let [coarse, fine] = [coarse?, fine?];
+
+        assert!(coarse > fine);
+        assert!(coarse <= Scalar::from(0.1));
+        assert!(fine <= Scalar::from(0.001));
+
+        Ok(())
     }
 }
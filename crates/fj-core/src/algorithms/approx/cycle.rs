@@ -10,11 +10,11 @@ use crate::objects::{Cycle, Surface};
 
 use super::{
     edge::{HalfEdgeApprox, HalfEdgeApproxCache},
-    Approx, ApproxPoint, Tolerance,
+    Approx, ApproxError, ApproxPoint, Tolerance,
 };
 
 impl Approx for (&Cycle, &Surface) {
-    type Approximation = CycleApprox;
+    type Approximation = Result<CycleApprox, ApproxError>;
     type Cache = HalfEdgeApproxCache;
 
     fn approx_with_cache(
@@ -31,9 +31,9 @@ impl Approx for (&Cycle, &Surface) {
             .map(|edge| {
                 (edge.deref(), surface).approx_with_cache(tolerance, cache)
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
-        CycleApprox { half_edges }
+        Ok(CycleApprox { half_edges })
     }
 }
 
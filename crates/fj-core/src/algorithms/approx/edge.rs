@@ -5,15 +5,20 @@
 //! approximations are usually used to build cycle approximations, and this way,
 //! the caller doesn't have to deal with duplicate vertices.
 
-use crate::objects::{HalfEdge, Surface};
+use std::collections::BTreeMap;
+
+use crate::{
+    objects::{Curve, Face, HalfEdge, Surface},
+    storage::{Handle, HandleWrapper},
+};
 
 use super::{
-    curve::CurveApproxCache, vertex::VertexApproxCache, Approx, ApproxPoint,
-    Tolerance,
+    curve::CurveApproxCache, strategy::ApproxStrategy,
+    vertex::VertexApproxCache, Approx, ApproxError, ApproxPoint, Tolerance,
 };
 
 impl Approx for (&HalfEdge, &Surface) {
-    type Approximation = HalfEdgeApprox;
+    type Approximation = Result<HalfEdgeApprox, ApproxError>;
     type Cache = HalfEdgeApproxCache;
 
     fn approx_with_cache(
@@ -41,8 +46,15 @@ impl Approx for (&HalfEdge, &Surface) {
         let first = ApproxPoint::new(start_position_surface, start_position);
 
         let rest = {
+            // The curve might be shared with a face that has its own tolerance.
+            let tolerance = cache
+                .curve_tolerances
+                .get(&HandleWrapper::from(edge.curve().clone()))
+                .copied()
+                .unwrap_or(tolerance);
+
             let approx = (edge.curve(), edge.path(), surface, edge.boundary())
-                .approx_with_cache(tolerance, &mut cache.curve);
+                .approx_with_cache(tolerance, &mut cache.curve)?;
 
             approx.points.into_iter().map(|point| {
                 let point_surface =
@@ -55,7 +67,7 @@ impl Approx for (&HalfEdge, &Surface) {
         let mut points = vec![first];
         points.extend(rest);
 
-        HalfEdgeApprox { points }
+        Ok(HalfEdgeApprox { points })
     }
 }
 
@@ -71,4 +83,54 @@ pub struct HalfEdgeApprox {
 pub struct HalfEdgeApproxCache {
     start_position: VertexApproxCache,
     curve: CurveApproxCache,
+    face_tolerances: BTreeMap<HandleWrapper<Face>, Tolerance>,
+    curve_tolerances: BTreeMap<HandleWrapper<Curve>, Tolerance>,
+}
+
+impl HalfEdgeApproxCache {
+    /// Create a cache that approximates half-edges using the provided strategy
+    ///
+    /// Pass this cache to [`Approx::approx_with_cache`], to approximate an
+    /// object using a strategy other than the default one.
+    pub fn with_strategy(strategy: impl ApproxStrategy + 'static) -> Self {
+        Self {
+            start_position: VertexApproxCache::default(),
+            curve: CurveApproxCache::with_strategy(strategy),
+            face_tolerances: BTreeMap::new(),
+            curve_tolerances: BTreeMap::new(),
+        }
+    }
+
+    /// Override the tolerance that is used for a specific face
+    ///
+    /// When a set of faces is approximated using this cache, which is the case
+    /// when approximating a shell or solid, the face is approximated using
+    /// this tolerance, instead of the one that is passed to
+    /// [`Approx::approx_with_cache`].
+    ///
+    /// Edges that are shared between faces are approximated using the smallest
+    /// tolerance of those faces, so their approximations still fit together.
+    pub fn override_tolerance(
+        &mut self,
+        face: Handle<Face>,
+        tolerance: impl Into<Tolerance>,
+    ) {
+        self.face_tolerances.insert(face.into(), tolerance.into());
+    }
+
+    pub(super) fn face_tolerance(
+        &self,
+        face: &Handle<Face>,
+    ) -> Option<Tolerance> {
+        self.face_tolerances
+            .get(&HandleWrapper::from(face.clone()))
+            .copied()
+    }
+
+    pub(super) fn set_curve_tolerances(
+        &mut self,
+        tolerances: BTreeMap<HandleWrapper<Curve>, Tolerance>,
+    ) {
+        self.curve_tolerances = tolerances;
+    }
 }
@@ -2,22 +2,27 @@
 //!
 //! See [`FaceApprox`].
 
-use std::{collections::BTreeSet, ops::Deref};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Deref,
+};
 
 use fj_interop::mesh::Color;
 
 use crate::{
+    geometry::SurfaceGeometry,
     objects::{Face, Handedness, ObjectSet},
+    storage::HandleWrapper,
     validate::ValidationConfig,
 };
 
 use super::{
-    cycle::CycleApprox, edge::HalfEdgeApproxCache, Approx, ApproxPoint,
-    Tolerance,
+    cycle::CycleApprox, edge::HalfEdgeApproxCache, Approx, ApproxError,
+    ApproxPoint, Tolerance,
 };
 
 impl Approx for &ObjectSet<Face> {
-    type Approximation = BTreeSet<FaceApprox>;
+    type Approximation = Result<BTreeSet<FaceApprox>, ApproxError>;
     type Cache = HalfEdgeApproxCache;
 
     fn approx_with_cache(
@@ -27,10 +32,36 @@ impl Approx for &ObjectSet<Face> {
     ) -> Self::Approximation {
         let tolerance = tolerance.into();
 
-        let approx = self
+        // Faces can have their own tolerance. Edges that are shared between
+        // faces need to be approximated the same way for each of them though,
+        // or the approximations won't fit together. So each curve uses the
+        // smallest tolerance of the faces it is part of.
+        let faces = self
+            .into_iter()
+            .map(|face| (face, cache.face_tolerance(face).unwrap_or(tolerance)))
+            .collect::<Vec<_>>();
+
+        let mut curve_tolerances = BTreeMap::new();
+        for &(face, tolerance) in &faces {
+            for cycle in face.region().all_cycles() {
+                for half_edge in cycle.half_edges() {
+                    curve_tolerances
+                        .entry(HandleWrapper::from(half_edge.curve().clone()))
+                        .and_modify(|t: &mut Tolerance| {
+                            *t = (*t).min(tolerance)
+                        })
+                        .or_insert(tolerance);
+                }
+            }
+        }
+
+        cache.set_curve_tolerances(curve_tolerances);
+        let approx = faces
             .into_iter()
-            .map(|face| face.approx_with_cache(tolerance, cache))
-            .collect();
+            .map(|(face, tolerance)| face.approx_with_cache(tolerance, cache))
+            .collect::<Result<BTreeSet<_>, _>>();
+        cache.set_curve_tolerances(BTreeMap::new());
+        let approx = approx?;
 
         let min_distance = ValidationConfig::default().distinct_min_distance;
         let mut all_points: BTreeSet<ApproxPoint<2>> = BTreeSet::new();
@@ -58,12 +89,12 @@ impl Approx for &ObjectSet<Face> {
             }
         }
 
-        approx
+        Ok(approx)
     }
 }
 
 impl Approx for &Face {
-    type Approximation = FaceApprox;
+    type Approximation = Result<FaceApprox, ApproxError>;
     type Cache = HalfEdgeApproxCache;
 
     fn approx_with_cache(
@@ -88,21 +119,22 @@ impl Approx for &Face {
 
         let exterior =
             (self.region().exterior().deref(), self.surface().deref())
-                .approx_with_cache(tolerance, cache);
+                .approx_with_cache(tolerance, cache)?;
 
         let mut interiors = BTreeSet::new();
         for cycle in self.region().interiors() {
             let cycle = (cycle.deref(), self.surface().deref())
-                .approx_with_cache(tolerance, cache);
+                .approx_with_cache(tolerance, cache)?;
             interiors.insert(cycle);
         }
 
-        FaceApprox {
+        Ok(FaceApprox {
             exterior,
             interiors,
             color: self.region().color(),
             coord_handedness: self.coord_handedness(),
-        }
+            surface: self.surface().geometry(),
+        })
     }
 }
 
@@ -120,6 +152,9 @@ pub struct FaceApprox {
 
     /// The handedness of the approximated face's front-side coordinate system
     pub coord_handedness: Handedness,
+
+    /// The geometry of the surface that the approximated face is defined on
+    pub surface: SurfaceGeometry,
 }
 
 impl FaceApprox {
@@ -136,3 +171,96 @@ impl FaceApprox {
         points
     }
 }
+
+/// The boundary of a [`Face`], approximated as closed polylines
+///
+/// Returned by [`Face::approx_boundary`].
+#[derive(Clone, Debug)]
+pub struct FaceBoundaryApprox {
+    /// The approximated exterior cycle
+    ///
+    /// The last point is the same as the first one.
+    pub exterior: Vec<ApproxPoint<2>>,
+
+    /// The approximated interior cycles
+    ///
+    /// In the same order as the interior cycles of the face. The last point of
+    /// each is the same as its first one.
+    pub interiors: Vec<Vec<ApproxPoint<2>>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::{
+            approx::{edge::HalfEdgeApproxCache, Approx, Tolerance},
+            triangulate::Triangulate,
+        },
+        geometry::{GlobalPath, SurfaceGeometry},
+        objects::{Shell, Solid},
+        operations::build::BuildSolid,
+        services::Services,
+    };
+
+    #[test]
+    fn face_tolerance_override() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let spacer = Solid::spacer(1., 0.5, 1., &mut services);
+        let shell = spacer.shells().first();
+
+        let is_inner_wall = |surface: &SurfaceGeometry| match surface.u {
+            GlobalPath::Circle(circle) => circle.radius() < Scalar::from(0.75),
+            GlobalPath::Line(_) => false,
+        };
+        let inner_wall = shell
+            .faces()
+            .iter()
+            .find(|face| is_inner_wall(&face.surface().geometry()))
+            .unwrap()
+            .clone();
+
+        let tolerance = Tolerance::from_scalar(0.1)?;
+
+        // Without an override, the inner wall has fewer triangles than the
+        // outer one, as its circles are smaller.
+        let mut cache = HalfEdgeApproxCache::default();
+        let [inner_wall_triangles, max_of_others] =
+            count_triangles(shell, tolerance, &mut cache, is_inner_wall)?;
+        assert!(inner_wall_triangles < max_of_others);
+
+        // With a finer tolerance for the inner wall, it has the most.
+        let mut cache = HalfEdgeApproxCache::default();
+        cache.override_tolerance(inner_wall, Tolerance::from_scalar(0.001)?);
+        let [inner_wall_triangles, max_of_others] =
+            count_triangles(shell, tolerance, &mut cache, is_inner_wall)?;
+        assert!(inner_wall_triangles > max_of_others);
+
+        Ok(())
+    }
+
+    fn count_triangles(
+        shell: &Shell,
+        tolerance: Tolerance,
+        cache: &mut HalfEdgeApproxCache,
+        is_inner_wall: impl Fn(&SurfaceGeometry) -> bool,
+    ) -> anyhow::Result<[usize; 2]> {
+        let mut inner_wall = 0;
+        let mut max_of_others = 0;
+
+        for face in shell.approx_with_cache(tolerance, cache)? {
+            let is_inner_wall = is_inner_wall(&face.surface);
+            let num_triangles = face.triangulate()?.triangles().count();
+
+            if is_inner_wall {
+                inner_wall = num_triangles;
+            } else {
+                max_of_others = max_of_others.max(num_triangles);
+            }
+        }
+
+        Ok([inner_wall, max_of_others])
+    }
+}
@@ -8,6 +8,8 @@ pub mod path;
 pub mod shell;
 pub mod sketch;
 pub mod solid;
+pub mod strategy;
+pub mod surface;
 pub mod tolerance;
 pub mod vertex;
 
@@ -21,7 +23,10 @@ use fj_math::Point;
 
 use crate::objects::Surface;
 
-pub use self::tolerance::{InvalidTolerance, Tolerance};
+pub use self::{
+    strategy::{ApproxStrategy, UniformStrategy},
+    tolerance::{InvalidTolerance, Tolerance},
+};
 
 /// Approximate an object
 pub trait Approx: Sized {
@@ -51,6 +56,21 @@ pub trait Approx: Sized {
     ) -> Self::Approximation;
 }
 
+/// Error approximating an object
+///
+/// Returned by the approximations of objects that are not (or not fully)
+/// supported yet.
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum ApproxError {
+    /// A circle on a curved surface can't be approximated yet
+    #[error("Approximating a circle on a curved surface is not supported yet")]
+    CircleOnCurvedSurface,
+
+    /// A spiral on a curved surface can't be approximated yet
+    #[error("Approximating a spiral on a curved surface is not supported yet")]
+    SpiralOnCurvedSurface,
+}
+
 /// A point from an approximation, with local and global forms
 #[derive(Clone, Copy, Debug)]
 pub struct ApproxPoint<const D: usize> {
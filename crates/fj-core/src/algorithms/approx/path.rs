@@ -30,46 +30,49 @@
 
 use std::iter;
 
-use fj_math::{Circle, Point, Scalar, Sign};
+use fj_math::{Circle, Point, Scalar, Sign, Spiral};
 
 use crate::geometry::{CurveBoundary, GlobalPath, SurfacePath};
 
-use super::{Approx, Tolerance};
+use super::{strategy::PathApproxCache, Approx, Tolerance};
 
 impl Approx for (&SurfacePath, CurveBoundary<Point<1>>) {
     type Approximation = Vec<(Point<1>, Point<2>)>;
-    type Cache = ();
+    type Cache = PathApproxCache;
 
     fn approx_with_cache(
         self,
         tolerance: impl Into<Tolerance>,
-        (): &mut Self::Cache,
+        cache: &mut Self::Cache,
     ) -> Self::Approximation {
         let (path, range) = self;
 
         match path {
             SurfacePath::Circle(circle) => {
-                approx_circle(circle, range, tolerance.into())
+                approx_circle(circle, range, tolerance.into(), cache)
             }
             SurfacePath::Line(_) => vec![],
+            SurfacePath::Spiral(spiral) => {
+                approx_spiral(spiral, range, tolerance.into())
+            }
         }
     }
 }
 
 impl Approx for (GlobalPath, CurveBoundary<Point<1>>) {
     type Approximation = Vec<(Point<1>, Point<3>)>;
-    type Cache = ();
+    type Cache = PathApproxCache;
 
     fn approx_with_cache(
         self,
         tolerance: impl Into<Tolerance>,
-        (): &mut Self::Cache,
+        cache: &mut Self::Cache,
     ) -> Self::Approximation {
         let (path, range) = self;
 
         match path {
             GlobalPath::Circle(circle) => {
-                approx_circle(&circle, range, tolerance.into())
+                approx_circle(&circle, range, tolerance.into(), cache)
             }
             GlobalPath::Line(_) => vec![],
         }
@@ -79,18 +82,21 @@ impl Approx for (GlobalPath, CurveBoundary<Point<1>>) {
 /// Approximate a circle
 ///
 /// `tolerance` specifies how much the approximation is allowed to deviate
-/// from the circle.
+/// from the circle. Which points are chosen is up to the strategy of `cache`.
 fn approx_circle<const D: usize>(
     circle: &Circle<D>,
     boundary: impl Into<CurveBoundary<Point<1>>>,
     tolerance: Tolerance,
+    cache: &PathApproxCache,
 ) -> Vec<(Point<1>, Point<D>)> {
     let boundary = boundary.into();
 
-    let params = PathApproxParams::for_circle(circle, tolerance);
+    let radius = circle.a().magnitude();
     let mut points = Vec::new();
 
-    for point_curve in params.points(boundary) {
+    for point_curve in
+        cache.strategy().circle_points(radius, boundary, tolerance)
+    {
         let point_global = circle.point_from_circle_coords(point_curve);
         points.push((point_curve, point_global));
     }
@@ -98,26 +104,103 @@ fn approx_circle<const D: usize>(
     points
 }
 
-struct PathApproxParams {
+/// Approximate a spiral
+///
+/// The curvature of a spiral changes along its length, so the increment
+/// between points changes too. To keep the approximation deterministic, the
+/// points are always computed by stepping outward from coordinate `0.`, before
+/// filtering those that are within the boundary.
+fn approx_spiral<const D: usize>(
+    spiral: &Spiral<D>,
+    boundary: CurveBoundary<Point<1>>,
+    tolerance: Tolerance,
+) -> Vec<(Point<1>, Point<D>)> {
+    let [a, b] = boundary.inner.map(|point| point.t);
+    let [min, max] = if a < b { [a, b] } else { [b, a] };
+
+    // The increment that keeps the approximation within the tolerance, for the
+    // section of the spiral that starts at `t`.
+    //
+    // `speed` is the length of the spiral per radian. For an Archimedean
+    // spiral, the radius of curvature is at least half of that, so a segment
+    // that spans the increment deviates from the spiral by at most
+    // `speed * increment^2 / 4`.
+    let increment_at = |t: Scalar| {
+        let radius = spiral.radius_at([t]);
+        let growth = spiral.growth();
+        let speed = (radius * radius + growth * growth).into_f64().sqrt();
+        Scalar::from((tolerance.inner().into_f64() / speed).sqrt() * 2.)
+    };
+
+    let step = |t: Scalar, direction: Scalar| {
+        // The increment gets smaller as the spiral gets further away from its
+        // center. Make sure to use the smaller one of both ends of the step.
+        let increment = increment_at(t);
+        let increment = increment.min(increment_at(t + increment * direction));
+        t + increment * direction
+    };
+
+    let mut coords = Vec::new();
+
+    let mut t = Scalar::ZERO;
+    while t < max {
+        if t > min {
+            coords.push(t);
+        }
+        t = step(t, Scalar::ONE);
+    }
+
+    let mut t = step(Scalar::ZERO, -Scalar::ONE);
+    while t > min {
+        if t < max {
+            coords.push(t);
+        }
+        t = step(t, -Scalar::ONE);
+    }
+
+    coords.sort();
+    if a > b {
+        coords.reverse();
+    }
+
+    coords
+        .into_iter()
+        .map(|t| {
+            let point_curve = Point::from([t]);
+            (point_curve, spiral.point_from_spiral_coords(point_curve))
+        })
+        .collect()
+}
+
+pub(super) struct PathApproxParams {
     increment: Scalar,
 }
 
 impl PathApproxParams {
+    #[cfg(test)]
     pub fn for_circle<const D: usize>(
         circle: &Circle<D>,
         tolerance: impl Into<Tolerance>,
     ) -> Self {
-        let radius = circle.a().magnitude();
+        Self::for_radius(circle.a().magnitude(), tolerance)
+    }
+
+    pub fn for_radius(radius: Scalar, tolerance: impl Into<Tolerance>) -> Self {
+        // A tolerance that is larger than the diameter of the circle would
+        // push the argument of `acos` out of its domain. Such a circle is
+        // approximated with the minimum number of vertices anyway.
+        let cos = (Scalar::ONE - (tolerance.into().inner() / radius))
+            .max(-Scalar::ONE);
 
-        let num_vertices_to_approx_full_circle = Scalar::max(
-            Scalar::PI
-                / (Scalar::ONE - (tolerance.into().inner() / radius)).acos(),
-            3.,
-        )
-        .ceil();
+        let num_vertices_to_approx_full_circle =
+            Scalar::max(Scalar::PI / cos.acos(), 3.).ceil();
 
         let increment = Scalar::TAU / num_vertices_to_approx_full_circle;
 
+        Self::for_increment(increment)
+    }
+
+    pub fn for_increment(increment: Scalar) -> Self {
         Self { increment }
     }
 
@@ -131,7 +214,20 @@ impl PathApproxParams {
     ) -> impl Iterator<Item = Point<1>> + '_ {
         let boundary = boundary.into();
 
-        let [a, b] = boundary.inner.map(|point| point.t / self.increment());
+        // Rounding errors can place a boundary slightly beyond a point of the
+        // approximation that it should coincide with. That point would then be
+        // generated right next to the boundary. Snap the boundary to it.
+        let snap = |t: Scalar| {
+            let rounded = t.round();
+            if (t - rounded).abs() < Scalar::from(1e-9) {
+                rounded
+            } else {
+                t
+            }
+        };
+
+        let [a, b] =
+            boundary.inner.map(|point| snap(point.t / self.increment()));
         let direction = (b - a).sign();
         let [min, max] = if a < b { [a, b] } else { [b, a] };
 
@@ -166,11 +262,14 @@ impl PathApproxParams {
 
 #[cfg(test)]
 mod tests {
-    use std::f64::consts::TAU;
+    use std::f64::consts::{FRAC_PI_2, TAU};
 
     use fj_math::{Circle, Point, Scalar};
 
-    use crate::algorithms::approx::{path::CurveBoundary, Tolerance};
+    use crate::{
+        algorithms::approx::{path::CurveBoundary, Approx, Tolerance},
+        geometry::SurfacePath,
+    };
 
     use super::PathApproxParams;
 
@@ -241,4 +340,101 @@ mod tests {
             assert_eq!(points, expected_points);
         }
     }
+
+    #[test]
+    fn points_for_partial_arc() {
+        let circle = Circle::from_center_and_radius([0., 0.], 1.);
+
+        // At this tolerance, a full circle is approximated using 24 vertices.
+        let params = PathApproxParams::for_circle(&circle, 0.009);
+        assert_eq!(params.increment(), Scalar::TAU / 24.);
+
+        let full = params.points([[0.], [TAU]]).collect::<Vec<_>>();
+        let quarter = params.points([[0.], [FRAC_PI_2]]).collect::<Vec<_>>();
+
+        // The quarter arc spans a quarter of the increments. Its ends are not
+        // part of the approximation, and neither is the point where the full
+        // circle closes.
+        assert_eq!(full.len(), 23);
+        assert_eq!(quarter.len(), 5);
+
+        // The points are evenly spaced between the ends of the arc.
+        for (i, point) in quarter.iter().enumerate() {
+            let expected = params.increment() * (i + 1) as f64;
+            assert!((point.t - expected).abs() < Scalar::from(1e-12));
+        }
+
+        // Rounding errors must not result in a point right at the end of the
+        // boundary.
+        let params = PathApproxParams::for_increment(Scalar::TAU / 61.);
+        assert_eq!(params.points([[0.], [TAU]]).count(), 60);
+    }
+
+    #[test]
+    fn points_for_subdivided_boundary() {
+        let circle = Circle::from_center_and_radius([0., 0.], 1.);
+        let params = PathApproxParams::for_circle(&circle, 0.01);
+
+        // Chosen such, that none of the points where the boundary is split
+        // happen to be part of the approximation. Those would be left out of
+        // both neighboring parts, as boundaries are not included.
+        let boundary = CurveBoundary::<Point<1>>::from([[0.1], [5.]]);
+        let whole = params.points(boundary).collect::<Vec<_>>();
+
+        for n in 1..=4 {
+            let parts = boundary
+                .subdivide(n)
+                .into_iter()
+                .flat_map(|part| params.points(part).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            assert_eq!(parts, whole);
+        }
+
+        let parts = boundary
+            .reverse()
+            .split_at([2.])
+            .into_iter()
+            .flat_map(|part| params.points(part).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            parts,
+            params.points(boundary.reverse()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn spiral_within_tolerance() {
+        let path =
+            SurfacePath::spiral_from_params([0., 0.], 0.5, 0.25).unwrap();
+        let tolerance = Tolerance::from_scalar(0.001).unwrap();
+        let boundary = CurveBoundary::<Point<1>>::from([[-1.], [10.]]);
+
+        let approx = (&path, boundary).approx(tolerance);
+
+        let [start, end] = boundary.inner;
+        let coords = [start]
+            .into_iter()
+            .chain(approx.iter().map(|&(point_curve, _)| point_curve))
+            .chain([end])
+            .collect::<Vec<_>>();
+
+        for segment in coords.windows(2) {
+            let [a, b] = [segment[0], segment[1]];
+            let [point_a, point_b] =
+                [a, b].map(|t| path.point_from_path_coords(t));
+
+            let middle_of_segment = point_a + (point_b - point_a) * 0.5;
+            let middle_of_spiral =
+                path.point_from_path_coords([(a.t + b.t) / 2.]);
+
+            assert!(
+                middle_of_segment.distance_to(&middle_of_spiral)
+                    < tolerance.inner()
+            );
+        }
+
+        let mut reversed = (&path, boundary.reverse()).approx(tolerance);
+        reversed.reverse();
+        assert_eq!(reversed, approx);
+    }
 }
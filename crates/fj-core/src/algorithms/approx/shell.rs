@@ -4,10 +4,12 @@ use std::collections::BTreeSet;
 
 use crate::objects::Shell;
 
-use super::{edge::HalfEdgeApproxCache, face::FaceApprox, Approx, Tolerance};
+use super::{
+    edge::HalfEdgeApproxCache, face::FaceApprox, Approx, ApproxError, Tolerance,
+};
 
 impl Approx for &Shell {
-    type Approximation = BTreeSet<FaceApprox>;
+    type Approximation = Result<BTreeSet<FaceApprox>, ApproxError>;
     type Cache = HalfEdgeApproxCache;
 
     fn approx_with_cache(
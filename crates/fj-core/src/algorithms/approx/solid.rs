@@ -4,10 +4,12 @@ use std::collections::BTreeSet;
 
 use crate::objects::Solid;
 
-use super::{edge::HalfEdgeApproxCache, face::FaceApprox, Approx, Tolerance};
+use super::{
+    edge::HalfEdgeApproxCache, face::FaceApprox, Approx, ApproxError, Tolerance,
+};
 
 impl Approx for &Solid {
-    type Approximation = BTreeSet<FaceApprox>;
+    type Approximation = Result<BTreeSet<FaceApprox>, ApproxError>;
     type Cache = HalfEdgeApproxCache;
 
     fn approx_with_cache(
@@ -17,9 +19,11 @@ impl Approx for &Solid {
     ) -> Self::Approximation {
         let tolerance = tolerance.into();
 
-        self.shells()
-            .iter()
-            .flat_map(|shell| shell.approx_with_cache(tolerance, cache))
-            .collect()
+        let mut approx = BTreeSet::new();
+        for shell in self.shells() {
+            approx.extend(shell.approx_with_cache(tolerance, cache)?);
+        }
+
+        Ok(approx)
     }
 }
@@ -0,0 +1,146 @@
+//! Strategies for approximating paths
+//!
+//! See [`ApproxStrategy`].
+
+use std::sync::Arc;
+
+use fj_math::{Point, Scalar};
+
+use crate::geometry::CurveBoundary;
+
+use super::{path::PathApproxParams, Tolerance};
+
+/// A strategy for choosing the points that approximate a path
+///
+/// Lines don't need to be approximated, as they are fully defined by their
+/// boundary. Circles do, and this trait decides which points on a circle are
+/// used for that.
+///
+/// # Determinism
+///
+/// Implementations must uphold the guarantee described in the documentation
+/// of the [`path`] module: For a given circle and tolerance, the returned
+/// points must be taken from a single set of points, regardless of the
+/// boundary. Otherwise, the approximations of adjacent edges might not fit
+/// together.
+///
+/// [`path`]: super::path
+pub trait ApproxStrategy: Send + Sync {
+    /// Choose the points that approximate a circle within the boundary
+    ///
+    /// Returns the points in circle coordinates, ordered from the start of the
+    /// boundary to its end. The boundary itself must not be included.
+    fn circle_points(
+        &self,
+        radius: Scalar,
+        boundary: CurveBoundary<Point<1>>,
+        tolerance: Tolerance,
+    ) -> Vec<Point<1>>;
+}
+
+/// The default approximation strategy
+///
+/// Divides circles into segments of equal angle, using as few segments as
+/// possible, while staying within the tolerance.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UniformStrategy;
+
+impl ApproxStrategy for UniformStrategy {
+    fn circle_points(
+        &self,
+        radius: Scalar,
+        boundary: CurveBoundary<Point<1>>,
+        tolerance: Tolerance,
+    ) -> Vec<Point<1>> {
+        PathApproxParams::for_radius(radius, tolerance)
+            .points(boundary)
+            .collect()
+    }
+}
+
+/// Cache for path approximations
+///
+/// Path approximations are cheap to compute, so this doesn't actually cache
+/// anything. It carries the [`ApproxStrategy`] that is used, which makes it
+/// possible to thread a custom strategy through the approximation of any
+/// object, by passing a cache that has been created with it.
+#[derive(Clone)]
+pub struct PathApproxCache {
+    strategy: Arc<dyn ApproxStrategy>,
+}
+
+impl PathApproxCache {
+    /// Create a cache that uses the provided strategy
+    pub fn with_strategy(strategy: impl ApproxStrategy + 'static) -> Self {
+        Self {
+            strategy: Arc::new(strategy),
+        }
+    }
+
+    /// Access the strategy
+    pub fn strategy(&self) -> &dyn ApproxStrategy {
+        self.strategy.as_ref()
+    }
+}
+
+impl Default for PathApproxCache {
+    fn default() -> Self {
+        Self::with_strategy(UniformStrategy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f64::consts::TAU, ops::Deref};
+
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::approx::{
+            curve::CurveApproxCache, path::PathApproxParams, Approx, Tolerance,
+        },
+        geometry::{CurveBoundary, SurfacePath},
+        objects::Curve,
+        operations::insert::Insert,
+        services::Services,
+    };
+
+    use super::ApproxStrategy;
+
+    /// Always uses a fixed number of points per circle
+    struct FixedStrategy(f64);
+
+    impl ApproxStrategy for FixedStrategy {
+        fn circle_points(
+            &self,
+            _: Scalar,
+            boundary: CurveBoundary<Point<1>>,
+            _: Tolerance,
+        ) -> Vec<Point<1>> {
+            PathApproxParams::for_increment(Scalar::TAU / self.0)
+                .points(boundary)
+                .collect()
+        }
+    }
+
+    #[test]
+    fn custom_strategy() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let curve = Curve::new().insert(&mut services);
+        let surface_path =
+            SurfacePath::circle_from_center_and_radius([0., 0.], 1.);
+        let boundary = CurveBoundary::from([[0.], [TAU]]);
+        let surface = services.objects.surfaces.xz_plane();
+
+        let mut cache = CurveApproxCache::with_strategy(FixedStrategy(8.));
+        let approx = (&curve, surface_path, surface.deref(), boundary)
+            .approx_with_cache(0.001, &mut cache)?;
+
+        // The boundary is not part of the approximation, so the point at its
+        // start is missing.
+        assert_eq!(approx.points.len(), 7);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+//! # Surface approximation
+//!
+//! Surfaces are infinite, so only a [`BoundedSurface`] can be approximated. The
+//! approximation covers exactly the range of the bounded surface, including
+//! its boundary.
+
+use fj_math::{Point, Scalar};
+
+use crate::geometry::{BoundedSurface, CurveBoundary};
+
+use super::{strategy::PathApproxCache, Approx, ApproxPoint, Tolerance};
+
+impl Approx for &BoundedSurface {
+    type Approximation = SurfaceApprox;
+    type Cache = PathApproxCache;
+
+    fn approx_with_cache(
+        self,
+        tolerance: impl Into<Tolerance>,
+        cache: &mut Self::Cache,
+    ) -> Self::Approximation {
+        let [min, max] = [self.boundary.min, self.boundary.max];
+
+        // The v-axis of a surface is always a straight line, so its boundaries
+        // are all we need. The u-axis might be curved, which is taken care of
+        // by approximating it as a path.
+        let u_path_approx = (
+            self.geometry.u,
+            CurveBoundary::from([Point::from([min.u]), Point::from([max.u])]),
+        )
+            .approx_with_cache(tolerance, cache);
+
+        let u = [min.u]
+            .into_iter()
+            .chain(u_path_approx.into_iter().map(|(point, _)| point.t))
+            .chain([max.u])
+            .collect::<Vec<Scalar>>();
+        let v = [min.v, max.v];
+
+        let points = v
+            .into_iter()
+            .map(|v| {
+                u.iter()
+                    .map(|&u| {
+                        let point_surface = Point::from([u, v]);
+                        ApproxPoint::new(
+                            point_surface,
+                            self.geometry
+                                .point_from_surface_coords(point_surface),
+                        )
+                    })
+                    .collect()
+            })
+            .collect();
+
+        SurfaceApprox { points }
+    }
+}
+
+/// An approximation of a [`BoundedSurface`]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SurfaceApprox {
+    /// The points that approximate the surface, arranged in a grid
+    ///
+    /// Each row contains the points with the same v-coordinate, ordered by
+    /// their u-coordinate. Neighboring points of neighboring rows form the
+    /// quads that make up the approximated surface.
+    pub points: Vec<Vec<ApproxPoint<2>>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::approx::{Approx, Tolerance},
+        geometry::{BoundedSurface, GlobalPath, SurfaceGeometry},
+    };
+
+    #[test]
+    fn approx_bounded_cylinder() -> anyhow::Result<()> {
+        let surface = BoundedSurface::new(
+            SurfaceGeometry {
+                u: GlobalPath::circle_from_radius(1.),
+                v: Vector::from([0., 0., 1.]),
+            },
+            [[0., 0.], [Scalar::PI.into_f64(), 2.]],
+        );
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let approx = (&surface).approx(tolerance);
+
+        assert_eq!(approx.points.len(), 2);
+        for (row, v) in approx.points.iter().zip([0., 2.]) {
+            let first = row.first().unwrap().local_form;
+            let last = row.last().unwrap().local_form;
+            assert_eq!(first, Point::from([0., v]));
+            assert_eq!(last, Point::from([Scalar::PI, Scalar::from(v)]));
+
+            // The half-cylinder is curved, so it needs more than its boundary
+            // to be approximated.
+            assert!(row.len() > 2);
+
+            for point in row {
+                assert!(surface.contains(point.local_form));
+
+                let radius = point.global_form.coords.xy().magnitude();
+                assert!((radius - 1.).abs() < Scalar::from(1e-12));
+                assert_eq!(point.global_form.z, Scalar::from(v));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -2,15 +2,17 @@
 //!
 //! See [`Tolerance`].
 
-use fj_math::Scalar;
+use fj_math::{Aabb, Scalar};
 
 /// A tolerance value
 ///
 /// A tolerance value is used during approximation. It defines the maximum
 /// allowed deviation of the approximation from the actual shape.
 ///
-/// The `Tolerance` type enforces that the tolerance value is always larger than
-/// zero, which is an attribute that the approximation code relies on.
+/// The `Tolerance` type enforces that the tolerance value is always finite and
+/// larger than zero, which is an attribute that the approximation code relies
+/// on. A tolerance of zero would require infinitely fine approximations, and an
+/// infinite one would result in meaningless ones.
 ///
 /// # Failing [`From`]/[`Into`] implementation
 ///
@@ -21,25 +23,47 @@ use fj_math::Scalar;
 /// A fallible [`Into`] provides a lot of convenience in test code. Since said
 /// documentation doesn't provide any actual reasoning for this requirement, I'm
 /// feeling free to just ignore it.
+///
+/// The blanket [`From`] implementation also means that `Tolerance` can't
+/// implement [`TryFrom`] for numeric types. Use [`Tolerance::from_scalar`], to
+/// handle invalid values without panicking.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct Tolerance(Scalar);
 
 impl Tolerance {
     /// Construct a `Tolerance` from a [`Scalar`]
     ///
-    /// Returns an error, if the passed scalar is not larger than zero.
+    /// Returns an error, if the passed scalar is not finite, or not larger than
+    /// zero.
     pub fn from_scalar(
         scalar: impl Into<Scalar>,
     ) -> Result<Self, InvalidTolerance> {
         let scalar = scalar.into();
 
-        if scalar <= Scalar::ZERO {
+        if scalar <= Scalar::ZERO || !scalar.into_f64().is_finite() {
             return Err(InvalidTolerance(scalar));
         }
 
         Ok(Self(scalar))
     }
 
+    /// Compute a reasonable default tolerance from a bounding box
+    ///
+    /// The tolerance is derived from the smallest non-zero extent of the
+    /// bounding box, divided by some value. If the bounding box has no extent
+    /// at all, as is the case for an empty shape, no tolerance is better than
+    /// any other, and a very large one is returned.
+    pub fn from_aabb(aabb: &Aabb<3>) -> Self {
+        let mut min_extent = Scalar::MAX;
+        for extent in aabb.size().components {
+            if extent > Scalar::ZERO && extent < min_extent {
+                min_extent = extent;
+            }
+        }
+
+        Self(min_extent / Scalar::from_f64(1000.))
+    }
+
     /// Return the [`Scalar`] that defines the tolerance
     pub fn inner(&self) -> Scalar {
         self.0
@@ -58,5 +82,20 @@ where
 
 /// Error converting scalar to tolerance
 #[derive(Debug, thiserror::Error)]
-#[error("Invalid tolerance ({0}); must be above zero")]
+#[error("Invalid tolerance ({0}); must be finite and above zero")]
 pub struct InvalidTolerance(Scalar);
+
+#[cfg(test)]
+mod tests {
+    use super::Tolerance;
+
+    #[test]
+    fn invalid_tolerance() {
+        for value in [0., -0., -1., f64::INFINITY, f64::NEG_INFINITY] {
+            assert!(Tolerance::from_scalar(value).is_err(), "{value}");
+        }
+
+        assert!(Tolerance::from_scalar(f64::MIN_POSITIVE).is_ok());
+        assert!(Tolerance::from_scalar(1.).is_ok());
+    }
+}
@@ -1,4 +1,4 @@
-use fj_math::{Aabb, Vector};
+use fj_math::{Aabb, Scalar, Vector};
 
 use crate::{geometry::SurfacePath, objects::HalfEdge};
 
@@ -17,6 +17,23 @@ impl super::BoundingVolume<2> for HalfEdge {
                     max: circle.center() + center_to_min_max,
                 })
             }
+            SurfacePath::Spiral(spiral) => {
+                // The distance from the center changes linearly along the
+                // spiral, so it is largest at one of the boundary points. A
+                // box around a circle of that radius contains the whole edge.
+                let radius = self
+                    .boundary()
+                    .inner
+                    .map(|point| spiral.radius_at(point).abs())
+                    .into_iter()
+                    .fold(Scalar::ZERO, Scalar::max);
+                let center_to_min_max = Vector::from([radius, radius]);
+
+                Some(Aabb {
+                    min: spiral.center() - center_to_min_max,
+                    max: spiral.center() + center_to_min_max,
+                })
+            }
             SurfacePath::Line(_) => {
                 let points = self.boundary().inner.map(|point_curve| {
                     self.path().point_from_path_coords(point_curve)
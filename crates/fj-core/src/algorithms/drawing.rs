@@ -0,0 +1,361 @@
+//! Projection of objects into 2D drawings
+
+use fj_interop::{drawing::Drawing, mesh::Mesh};
+use fj_math::{Plane, Point, Scalar, Segment};
+
+use crate::{objects::Solid, queries::AllEdges};
+
+use super::{
+    approx::{ApproxError, Tolerance},
+    triangulate::Triangulate,
+};
+
+/// Project an object onto a plane, creating a 2D drawing
+pub trait ProjectToDrawing {
+    /// Project the object onto the provided view plane
+    ///
+    /// The projection is orthographic, along the normal of the view plane. The
+    /// object is viewed from the side of the plane that its normal points to.
+    /// The coordinates of the drawing are the coordinates within the view
+    /// plane, meaning they are only true to scale, if the plane's `u` and `v`
+    /// vectors are orthonormal.
+    ///
+    /// Curved edges and faces are approximated using the provided tolerance.
+    ///
+    /// Edges, or parts of edges, that are occluded by faces of the object are
+    /// added to the drawing as hidden segments.
+    ///
+    /// # Implementation Note
+    ///
+    /// Hidden-line removal is based on the triangulation of the object. It is
+    /// only exact for planar faces. For curved faces, it is as accurate as
+    /// their approximation.
+    ///
+    /// Returns an error, if the object can't be approximated.
+    fn project_to_drawing(
+        &self,
+        view_plane: &Plane,
+        tolerance: impl Into<Tolerance>,
+    ) -> Result<Drawing, ApproxError>;
+}
+
+impl ProjectToDrawing for Solid {
+    fn project_to_drawing(
+        &self,
+        view_plane: &Plane,
+        tolerance: impl Into<Tolerance>,
+    ) -> Result<Drawing, ApproxError> {
+        let tolerance = tolerance.into();
+
+        let mesh = (self, tolerance).triangulate()?;
+        let occluders = Occluders::new(&mesh, view_plane, tolerance);
+
+        let mut drawing = Drawing::new();
+
+        for polyline in self.all_edges_approx(tolerance)? {
+            for segment in polyline.segments() {
+                for (piece, is_hidden) in occluders.classify(segment) {
+                    let points =
+                        piece.map(|point| view_plane.project_point(point));
+
+                    // Edges that are parallel to the view direction project to
+                    // a single point. Due to rounding errors, the projected
+                    // points might not be exactly identical, so ignore
+                    // anything that is shorter than the tolerance. Edges that
+                    // project onto a segment that's already there are ignored
+                    // by the drawing.
+                    let [a, b] = points;
+                    if (b - a).magnitude() < tolerance.inner() {
+                        continue;
+                    }
+
+                    if is_hidden {
+                        drawing.push_hidden_segment(points);
+                    } else {
+                        drawing.push_segment(points);
+                    }
+                }
+            }
+        }
+
+        Ok(drawing)
+    }
+}
+
+/// The triangles of an object, projected onto a view plane
+struct Occluders<'r> {
+    view_plane: &'r Plane,
+    triangles: Vec<[(Point<2>, Scalar); 3]>,
+    tolerance: Tolerance,
+}
+
+impl<'r> Occluders<'r> {
+    fn new(
+        mesh: &Mesh<Point<3>>,
+        view_plane: &'r Plane,
+        tolerance: Tolerance,
+    ) -> Self {
+        let triangles = mesh
+            .triangles()
+            .map(|triangle| {
+                triangle.inner.points().map(|point| {
+                    (view_plane.project_point(point), depth(view_plane, point))
+                })
+            })
+            // Triangles that are parallel to the view direction can't occlude
+            // anything. Due to rounding errors, their projection might not be
+            // completely flat, so ignore all that are narrower than the
+            // tolerance.
+            .filter(|&[(a, _), (b, _), (c, _)]| {
+                let [ab, bc, ca] =
+                    [b - a, c - b, a - c].map(|edge| edge.magnitude());
+                let longest_edge = ab.max(bc).max(ca);
+
+                // Twice the area of the triangle, divided by its longest edge,
+                // is its smallest height.
+                let double_area = (b - a).cross2d(&(c - a)).abs();
+
+                longest_edge > Scalar::ZERO
+                    && double_area >= tolerance.inner() * longest_edge
+            })
+            .collect();
+
+        Self {
+            view_plane,
+            triangles,
+            tolerance,
+        }
+    }
+
+    /// Split a segment into pieces, and determine which of them are hidden
+    ///
+    /// The segment is split wherever its projection crosses the projection of
+    /// a triangle edge. Within each piece, visibility doesn't change, so it's
+    /// enough to check a single point.
+    fn classify(&self, segment: Segment<3>) -> Vec<([Point<3>; 2], bool)> {
+        let [a, b] = segment.points();
+        let [a_2d, b_2d] =
+            [a, b].map(|point| self.view_plane.project_point(point));
+
+        let ab = b_2d - a_2d;
+
+        let mut split_at = vec![Scalar::ZERO, Scalar::ONE];
+        for triangle in &self.triangles {
+            let [p0, p1, p2] = triangle.map(|(point, _)| point);
+
+            for [c, d] in [[p0, p1], [p1, p2], [p2, p0]] {
+                let cd = d - c;
+                let ac = c - a_2d;
+
+                // The distance of the triangle edge's end points from the
+                // line through the segment differ by this much. If it's below
+                // the tolerance, the edges are parallel. If they overlap,
+                // visibility changes at the end points of the triangle edge,
+                // which are also end points of other triangle edges.
+                let denominator = ab.cross2d(&cd);
+                if denominator.abs() < self.tolerance.inner() * ab.magnitude() {
+                    continue;
+                }
+
+                let t = ac.cross2d(&cd) / denominator;
+                let u = ac.cross2d(&ab) / denominator;
+
+                if t > Scalar::ZERO
+                    && t < Scalar::ONE
+                    && u >= Scalar::ZERO
+                    && u <= Scalar::ONE
+                {
+                    split_at.push(t);
+                }
+            }
+        }
+
+        split_at.sort();
+        split_at.dedup();
+
+        let point_at = |t: Scalar| a + (b - a) * t;
+
+        split_at
+            .windows(2)
+            .map(|t| {
+                let [t0, t1] = [t[0], t[1]];
+
+                let is_hidden =
+                    self.is_hidden(point_at((t0 + t1) / Scalar::TWO));
+                ([point_at(t0), point_at(t1)], is_hidden)
+            })
+            .collect()
+    }
+
+    /// Determine whether a point is occluded by any triangle
+    fn is_hidden(&self, point: Point<3>) -> bool {
+        let point_2d = self.view_plane.project_point(point);
+        let point_depth = depth(self.view_plane, point);
+
+        self.triangles.iter().any(|&[(a, da), (b, db), (c, dc)]| {
+            let area = (b - a).cross2d(&(c - a));
+
+            // Barycentric coordinates of the point, relative to the triangle.
+            // Points on the boundary of the triangle are considered to be
+            // within it. Otherwise, points that are behind a triangle edge
+            // wouldn't be hidden.
+            //
+            // We allow for a bit of numerical error here, or points that are
+            // right on the boundary could be missed.
+            let wa = (b - point_2d).cross2d(&(c - point_2d)) / area;
+            let wb = (c - point_2d).cross2d(&(a - point_2d)) / area;
+            let wc = Scalar::ONE - wa - wb;
+
+            let min_weight = -Scalar::from(1e-9);
+            if wa < min_weight || wb < min_weight || wc < min_weight {
+                return false;
+            }
+
+            // A point that is on a face has the same depth as the face. Only
+            // count faces that are clearly in front of the point.
+            let triangle_depth = da * wa + db * wb + dc * wc;
+            triangle_depth > point_depth + self.tolerance.inner()
+        })
+    }
+}
+
+/// The distance of a point from the view plane, towards the viewer
+fn depth(view_plane: &Plane, point: Point<3>) -> Scalar {
+    (point - view_plane.origin()).dot(&view_plane.normal())
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::mesh::{Color, Mesh};
+    use fj_math::{Plane, Point};
+
+    use crate::{
+        algorithms::{approx::Tolerance, triangulate::Triangulate},
+        fixtures,
+        services::Services,
+    };
+
+    use super::{Occluders, ProjectToDrawing};
+
+    #[test]
+    fn cuboid_top_view() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let solid = fixtures::cube(&mut services);
+
+        let view_plane =
+            Plane::from_parametric([0., 0., 0.], [1., 0., 0.], [0., 1., 0.]);
+        let drawing = solid
+            .project_to_drawing(&view_plane, Tolerance::from_scalar(0.01)?)?;
+
+        let a = [-0.5, -0.5];
+        let b = [0.5, -0.5];
+        let c = [0.5, 0.5];
+        let d = [-0.5, 0.5];
+
+        // Top and bottom edges project onto the same square outline. Vertical
+        // edges project onto its corners. The bottom edges are hidden, but
+        // that doesn't matter, as the top edges cover them.
+        assert_eq!(drawing.segments().count(), 4);
+        assert!(drawing.contains_segment([a, b]));
+        assert!(drawing.contains_segment([b, c]));
+        assert!(drawing.contains_segment([c, d]));
+        assert!(drawing.contains_segment([d, a]));
+        assert_eq!(drawing.hidden_segments().count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn cuboid_back_edges_are_hidden() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let solid = fixtures::cube(&mut services);
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        // Look at the cube from a direction where no edges overlap in the
+        // projection. The bottom corner at `[-0.5, -0.5]` is at the back.
+        let view_plane =
+            Plane::from_parametric([0., 0., 0.], [2., -1., 0.], [3., 6., -5.]);
+
+        let mesh: Mesh<Point<3>> = (&solid, tolerance).triangulate()?;
+        let occluders = Occluders::new(&mesh, &view_plane, tolerance);
+
+        let back_edges = [
+            [[-0.5, -0.5, 0.], [0.5, -0.5, 0.]],
+            [[-0.5, -0.5, 0.], [-0.5, 0.5, 0.]],
+            [[-0.5, -0.5, 0.], [-0.5, -0.5, 1.]],
+        ];
+        let visible_edges = [
+            [[0.5, 0.5, 1.], [-0.5, 0.5, 1.]],
+            [[0.5, 0.5, 1.], [0.5, -0.5, 1.]],
+            [[0.5, 0.5, 1.], [0.5, 0.5, 0.]],
+            [[0.5, -0.5, 0.], [0.5, 0.5, 0.]],
+            [[0.5, 0.5, 0.], [-0.5, 0.5, 0.]],
+            [[-0.5, 0.5, 0.], [-0.5, 0.5, 1.]],
+            [[-0.5, 0.5, 1.], [-0.5, -0.5, 1.]],
+            [[-0.5, -0.5, 1.], [0.5, -0.5, 1.]],
+            [[0.5, -0.5, 1.], [0.5, -0.5, 0.]],
+        ];
+
+        for [a, b] in back_edges {
+            let center =
+                Point::from(a) + (Point::from(b) - Point::from(a)) / 2.;
+            assert!(occluders.is_hidden(center));
+        }
+        for [a, b] in visible_edges {
+            let center =
+                Point::from(a) + (Point::from(b) - Point::from(a)) / 2.;
+            assert!(!occluders.is_hidden(center));
+        }
+
+        let drawing = solid.project_to_drawing(&view_plane, tolerance)?;
+        assert!(drawing.hidden_segments().count() >= back_edges.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn triangles_along_view_direction_are_ignored() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        // Viewed from the top, this triangle is almost, but not completely,
+        // flat.
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1e-12, 1.]].map(Point::from),
+            Color::default(),
+        );
+
+        let view_plane =
+            Plane::from_parametric([0., 0., 0.], [1., 0., 0.], [0., 1., 0.]);
+        let occluders = Occluders::new(&mesh, &view_plane, tolerance);
+
+        assert!(occluders.triangles.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn edges_along_view_direction_are_ignored() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let solid = fixtures::cube(&mut services);
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        // Look at the cube from the top, but with a view plane whose `u` and
+        // `v` aren't aligned with the axes. The projection of the vertical
+        // edges is then subject to rounding errors.
+        let view_plane =
+            Plane::from_parametric([0., 0., 0.], [3., 1., 0.], [-1., 3., 0.]);
+
+        let drawing = solid.project_to_drawing(&view_plane, tolerance)?;
+
+        for segment in drawing.segments().chain(drawing.hidden_segments()) {
+            let [a, b] = segment.points();
+            assert!((b - a).magnitude() >= tolerance.inner());
+        }
+
+        Ok(())
+    }
+}
@@ -8,111 +8,119 @@ use crate::{
 };
 
 use super::{
-    ray_segment::RaySegmentIntersection, HorizontalRayToTheRight, Intersect,
+    ray_edge::RayCastError, ray_segment::RaySegmentIntersection,
+    HorizontalRayToTheRight, Intersect,
 };
 
 impl Intersect for (&Face, &Point<2>) {
-    type Intersection = FacePointIntersection;
+    type Intersection = Result<FacePointIntersection, RayCastError>;
 
     fn intersect(self) -> Option<Self::Intersection> {
         let (face, point) = self;
+        intersect_face_point(face, point).transpose()
+    }
+}
 
-        let ray = HorizontalRayToTheRight { origin: *point };
-
-        let mut num_hits = 0;
-
-        for cycle in face.region().all_cycles() {
-            // We need to properly detect the ray passing the boundary at the
-            // "seam" of the polygon, i.e. the vertex between the last and the
-            // first segment. The logic in the loop properly takes care of that,
-            // as long as we initialize the `previous_hit` variable with the
-            // result of the last segment.
-            let mut previous_hit = cycle
-                .half_edges()
-                .iter()
-                .last()
-                .and_then(|edge| (&ray, edge).intersect());
-
-            for (edge, next_edge) in cycle.half_edges().pairs() {
-                let hit = (&ray, edge).intersect();
-
-                let count_hit = match (hit, previous_hit) {
-                    (
-                        Some(RaySegmentIntersection::RayStartsOnSegment),
-                        _,
-                    ) => {
-                        // If the ray starts on the boundary of the face,
-                        // there's nothing to else check.
-                        return Some(FacePointIntersection::PointIsOnEdge(
-                            edge.clone()
-                        ));
-                    }
-                    (Some(RaySegmentIntersection::RayStartsOnOnFirstVertex), _) => {
-                        let vertex = edge.start_position();
-                        return Some(
-                            FacePointIntersection::PointIsOnVertex(vertex)
-                        );
-                    }
-                    (Some(RaySegmentIntersection::RayStartsOnSecondVertex), _) => {
-                        let vertex = next_edge.start_position();
-                        return Some(
-                            FacePointIntersection::PointIsOnVertex(vertex)
-                        );
-                    }
-                    (Some(RaySegmentIntersection::RayHitsSegment), _) => {
-                        // We're hitting a segment right-on. Clear case.
-                        true
-                    }
-                    (
-                        Some(RaySegmentIntersection::RayHitsUpperVertex),
-                        Some(RaySegmentIntersection::RayHitsLowerVertex),
-                    )
-                    | (
-                        Some(RaySegmentIntersection::RayHitsLowerVertex),
-                        Some(RaySegmentIntersection::RayHitsUpperVertex),
-                    ) => {
-                        // If we're hitting a vertex, only count it if we've hit
-                        // the other kind of vertex right before.
-                        //
-                        // That means, we're passing through the polygon
-                        // boundary at where two edges touch. Depending on the
-                        // order in which edges are checked, we're seeing this
-                        // as a hit to one edge's lower/upper vertex, then the
-                        // other edge's opposite vertex.
-                        //
-                        // If we're seeing two of the same vertices in a row,
-                        // we're not actually passing through the polygon
-                        // boundary. Then we're just touching a vertex without
-                        // passing through anything.
-                        true
-                    }
-                    (Some(RaySegmentIntersection::RayHitsSegmentAndAreParallel), _) => {
-                        // A parallel edge must be completely ignored. Its
-                        // presence won't change anything, so we can treat it as
-                        // if it wasn't there, and its neighbors were connected
-                        // to each other.
-                        continue;
-                    }
-                    _ => {
-                        // Any other case is not a valid hit.
-                        false
-                    }
-                };
-
-                if count_hit {
-                    num_hits += 1;
+fn intersect_face_point(
+    face: &Face,
+    point: &Point<2>,
+) -> Result<Option<FacePointIntersection>, RayCastError> {
+    let ray = HorizontalRayToTheRight { origin: *point };
+
+    let mut num_hits = 0;
+
+    for cycle in face.region().all_cycles() {
+        // We need to properly detect the ray passing the boundary at the
+        // "seam" of the polygon, i.e. the vertex between the last and the
+        // first segment. The logic in the loop properly takes care of that,
+        // as long as we initialize the `previous_hit` variable with the
+        // result of the last segment.
+        let mut previous_hit = cycle
+            .half_edges()
+            .iter()
+            .last()
+            .and_then(|edge| (&ray, edge).intersect())
+            .transpose()?;
+
+        for (edge, next_edge) in cycle.half_edges().pairs() {
+            let hit = (&ray, edge).intersect().transpose()?;
+
+            let count_hit = match (hit, previous_hit) {
+                (Some(RaySegmentIntersection::RayStartsOnSegment), _) => {
+                    // If the ray starts on the boundary of the face,
+                    // there's nothing to else check.
+                    return Ok(Some(FacePointIntersection::PointIsOnEdge(
+                        edge.clone(),
+                    )));
+                }
+                (Some(RaySegmentIntersection::RayStartsOnOnFirstVertex), _) => {
+                    let vertex = edge.start_position();
+                    return Ok(Some(FacePointIntersection::PointIsOnVertex(
+                        vertex,
+                    )));
+                }
+                (Some(RaySegmentIntersection::RayStartsOnSecondVertex), _) => {
+                    let vertex = next_edge.start_position();
+                    return Ok(Some(FacePointIntersection::PointIsOnVertex(
+                        vertex,
+                    )));
+                }
+                (Some(RaySegmentIntersection::RayHitsSegment), _) => {
+                    // We're hitting a segment right-on. Clear case.
+                    true
                 }
+                (
+                    Some(RaySegmentIntersection::RayHitsUpperVertex),
+                    Some(RaySegmentIntersection::RayHitsLowerVertex),
+                )
+                | (
+                    Some(RaySegmentIntersection::RayHitsLowerVertex),
+                    Some(RaySegmentIntersection::RayHitsUpperVertex),
+                ) => {
+                    // If we're hitting a vertex, only count it if we've hit
+                    // the other kind of vertex right before.
+                    //
+                    // That means, we're passing through the polygon
+                    // boundary at where two edges touch. Depending on the
+                    // order in which edges are checked, we're seeing this
+                    // as a hit to one edge's lower/upper vertex, then the
+                    // other edge's opposite vertex.
+                    //
+                    // If we're seeing two of the same vertices in a row,
+                    // we're not actually passing through the polygon
+                    // boundary. Then we're just touching a vertex without
+                    // passing through anything.
+                    true
+                }
+                (
+                    Some(RaySegmentIntersection::RayHitsSegmentAndAreParallel),
+                    _,
+                ) => {
+                    // A parallel edge must be completely ignored. Its
+                    // presence won't change anything, so we can treat it as
+                    // if it wasn't there, and its neighbors were connected
+                    // to each other.
+                    continue;
+                }
+                _ => {
+                    // Any other case is not a valid hit.
+                    false
+                }
+            };
 
-                previous_hit = hit;
+            if count_hit {
+                num_hits += 1;
             }
-        }
 
-        if num_hits % 2 == 1 {
-            Some(FacePointIntersection::PointIsInsideFace)
-        } else {
-            None
+            previous_hit = hit;
         }
     }
+
+    if num_hits % 2 == 1 {
+        Ok(Some(FacePointIntersection::PointIsInsideFace))
+    } else {
+        Ok(None)
+    }
 }
 
 /// The intersection between a face and a point
@@ -189,7 +197,7 @@ mod tests {
         let intersection = (&face, &point).intersect();
         assert_eq!(
             intersection,
-            Some(FacePointIntersection::PointIsInsideFace)
+            Some(Ok(FacePointIntersection::PointIsInsideFace))
         );
     }
 
@@ -215,7 +223,7 @@ mod tests {
         let intersection = (&face, &point).intersect();
         assert_eq!(
             intersection,
-            Some(FacePointIntersection::PointIsInsideFace)
+            Some(Ok(FacePointIntersection::PointIsInsideFace))
         );
     }
 
@@ -241,7 +249,7 @@ mod tests {
         let intersection = (&face, &point).intersect();
         assert_eq!(
             intersection,
-            Some(FacePointIntersection::PointIsInsideFace)
+            Some(Ok(FacePointIntersection::PointIsInsideFace))
         );
     }
 
@@ -267,7 +275,7 @@ mod tests {
         let intersection = (&face, &point).intersect();
         assert_eq!(
             intersection,
-            Some(FacePointIntersection::PointIsInsideFace)
+            Some(Ok(FacePointIntersection::PointIsInsideFace))
         );
     }
 
@@ -299,7 +307,7 @@ mod tests {
         let intersection = (&face, &point).intersect();
         assert_eq!(
             intersection,
-            Some(FacePointIntersection::PointIsInsideFace)
+            Some(Ok(FacePointIntersection::PointIsInsideFace))
         );
     }
 
@@ -333,7 +341,7 @@ mod tests {
             .unwrap();
         assert_eq!(
             intersection,
-            Some(FacePointIntersection::PointIsOnEdge(edge.clone()))
+            Some(Ok(FacePointIntersection::PointIsOnEdge(edge.clone())))
         );
     }
 
@@ -368,7 +376,7 @@ mod tests {
             .unwrap();
         assert_eq!(
             intersection,
-            Some(FacePointIntersection::PointIsOnVertex(vertex))
+            Some(Ok(FacePointIntersection::PointIsOnVertex(vertex)))
         );
     }
 }
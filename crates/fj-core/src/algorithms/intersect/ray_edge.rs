@@ -12,16 +12,15 @@ use crate::{
 use super::ray_segment::RaySegmentIntersection;
 
 impl Intersect for (&HorizontalRayToTheRight<2>, &Handle<HalfEdge>) {
-    type Intersection = RaySegmentIntersection;
+    type Intersection = Result<RaySegmentIntersection, RayCastError>;
 
     fn intersect(self) -> Option<Self::Intersection> {
         let (ray, edge) = self;
 
         let line = match edge.path() {
             SurfacePath::Line(line) => line,
-            SurfacePath::Circle(_) => {
-                todo!("Casting rays against circles is not supported yet")
-            }
+            SurfacePath::Circle(_) => return Some(Err(RayCastError::Circle)),
+            SurfacePath::Spiral(_) => return Some(Err(RayCastError::Spiral)),
         };
 
         let points = edge
@@ -30,6 +29,61 @@ impl Intersect for (&HorizontalRayToTheRight<2>, &Handle<HalfEdge>) {
             .map(|point| line.point_from_line_coords(point));
         let segment = Segment::from_points(points);
 
-        (ray, &segment).intersect()
+        (ray, &segment).intersect().map(Ok)
+    }
+}
+
+/// Error casting a ray
+///
+/// Rays can only be cast against edges that are line segments, and faces
+/// whose surface is a plane, so far. Where that is not the case, the
+/// intersection can't be determined, and this error is returned instead.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum RayCastError {
+    /// The ray was cast against a circle
+    #[error("Casting rays against circles is not supported yet")]
+    Circle,
+
+    /// The ray was cast against a spiral
+    #[error("Casting rays against spirals is not supported yet")]
+    Spiral,
+
+    /// The ray was cast against a face on a swept circle
+    #[error("Casting a ray against a swept circle is not supported yet")]
+    SweptCircle,
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{InvalidSpiral, Point};
+
+    use crate::{
+        algorithms::intersect::{HorizontalRayToTheRight, Intersect},
+        objects::HalfEdge,
+        operations::{build::BuildHalfEdge, insert::Insert},
+        services::Services,
+    };
+
+    use super::RayCastError;
+
+    #[test]
+    fn ray_against_spiral() -> Result<(), InvalidSpiral> {
+        let mut services = Services::new();
+
+        let ray = HorizontalRayToTheRight {
+            origin: Point::origin(),
+        };
+        let edge = HalfEdge::spiral_from_params(
+            [0., 0.],
+            1.,
+            0.25,
+            [[0.], [1.]],
+            &mut services,
+        )?
+        .insert(&mut services);
+
+        assert_eq!((&ray, &edge).intersect(), Some(Err(RayCastError::Spiral)));
+
+        Ok(())
     }
 }
@@ -9,18 +9,18 @@ use crate::{
     storage::Handle,
 };
 
-use super::{HorizontalRayToTheRight, Intersect};
+use super::{ray_edge::RayCastError, HorizontalRayToTheRight, Intersect};
 
 impl Intersect for (&HorizontalRayToTheRight<3>, &Face) {
-    type Intersection = RayFaceIntersection;
+    type Intersection = Result<RayFaceIntersection, RayCastError>;
 
     fn intersect(self) -> Option<Self::Intersection> {
         let (ray, face) = self;
 
         let plane = match face.surface().geometry().u {
-            GlobalPath::Circle(_) => todo!(
-                "Casting a ray against a swept circle is not supported yet"
-            ),
+            GlobalPath::Circle(_) => {
+                return Some(Err(RayCastError::SweptCircle));
+            }
             GlobalPath::Line(line) => Plane::from_parametric(
                 line.origin(),
                 line.direction(),
@@ -40,7 +40,9 @@ impl Intersect for (&HorizontalRayToTheRight<3>, &Face) {
                 .map(|[x, y, z]| robust::Coord3D { x, y, z });
 
             if robust::orient3d(a, b, c, d) == 0. {
-                return Some(RayFaceIntersection::RayHitsFaceAndAreParallel);
+                return Some(Ok(
+                    RayFaceIntersection::RayHitsFaceAndAreParallel,
+                ));
             } else {
                 return None;
             }
@@ -109,18 +111,19 @@ impl Intersect for (&HorizontalRayToTheRight<3>, &Face) {
 
         let point = Point::from([u, v]);
         let intersection = match (face, &point).intersect()? {
-            FacePointIntersection::PointIsInsideFace => {
+            Ok(FacePointIntersection::PointIsInsideFace) => {
                 RayFaceIntersection::RayHitsFace
             }
-            FacePointIntersection::PointIsOnEdge(edge) => {
+            Ok(FacePointIntersection::PointIsOnEdge(edge)) => {
                 RayFaceIntersection::RayHitsEdge(edge)
             }
-            FacePointIntersection::PointIsOnVertex(vertex) => {
+            Ok(FacePointIntersection::PointIsOnVertex(vertex)) => {
                 RayFaceIntersection::RayHitsVertex(vertex)
             }
+            Err(err) => return Some(Err(err)),
         };
 
-        Some(intersection)
+        Some(Ok(intersection))
     }
 }
 
@@ -208,7 +211,7 @@ mod tests {
 
         assert_eq!(
             (&ray, &face).intersect(),
-            Some(RayFaceIntersection::RayHitsFace)
+            Some(Ok(RayFaceIntersection::RayHitsFace))
         );
     }
 
@@ -266,7 +269,7 @@ mod tests {
             .unwrap();
         assert_eq!(
             (&ray, &face).intersect(),
-            Some(RayFaceIntersection::RayHitsEdge(edge.clone()))
+            Some(Ok(RayFaceIntersection::RayHitsEdge(edge.clone())))
         );
     }
 
@@ -301,7 +304,7 @@ mod tests {
             .unwrap();
         assert_eq!(
             (&ray, &face).intersect(),
-            Some(RayFaceIntersection::RayHitsVertex(vertex))
+            Some(Ok(RayFaceIntersection::RayHitsVertex(vertex)))
         );
     }
 
@@ -327,7 +330,7 @@ mod tests {
 
         assert_eq!(
             (&ray, &face).intersect(),
-            Some(RayFaceIntersection::RayHitsFaceAndAreParallel)
+            Some(Ok(RayFaceIntersection::RayHitsFaceAndAreParallel))
         );
     }
 
@@ -12,6 +12,7 @@
 
 pub mod approx;
 pub mod bounding_volume;
+pub mod drawing;
 pub mod intersect;
 pub mod transform;
 pub mod triangulate;
@@ -0,0 +1,158 @@
+use std::{
+    collections::{btree_map::Entry, BTreeMap},
+    ops::Deref,
+};
+
+use fj_interop::mesh::Mesh;
+use fj_math::{Point, Transform};
+
+use crate::{
+    algorithms::{
+        approx::{ApproxError, Tolerance},
+        triangulate::Triangulate,
+    },
+    objects::Solid,
+    services::Services,
+    storage::Handle,
+};
+
+use super::TransformObject;
+
+/// A solid, placed using a transform, without transforming its objects
+///
+/// Transforming a solid using [`TransformObject`] creates new objects for all
+/// of its parts. For patterns that repeat a complex solid many times, that adds
+/// up to a lot of memory. An instance only refers to the shared base solid, and
+/// stores the transform. It is expanded into geometry only when triangulated,
+/// and [`Instance::triangulate_all`] approximates each base solid only once,
+/// no matter how many instances refer to it.
+///
+/// This comes with tradeoffs, compared to transformed copies:
+///
+/// - An instance is not an object. Operations and validation don't apply to
+///   it, unless it is turned into a transformed copy using
+///   [`Instance::materialize`].
+/// - The base solid is approximated in its own coordinates. If the transform
+///   scales it up, the approximation error is scaled up too, beyond the
+///   tolerance that was used.
+#[derive(Clone, Debug)]
+pub struct Instance {
+    solid: Handle<Solid>,
+    transform: Transform,
+}
+
+impl Instance {
+    /// Create an instance of the provided solid
+    pub fn new(solid: Handle<Solid>, transform: Transform) -> Self {
+        Self { solid, transform }
+    }
+
+    /// Access the base solid of the instance
+    pub fn solid(&self) -> &Handle<Solid> {
+        &self.solid
+    }
+
+    /// Access the transform that places the base solid
+    pub fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    /// Create a transformed copy of the base solid
+    pub fn materialize(&self, services: &mut Services) -> Solid {
+        self.solid
+            .clone_object()
+            .transform(&self.transform, services)
+    }
+
+    /// Triangulate the instance
+    pub fn triangulate(
+        &self,
+        tolerance: impl Into<Tolerance>,
+    ) -> Result<Mesh<Point<3>>, ApproxError> {
+        Self::triangulate_all([self], tolerance)
+    }
+
+    /// Triangulate all provided instances into a single mesh
+    ///
+    /// Each base solid is triangulated once, and the mesh is transformed for
+    /// each instance that refers to it.
+    pub fn triangulate_all<'r>(
+        instances: impl IntoIterator<Item = &'r Instance>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Result<Mesh<Point<3>>, ApproxError> {
+        let tolerance = tolerance.into();
+
+        let mut meshes = BTreeMap::new();
+        let mut mesh = Mesh::new();
+
+        for instance in instances {
+            let base = match meshes.entry(instance.solid.id()) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry
+                    .insert((instance.solid.deref(), tolerance).triangulate()?),
+            };
+
+            for triangle in base.transform(&instance.transform).triangles() {
+                mesh.push_triangle(triangle.inner, triangle.color);
+            }
+        }
+
+        Ok(mesh)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Transform, Vector};
+
+    use crate::{
+        algorithms::{approx::Tolerance, triangulate::Triangulate},
+        objects::Solid,
+        operations::{build::BuildSolid, insert::Insert},
+        services::Services,
+    };
+
+    use super::Instance;
+
+    #[test]
+    fn triangulate_instances() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let solid = Solid::tetrahedron(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+            &mut services,
+        )
+        .solid
+        .insert(&mut services);
+
+        let offset = Vector::from([2., 0., 0.]);
+        let instances = [
+            Instance::new(solid.clone(), Transform::identity()),
+            Instance::new(solid, Transform::translation(offset)),
+        ];
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let base = instances[0].triangulate(tolerance)?;
+        let mesh = Instance::triangulate_all(&instances, tolerance)?;
+
+        assert_eq!(mesh.triangles().count(), base.triangles().count() * 2);
+        for triangle in base.triangles() {
+            let [a, b, c] = triangle.inner.points();
+            assert!(mesh.contains_triangle([a, b, c]));
+            assert!(mesh.contains_triangle([
+                a + offset,
+                b + offset,
+                c + offset
+            ]));
+        }
+
+        // The materialized copy matches the triangulated instance.
+        let materialized = instances[1].materialize(&mut services);
+        let materialized = (&materialized, tolerance).triangulate()?;
+        for triangle in materialized.triangles() {
+            assert!(mesh.contains_triangle(triangle.inner));
+        }
+
+        Ok(())
+    }
+}
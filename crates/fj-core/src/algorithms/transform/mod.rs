@@ -4,6 +4,7 @@ mod curve;
 mod cycle;
 mod edge;
 mod face;
+mod instance;
 mod shell;
 mod solid;
 mod surface;
@@ -14,10 +15,13 @@ use std::collections::BTreeMap;
 use fj_math::{Transform, Vector};
 use type_map::TypeMap;
 
+pub use self::instance::Instance;
+
 use crate::{
     operations::insert::Insert,
     services::Services,
     storage::{Handle, ObjectId},
+    trace::operation_span,
 };
 
 /// Transform an object
@@ -32,8 +36,21 @@ use crate::{
 pub trait TransformObject: Sized {
     /// Transform the object
     fn transform(self, transform: &Transform, services: &mut Services) -> Self {
+        operation_span!(
+            let span = "transform",
+            objects = tracing::field::Empty
+        );
+
         let mut cache = TransformCache::default();
-        self.transform_with_cache(transform, services, &mut cache)
+        let transformed =
+            self.transform_with_cache(transform, services, &mut cache);
+
+        // How many objects there are, is only known after they have all been
+        // transformed.
+        #[cfg(feature = "tracing")]
+        span.record("objects", cache.num_objects);
+
+        transformed
     }
 
     /// Transform the object using the provided cache
@@ -96,7 +113,12 @@ where
 ///
 /// See [`TransformObject`].
 #[derive(Default)]
-pub struct TransformCache(TypeMap);
+pub struct TransformCache {
+    objects: TypeMap,
+
+    #[cfg(feature = "tracing")]
+    num_objects: usize,
+}
 
 impl TransformCache {
     fn get<T: 'static>(&mut self, key: &Handle<T>) -> Option<&Handle<T>> {
@@ -104,7 +126,7 @@ impl TransformCache {
         // https://github.com/rust-lang/rust-clippy/issues/11390#issuecomment-1750951533
         #[allow(clippy::unwrap_or_default)]
         let map = self
-            .0
+            .objects
             .entry::<BTreeMap<ObjectId, Handle<T>>>()
             .or_insert_with(BTreeMap::new);
 
@@ -116,10 +138,15 @@ impl TransformCache {
         // https://github.com/rust-lang/rust-clippy/issues/11390#issuecomment-1750951533
         #[allow(clippy::unwrap_or_default)]
         let map = self
-            .0
+            .objects
             .entry::<BTreeMap<ObjectId, Handle<T>>>()
             .or_insert_with(BTreeMap::new);
 
         map.insert(key.id(), value);
+
+        #[cfg(feature = "tracing")]
+        {
+            self.num_objects += 1;
+        }
     }
 }
@@ -0,0 +1,219 @@
+use fj_interop::mesh::Mesh;
+use fj_math::{Point, Scalar};
+
+use crate::algorithms::approx::{
+    edge::HalfEdgeApproxCache, face::FaceApprox, Approx, ApproxError, Tolerance,
+};
+
+use super::Triangulate;
+
+/// Triangulate a shape, using no more than a given number of triangles
+///
+/// This is useful for interactive use on weak hardware, where the number of
+/// triangles is what matters most, and the accuracy of the mesh is secondary.
+pub trait TriangulateWithinBudget {
+    /// Triangulate the shape within the provided budget
+    ///
+    /// Starts with the provided tolerance, and relaxes it as defined by the
+    /// budget, until the triangulation fits into the budget. The approximation
+    /// cache is reused between iterations.
+    ///
+    /// Relaxing the tolerance only reduces the number of triangles for curved
+    /// edges and faces. If relaxing it further no longer helps, or if the
+    /// maximum number of iterations has been reached, the coarsest mesh is
+    /// returned, even if it doesn't fit into the budget. Check
+    /// [`BudgetedMesh::num_triangles`] to find out.
+    ///
+    /// Returns an error, if the shape can't be approximated.
+    fn triangulate_within_budget(
+        self,
+        budget: FacetBudget,
+    ) -> Result<BudgetedMesh, ApproxError>;
+}
+
+impl<T, A> TriangulateWithinBudget for (T, Tolerance)
+where
+    T: Approx<
+            Approximation = Result<A, ApproxError>,
+            Cache = HalfEdgeApproxCache,
+        > + Copy,
+    A: IntoIterator<Item = FaceApprox>,
+{
+    fn triangulate_within_budget(
+        self,
+        budget: FacetBudget,
+    ) -> Result<BudgetedMesh, ApproxError> {
+        let (shape, mut tolerance) = self;
+
+        let mut cache = HalfEdgeApproxCache::default();
+        let mut previous_num_triangles = None;
+        let mut iteration = 0;
+
+        loop {
+            let mut mesh = Mesh::new();
+            for approx in shape.approx_with_cache(tolerance, &mut cache)? {
+                approx.triangulate_into_mesh(&mut mesh)?;
+            }
+            mesh.sort_triangles();
+
+            let num_triangles = mesh.triangles().count();
+            iteration += 1;
+
+            let is_within_budget = num_triangles <= budget.max_triangles;
+            let is_converged = previous_num_triangles
+                .is_some_and(|previous| num_triangles >= previous);
+            let is_done = is_within_budget
+                || is_converged
+                || iteration >= budget.max_iterations;
+
+            // The relaxed tolerance is only invalid, if it overflows. It can't
+            // be relaxed any further then.
+            let relaxed = Tolerance::from_scalar(
+                tolerance.inner() * budget.relaxation_factor,
+            );
+
+            match relaxed {
+                Ok(relaxed) if !is_done => {
+                    previous_num_triangles = Some(num_triangles);
+                    tolerance = relaxed;
+                }
+                _ => {
+                    return Ok(BudgetedMesh {
+                        mesh,
+                        tolerance,
+                        num_triangles,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// A limit on the number of triangles, for [`TriangulateWithinBudget`]
+#[derive(Clone, Copy, Debug)]
+pub struct FacetBudget {
+    /// The maximum number of triangles
+    pub max_triangles: usize,
+
+    /// The factor by which the tolerance is multiplied in each iteration
+    ///
+    /// Set via [`FacetBudget::with_relaxation_factor`], which makes sure that
+    /// it is larger than `1`.
+    relaxation_factor: Scalar,
+
+    /// The maximum number of triangulations to try
+    pub max_iterations: usize,
+}
+
+impl FacetBudget {
+    /// Create a budget with the provided maximum number of triangles
+    ///
+    /// Doubles the tolerance in each iteration, for up to 16 iterations.
+    pub fn new(max_triangles: usize) -> Self {
+        Self {
+            max_triangles,
+            relaxation_factor: Scalar::from(2.),
+            max_iterations: 16,
+        }
+    }
+
+    /// Set the factor by which the tolerance is multiplied in each iteration
+    ///
+    /// Returns an error, if the factor is not finite, or not larger than `1`.
+    /// The tolerance wouldn't be relaxed otherwise.
+    pub fn with_relaxation_factor(
+        mut self,
+        relaxation_factor: impl Into<Scalar>,
+    ) -> Result<Self, InvalidRelaxationFactor> {
+        let relaxation_factor = relaxation_factor.into();
+
+        if relaxation_factor <= Scalar::ONE
+            || !relaxation_factor.into_f64().is_finite()
+        {
+            return Err(InvalidRelaxationFactor(relaxation_factor));
+        }
+
+        self.relaxation_factor = relaxation_factor;
+        Ok(self)
+    }
+
+    /// Access the factor by which the tolerance is multiplied in each iteration
+    pub fn relaxation_factor(&self) -> Scalar {
+        self.relaxation_factor
+    }
+}
+
+/// Error setting the relaxation factor of a [`FacetBudget`]
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid relaxation factor ({0}); must be finite and above one")]
+pub struct InvalidRelaxationFactor(Scalar);
+
+/// A mesh, as returned by [`TriangulateWithinBudget`]
+#[derive(Clone, Debug)]
+pub struct BudgetedMesh {
+    /// The mesh
+    pub mesh: Mesh<Point<3>>,
+
+    /// The tolerance that was used for the mesh
+    pub tolerance: Tolerance,
+
+    /// The number of triangles in the mesh
+    pub num_triangles: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Triangle};
+
+    use crate::{
+        algorithms::{approx::Tolerance, triangulate::Triangulate},
+        fixtures,
+        services::Services,
+    };
+
+    use super::{FacetBudget, TriangulateWithinBudget};
+
+    #[test]
+    fn invalid_relaxation_factor() {
+        for value in [1., 0.5, 0., -0., -2., f64::INFINITY] {
+            assert!(FacetBudget::new(100)
+                .with_relaxation_factor(value)
+                .is_err());
+        }
+
+        assert!(FacetBudget::new(100).with_relaxation_factor(1.5).is_ok());
+    }
+
+    #[test]
+    fn tight_budget_on_spacer() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let spacer = fixtures::spacer(&mut services);
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let unlimited = (&spacer, tolerance).triangulate()?;
+        let max_triangles = 100;
+        assert!(unlimited.triangles().count() > max_triangles);
+
+        let budgeted = (&spacer, tolerance)
+            .triangulate_within_budget(FacetBudget::new(max_triangles))?;
+
+        assert!(budgeted.num_triangles <= max_triangles);
+        assert_eq!(budgeted.mesh.triangles().count(), budgeted.num_triangles);
+        assert!(budgeted.tolerance > tolerance);
+
+        // The mesh is coarser, but still a reasonable approximation of the
+        // spacer, with an outer radius of `1`, an inner radius of `0.5`, and a
+        // height of `1`.
+        for triangle in budgeted.mesh.triangles() {
+            let [a, b, c]: [Point<3>; 3] = triangle.inner.points();
+            assert!(Triangle::from_points([a, b, c]).is_ok());
+        }
+        let volume = fixtures::volume_of_mesh(&budgeted.mesh);
+
+        let expected = Scalar::PI * (1. - 0.25);
+        assert!((volume - expected).abs() < expected * 0.2);
+
+        Ok(())
+    }
+}
@@ -1,14 +1,25 @@
 use std::collections::BTreeMap;
 
 use fj_math::{Point, Scalar, Triangle, Winding};
-use spade::HasPosition;
+use spade::{AngleLimit, HasPosition, RefinementParameters};
 
-use crate::{algorithms::approx::cycle::CycleApprox, objects::Handedness};
+use crate::{
+    algorithms::approx::cycle::CycleApprox, geometry::SurfaceGeometry,
+    objects::Handedness,
+};
 
 /// Create a Delaunay triangulation of all points
+///
+/// If a surface is passed as `refine_in`, the triangulation is refined by
+/// adding points to its interior, until its triangles are well-shaped. The
+/// global positions of these new points are computed using that surface.
+///
+/// Refinement never adds points to the boundary of the triangulation, so the
+/// resulting triangles still fit together with those of neighboring faces.
 pub fn triangulate(
     cycles: impl IntoIterator<Item = CycleApprox>,
     coord_handedness: Handedness,
+    refine_in: Option<&SurfaceGeometry>,
 ) -> Vec<[TriangulationPoint; 3]> {
     use spade::Triangulation as _;
 
@@ -37,16 +48,53 @@ pub fn triangulate(
             };
 
             if let Some(handle_prev) = handle_prev {
-                triangulation.add_constraint(handle_prev, handle);
+                // If a cycle touches another one, or itself (for example, if a
+                // hole has been bridged to the exterior), it can share points
+                // with it. Then we might end up here with the same point twice
+                // in a row, which doesn't make for a valid constraint.
+                //
+                // If the boundary of a face crosses itself, the face is
+                // invalid. We still need to triangulate it though, to be able
+                // to detect that (see `SelfIntersections`). Constraints that
+                // would cross an existing one are skipped in that case.
+                if handle_prev != handle
+                    && triangulation.can_add_constraint(handle_prev, handle)
+                {
+                    triangulation.add_constraint(handle_prev, handle);
+                }
             }
 
             handle_prev = Some(handle);
         }
     }
 
+    // All points inserted so far are points of the boundary approximation. Any
+    // point that is added from here on, was added by the refinement.
+    let num_boundary_points = triangulation.num_vertices();
+
+    if refine_in.is_some() {
+        triangulation.refine(
+            RefinementParameters::new()
+                .with_angle_limit(AngleLimit::from_deg(30.))
+                .keep_constraint_edges()
+                .exclude_outer_faces(true),
+        );
+    }
+
     let mut triangles = Vec::new();
     for triangle in triangulation.inner_faces() {
-        let [v0, v1, v2] = triangle.vertices().map(|vertex| *vertex.data());
+        let [v0, v1, v2] = triangle.vertices().map(|vertex| {
+            let mut point = *vertex.data();
+
+            if let Some(surface) = refine_in {
+                if vertex.fix().index() >= num_boundary_points {
+                    point.point_global =
+                        surface.point_from_surface_coords(point.point_surface);
+                }
+            }
+
+            point
+        });
         let triangle_winding = Triangle::<2>::from_points([
             v0.point_surface,
             v1.point_surface,
@@ -78,6 +126,18 @@ pub struct TriangulationPoint {
     pub point_global: Point<3>,
 }
 
+// Required by the refinement of the triangulation, which creates new points
+// from their surface position. Their global position can't be known here, and
+// is computed later, from the surface that the triangulation is refined in.
+impl From<spade::Point2<Scalar>> for TriangulationPoint {
+    fn from(point: spade::Point2<Scalar>) -> Self {
+        Self {
+            point_surface: Point::from([point.x, point.y]),
+            point_global: Point::origin(),
+        }
+    }
+}
+
 // Enables the use of `LocalPoint` in the triangulation.
 impl HasPosition for TriangulationPoint {
     type Scalar = Scalar;
@@ -1,77 +1,168 @@
 //! Shape triangulation
 
+mod budget;
 mod delaunay;
 mod polygon;
 
 use fj_interop::mesh::Mesh;
 use fj_math::Point;
 
+use crate::geometry::GlobalPath;
+
 use self::polygon::Polygon;
 
-use super::approx::{face::FaceApprox, Approx, Tolerance};
+pub use self::budget::{
+    BudgetedMesh, FacetBudget, InvalidRelaxationFactor, TriangulateWithinBudget,
+};
+
+use super::approx::{face::FaceApprox, Approx, ApproxError, Tolerance};
 
 /// Triangulate a shape
 pub trait Triangulate: Sized {
     /// Triangulate the shape
-    fn triangulate(self) -> Mesh<Point<3>> {
+    ///
+    /// The triangles of the resulting mesh are in a canonical order, so
+    /// triangulating the same shape always results in the same mesh. See
+    /// [`Mesh::sort_triangles`].
+    ///
+    /// Returns an error, if the shape can't be approximated. Triangulating an
+    /// approximation that already exists can't fail.
+    fn triangulate(self) -> Result<Mesh<Point<3>>, ApproxError> {
         let mut mesh = Mesh::new();
-        self.triangulate_into_mesh(&mut mesh);
-        mesh
+        self.triangulate_into_mesh(&mut mesh)?;
+        mesh.sort_triangles();
+        Ok(mesh)
     }
 
     /// Triangulate a partial shape into the provided mesh
     ///
     /// This is a low-level method, intended for implementation of
     /// `Triangulate`. Most callers should prefer [`Triangulate::triangulate`].
-    fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>);
+    fn triangulate_into_mesh(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+    ) -> Result<(), ApproxError>;
 }
 
-impl<T> Triangulate for (T, Tolerance)
+impl<T, A> Triangulate for (T, Tolerance)
 where
-    T: Approx,
-    T::Approximation: IntoIterator<Item = FaceApprox>,
+    T: Approx<Approximation = Result<A, ApproxError>>,
+    A: IntoIterator<Item = FaceApprox>,
 {
-    fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>) {
+    fn triangulate_into_mesh(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+    ) -> Result<(), ApproxError> {
         let (approx, tolerance) = self;
+        (approx, tolerance, TriangulationQuality::default())
+            .triangulate_into_mesh(mesh)
+    }
+}
 
-        let approx = approx.approx(tolerance);
+impl<T, A> Triangulate for (T, Tolerance, TriangulationQuality)
+where
+    T: Approx<Approximation = Result<A, ApproxError>>,
+    A: IntoIterator<Item = FaceApprox>,
+{
+    fn triangulate_into_mesh(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+    ) -> Result<(), ApproxError> {
+        let (approx, tolerance, quality) = self;
+
+        let approx = approx.approx(tolerance)?;
 
         for approx in approx {
-            approx.triangulate_into_mesh(mesh);
+            (approx, quality).triangulate_into_mesh(mesh)?;
         }
+
+        Ok(())
     }
 }
 
 impl Triangulate for FaceApprox {
-    fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>) {
+    fn triangulate_into_mesh(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+    ) -> Result<(), ApproxError> {
+        (self, TriangulationQuality::default()).triangulate_into_mesh(mesh)
+    }
+}
+
+impl Triangulate for (FaceApprox, TriangulationQuality) {
+    fn triangulate_into_mesh(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+    ) -> Result<(), ApproxError> {
+        let (approx, quality) = self;
+
         let face_as_polygon = Polygon::new()
             .with_exterior(
-                self.exterior
+                approx
+                    .exterior
                     .points()
                     .into_iter()
                     .map(|point| point.local_form),
             )
-            .with_interiors(self.interiors.iter().map(|interior| {
+            .with_interiors(approx.interiors.iter().map(|interior| {
                 interior.points().into_iter().map(|point| point.local_form)
             }));
 
-        let cycles = [self.exterior].into_iter().chain(self.interiors);
+        // Refinement adds points to the interior of the face. We can only
+        // compute the correct global position for those, if the face is
+        // planar. For curved faces, the boundary approximation is all we've
+        // got.
+        let refine_in = match (quality, approx.surface.u) {
+            (TriangulationQuality::Refined, GlobalPath::Line(_)) => {
+                Some(&approx.surface)
+            }
+            _ => None,
+        };
+
+        let cycles = [approx.exterior].into_iter().chain(approx.interiors);
         let mut triangles =
-            delaunay::triangulate(cycles, self.coord_handedness);
+            delaunay::triangulate(cycles, approx.coord_handedness, refine_in);
         triangles.retain(|triangle| {
             face_as_polygon
                 .contains_triangle(triangle.map(|point| point.point_surface))
         });
 
-        let color = self.color.unwrap_or_default();
+        let color = approx.color.unwrap_or_default();
 
         for triangle in triangles {
             let points = triangle.map(|point| point.point_global);
             mesh.push_triangle(points, color);
         }
+
+        Ok(())
     }
 }
 
+/// The quality of a triangulation
+///
+/// Can be passed alongside a [`Tolerance`] to [`Triangulate`], to control how
+/// faces are triangulated.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum TriangulationQuality {
+    /// Only use the points of the boundary approximation
+    ///
+    /// This produces the smallest number of triangles, but can result in
+    /// sliver triangles on complex faces.
+    #[default]
+    Boundary,
+
+    /// Refine the triangulation of planar faces, to avoid sliver triangles
+    ///
+    /// Adds points to the interior of planar faces (a constrained Delaunay
+    /// refinement), until the triangles are well-shaped. No points are added to
+    /// the boundary of a face, so the triangles still fit together with those
+    /// of neighboring faces.
+    ///
+    /// Curved faces are triangulated as with
+    /// [`TriangulationQuality::Boundary`].
+    Refined,
+}
+
 #[cfg(test)]
 mod tests {
     use fj_interop::mesh::Mesh;
@@ -79,6 +170,7 @@ mod tests {
 
     use crate::{
         algorithms::approx::{Approx, Tolerance},
+        fixtures,
         objects::{Cycle, Face},
         operations::{
             build::{BuildCycle, BuildFace},
@@ -88,7 +180,7 @@ mod tests {
         services::Services,
     };
 
-    use super::Triangulate;
+    use super::{Triangulate, TriangulationQuality};
 
     #[test]
     fn simple() -> anyhow::Result<()> {
@@ -236,8 +328,152 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn hole_bridged_to_exterior() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        // d-----------c
+        // |           |
+        // |   f---g   |
+        // |   |   |   |
+        // |   e---h   |
+        // |  /        |
+        // a-----------b
+
+        let a = [0., 0.];
+        let b = [4., 0.];
+        let c = [4., 4.];
+        let d = [0., 4.];
+
+        let e = [1., 1.];
+        let f = [1., 3.];
+        let g = [3., 3.];
+        let h = [3., 1.];
+
+        let surface = services.objects.surfaces.xy_plane();
+
+        // The hole is connected to the outer boundary by a bridge (`a`-`e`),
+        // which is traversed in both directions. This makes the hole part of
+        // the exterior cycle.
+        let face = Face::unbound(surface.clone(), &mut services).update_region(
+            |region| {
+                region
+                    .update_exterior(|_| {
+                        Cycle::polygon(
+                            [a, b, c, d, a, e, f, g, h, e],
+                            &mut services,
+                        )
+                        .insert(&mut services)
+                    })
+                    .insert(&mut services)
+            },
+        );
+
+        let triangles = triangulate(face)?;
+
+        let e = surface.geometry().point_from_surface_coords(e);
+        let f = surface.geometry().point_from_surface_coords(f);
+        let g = surface.geometry().point_from_surface_coords(g);
+        let h = surface.geometry().point_from_surface_coords(h);
+
+        // Shouldn't contain any possible triangle from the hole.
+        assert!(!triangles.contains_triangle([e, f, g]));
+        assert!(!triangles.contains_triangle([e, g, h]));
+        assert!(!triangles.contains_triangle([e, f, h]));
+        assert!(!triangles.contains_triangle([f, g, h]));
+
+        // But everything else should be covered.
+        let area = triangles
+            .triangles()
+            .map(|triangle| {
+                let [a, b, c] = triangle.inner.points();
+                (b - a).cross(&(c - a)).magnitude() / 2.
+            })
+            .fold(Scalar::ZERO, |sum, area| sum + area);
+        assert_eq!(area, Scalar::from(16. - 4.));
+
+        Ok(())
+    }
+
+    #[test]
+    fn refined_planar_faces() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        let spacer = fixtures::spacer(&mut services);
+        let planar_faces = spacer
+            .shells()
+            .only()
+            .faces()
+            .iter()
+            .filter(|face| face.normal().is_some());
+
+        for face in planar_faces {
+            let unrefined = face.approx(tolerance)?.triangulate()?;
+            let refined =
+                (face.approx(tolerance)?, TriangulationQuality::Refined)
+                    .triangulate()?;
+
+            // The refinement aims for a minimum angle of 30 degrees. Leave
+            // some room for the triangles along the boundary, which can't be
+            // split.
+            assert!(min_angle(&refined) > Scalar::from(20_f64.to_radians()));
+
+            // The points of the boundary must remain, so the triangles still
+            // fit together with those of neighboring faces.
+            for point in unrefined.vertices() {
+                assert!(refined.vertices().any(|p| p == point));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn deterministic_order() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        let [a, b] = [(), ()].map(|()| {
+            let mut services = Services::new();
+            let spacer = fixtures::spacer(&mut services);
+            (&spacer, tolerance).triangulate()
+        });
+        let [a, b] = [a?, b?];
+
+        assert_eq!(
+            a.triangles().collect::<Vec<_>>(),
+            b.triangles().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            a.vertices().collect::<Vec<_>>(),
+            b.vertices().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            a.indices().collect::<Vec<_>>(),
+            b.indices().collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+
+    fn min_angle(mesh: &Mesh<Point<3>>) -> Scalar {
+        mesh.triangles()
+            .flat_map(|triangle| {
+                let [a, b, c] = triangle.inner.points();
+
+                [(a, b, c), (b, c, a), (c, a, b)].map(|(a, b, c)| {
+                    let ab = b - a;
+                    let ac = c - a;
+
+                    (ab.dot(&ac) / (ab.magnitude() * ac.magnitude())).acos()
+                })
+            })
+            .min()
+            .unwrap_or(Scalar::ZERO)
+    }
+
     fn triangulate(face: Face) -> anyhow::Result<Mesh<Point<3>>> {
         let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
-        Ok(face.approx(tolerance).triangulate())
+        Ok(face.approx(tolerance)?.triangulate()?)
     }
 }
@@ -45,6 +45,7 @@ impl Polygon {
         let [a, b, c] = triangle.into().points();
 
         let mut might_be_hole = true;
+        let mut all_edges_on_boundary = true;
 
         for &edge in [a, b, c, a].as_slice().array_windows_ext() {
             let edge = Segment::from(edge);
@@ -65,6 +66,8 @@ impl Polygon {
                 continue;
             }
 
+            all_edges_on_boundary = false;
+
             // To determine if the edge is within the polygon, we determine if
             // its center point is in the polygon.
             //
@@ -85,6 +88,23 @@ impl Polygon {
             return false;
         }
 
+        // If all edges of the triangle are polygon edges, we haven't actually
+        // checked whether it is inside of the polygon. This can happen, if an
+        // interior cycle touches the exterior, or if a hole has been bridged to
+        // the exterior (a "keyhole"). Then the edges of the hole are part of
+        // the exterior, and a triangle that fills out the hole is made up of
+        // exterior edges only.
+        //
+        // The center of the triangle can't be on the polygon boundary, as no
+        // boundary edge passes through the triangle. So we can check that.
+        if all_edges_on_boundary {
+            let center = Point {
+                coords: (a.coords + b.coords + c.coords) / 3.,
+            };
+
+            return self.contains_point(center);
+        }
+
         // If we didn't throw away the triangle up till now, this means all its
         // edges are within the face.
         true
@@ -226,6 +246,44 @@ mod tests {
         assert!(!polygon.contains_triangle([d, e, f]));
     }
 
+    #[test]
+    fn contains_triangle_with_keyhole() {
+        let a = [0., 0.];
+        let b = [4., 0.];
+        let c = [0., 4.];
+
+        let d = [1., 1.];
+        let e = [2., 1.];
+        let f = [1., 2.];
+
+        // The hole is bridged to the exterior, making it part of the exterior.
+        let polygon = Polygon::new()
+            .with_exterior(PolyChain::from([a, b, c, a, d, f, e, d]).close());
+
+        assert!(!polygon.contains_triangle([d, e, f]));
+        assert!(polygon.contains_triangle([a, b, e]));
+    }
+
+    #[test]
+    fn contains_triangle_with_hole_touching_exterior() {
+        let a = [0., 0.];
+        let b = [4., 0.];
+        let c = [0., 4.];
+
+        let d = [1., 0.];
+        let e = [2., 1.];
+        let f = [1., 1.];
+
+        // The hole touches the exterior in the vertex `d`.
+        let polygon = Polygon::new()
+            .with_exterior(PolyChain::from([a, d, b, c]).close())
+            .with_interiors([PolyChain::from([d, e, f]).close()]);
+
+        assert!(!polygon.contains_triangle([d, e, f]));
+        assert!(polygon.contains_triangle([d, b, e]));
+        assert!(polygon.contains_triangle([a, d, f]));
+    }
+
     #[test]
     fn contains_point_ray_hits_vertex_while_passing_outside() {
         let a = [0., 0.];
@@ -0,0 +1,189 @@
+//! # Standard models for tests and benchmarks
+//!
+//! Many tests need a solid to work with, and most of them don't care about
+//! its exact shape, as long as it has certain features, like a curved face or
+//! a hole. This module provides a small set of canonical models, so tests and
+//! benchmarks across the crates can share them, instead of each defining their
+//! own.
+//!
+//! All models are valid solids. Their dimensions are documented, so tests can
+//! rely on them.
+
+use std::f64::consts::{PI, TAU};
+
+use fj_interop::mesh::{Color, Mesh};
+use fj_math::{Point, Scalar};
+
+use crate::{
+    objects::{Cycle, HalfEdge, Region, Sketch, Solid},
+    operations::{
+        build::{BuildHalfEdge, BuildSketch, BuildSolid},
+        insert::Insert,
+        sweep::SweepSketch,
+        update::UpdateSketch,
+    },
+    services::Services,
+};
+
+/// Build a cube with an edge length of `1`
+///
+/// The cube stands on the xy-plane, centered on the z-axis.
+pub fn cube(services: &mut Services) -> Solid {
+    Solid::cuboid([1., 1., 1.], services)
+}
+
+/// Build a spacer with an outer radius of `1`, an inner radius of `0.5`, and a
+/// height of `1`
+///
+/// The spacer stands on the xy-plane, centered on the z-axis. It has two planar
+/// faces, top and bottom, and two curved faces, inside and outside.
+pub fn spacer(services: &mut Services) -> Solid {
+    Solid::spacer(1., 0.5, 1., services)
+}
+
+/// Build a faceted sphere with a radius of `1`
+///
+/// The sphere is centered on the origin, and divided into 16 segments around
+/// the z-axis. See [`BuildSolid::faceted_sphere`].
+pub fn sphere(services: &mut Services) -> Solid {
+    Solid::faceted_sphere(1., 16, services)
+}
+
+/// Build a faceted torus with a major radius of `1` and a minor radius of
+/// `0.25`
+///
+/// The torus is centered on the origin, around the z-axis. It's divided into
+/// 16 segments around the z-axis, and 8 segments around its tube. Each facet
+/// is a separate planar face.
+pub fn torus(services: &mut Services) -> Solid {
+    let major_radius = 1.;
+    let minor_radius = 0.25;
+    let num_segments = 16;
+    let num_tube_segments = 8;
+
+    let point = |segment: usize, tube_segment: usize| {
+        let theta = TAU * (segment % num_segments) as f64 / num_segments as f64;
+        let phi = TAU * (tube_segment % num_tube_segments) as f64
+            / num_tube_segments as f64;
+        let distance = major_radius + minor_radius * phi.cos();
+
+        Point::from([
+            distance * theta.cos(),
+            distance * theta.sin(),
+            minor_radius * phi.sin(),
+        ])
+    };
+
+    let mut mesh = Mesh::new();
+    for segment in 0..num_segments {
+        for tube_segment in 0..num_tube_segments {
+            let [a, b, c, d] = [
+                point(segment, tube_segment),
+                point(segment + 1, tube_segment),
+                point(segment + 1, tube_segment + 1),
+                point(segment, tube_segment + 1),
+            ];
+
+            mesh.push_triangle([a, b, c], Color::default());
+            mesh.push_triangle([a, c, d], Color::default());
+        }
+    }
+
+    Solid::from_triangle_mesh(&mesh, minor_radius * 1e-9, services)
+        .expect("Mesh of torus is closed and manifold")
+}
+
+/// Build a cube with an edge length of `1`, with one rounded vertical edge
+///
+/// The cube stands on the xy-plane, with one of its corners at the origin, and
+/// the opposite corner at `[1., 1., 1.]`. The vertical edge at `x = 1` and
+/// `y = 1` is rounded off with a radius of `0.25`, which results in a curved
+/// face between the two adjacent planar faces.
+pub fn filleted_cube(services: &mut Services) -> Solid {
+    let radius = 0.25;
+
+    let exterior = Cycle::new(
+        [
+            HalfEdge::line_segment([[0., 0.], [1., 0.]], None, services),
+            HalfEdge::line_segment(
+                [[1., 0.], [1., 1. - radius]],
+                None,
+                services,
+            ),
+            HalfEdge::arc(
+                [1., 1. - radius],
+                [1. - radius, 1.],
+                PI / 2.,
+                services,
+            ),
+            HalfEdge::line_segment(
+                [[1. - radius, 1.], [0., 1.]],
+                None,
+                services,
+            ),
+            HalfEdge::line_segment([[0., 1.], [0., 0.]], None, services),
+        ]
+        .map(|half_edge| half_edge.insert(services)),
+    )
+    .insert(services);
+
+    let surface = services.objects.surfaces.xy_plane();
+    Sketch::empty()
+        .add_region(Region::new(exterior, [], None).insert(services))
+        .sweep_sketch(
+            surface,
+            [Scalar::ZERO, Scalar::ZERO, Scalar::ONE],
+            services,
+        )
+        .expect("Profile of the cube encloses an area")
+}
+
+/// Compute the volume of a solid, from its triangle mesh
+///
+/// Tests use this to check the result of operations that change the volume of a
+/// solid.
+#[cfg(test)]
+pub(crate) fn volume(
+    solid: &Solid,
+    tolerance: crate::algorithms::approx::Tolerance,
+) -> anyhow::Result<Scalar> {
+    use crate::algorithms::triangulate::Triangulate;
+
+    let mesh = (solid, tolerance).triangulate()?;
+    Ok(volume_of_mesh(&mesh))
+}
+
+/// Compute the volume enclosed by a closed triangle mesh
+#[cfg(test)]
+pub(crate) fn volume_of_mesh(mesh: &Mesh<Point<3>>) -> Scalar {
+    mesh.triangles().fold(Scalar::ZERO, |volume, triangle| {
+        let [a, b, c] = triangle.inner.points();
+        volume + a.coords.dot(&b.coords.cross(&c.coords)) / 6.
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{objects::Solid, services::Services, validate::validate_all};
+
+    #[test]
+    fn fixtures_are_valid() {
+        let mut services = Services::new();
+
+        let fixtures: [(&str, fn(&mut Services) -> Solid); 5] = [
+            ("cube", super::cube),
+            ("spacer", super::spacer),
+            ("sphere", super::sphere),
+            ("torus", super::torus),
+            ("filleted cube", super::filleted_cube),
+        ];
+
+        for (name, fixture) in fixtures {
+            let solid = fixture(&mut services);
+
+            assert_eq!(solid.shells().len(), 1, "{name}");
+            let report = validate_all(&solid);
+            assert!(report.is_valid(), "{name}: {report}");
+        }
+    }
+}
@@ -82,6 +82,56 @@ impl CurveBoundary<Point<1>> {
         a_low <= b_high && a_high >= b_low
     }
 
+    /// Split the boundary at the given point
+    ///
+    /// Returns two boundaries that have the same direction as this one. The
+    /// first ends at the given point, the second starts there.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the boundary doesn't contain the point.
+    pub fn split_at(self, point: impl Into<Point<1>>) -> [Self; 2] {
+        let point = point.into();
+        assert!(
+            self.contains(point),
+            "Can't split boundary at point it doesn't contain"
+        );
+
+        let [start, end] = self.inner;
+        [[start, point], [point, end]].map(|inner| Self { inner })
+    }
+
+    /// Subdivide the boundary into the given number of equal parts
+    ///
+    /// Returns the parts in order, each with the same direction as this
+    /// boundary. Adjacent parts share their bounding elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `n` is zero.
+    pub fn subdivide(self, n: usize) -> Vec<Self> {
+        assert!(n > 0, "Can't subdivide boundary into zero parts");
+
+        let [start, end] = self.inner;
+        let point_at = |i: usize| {
+            // Use the exact bounding elements at the ends, instead of
+            // computing them, to avoid rounding errors there.
+            if i == 0 {
+                start
+            } else if i == n {
+                end
+            } else {
+                start + (end - start) * (i as f64 / n as f64)
+            }
+        };
+
+        (0..n)
+            .map(|i| Self {
+                inner: [point_at(i), point_at(i + 1)],
+            })
+            .collect()
+    }
+
     /// Create the difference of this boundary and another
     ///
     /// The result will be normalized.
@@ -358,6 +408,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn split_at() {
+        let [a, b] = array_to_boundary([0., 2.]).split_at([0.5]);
+        assert_eq!(a, array_to_boundary([0., 0.5]));
+        assert_eq!(b, array_to_boundary([0.5, 2.]));
+
+        // The direction of the boundary is preserved.
+        let [a, b] = array_to_boundary([2., 0.]).split_at([0.5]);
+        assert_eq!(a, array_to_boundary([2., 0.5]));
+        assert_eq!(b, array_to_boundary([0.5, 0.]));
+    }
+
+    #[test]
+    fn subdivide() {
+        let parts = array_to_boundary([0., 2.]).subdivide(4);
+        assert_eq!(
+            parts,
+            [[0., 0.5], [0.5, 1.], [1., 1.5], [1.5, 2.]].map(array_to_boundary)
+        );
+
+        let parts = array_to_boundary([2., 0.]).subdivide(2);
+        assert_eq!(parts, [[2., 1.], [1., 0.]].map(array_to_boundary));
+
+        let parts = array_to_boundary([0., 2.]).subdivide(1);
+        assert_eq!(parts, [array_to_boundary([0., 2.])]);
+    }
+
     fn array_to_boundary(array: [f64; 2]) -> CurveBoundary<Point<1>> {
         CurveBoundary::from(array.map(|element| [element]))
     }
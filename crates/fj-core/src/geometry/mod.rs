@@ -2,10 +2,12 @@
 
 mod boundary;
 mod path;
+mod snap;
 mod surface;
 
 pub use self::{
     boundary::{CurveBoundary, CurveBoundaryElement},
     path::{GlobalPath, SurfacePath},
-    surface::SurfaceGeometry,
+    snap::SnapGrid,
+    surface::{BoundedSurface, SurfaceGeometry},
 };
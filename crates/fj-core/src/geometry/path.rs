@@ -2,7 +2,9 @@
 //!
 //! See [`SurfacePath`] and [`GlobalPath`].
 
-use fj_math::{Circle, Line, Point, Scalar, Transform, Vector};
+use fj_math::{
+    Circle, InvalidSpiral, Line, Point, Scalar, Spiral, Transform, Vector,
+};
 
 /// A path through surface (2D) space
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -12,6 +14,13 @@ pub enum SurfacePath {
 
     /// A line
     Line(Line<2>),
+
+    /// An Archimedean spiral
+    ///
+    /// Spirals are only partially supported. They can be approximated, but
+    /// sweeping them, or using them in boolean operations, is not supported
+    /// yet.
+    Spiral(Spiral<2>),
 }
 
 impl SurfacePath {
@@ -23,6 +32,28 @@ impl SurfacePath {
         Self::Circle(Circle::from_center_and_radius(center, radius))
     }
 
+    /// Build an Archimedean spiral from its parameters
+    ///
+    /// The distance of the spiral from the center is `radius` at path
+    /// coordinate `0.`, and grows by `growth` per radian. The spiral starts in
+    /// the direction of the u-axis, and turns towards the v-axis.
+    ///
+    /// Returns an error, if `growth` is zero. Use a circle instead.
+    pub fn spiral_from_params(
+        center: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+        growth: impl Into<Scalar>,
+    ) -> Result<Self, InvalidSpiral> {
+        let spiral = Spiral::try_new(
+            center,
+            Vector::unit_u(),
+            Vector::unit_v(),
+            radius,
+            growth,
+        )?;
+        Ok(Self::Spiral(spiral))
+    }
+
     /// Build a line that represents the u-axis of the surface its on
     pub fn u_axis() -> Self {
         let a = Point::origin();
@@ -66,6 +97,25 @@ impl SurfacePath {
         match self {
             Self::Circle(circle) => circle.point_from_circle_coords(point),
             Self::Line(line) => line.point_from_line_coords(point),
+            Self::Spiral(spiral) => spiral.point_from_spiral_coords(point),
+        }
+    }
+
+    /// Compute the tangent of the path at the given point
+    ///
+    /// The tangent points in the direction of increasing path coordinates. Its
+    /// magnitude is the rate of change of the surface point per unit of path
+    /// coordinate.
+    pub fn tangent_at(&self, point: impl Into<Point<1>>) -> Vector<2> {
+        match self {
+            Self::Circle(circle) => {
+                // The derivative of a circle is the same circle, a quarter
+                // turn ahead.
+                let point = point.into();
+                circle.vector_from_circle_coords([point.t + Scalar::PI / 2.])
+            }
+            Self::Line(line) => line.direction(),
+            Self::Spiral(spiral) => spiral.tangent_at(point),
         }
     }
 
@@ -75,6 +125,7 @@ impl SurfacePath {
         match self {
             Self::Circle(circle) => Self::Circle(circle.reverse()),
             Self::Line(line) => Self::Line(line.reverse()),
+            Self::Spiral(spiral) => Self::Spiral(spiral.reverse()),
         }
     }
 }
@@ -0,0 +1,87 @@
+use fj_math::{Point, Scalar};
+
+/// A grid that points can be snapped to
+///
+/// Points that are authored by hand, or computed from such points, often end
+/// up very close to each other, without being identical. Validation considers
+/// such points to be distinct, which can lead to errors that are hard to
+/// understand. Snapping those points to a grid makes them identical.
+///
+/// Enable snapping for building geometry using [`Services::with_snap`].
+///
+/// [`Services::with_snap`]: crate::services::Services::with_snap
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct SnapGrid {
+    resolution: Scalar,
+}
+
+impl SnapGrid {
+    /// Create a grid with the provided resolution
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `resolution` is not positive.
+    pub fn new(resolution: impl Into<Scalar>) -> Self {
+        let resolution = resolution.into();
+        assert!(
+            resolution > Scalar::ZERO,
+            "Snap grid resolution must be positive"
+        );
+
+        Self { resolution }
+    }
+
+    /// Access the resolution of the grid
+    pub fn resolution(&self) -> Scalar {
+        self.resolution
+    }
+
+    /// Snap the point to the nearest point of the grid
+    pub fn snap<const D: usize>(&self, point: impl Into<Point<D>>) -> Point<D> {
+        let point = point.into();
+        let components = point.coords.components.map(|component| {
+            (component / self.resolution).round() * self.resolution
+        });
+
+        Point::from(components)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::{
+        objects::HalfEdge, operations::build::BuildHalfEdge, services::Services,
+    };
+
+    use super::SnapGrid;
+
+    #[test]
+    fn snap() {
+        let grid = SnapGrid::new(0.001);
+
+        assert_eq!(grid.snap([1.0000001, 0.]), grid.snap([0.9999999, 0.]));
+        assert_eq!(grid.snap([1.0004, 2.]), Point::from([1., 2.]));
+    }
+
+    #[test]
+    fn snap_when_building() {
+        let mut services = Services::new().with_snap(0.001);
+
+        let a = HalfEdge::line_segment(
+            [[0., 0.], [1.0000001, 0.]],
+            None,
+            &mut services,
+        );
+        let b = HalfEdge::line_segment(
+            [[0.9999999, 0.], [1., 1.]],
+            None,
+            &mut services,
+        );
+
+        let [_, end_of_a] = a.boundary().inner;
+        let end_of_a = a.path().point_from_path_coords(end_of_a);
+        assert_eq!(end_of_a, b.start_position());
+    }
+}
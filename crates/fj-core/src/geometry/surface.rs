@@ -1,6 +1,6 @@
 //! The geometry that defines a surface
 
-use fj_math::{Line, Plane, Point, Transform, Vector};
+use fj_math::{Aabb, Line, Plane, Point, Scalar, Transform, Vector};
 
 use super::GlobalPath;
 
@@ -50,6 +50,33 @@ impl SurfaceGeometry {
         plane.project_point(point)
     }
 
+    /// Compute the distance between the surface and a global point
+    ///
+    /// For planes, this is the distance to the point's projection into the
+    /// plane. For surfaces that are swept from a circle, it is the distance to
+    /// the circle, after moving the point along the surface's v-axis into the
+    /// plane of the circle. That is the true distance, if the v-axis is
+    /// perpendicular to the circle. Otherwise, it still is zero only for
+    /// points on the surface.
+    pub fn distance_to_point(&self, point: impl Into<Point<3>>) -> Scalar {
+        let point = point.into();
+
+        match self.u {
+            GlobalPath::Line(_) => {
+                let projected = self.project_global_point(point);
+                point.distance_to(&self.point_from_surface_coords(projected))
+            }
+            GlobalPath::Circle(circle) => {
+                let normal = circle.a().cross(&circle.b());
+                let t = normal.dot(&(point - circle.center()))
+                    / normal.dot(&self.v);
+                let in_plane = point - self.v * t;
+
+                (in_plane.distance_to(&circle.center()) - circle.radius()).abs()
+            }
+        }
+    }
+
     /// Transform the surface geometry
     #[must_use]
     pub fn transform(self, transform: &Transform) -> Self {
@@ -59,6 +86,50 @@ impl SurfaceGeometry {
     }
 }
 
+/// A surface, restricted to a range of surface coordinates
+///
+/// Surfaces are infinite, so code that needs to cover a surface as a whole,
+/// like approximating it, needs a range to work within. A plane has no natural
+/// range at all, and a cylinder has none along its axis.
+///
+/// The approximation of surfaces is only available for bounded surfaces, to
+/// prevent attempts to approximate an infinite surface.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct BoundedSurface {
+    /// The geometry of the surface
+    pub geometry: SurfaceGeometry,
+
+    /// The range of surface coordinates that the surface is restricted to
+    pub boundary: Aabb<2>,
+}
+
+impl BoundedSurface {
+    /// Restrict the surface to the range between the two points
+    ///
+    /// The points are opposite corners of the range. They don't need to be
+    /// ordered.
+    pub fn new(
+        geometry: SurfaceGeometry,
+        corners: [impl Into<Point<2>>; 2],
+    ) -> Self {
+        let corners: [Point<2>; 2] = corners.map(Into::into);
+        let boundary = Aabb::<2>::from_points(corners);
+
+        Self { geometry, boundary }
+    }
+
+    /// Determine whether the range contains the provided surface point
+    pub fn contains(&self, point: impl Into<Point<2>>) -> bool {
+        let point = point.into();
+        let Aabb { min, max } = self.boundary;
+
+        min.u <= point.u
+            && point.u <= max.u
+            && min.v <= point.v
+            && point.v <= max.v
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use fj_math::{Line, Point, Vector};
@@ -82,10 +82,17 @@
 //! [Fornjot]: https://www.fornjot.app/
 
 pub mod algorithms;
+pub mod fixtures;
 pub mod geometry;
 pub mod objects;
 pub mod operations;
+pub mod prelude;
 pub mod queries;
+pub mod serialize;
 pub mod services;
+pub mod sketch;
 pub mod storage;
+pub mod tree;
 pub mod validate;
+
+mod trace;
@@ -24,37 +24,74 @@ impl Cycle {
         &self.half_edges
     }
 
+    /// Indicate whether the cycle is closed
+    ///
+    /// A cycle is closed, if each of its half-edges ends where the next one
+    /// starts, within the provided tolerance. This includes the last half-edge,
+    /// which must end where the first one starts.
+    ///
+    /// An empty cycle is not considered to be closed.
+    pub fn is_closed(&self, tolerance: impl Into<Scalar>) -> bool {
+        let tolerance = tolerance.into();
+
+        if self.half_edges.is_empty() {
+            return false;
+        }
+
+        self.half_edges().pairs().all(|(first, second)| {
+            let [_, end] = first.boundary().inner;
+            let end_of_first = first.path().point_from_path_coords(end);
+
+            (end_of_first - second.start_position()).magnitude() <= tolerance
+        })
+    }
+
     /// Indicate the cycle's winding, assuming a right-handed coordinate system
     ///
     /// Please note that this is not *the* winding of the cycle, only one of the
     /// two possible windings, depending on the direction you look at the
     /// surface that the cycle is defined on from.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the cycle doesn't enclose any area. Use
+    /// [`Cycle::try_winding`] to handle that case.
     pub fn winding(&self) -> Winding {
+        self.try_winding().unwrap_or_else(|| {
+            panic!("Can't determine winding of cycle without area: {self:#?}")
+        })
+    }
+
+    /// Indicate the cycle's winding, if it encloses any area
+    ///
+    /// Works like [`Cycle::winding`], but returns `None`, if the cycle has no
+    /// edges, or if it has collapsed to zero area. That can happen, if all of
+    /// its vertices lie on a line, or if it consists of a circle without
+    /// radius.
+    pub fn try_winding(&self) -> Option<Winding> {
         // The cycle could be made up of one or two circles. If that is the
         // case, the winding of the cycle is determined by the winding of the
         // first circle.
         if self.half_edges.len() < 3 {
-            let first = self
-                .half_edges()
-                .iter()
-                .next()
-                .expect("Invalid cycle: expected at least one edge");
+            let first = self.half_edges().iter().next()?;
 
             let [a, b] = first.boundary().inner;
             let edge_direction_positive = a < b;
 
             let circle = match first.path() {
                 SurfacePath::Circle(circle) => circle,
-                SurfacePath::Line(_) => unreachable!(
-                    "Invalid cycle: less than 3 edges, but not all are circles"
-                ),
+                SurfacePath::Line(_) | SurfacePath::Spiral(_) => return None,
             };
-            let cross_positive = circle.a().cross2d(&circle.b()) > Scalar::ZERO;
+            let cross = circle.a().cross2d(&circle.b());
+            if cross == Scalar::ZERO {
+                return None;
+            }
+            let cross_positive = cross > Scalar::ZERO;
 
             if edge_direction_positive == cross_positive {
-                return Winding::Ccw;
+                return Some(Winding::Ccw);
             } else {
-                return Winding::Cw;
+                return Some(Winding::Cw);
             }
         }
 
@@ -71,12 +108,60 @@ impl Cycle {
         }
 
         if sum > Scalar::ZERO {
-            return Winding::Cw;
+            return Some(Winding::Cw);
         }
         if sum < Scalar::ZERO {
-            return Winding::Ccw;
+            return Some(Winding::Ccw);
         }
 
-        unreachable!("Encountered invalid cycle: {self:#?}");
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::{
+        objects::{Cycle, HalfEdge},
+        operations::{
+            build::{BuildCycle, BuildHalfEdge},
+            insert::Insert,
+            reverse::Reverse,
+            update::UpdateCycle,
+        },
+        services::Services,
+    };
+
+    #[test]
+    fn is_closed() {
+        let mut services = Services::new();
+
+        let closed =
+            Cycle::polygon([[0., 0.], [1., 0.], [0., 1.]], &mut services);
+        assert!(closed.is_closed(1e-10));
+
+        let open = Cycle::empty().add_half_edges(
+            [[[0., 0.], [1., 0.]], [[1., 0.], [0., 1.]]].map(|points| {
+                HalfEdge::line_segment(points, None, &mut services)
+                    .insert(&mut services)
+            }),
+        );
+        assert!(!open.is_closed(1e-10));
+
+        assert!(!Cycle::empty().is_closed(1e-10));
+    }
+
+    #[test]
+    fn winding_of_spacer_cycles() {
+        let mut services = Services::new();
+
+        let outer = Cycle::circle(Point::origin(), 1., &mut services);
+        let inner = Cycle::circle(Point::origin(), 0.5, &mut services)
+            .reverse(&mut services);
+
+        assert!(outer.is_closed(1e-10));
+        assert!(inner.is_closed(1e-10));
+        assert_ne!(outer.winding(), inner.winding());
     }
 }
@@ -1,7 +1,14 @@
-use fj_math::Winding;
+use std::ops::Deref;
+
+use fj_math::{Vector, Winding};
 
 use crate::{
-    objects::{Region, Surface},
+    algorithms::approx::{
+        edge::HalfEdgeApproxCache, face::FaceBoundaryApprox, Approx,
+        ApproxError, Tolerance,
+    },
+    geometry::GlobalPath,
+    objects::{Cycle, Region, Surface},
     storage::Handle,
 };
 
@@ -67,6 +74,62 @@ impl Face {
             Winding::Cw => Handedness::LeftHanded,
         }
     }
+
+    /// Compute the unit normal of the face, pointing away from its front side
+    ///
+    /// For a face that is part of a shell, this points outward. Returns `None`,
+    /// if the surface of the face is curved, and the face thus has no single
+    /// normal.
+    pub fn normal(&self) -> Option<Vector<3>> {
+        let geometry = self.surface.geometry();
+
+        let GlobalPath::Line(line) = geometry.u else {
+            return None;
+        };
+        let normal = line.direction().cross(&geometry.v).normalize();
+
+        match self.coord_handedness() {
+            Handedness::RightHanded => Some(normal),
+            Handedness::LeftHanded => Some(-normal),
+        }
+    }
+
+    /// Approximate the boundary of the face as closed polylines
+    ///
+    /// Returns one polyline for the exterior cycle, and one for each interior
+    /// cycle. Each polyline follows the half-edges of its cycle, and ends at
+    /// the point it starts at. Seen from the front side of the face, the
+    /// exterior polyline is wound counter-clockwise, and the interior ones
+    /// clockwise. See [`Face::coord_handedness`], for how that relates to the
+    /// surface coordinates of the points.
+    pub fn approx_boundary(
+        &self,
+        tolerance: impl Into<Tolerance>,
+    ) -> Result<FaceBoundaryApprox, ApproxError> {
+        let tolerance = tolerance.into();
+
+        // Use the same cache for all cycles, so their shared vertices are
+        // approximated consistently.
+        let mut cache = HalfEdgeApproxCache::default();
+        let mut approx_cycle = |cycle: &Handle<Cycle>| {
+            (cycle.deref(), self.surface.deref())
+                .approx_with_cache(tolerance, &mut cache)
+                .map(|approx| approx.points())
+        };
+
+        let exterior = approx_cycle(self.region.exterior())?;
+        let interiors = self
+            .region
+            .interiors()
+            .iter()
+            .map(&mut approx_cycle)
+            .collect::<Result<_, _>>()?;
+
+        Ok(FaceBoundaryApprox {
+            exterior,
+            interiors,
+        })
+    }
 }
 
 /// The handedness of a face's coordinate system
@@ -80,3 +143,82 @@ pub enum Handedness {
     /// The face's coordinate system is right-handed
     RightHanded,
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::approx::{ApproxPoint, Tolerance},
+        objects::Solid,
+        operations::build::BuildSolid,
+        queries::SelectFaces,
+        services::Services,
+    };
+
+    #[test]
+    fn normal() {
+        let mut services = Services::new();
+
+        let spacer = Solid::spacer(1., 0.5, 1., &mut services);
+
+        let mut normals = spacer
+            .shells()
+            .only()
+            .faces()
+            .iter()
+            .filter_map(|face| face.normal())
+            .collect::<Vec<_>>();
+        normals.sort();
+
+        assert_eq!(
+            normals,
+            [Vector::from([0., 0., -1.]), Vector::from([0., 0., 1.])]
+        );
+    }
+
+    #[test]
+    fn approx_boundary() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let spacer = Solid::spacer(1., 0.5, 1., &mut services);
+        let top = spacer
+            .faces_where(tolerance, |face| {
+                face.faces_direction([0., 0., 1.], 1e-6)
+            })?
+            .remove(0);
+
+        let boundary = top.approx_boundary(tolerance)?;
+        assert_eq!(boundary.interiors.len(), 1);
+
+        let exterior = &boundary.exterior;
+        let interior = &boundary.interiors[0];
+
+        for polyline in [exterior, interior] {
+            let first = polyline.first().unwrap().global_form;
+            let last = polyline.last().unwrap().global_form;
+            assert_eq!(first, last);
+        }
+
+        let expected = Scalar::PI;
+        assert!((signed_area(exterior) - expected).abs() < Scalar::from(0.01));
+
+        let expected = -Scalar::PI * 0.25;
+        assert!((signed_area(interior) - expected).abs() < Scalar::from(0.01));
+
+        Ok(())
+    }
+
+    fn signed_area(polyline: &[ApproxPoint<2>]) -> Scalar {
+        polyline
+            .windows(2)
+            .map(|points| {
+                let [a, b]: [Point<2>; 2] =
+                    [points[0].local_form, points[1].local_form];
+                (a.u * b.v - b.u * a.v) / 2.
+            })
+            .fold(Scalar::ZERO, |area, part| area + part)
+    }
+}
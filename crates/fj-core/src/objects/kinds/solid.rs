@@ -1,4 +1,12 @@
+use fj_interop::mesh::Mesh;
+use fj_math::{Aabb, Point};
+
 use crate::{
+    algorithms::{
+        approx::{ApproxError, Tolerance},
+        bounding_volume::BoundingVolume,
+        triangulate::Triangulate,
+    },
     objects::{ObjectSet, Shell},
     storage::Handle,
 };
@@ -28,4 +36,56 @@ impl Solid {
     pub fn shells(&self) -> &ObjectSet<Shell> {
         &self.shells
     }
+
+    /// Convert the solid into a triangle mesh
+    ///
+    /// This is a thin wrapper around [`Triangulate`], which approximates the
+    /// solid using the provided tolerance, then triangulates the approximation.
+    pub fn to_mesh(
+        &self,
+        tolerance: impl Into<Tolerance>,
+    ) -> Result<Mesh<Point<3>>, ApproxError> {
+        (self, tolerance.into()).triangulate()
+    }
+
+    /// Convert the solid into a triangle mesh, using a default tolerance
+    ///
+    /// Works like [`Solid::to_mesh`], but derives the tolerance from the
+    /// bounding box of the solid, using [`Tolerance::from_aabb`].
+    pub fn to_mesh_default(&self) -> Result<Mesh<Point<3>>, ApproxError> {
+        let aabb = self.aabb().unwrap_or(Aabb {
+            min: Point::origin(),
+            max: Point::origin(),
+        });
+
+        self.to_mesh(Tolerance::from_aabb(&aabb))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::approx::Tolerance, objects::Solid,
+        operations::build::BuildSolid, services::Services,
+    };
+
+    #[test]
+    fn to_mesh() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let solid = Solid::tetrahedron(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+            &mut services,
+        )
+        .solid;
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = solid.to_mesh(tolerance)?;
+        assert_eq!(mesh.triangles().count(), 4);
+
+        let mesh = solid.to_mesh_default()?;
+        assert_eq!(mesh.triangles().count(), 4);
+
+        Ok(())
+    }
 }
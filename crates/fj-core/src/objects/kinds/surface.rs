@@ -1,3 +1,5 @@
+use fj_math::{Point, Scalar};
+
 use crate::geometry::SurfaceGeometry;
 
 /// A two-dimensional shape
@@ -16,4 +18,77 @@ impl Surface {
     pub fn geometry(&self) -> SurfaceGeometry {
         self.geometry
     }
+
+    /// Determine whether a global point lies on the surface
+    ///
+    /// Points that are no further from the surface than `tolerance` are
+    /// considered to lie on it. See [`SurfaceGeometry::distance_to_point`].
+    pub fn contains_point(
+        &self,
+        point: impl Into<Point<3>>,
+        tolerance: impl Into<Scalar>,
+    ) -> bool {
+        self.geometry.distance_to_point(point) <= tolerance.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Deref;
+
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::approx::{Approx, Tolerance},
+        fixtures,
+        services::Services,
+    };
+
+    #[test]
+    fn contains_points_of_spacer() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let spacer = fixtures::spacer(&mut services);
+        let xy_plane = services.objects.surfaces.xy_plane();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let mut num_bottom_faces = 0;
+
+        for face in spacer.shells().only().faces() {
+            let points = face
+                .region()
+                .all_cycles()
+                .flat_map(|cycle| cycle.half_edges().iter())
+                .map(|half_edge| {
+                    (half_edge.deref(), face.surface().deref())
+                        .approx(tolerance)
+                })
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .flat_map(|approx| approx.points)
+                .map(|point| point.global_form)
+                .collect::<Vec<_>>();
+
+            for &point in &points {
+                assert!(face.surface().contains_point(point, 1e-9));
+            }
+
+            // The spacer stands on the xy-plane, so the circles of its bottom
+            // face lie on it.
+            if points.iter().all(|point| point.z == Scalar::ZERO) {
+                num_bottom_faces += 1;
+
+                for &point in &points {
+                    assert!(xy_plane.contains_point(point, 1e-9));
+                    assert!(
+                        !xy_plane.contains_point(point + [0., 0., 0.5], 1e-9)
+                    );
+                }
+            }
+        }
+
+        assert_eq!(num_bottom_faces, 1);
+
+        Ok(())
+    }
 }
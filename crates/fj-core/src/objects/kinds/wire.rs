@@ -0,0 +1,32 @@
+use crate::{
+    objects::{HalfEdge, ObjectSet},
+    storage::Handle,
+};
+
+/// A chain of connected edges that is not closed
+///
+/// Unlike a [`Cycle`], the last half-edge of a wire doesn't need to end where
+/// the first one starts. Wires can't bound a region, but they can be swept
+/// into open shells, to create thin-walled surfaces.
+///
+/// Since a [`HalfEdge`] only refers to its start vertex, the end of a wire's
+/// last half-edge has no vertex object.
+///
+/// [`Cycle`]: crate::objects::Cycle
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Wire {
+    half_edges: ObjectSet<HalfEdge>,
+}
+
+impl Wire {
+    /// Create an instance of `Wire`
+    pub fn new(half_edges: impl IntoIterator<Item = Handle<HalfEdge>>) -> Self {
+        let half_edges = half_edges.into_iter().collect();
+        Self { half_edges }
+    }
+
+    /// Access the edges that make up the wire
+    pub fn half_edges(&self) -> &ObjectSet<HalfEdge> {
+        &self.half_edges
+    }
+}
@@ -56,8 +56,9 @@ pub use self::{
         solid::Solid,
         surface::Surface,
         vertex::Vertex,
+        wire::Wire,
     },
     object::{Bare, BehindHandle, Form, Object, WithHandle},
     object_set::ObjectSet,
-    stores::{Objects, Surfaces},
+    stores::{Objects, ObjectsUsage, Surfaces},
 };
@@ -1,7 +1,7 @@
 use crate::{
     objects::{
         Curve, Cycle, Face, HalfEdge, Objects, Region, Shell, Sketch, Solid,
-        Surface, Vertex,
+        Surface, Vertex, Wire,
     },
     storage::{Handle, HandleWrapper, ObjectId},
     validate::{Validate, ValidationError},
@@ -101,6 +101,7 @@ object!(
     Solid, "solid", solids;
     Surface, "surface", surfaces;
     Vertex, "vertex", vertices;
+    Wire, "wire", wires;
 );
 
 /// The form that an object can take
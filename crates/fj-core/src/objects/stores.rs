@@ -2,14 +2,21 @@ use fj_math::Vector;
 
 use crate::{
     geometry::{GlobalPath, SurfaceGeometry},
-    storage::{Handle, Store},
+    storage::{Handle, Store, StoreUsage},
 };
 
 use super::{
     Curve, Cycle, Face, HalfEdge, Region, Shell, Sketch, Solid, Surface, Vertex,
+    Wire,
 };
 
 /// The available object stores
+///
+/// Handles are allocated deterministically. Performing the same sequence of
+/// operations with a new instance of `Objects` results in handles whose ids
+/// are ordered and printed the same way. See [`ObjectId`] for details.
+///
+/// [`ObjectId`]: crate::storage::ObjectId
 #[derive(Debug, Default)]
 pub struct Objects {
     /// Store for [`Curve`]s
@@ -41,6 +48,9 @@ pub struct Objects {
 
     /// Store for [`Vertex`] objects
     pub vertices: Store<Vertex>,
+
+    /// Store for [`Wire`]s
+    pub wires: Store<Wire>,
 }
 
 impl Objects {
@@ -48,6 +58,83 @@ impl Objects {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Report the memory usage of the stores
+    pub fn usage(&self) -> ObjectsUsage {
+        ObjectsUsage {
+            curves: self.curves.usage(),
+            cycles: self.cycles.usage(),
+            faces: self.faces.usage(),
+            half_edges: self.half_edges.usage(),
+            regions: self.regions.usage(),
+            shells: self.shells.usage(),
+            sketches: self.sketches.usage(),
+            solids: self.solids.usage(),
+            surfaces: self.surfaces.usage(),
+            vertices: self.vertices.usage(),
+            wires: self.wires.usage(),
+        }
+    }
+}
+
+/// The memory usage of the object stores, per kind of object
+///
+/// Returned by [`Objects::usage`]. See [`StoreUsage`] for details.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ObjectsUsage {
+    /// Usage of the [`Curve`] store
+    pub curves: StoreUsage,
+
+    /// Usage of the [`Cycle`] store
+    pub cycles: StoreUsage,
+
+    /// Usage of the [`Face`] store
+    pub faces: StoreUsage,
+
+    /// Usage of the [`HalfEdge`] store
+    pub half_edges: StoreUsage,
+
+    /// Usage of the [`Region`] store
+    pub regions: StoreUsage,
+
+    /// Usage of the [`Shell`] store
+    pub shells: StoreUsage,
+
+    /// Usage of the [`Sketch`] store
+    pub sketches: StoreUsage,
+
+    /// Usage of the [`Solid`] store
+    pub solids: StoreUsage,
+
+    /// Usage of the [`Surface`] store
+    pub surfaces: StoreUsage,
+
+    /// Usage of the [`Vertex`] store
+    pub vertices: StoreUsage,
+
+    /// Usage of the [`Wire`] store
+    pub wires: StoreUsage,
+}
+
+impl ObjectsUsage {
+    /// Compute the combined usage of all stores
+    pub fn total(&self) -> StoreUsage {
+        [
+            self.curves,
+            self.cycles,
+            self.faces,
+            self.half_edges,
+            self.regions,
+            self.shells,
+            self.sketches,
+            self.solids,
+            self.surfaces,
+            self.vertices,
+            self.wires,
+        ]
+        .into_iter()
+        .fold(StoreUsage::default(), |total, usage| total + usage)
+    }
 }
 
 /// Store for [`Surface`]s
@@ -71,6 +158,11 @@ impl Surfaces {
         self.store.insert(handle, surface);
     }
 
+    /// Report the memory usage of the store
+    pub fn usage(&self) -> StoreUsage {
+        self.store.usage()
+    }
+
     /// Access the xy-plane
     pub fn xy_plane(&self) -> Handle<Surface> {
         self.xy_plane.clone()
@@ -125,3 +217,65 @@ impl Default for Surfaces {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        objects::Solid,
+        operations::{build::BuildSolid, insert::Insert},
+        services::Services,
+        storage::Store,
+    };
+
+    #[test]
+    fn usage() {
+        let mut services = Services::new();
+
+        let before = services.objects.usage();
+        assert_eq!(before.solids.num_objects, 0);
+
+        Solid::spacer(1., 0.5, 1., &mut services).insert(&mut services);
+
+        let after = services.objects.usage();
+        assert_eq!(after.solids.num_objects, 1);
+        assert_eq!(after.shells.num_objects, 1);
+
+        // Bottom, top, and the inner and outer walls.
+        assert_eq!(after.faces.num_objects, 4);
+
+        let [before, after] = [before, after].map(|usage| usage.total());
+        assert!(after.num_objects > before.num_objects);
+        assert!(after.size_of_objects > before.size_of_objects);
+        assert!(after.size_of_objects <= after.size_allocated);
+    }
+
+    #[test]
+    fn deterministic_ids() {
+        let [a, b] = [(), ()].map(|()| {
+            let mut services = Services::new();
+            Solid::spacer(1., 0.5, 1., &mut services).insert(&mut services);
+
+            let objects = &services.objects;
+            let mut ids = Vec::new();
+            ids.extend(ids_of(&objects.curves));
+            ids.extend(ids_of(&objects.cycles));
+            ids.extend(ids_of(&objects.faces));
+            ids.extend(ids_of(&objects.half_edges));
+            ids.extend(ids_of(&objects.regions));
+            ids.extend(ids_of(&objects.shells));
+            ids.extend(ids_of(&objects.solids));
+            ids.extend(ids_of(&objects.vertices));
+
+            ids
+        });
+
+        assert!(!a.is_empty());
+        assert_eq!(a, b);
+    }
+
+    fn ids_of<T>(store: &Store<T>) -> Vec<String> {
+        // The ids identify their store, so ids from different instances of
+        // `Objects` never compare equal. But their debug output is the same.
+        store.iter().map(|handle| format!("{:?}", handle.id())).collect()
+    }
+}
@@ -0,0 +1,926 @@
+//! Boolean operations on 2D shapes
+//!
+//! See [`Boolean`].
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use fj_math::{Circle, Line, Point, Scalar, Vector, Winding};
+
+use crate::{
+    geometry::{CurveBoundary, SurfacePath},
+    objects::{Region, Sketch},
+    services::Services,
+    trace::operation_span,
+    validate::ValidationConfig,
+};
+
+use super::section::{build_cycle, build_sketch, is_same_point, SectionEdge};
+
+/// Combine 2D shapes using boolean operations
+///
+/// The shapes are expected to be defined in the same surface coordinates. Their
+/// edges are intersected with each other, and the resulting pieces are
+/// reassembled into the regions of a new sketch.
+pub trait Boolean {
+    /// Combine both shapes using the provided operation
+    ///
+    /// Progress is reported to `progress` while the operation is running, and
+    /// its cancellation token is checked at the same time.
+    ///
+    /// Returns an error, if the operation was cancelled. Any partial result is
+    /// discarded then. Cancellation is only checked before the result is
+    /// assembled, so a cancelled operation doesn't insert any objects.
+    ///
+    /// Also returns an error, if the edges of the result don't form closed
+    /// cycles. This can only happen, if the cycles of the shapes aren't
+    /// closed to begin with. And if any of the edges is a spiral, which is not
+    /// supported yet, or if any of the cycles encloses no area.
+    fn boolean(
+        &self,
+        other: &Self,
+        operation: BooleanOperation,
+        progress: &mut BooleanProgress,
+        services: &mut Services,
+    ) -> Result<Sketch, BooleanError>;
+
+    /// Compute the union of both shapes
+    ///
+    /// See [`Boolean::boolean`] for the errors that can be returned.
+    fn union(
+        &self,
+        other: &Self,
+        services: &mut Services,
+    ) -> Result<Sketch, BooleanError> {
+        self.boolean(
+            other,
+            BooleanOperation::Union,
+            &mut BooleanProgress::default(),
+            services,
+        )
+    }
+
+    /// Compute the intersection of both shapes
+    ///
+    /// See [`Boolean::boolean`] for the errors that can be returned.
+    fn intersection(
+        &self,
+        other: &Self,
+        services: &mut Services,
+    ) -> Result<Sketch, BooleanError> {
+        self.boolean(
+            other,
+            BooleanOperation::Intersection,
+            &mut BooleanProgress::default(),
+            services,
+        )
+    }
+
+    /// Compute the difference of both shapes, removing `other` from `self`
+    ///
+    /// See [`Boolean::boolean`] for the errors that can be returned.
+    fn difference(
+        &self,
+        other: &Self,
+        services: &mut Services,
+    ) -> Result<Sketch, BooleanError> {
+        self.boolean(
+            other,
+            BooleanOperation::Difference,
+            &mut BooleanProgress::default(),
+            services,
+        )
+    }
+}
+
+impl Boolean for Region {
+    fn boolean(
+        &self,
+        other: &Self,
+        operation: BooleanOperation,
+        progress: &mut BooleanProgress,
+        services: &mut Services,
+    ) -> Result<Sketch, BooleanError> {
+        combine([self], [other], operation, progress, services)
+    }
+}
+
+impl Boolean for Sketch {
+    fn boolean(
+        &self,
+        other: &Self,
+        operation: BooleanOperation,
+        progress: &mut BooleanProgress,
+        services: &mut Services,
+    ) -> Result<Sketch, BooleanError> {
+        operation_span!(
+            "boolean",
+            ?operation,
+            regions = self.regions().len(),
+            regions_other = other.regions().len(),
+        );
+
+        let [a, b] = [self, other].map(|sketch| {
+            sketch
+                .regions()
+                .iter()
+                .map(|region| &**region)
+                .collect::<Vec<_>>()
+        });
+        combine(a, b, operation, progress, services)
+    }
+}
+
+/// A boolean operation, as performed by [`Boolean`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BooleanOperation {
+    /// Combine the areas of both shapes
+    Union,
+
+    /// Keep only the area that both shapes share
+    Intersection,
+
+    /// Remove the area of the second shape from the first one
+    Difference,
+}
+
+/// Track the progress of a boolean operation, and cancel it
+///
+/// The default instance ignores progress, and its operation can't be
+/// cancelled.
+pub struct BooleanProgress<'r> {
+    cancellation: CancellationToken,
+    report: Box<dyn FnMut(f64) + 'r>,
+}
+
+impl<'r> BooleanProgress<'r> {
+    /// Create an instance of `BooleanProgress`
+    ///
+    /// `report` is called with the fraction of the operation that is done, in
+    /// the range from `0.` to `1.`.
+    pub fn new(
+        cancellation: CancellationToken,
+        report: impl FnMut(f64) + 'r,
+    ) -> Self {
+        Self {
+            cancellation,
+            report: Box::new(report),
+        }
+    }
+
+    fn update(&mut self, done: f64) -> Result<(), BooleanError> {
+        (self.report)(done);
+
+        if self.cancellation.is_cancelled() {
+            return Err(BooleanError::Cancelled);
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for BooleanProgress<'_> {
+    fn default() -> Self {
+        Self::new(CancellationToken::new(), |_| {})
+    }
+}
+
+/// A token that can be used to cancel a boolean operation
+///
+/// Clones of the token share their state, so a token that is passed to an
+/// operation can be cancelled from another thread.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Create a token that hasn't been cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the operation that the token was passed to
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Determine whether the token was cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Error performing a boolean operation
+///
+/// See [`Boolean::boolean`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum BooleanError {
+    /// The operation was cancelled
+    #[error("Boolean operation was cancelled")]
+    Cancelled,
+
+    /// The edges of the result don't form closed cycles
+    #[error("Combined regions don't consist of closed cycles")]
+    OpenCycle,
+
+    /// An edge of the shapes is a spiral
+    #[error("Boolean operations on spirals are not supported yet")]
+    Spiral,
+
+    /// A cycle of the shapes encloses no area, so its orientation is undefined
+    #[error("Combined regions have a cycle that encloses no area")]
+    DegenerateCycle,
+}
+
+/// Where a piece of an edge is located, relative to the other shape
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Location {
+    Inside,
+    Outside,
+
+    /// The piece is on the boundary of the other shape
+    ///
+    /// `same_direction` specifies whether the boundary runs in the same
+    /// direction there, which means that both shapes are on the same side of
+    /// it.
+    Boundary {
+        same_direction: bool,
+    },
+}
+
+fn combine<'r>(
+    a: impl IntoIterator<Item = &'r Region>,
+    b: impl IntoIterator<Item = &'r Region>,
+    operation: BooleanOperation,
+    progress: &mut BooleanProgress,
+    services: &mut Services,
+) -> Result<Sketch, BooleanError> {
+    use BooleanOperation::{Difference, Intersection, Union};
+
+    let a = edges_of(a)?;
+    let b = edges_of(b)?;
+
+    // Splitting the edges makes up the first half of the progress, locating
+    // the resulting pieces the second half.
+    let num_edges = (a.len() + b.len()) as f64;
+    let mut pieces_a = Vec::new();
+    let mut pieces_b = Vec::new();
+
+    for (i, edge) in a.iter().enumerate() {
+        pieces_a.extend(split_edge(edge, &b));
+        progress.update((i + 1) as f64 / num_edges / 2.)?;
+    }
+    for (i, edge) in b.iter().enumerate() {
+        pieces_b.extend(split_edge(edge, &a));
+        progress.update((a.len() + i + 1) as f64 / num_edges / 2.)?;
+    }
+
+    let num_pieces = (pieces_a.len() + pieces_b.len()) as f64;
+    let mut edges = Vec::new();
+
+    // Pieces that are on the boundary of both shapes are only taken from `a`,
+    // to not end up with duplicate edges.
+    for (i, piece) in pieces_a.iter().enumerate() {
+        let keep = match (locate(piece, &b), operation) {
+            (Location::Outside, Union | Difference)
+            | (Location::Inside, Intersection) => true,
+            (Location::Boundary { same_direction }, Union | Intersection) => {
+                same_direction
+            }
+            (Location::Boundary { same_direction }, Difference) => {
+                !same_direction
+            }
+            _ => false,
+        };
+
+        if keep {
+            edges.push(*piece);
+        }
+
+        progress.update(0.5 + (i + 1) as f64 / num_pieces / 2.)?;
+    }
+    for (i, piece) in pieces_b.iter().enumerate() {
+        match (locate(piece, &a), operation) {
+            (Location::Outside, Union) | (Location::Inside, Intersection) => {
+                edges.push(*piece);
+            }
+            (Location::Inside, Difference) => {
+                edges.push(piece.reverse());
+            }
+            _ => {}
+        }
+
+        let done = pieces_a.len() + i + 1;
+        progress.update(0.5 + done as f64 / num_pieces / 2.)?;
+    }
+
+    let cycles = connect_edges(edges)?
+        .into_iter()
+        .map(|edges| build_cycle(edges, services))
+        .collect();
+    let sketch = build_sketch(cycles, services);
+
+    (progress.report)(1.);
+
+    Ok(sketch)
+}
+
+/// Determine whether `inner` lies within `outer`
+///
+/// Both regions are expected to be defined in the same surface coordinates.
+/// `inner` must not cross or share any part of the boundary of `outer`, and
+/// must not cover any of the holes in it.
+///
+/// Returns an error, if any of the edges is a spiral.
+pub(crate) fn contains_region(
+    outer: &Region,
+    inner: &Region,
+) -> Result<bool, BooleanError> {
+    let outer = edges_of([outer])?;
+    let inner = edges_of([inner])?;
+
+    let inner_is_inside = inner
+        .iter()
+        .flat_map(|edge| split_edge(edge, &outer))
+        .all(|piece| locate(&piece, &outer) == Location::Inside);
+    let outer_is_outside = outer
+        .iter()
+        .flat_map(|edge| split_edge(edge, &inner))
+        .all(|piece| locate(&piece, &inner) == Location::Outside);
+
+    Ok(inner_is_inside && outer_is_outside)
+}
+
+/// Collect the edges of the regions, oriented such that each region is on the
+/// left side of its edges
+///
+/// Spirals are rejected here, so none of the code that deals with the edges
+/// later has to handle them.
+fn edges_of<'r>(
+    regions: impl IntoIterator<Item = &'r Region>,
+) -> Result<Vec<SectionEdge>, BooleanError> {
+    let mut edges = Vec::new();
+
+    for region in regions {
+        let exterior = [(region.exterior(), Winding::Ccw)];
+        let interiors =
+            region.interiors().iter().map(|cycle| (cycle, Winding::Cw));
+
+        for (cycle, winding) in exterior.into_iter().chain(interiors) {
+            let Some(cycle_winding) = cycle.try_winding() else {
+                return Err(BooleanError::DegenerateCycle);
+            };
+            let reverse = cycle_winding != winding;
+
+            for half_edge in cycle.half_edges() {
+                if let SurfacePath::Spiral(_) = half_edge.path() {
+                    return Err(BooleanError::Spiral);
+                }
+
+                let edge = SectionEdge {
+                    path: half_edge.path(),
+                    boundary: half_edge.boundary(),
+                };
+
+                edges.push(if reverse { edge.reverse() } else { edge });
+            }
+        }
+    }
+
+    Ok(edges)
+}
+
+/// Split the edge at all points where it intersects any of the other edges
+fn split_edge(edge: &SectionEdge, others: &[SectionEdge]) -> Vec<SectionEdge> {
+    let epsilon = ValidationConfig::default().distinct_min_distance;
+
+    let [start, end] = edge.boundary.inner.map(|point| point.t);
+
+    // Where edges overlap, their paths don't intersect in a single point. But
+    // the end points of the other edges split this one there.
+    let mut coords = others
+        .iter()
+        .flat_map(|other| {
+            intersect_paths(edge.path, other.path)
+                .into_iter()
+                .filter(|&point| point_on_edge(other, point).is_some())
+                .chain([other.start(), other.end()])
+                .filter_map(|point| point_on_edge(edge, point))
+        })
+        .filter(|&t| (t - start).abs() > epsilon && (t - end).abs() > epsilon)
+        .collect::<Vec<_>>();
+
+    if end > start {
+        coords.sort();
+    } else {
+        coords.sort_by(|a, b| b.cmp(a));
+    }
+    coords.dedup_by(|a, b| (*a - *b).abs() < epsilon);
+
+    let coords = [start].into_iter().chain(coords).chain([end]);
+    let coords = coords.collect::<Vec<_>>();
+
+    coords
+        .windows(2)
+        .map(|window| SectionEdge {
+            path: edge.path,
+            boundary: CurveBoundary::from(
+                [window[0], window[1]].map(|t| Point::from([t])),
+            ),
+        })
+        .collect()
+}
+
+/// Compute the points where two paths intersect
+///
+/// Paths that coincide are not considered to intersect.
+fn intersect_paths(a: SurfacePath, b: SurfacePath) -> Vec<Point<2>> {
+    match (a, b) {
+        (SurfacePath::Line(a), SurfacePath::Line(b)) => {
+            let cross = a.direction().cross2d(&b.direction());
+            if cross.abs() < Scalar::default_epsilon() {
+                return Vec::new();
+            }
+
+            let t = (b.origin() - a.origin()).cross2d(&b.direction()) / cross;
+            vec![a.origin() + a.direction() * t]
+        }
+        (SurfacePath::Line(line), SurfacePath::Circle(circle))
+        | (SurfacePath::Circle(circle), SurfacePath::Line(line)) => {
+            intersect_line_circle(line, circle)
+        }
+        (SurfacePath::Circle(a), SurfacePath::Circle(b)) => {
+            intersect_circles(a, b)
+        }
+        (SurfacePath::Spiral(_), _) | (_, SurfacePath::Spiral(_)) => {
+            unreachable!("Spirals are rejected when collecting the edges")
+        }
+    }
+}
+
+fn intersect_line_circle(line: Line<2>, circle: Circle<2>) -> Vec<Point<2>> {
+    let direction = line.direction();
+    let to_origin = line.origin() - circle.center();
+
+    // Solve `|origin + direction * t - center| = radius` for `t`.
+    let a = direction.dot(&direction);
+    let b = to_origin.dot(&direction) * 2.;
+    let c = to_origin.dot(&to_origin) - circle.radius() * circle.radius();
+
+    let discriminant = b * b - a * c * 4.;
+    if discriminant < Scalar::ZERO {
+        return Vec::new();
+    }
+
+    let root = Scalar::from(discriminant.into_f64().sqrt());
+    [-1., 1.]
+        .map(|sign| (-b + root * sign) / (a * 2.))
+        .into_iter()
+        .map(|t| line.origin() + direction * t)
+        .collect()
+}
+
+fn intersect_circles(a: Circle<2>, b: Circle<2>) -> Vec<Point<2>> {
+    let [r_a, r_b] = [a.radius(), b.radius()];
+
+    let between = b.center() - a.center();
+    let distance = between.magnitude();
+
+    if distance < Scalar::default_epsilon()
+        || distance > r_a + r_b
+        || distance < (r_a - r_b).abs()
+    {
+        return Vec::new();
+    }
+
+    // The intersection points are on the line that is perpendicular to the
+    // line between the centers, at this distance from the center of `a`.
+    let along = (r_a * r_a - r_b * r_b + distance * distance) / (distance * 2.);
+    let across = Scalar::from(
+        (r_a * r_a - along * along)
+            .max(Scalar::ZERO)
+            .into_f64()
+            .sqrt(),
+    );
+
+    let direction = between / distance;
+    let normal = Vector::from([-direction.v, direction.u]);
+    let base = a.center() + direction * along;
+
+    vec![base + normal * across, base - normal * across]
+}
+
+/// Compute the coordinate of the point on the edge's path, if it is within the
+/// boundary of the edge
+///
+/// Expects the point to be on the path.
+fn coord_on_edge(edge: &SectionEdge, point: Point<2>) -> Option<Scalar> {
+    let epsilon = ValidationConfig::default().distinct_min_distance;
+
+    let [start, end] = edge.boundary.inner.map(|point| point.t);
+    let [min, max] = [start.min(end), start.max(end)];
+
+    let t = match edge.path {
+        SurfacePath::Line(line) => line.point_to_line_coords(point).t,
+        SurfacePath::Circle(circle) => {
+            // Circle coordinates repeat every full turn. We need the one that
+            // falls into the boundary, if any.
+            let angle = circle.point_to_circle_coords(point).t;
+            let turns = ((min - epsilon - angle) / Scalar::TAU).ceil();
+            angle + Scalar::TAU * turns
+        }
+        SurfacePath::Spiral(_) => {
+            unreachable!("Spirals are rejected when collecting the edges")
+        }
+    };
+
+    let is_within = t >= min - epsilon && t <= max + epsilon;
+    is_within.then_some(t)
+}
+
+/// Compute the coordinate of the point on the edge, if it is on the edge
+fn point_on_edge(edge: &SectionEdge, point: Point<2>) -> Option<Scalar> {
+    let t = coord_on_edge(edge, point)?;
+    is_same_point(edge.path.point_from_path_coords([t]), point).then_some(t)
+}
+
+/// The direction in which the edge runs at the provided coordinate
+fn direction_of_edge(edge: &SectionEdge, t: Scalar) -> Vector<2> {
+    let [start, end] = edge.boundary.inner.map(|point| point.t);
+
+    let direction = match edge.path {
+        SurfacePath::Line(line) => line.direction(),
+        SurfacePath::Circle(circle) => {
+            // The derivative of a circle is the same circle, a quarter turn
+            // ahead.
+            circle.vector_from_circle_coords([t + Scalar::PI / 2.])
+        }
+        SurfacePath::Spiral(spiral) => spiral.tangent_at([t]),
+    };
+
+    if end > start {
+        direction
+    } else {
+        -direction
+    }
+}
+
+/// Determine where an edge is located, relative to the shape that is bounded by
+/// the provided edges
+///
+/// The edge must not cross any of the provided edges, which means it is
+/// enough to check its midpoint.
+fn locate(edge: &SectionEdge, others: &[SectionEdge]) -> Location {
+    let [start, end] = edge.boundary.inner.map(|point| point.t);
+    let t = (start + end) / 2.;
+    let point = edge.path.point_from_path_coords([t]);
+
+    for other in others {
+        if let Some(t_other) = point_on_edge(other, point) {
+            let direction = direction_of_edge(edge, t);
+            let direction_other = direction_of_edge(other, t_other);
+
+            return Location::Boundary {
+                same_direction: direction.dot(&direction_other) > Scalar::ZERO,
+            };
+        }
+    }
+
+    if winding_number(others, point) == 0 {
+        Location::Outside
+    } else {
+        Location::Inside
+    }
+}
+
+/// Compute how often the edges wind around the point
+///
+/// Each edge contributes the angle it sweeps out, as seen from the point. For
+/// a line segment, that's the angle between its end points. For an arc, it's
+/// the same, unless the point is located between the arc and its chord. Then
+/// the arc sweeps out an additional full turn.
+fn winding_number(edges: &[SectionEdge], point: Point<2>) -> i64 {
+    let mut angle = Scalar::ZERO;
+
+    for edge in edges {
+        let [a, b] = [edge.start(), edge.end()].map(|p| p - point);
+        angle += a.cross2d(&b).atan2(a.dot(&b));
+
+        if let SurfacePath::Circle(circle) = edge.path {
+            let [start, end] = edge.boundary.inner.map(|point| point.t);
+
+            let is_in_circle =
+                (point - circle.center()).magnitude() < circle.radius();
+            let is_between_arc_and_chord = if edge.is_closed() {
+                is_in_circle
+            } else {
+                let chord = edge.end() - edge.start();
+                let middle =
+                    edge.path.point_from_path_coords([(start + end) / 2.]);
+
+                let side_of_arc = chord.cross2d(&(middle - edge.start()));
+                let side_of_point = chord.cross2d(&(point - edge.start()));
+
+                is_in_circle && side_of_arc.sign() == side_of_point.sign()
+            };
+
+            if is_between_arc_and_chord {
+                // Whether the arc runs counter-clockwise depends on the
+                // orientation of the circle, and on the direction of the edge.
+                let is_ccw = (circle.a().cross2d(&circle.b()) > Scalar::ZERO)
+                    == (end > start);
+
+                if is_ccw {
+                    angle += Scalar::TAU;
+                } else {
+                    angle -= Scalar::TAU;
+                }
+            }
+        }
+    }
+
+    (angle / Scalar::TAU).round().into_f64() as i64
+}
+
+/// Connect the edges into closed cycles
+///
+/// All edges are expected to be oriented consistently already.
+fn connect_edges(
+    mut edges: Vec<SectionEdge>,
+) -> Result<Vec<Vec<SectionEdge>>, BooleanError> {
+    let mut cycles = Vec::new();
+
+    while let Some(first) = edges.pop() {
+        let mut cycle = vec![first];
+
+        loop {
+            let last = cycle.last().expect("Cycle has at least one edge");
+            let end = last.end();
+            if is_same_point(end, first.start()) {
+                break;
+            }
+
+            // Where regions touch in a single point, more than one edge starts
+            // there. Taking the one that turns left the most keeps the region
+            // on the left side of the cycle, and the regions separate.
+            let [_, t] = last.boundary.inner.map(|point| point.t);
+            let direction = direction_of_edge(last, t);
+
+            let next = edges
+                .iter()
+                .enumerate()
+                .filter(|(_, edge)| is_same_point(edge.start(), end))
+                .max_by_key(|(_, edge)| {
+                    let [t, _] = edge.boundary.inner.map(|point| point.t);
+                    let next_direction = direction_of_edge(edge, t);
+
+                    direction
+                        .cross2d(&next_direction)
+                        .atan2(direction.dot(&next_direction))
+                })
+                .map(|(index, _)| index)
+                .ok_or(BooleanError::OpenCycle)?;
+            cycle.push(edges.swap_remove(next));
+        }
+
+        cycles.push(cycle);
+    }
+
+    Ok(cycles)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{InvalidSpiral, Point, Scalar};
+
+    use crate::{
+        geometry::SurfacePath,
+        objects::{Cycle, HalfEdge, Region},
+        operations::{
+            build::{BuildHalfEdge, BuildRegion},
+            insert::Insert,
+        },
+        services::Services,
+    };
+
+    use super::{
+        Boolean, BooleanError, BooleanOperation, BooleanProgress,
+        CancellationToken,
+    };
+
+    #[test]
+    fn difference_of_square_and_circle() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let square = Region::polygon(
+            [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+            &mut services,
+        );
+        let circle = Region::circle([1., 1.], 0.5, &mut services);
+
+        let sketch = square.difference(&circle, &mut services)?;
+
+        let region = sketch.regions().only();
+        assert_eq!(region.exterior().half_edges().len(), 4);
+
+        let interior = region.interiors().only();
+        let half_edge = interior.half_edges().only();
+        assert!(matches!(
+            half_edge.path(),
+            SurfacePath::Circle(circle)
+                if circle.center() == Point::from([1., 1.])
+                    && circle.radius() == Scalar::from(0.5)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn union_of_disjoint_squares() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let a = Region::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut services,
+        );
+        let b = Region::polygon(
+            [[2., 0.], [3., 0.], [3., 1.], [2., 1.]],
+            &mut services,
+        );
+
+        let sketch = a.union(&b, &mut services)?;
+        assert_eq!(sketch.regions().len(), 2);
+
+        // A region that's fully contained in another doesn't add anything to
+        // the union.
+        let c = Region::circle([0.5, 0.5], 0.25, &mut services);
+
+        let sketch = a.union(&c, &mut services)?;
+        let region = sketch.regions().only();
+        assert_eq!(region.exterior().half_edges().len(), 4);
+        assert_eq!(region.interiors().len(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn union_of_squares_touching_at_vertex() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let a = Region::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut services,
+        );
+        let b = Region::polygon(
+            [[1., 1.], [2., 1.], [2., 2.], [1., 2.]],
+            &mut services,
+        );
+
+        // Both squares keep their own boundary, instead of being merged into a
+        // single region that touches itself.
+        let sketch = a.union(&b, &mut services)?;
+        assert_eq!(sketch.regions().len(), 2);
+        for region in sketch.regions() {
+            assert_eq!(region.exterior().half_edges().len(), 4);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn union_with_open_cycle() {
+        let mut services = Services::new();
+
+        let a = Region::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut services,
+        );
+
+        // The last edge of the exterior stops short of where the first one
+        // starts.
+        let exterior = [
+            [[2., 0.], [3., 0.]],
+            [[3., 0.], [3., 1.]],
+            [[3., 1.], [2., 0.5]],
+        ]
+        .map(|points| {
+            HalfEdge::line_segment(points, None, &mut services)
+                .insert(&mut services)
+        });
+        let b =
+            Region::new(Cycle::new(exterior).insert(&mut services), [], None);
+
+        assert_eq!(a.union(&b, &mut services), Err(BooleanError::OpenCycle));
+    }
+
+    #[test]
+    fn union_with_spiral() -> Result<(), InvalidSpiral> {
+        let mut services = Services::new();
+
+        let a = Region::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut services,
+        );
+
+        let spiral = HalfEdge::spiral_from_params(
+            [0.5, 0.5],
+            1.,
+            0.25,
+            [[0.], [1.]],
+            &mut services,
+        )?
+        .insert(&mut services);
+        let b =
+            Region::new(Cycle::new([spiral]).insert(&mut services), [], None);
+
+        assert_eq!(a.union(&b, &mut services), Err(BooleanError::Spiral));
+
+        Ok(())
+    }
+
+    #[test]
+    fn union_with_degenerate_cycle() {
+        let mut services = Services::new();
+
+        let a = Region::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut services,
+        );
+        let b = Region::polygon([[0., 2.], [1., 2.], [2., 2.]], &mut services);
+
+        assert_eq!(
+            a.union(&b, &mut services),
+            Err(BooleanError::DegenerateCycle)
+        );
+    }
+
+    #[test]
+    fn intersection_of_overlapping_squares() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let a = Region::polygon(
+            [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+            &mut services,
+        );
+        let b = Region::polygon(
+            [[1., 1.], [3., 1.], [3., 3.], [1., 3.]],
+            &mut services,
+        );
+
+        let sketch = a.intersection(&b, &mut services)?;
+
+        let region = sketch.regions().only();
+        let mut corners = region
+            .exterior()
+            .half_edges()
+            .iter()
+            .map(|half_edge| half_edge.start_position())
+            .collect::<Vec<_>>();
+        corners.sort();
+
+        let expected = [[1., 1.], [1., 2.], [2., 1.], [2., 2.]];
+        assert_eq!(corners.len(), expected.len());
+        for (corner, expected) in corners.into_iter().zip(expected) {
+            let distance = (corner - Point::from(expected)).magnitude();
+            assert!(distance < Scalar::from(1e-12));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn cancel_operation() {
+        let mut services = Services::new();
+
+        let a = Region::polygon(
+            [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+            &mut services,
+        );
+        let b = Region::circle([1., 1.], 0.5, &mut services);
+
+        let mut reported = Vec::new();
+        let result = a.boolean(
+            &b,
+            BooleanOperation::Difference,
+            &mut BooleanProgress::new(CancellationToken::new(), |done| {
+                reported.push(done)
+            }),
+            &mut services,
+        );
+        assert!(result.is_ok());
+        assert!(reported.windows(2).all(|done| done[0] <= done[1]));
+        assert_eq!(reported.last(), Some(&1.));
+
+        let cancellation = CancellationToken::new();
+        cancellation.cancel();
+
+        let result = a.boolean(
+            &b,
+            BooleanOperation::Difference,
+            &mut BooleanProgress::new(cancellation, |_| {}),
+            &mut services,
+        );
+        assert_eq!(result.map(|_| ()), Err(BooleanError::Cancelled));
+    }
+}
@@ -31,6 +31,8 @@ pub trait BuildFace {
         points: [impl Into<Point<3>>; 3],
         services: &mut Services,
     ) -> Polygon<3> {
+        let points = points.map(|point| services.snap::<3>(point));
+
         let (surface, points_surface) = Surface::plane_from_points(points);
         let surface = surface.insert(services);
 
@@ -1,5 +1,5 @@
 use fj_interop::ext::ArrayExt;
-use fj_math::{Arc, Point, Scalar};
+use fj_math::{Arc, InvalidArc, InvalidSpiral, Point, Scalar};
 
 use crate::{
     geometry::{CurveBoundary, SurfacePath},
@@ -56,14 +56,50 @@ pub trait BuildHalfEdge {
             panic!("arc angle must be in the range (-2pi, 2pi) radians");
         }
 
+        let [start, end] = [start.into(), end.into()]
+            .map(|point: Point<2>| services.snap(point));
+
         let arc = Arc::from_endpoints_and_angle(start, end, angle_rad);
+        unjoined_arc(arc, services)
+    }
 
-        let path =
-            SurfacePath::circle_from_center_and_radius(arc.center, arc.radius);
-        let boundary =
-            [arc.start_angle, arc.end_angle].map(|coord| Point::from([coord]));
+    /// Create an arc that starts at `start`, passes through `through`, and
+    /// ends at `end`
+    ///
+    /// Returns an error, if the points are collinear, as no arc passes through
+    /// them.
+    fn arc_from_three_points(
+        start: impl Into<Point<2>>,
+        through: impl Into<Point<2>>,
+        end: impl Into<Point<2>>,
+        services: &mut Services,
+    ) -> Result<HalfEdge, InvalidArc> {
+        let [start, through, end] = [start.into(), through.into(), end.into()]
+            .map(|point: Point<2>| services.snap(point));
 
-        HalfEdge::unjoined(path, boundary, services)
+        let arc = Arc::from_three_points(start, through, end)?;
+        Ok(unjoined_arc(arc, services))
+    }
+
+    /// Create a counter-clockwise arc from its endpoints and radius
+    ///
+    /// A positive radius creates the minor arc (less than a half turn), a
+    /// negative radius creates the major arc (more than a half turn). See
+    /// [`Arc::from_endpoints_and_radius`].
+    ///
+    /// Returns an error, if the endpoints are coincident, or if the radius is
+    /// too small to connect them.
+    fn arc_from_endpoints_and_radius(
+        start: impl Into<Point<2>>,
+        end: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Result<HalfEdge, InvalidArc> {
+        let [start, end] = [start.into(), end.into()]
+            .map(|point: Point<2>| services.snap(point));
+
+        let arc = Arc::from_endpoints_and_radius(start, end, radius)?;
+        Ok(unjoined_arc(arc, services))
     }
 
     /// Create a circle
@@ -72,6 +108,8 @@ pub trait BuildHalfEdge {
         radius: impl Into<Scalar>,
         services: &mut Services,
     ) -> HalfEdge {
+        let center = services.snap::<2>(center);
+
         let path = SurfacePath::circle_from_center_and_radius(center, radius);
         let boundary =
             [Scalar::ZERO, Scalar::TAU].map(|coord| Point::from([coord]));
@@ -79,12 +117,34 @@ pub trait BuildHalfEdge {
         HalfEdge::unjoined(path, boundary, services)
     }
 
+    /// Create an Archimedean spiral
+    ///
+    /// The half-edge covers the spiral coordinates in `boundary`. See
+    /// [`SurfacePath::spiral_from_params`] for the other parameters.
+    ///
+    /// Returns an error, if `growth` is zero. Use a circle instead.
+    fn spiral_from_params(
+        center: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+        growth: impl Into<Scalar>,
+        boundary: impl Into<CurveBoundary<Point<1>>>,
+        services: &mut Services,
+    ) -> Result<HalfEdge, InvalidSpiral> {
+        let center = services.snap::<2>(center);
+
+        let path = SurfacePath::spiral_from_params(center, radius, growth)?;
+        Ok(HalfEdge::unjoined(path, boundary, services))
+    }
+
     /// Create a line segment
     fn line_segment(
         points_surface: [impl Into<Point<2>>; 2],
         boundary: Option<[Point<1>; 2]>,
         services: &mut Services,
     ) -> HalfEdge {
+        let points_surface =
+            points_surface.map(|point| services.snap::<2>(point));
+
         let boundary =
             boundary.unwrap_or_else(|| [[0.], [1.]].map(Point::from));
         let path = SurfacePath::line_from_points_with_coords(
@@ -96,3 +156,12 @@ pub trait BuildHalfEdge {
 }
 
 impl BuildHalfEdge for HalfEdge {}
+
+fn unjoined_arc(arc: Arc, services: &mut Services) -> HalfEdge {
+    let path =
+        SurfacePath::circle_from_center_and_radius(arc.center, arc.radius);
+    let boundary =
+        [arc.start_angle, arc.end_angle].map(|coord| Point::from([coord]));
+
+    HalfEdge::unjoined(path, boundary, services)
+}
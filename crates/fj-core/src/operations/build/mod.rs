@@ -25,14 +25,18 @@ mod shell;
 mod sketch;
 mod solid;
 mod surface;
+mod wire;
 
 pub use self::{
     cycle::BuildCycle,
     face::{BuildFace, Polygon},
     half_edge::BuildHalfEdge,
-    region::BuildRegion,
-    shell::{BuildShell, TetrahedronShell},
+    region::{BuildRegion, BuildRegionError},
+    shell::{
+        BuildShell, FromTriangleMeshError, MergedPoints, TetrahedronShell,
+    },
     sketch::BuildSketch,
     solid::{BuildSolid, Tetrahedron},
     surface::BuildSurface,
+    wire::BuildWire,
 };
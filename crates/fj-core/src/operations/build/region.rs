@@ -1,9 +1,11 @@
+use fj_interop::mesh::Color;
 use fj_math::{Point, Scalar};
 
 use crate::{
     objects::{Cycle, Region},
-    operations::{build::BuildCycle, insert::Insert},
+    operations::{build::BuildCycle, insert::Insert, reverse::Reverse},
     services::Services,
+    storage::Handle,
 };
 
 /// Build a [`Region`]
@@ -41,6 +43,169 @@ pub trait BuildRegion {
         let exterior = Cycle::polygon(points, services).insert(services);
         Region::new(exterior, [], None)
     }
+
+    /// Build and insert a polygonal region from a list of points
+    ///
+    /// This is meant for quick prototyping and tests, and requires as little
+    /// ceremony as possible. The polygon is closed automatically. If the last
+    /// point is the same as the first one, it is ignored.
+    ///
+    /// Returns an error, if the points don't enclose any area, which is the
+    /// case if there are less than 3 of them, or if they are all collinear.
+    fn from_points(
+        points: impl IntoIterator<Item = impl Into<Point<2>>>,
+        services: &mut Services,
+    ) -> Result<Handle<Region>, BuildRegionError> {
+        let mut points = points
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<Point<2>>>();
+
+        if points.len() > 1 && points.first() == points.last() {
+            points.pop();
+        }
+
+        let area =
+            points
+                .iter()
+                .enumerate()
+                .fold(Scalar::ZERO, |area, (i, a)| {
+                    let b = points[(i + 1) % points.len()];
+                    area + a.coords.cross2d(&b.coords)
+                });
+        if area.abs() <= Scalar::default_epsilon() {
+            return Err(BuildRegionError::NoArea { points });
+        }
+
+        Ok(Region::polygon(points, services).insert(services))
+    }
+
+    /// Build a region, reversing interior cycles as necessary
+    ///
+    /// To form holes, the interior cycles of a region must be wound in the
+    /// opposite direction of the exterior cycle. Any interior cycle that is
+    /// wound in the same direction is reversed, before it is added to the
+    /// region.
+    ///
+    /// Returns an error, if any of the cycles doesn't enclose any area, as its
+    /// winding is undefined then.
+    fn with_interiors_auto_orient(
+        exterior: Handle<Cycle>,
+        interiors: impl IntoIterator<Item = Handle<Cycle>>,
+        color: Option<Color>,
+        services: &mut Services,
+    ) -> Result<Region, BuildRegionError> {
+        let winding_of = |cycle: &Handle<Cycle>| {
+            cycle.try_winding().ok_or_else(|| {
+                BuildRegionError::DegenerateCycle {
+                    cycle: cycle.clone(),
+                }
+            })
+        };
+
+        let exterior_winding = winding_of(&exterior)?;
+
+        let interiors = interiors
+            .into_iter()
+            .map(|interior| {
+                if winding_of(&interior)? == exterior_winding {
+                    Ok(interior.reverse(services).insert(services))
+                } else {
+                    Ok(interior)
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Region::new(exterior, interiors, color))
+    }
 }
 
 impl BuildRegion for Region {}
+
+/// Error building a [`Region`]
+///
+/// See [`BuildRegion::from_points`] and
+/// [`BuildRegion::with_interiors_auto_orient`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum BuildRegionError {
+    /// The points don't enclose any area
+    #[error("Points of region don't enclose any area: {points:?}")]
+    NoArea {
+        /// The points, without the closing one, if it was provided
+        points: Vec<Point<2>>,
+    },
+
+    /// A cycle doesn't enclose any area, so its winding is undefined
+    #[error("Cycle of region doesn't enclose any area")]
+    DegenerateCycle {
+        /// The cycle that doesn't enclose any area
+        cycle: Handle<Cycle>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::{
+        objects::{Cycle, Face, Region},
+        operations::{
+            build::{BuildCycle, BuildRegion},
+            insert::Insert,
+        },
+        services::Services,
+        validate::Validate,
+    };
+
+    #[test]
+    fn with_interiors_auto_orient() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let exterior = Cycle::circle(Point::origin(), 1., &mut services)
+            .insert(&mut services);
+        let interior = Cycle::circle(Point::origin(), 0.5, &mut services)
+            .insert(&mut services);
+        assert_eq!(exterior.winding(), interior.winding());
+
+        let region = Region::with_interiors_auto_orient(
+            exterior,
+            [interior],
+            None,
+            &mut services,
+        )?;
+        for interior in region.interiors() {
+            assert_ne!(interior.winding(), region.exterior().winding());
+        }
+
+        let surface = services.objects.surfaces.xy_plane();
+        let face = Face::new(surface, region.insert(&mut services));
+        face.validate_and_return_first_error()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_points() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let square = [[0., 0.], [1., 0.], [1., 1.], [0., 1.]];
+        let region = Region::from_points(square, &mut services)?;
+        assert_eq!(region.exterior().half_edges().len(), 4);
+
+        // Explicitly closing the polygon doesn't make a difference.
+        let closed = square.into_iter().chain([square[0]]);
+        let region = Region::from_points(closed, &mut services)?;
+        assert_eq!(region.exterior().half_edges().len(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_collinear_points() {
+        let mut services = Services::new();
+
+        let result =
+            Region::from_points([[0., 0.], [1., 0.], [2., 0.]], &mut services);
+        assert!(result.is_err());
+    }
+}
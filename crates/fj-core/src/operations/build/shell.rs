@@ -1,7 +1,12 @@
-use fj_math::Point;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use fj_interop::mesh::Mesh;
+use fj_math::{Line, Point, Scalar};
 
 use crate::{
-    objects::{Face, Shell},
+    algorithms::approx::Tolerance,
+    geometry::{GlobalPath, SurfaceGeometry, SurfacePath},
+    objects::{Curve, Cycle, Face, HalfEdge, Region, Shell, Surface, Vertex},
     operations::{
         build::{BuildFace, Polygon},
         insert::{Insert, IsInserted, IsInsertedNo, IsInsertedYes},
@@ -10,6 +15,7 @@ use crate::{
         update::{UpdateCycle, UpdateFace, UpdateRegion},
     },
     services::Services,
+    validate::ValidationConfig,
 };
 
 /// Build a [`Shell`]
@@ -65,6 +71,7 @@ pub trait BuildShell {
                             0..=0,
                             services,
                         )
+                        .expect("Ranges have the same length")
                         .insert(services)
                 })
                 .insert(services)
@@ -86,6 +93,7 @@ pub trait BuildShell {
                             2..=2,
                             services,
                         )
+                        .expect("Ranges have the same length")
                         .update_half_edge(
                             cycle.half_edges().nth_circular(0),
                             |edge| {
@@ -99,6 +107,7 @@ pub trait BuildShell {
                             1..=1,
                             services,
                         )
+                        .expect("Ranges have the same length")
                         .insert(services)
                 })
                 .insert(services)
@@ -134,18 +143,21 @@ pub trait BuildShell {
                             1..=1,
                             services,
                         )
+                        .expect("Ranges have the same length")
                         .join_to(
                             bad.face.region().exterior(),
                             1..=1,
                             2..=2,
                             services,
                         )
+                        .expect("Ranges have the same length")
                         .join_to(
                             dac.face.region().exterior(),
                             2..=2,
                             2..=2,
                             services,
                         )
+                        .expect("Ranges have the same length")
                         .insert(services)
                 })
                 .insert(services)
@@ -166,6 +178,139 @@ pub trait BuildShell {
             cbd,
         }
     }
+
+    /// Build a shell from a triangle mesh
+    ///
+    /// Vertices of the mesh that are within `tolerance` of each other are
+    /// merged. Adjacent triangles that lie in the same plane are merged into a
+    /// single planar face, which is bounded by the mesh edges around that group
+    /// of triangles. Curved regions of the mesh are not detected, and remain
+    /// faceted, as many small planar faces.
+    ///
+    /// Triangles that are degenerate within the tolerance, meaning that one of
+    /// their points is that close to the line through the other two, don't
+    /// span any area that could be represented. They are skipped.
+    ///
+    /// The mesh is expected to be closed, manifold, and consistently oriented,
+    /// with the counter-clockwise sides of its triangles facing outward. If it
+    /// isn't, the resulting shell is going to be invalid. Returns an error, if
+    /// this results in faces that can't be built, because multiple triangles
+    /// share an edge in the same direction, or because the boundary of a face
+    /// isn't closed.
+    fn from_triangle_mesh(
+        mesh: &Mesh<Point<3>>,
+        tolerance: impl Into<Tolerance>,
+        services: &mut Services,
+    ) -> Result<Shell, FromTriangleMeshError> {
+        let tolerance = tolerance.into();
+
+        let mut points = MergedPoints::new(tolerance);
+        let mut triangles = Vec::new();
+        let mut colors = Vec::new();
+
+        for triangle in mesh.triangles() {
+            let indices =
+                triangle.inner.points().map(|point| points.index_of(point));
+
+            // Merging points might have collapsed the triangle, or it might
+            // have been too thin to begin with.
+            if points.is_degenerate(indices) {
+                continue;
+            }
+
+            triangles.push(indices);
+            colors.push(triangle.color);
+        }
+
+        let points = points.into_points();
+        let max_distance_from_plane = (tolerance.inner()
+            * MAX_DISTANCE_FROM_PLANE)
+            .min(ValidationConfig::default().identical_max_distance);
+        let vertices = points
+            .iter()
+            .map(|_| Vertex::new().insert(services))
+            .collect::<Vec<_>>();
+        let mut curves = BTreeMap::new();
+
+        let mut faces = Vec::new();
+        for group in
+            coplanar_groups(&points, &triangles, max_distance_from_plane)?
+        {
+            let [a, b, c] = triangles[group[0]].map(|i| points[i]);
+
+            let normal = (b - a).cross(&(c - a)).normalize();
+            let u = (b - a).normalize();
+            let v = normal.cross(&u);
+
+            let surface = Surface::new(SurfaceGeometry {
+                u: GlobalPath::Line(Line::from_origin_and_direction(a, u)),
+                v,
+            })
+            .insert(services);
+            let to_surface = |i: usize| {
+                let vector = points[i] - a;
+                Point::from([vector.dot(&u), vector.dot(&v)])
+            };
+
+            let mut cycles = Vec::new();
+            for boundary_loop in boundary_loops(&points, &triangles, &group)? {
+                let mut half_edges = Vec::new();
+                let mut area = Scalar::ZERO;
+
+                let ends = boundary_loop.iter().cycle().skip(1);
+                for (&start, &end) in boundary_loop.iter().zip(ends) {
+                    // Both half-edges of an edge refer to the same curve. Its
+                    // coordinates go from the vertex with the lower index to
+                    // the one with the higher index.
+                    let (low, high, boundary) = if start < end {
+                        (start, end, [[0.], [1.]])
+                    } else {
+                        (end, start, [[1.], [0.]])
+                    };
+
+                    let path = SurfacePath::line_from_points_with_coords([
+                        ([0.], to_surface(low)),
+                        ([1.], to_surface(high)),
+                    ]);
+                    let curve = curves
+                        .entry([low, high])
+                        .or_insert_with(|| Curve::new().insert(services))
+                        .clone();
+
+                    half_edges.push(
+                        HalfEdge::new(
+                            path,
+                            boundary,
+                            curve,
+                            vertices[start].clone(),
+                        )
+                        .insert(services),
+                    );
+                    area += to_surface(start)
+                        .coords
+                        .cross2d(&to_surface(end).coords);
+                }
+
+                cycles.push((Cycle::new(half_edges).insert(services), area));
+            }
+
+            // The exterior of the face is counter-clockwise and encloses all
+            // the other cycles, so it has the largest signed area.
+            cycles.sort_by(|(_, a), (_, b)| b.cmp(a));
+            let mut cycles = cycles.into_iter().map(|(cycle, _)| cycle);
+            let Some(exterior) = cycles.next() else {
+                return Err(FromTriangleMeshError::InvalidBoundary {
+                    point: points[triangles[group[0]][0]],
+                });
+            };
+
+            let region = Region::new(exterior, cycles, Some(colors[group[0]]))
+                .insert(services);
+            faces.push(Face::new(surface, region).insert(services));
+        }
+
+        Ok(Shell::new(faces))
+    }
 }
 
 impl BuildShell for Shell {}
@@ -193,3 +338,259 @@ pub struct TetrahedronShell<I: IsInserted = IsInsertedNo> {
     /// The face formed by the points `c`, `b`, and `d`.
     pub cbd: Polygon<3, IsInsertedYes>,
 }
+
+/// Error building a shell from a triangle mesh
+///
+/// See [`BuildShell::from_triangle_mesh`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum FromTriangleMeshError {
+    /// Multiple triangles share an edge in the same direction
+    ///
+    /// This means that the mesh is not manifold, or not consistently oriented.
+    #[error("Multiple triangles share edge from {a:?} to {b:?}")]
+    DuplicateEdge {
+        /// The start of the edge
+        a: Point<3>,
+
+        /// The end of the edge
+        b: Point<3>,
+    },
+
+    /// The boundary of a face doesn't consist of closed loops
+    ///
+    /// This means that the mesh is not closed, or not manifold.
+    #[error("Boundary of face is not closed at {point:?}")]
+    InvalidBoundary {
+        /// A point on the face, where its boundary is not closed
+        point: Point<3>,
+    },
+}
+
+/// The maximum distance of a vertex from the plane of the face it ends up in,
+/// relative to the tolerance
+///
+/// Vertices are not moved into that plane, so the distance is also limited to
+/// what validation considers to be identical points. Otherwise, the resulting
+/// shell wouldn't pass validation.
+const MAX_DISTANCE_FROM_PLANE: f64 = 1e-8;
+
+/// Points of a mesh, with points that are close to each other merged
+///
+/// Points that are within the tolerance of each other are considered to be the
+/// same point. This is what [`BuildShell::from_triangle_mesh`] uses to find the
+/// vertices of a mesh.
+pub struct MergedPoints {
+    tolerance: Scalar,
+    points: Vec<Point<3>>,
+    points_by_cell: BTreeMap<[i64; 3], Vec<usize>>,
+}
+
+impl MergedPoints {
+    /// Create an empty instance of `MergedPoints`
+    pub fn new(tolerance: impl Into<Tolerance>) -> Self {
+        let tolerance = tolerance.into();
+
+        Self {
+            tolerance: tolerance.inner(),
+            points: Vec::new(),
+            points_by_cell: BTreeMap::new(),
+        }
+    }
+
+    /// Return the index of the point, adding it, if no close point exists yet
+    pub fn index_of(&mut self, point: Point<3>) -> usize {
+        // Space is divided into cubic cells, whose size matches the tolerance.
+        // Any points close enough to be merged with `point` must be in the same
+        // cell, or in one of the adjacent ones.
+        let cell = point
+            .coords
+            .components
+            .map(|coord| (coord / self.tolerance).floor().into_f64() as i64);
+
+        for x in -1..=1 {
+            for y in -1..=1 {
+                for z in -1..=1 {
+                    let neighbor = [cell[0] + x, cell[1] + y, cell[2] + z];
+
+                    let Some(indices) = self.points_by_cell.get(&neighbor)
+                    else {
+                        continue;
+                    };
+
+                    for &index in indices {
+                        if self.points[index].distance_to(&point)
+                            <= self.tolerance
+                        {
+                            return index;
+                        }
+                    }
+                }
+            }
+        }
+
+        let index = self.points.len();
+        self.points.push(point);
+        self.points_by_cell.entry(cell).or_default().push(index);
+
+        index
+    }
+
+    /// Determine whether the triangle with the given points is degenerate
+    ///
+    /// A triangle is degenerate, if any of its points is within the tolerance
+    /// of the line through the other two. This includes triangles whose points
+    /// have been merged. Such a triangle has no well-defined normal.
+    pub fn is_degenerate(&self, triangle: [usize; 3]) -> bool {
+        let [a, b, c] = triangle.map(|i| self.points[i]);
+
+        let longest_edge = [b - a, c - b, a - c]
+            .into_iter()
+            .fold(Scalar::ZERO, |longest, edge| longest.max(edge.magnitude()));
+
+        // The cross product is twice the area of the triangle, which is its
+        // longest edge times the distance of the opposite point from that
+        // edge.
+        let area_times_two = (b - a).cross(&(c - a)).magnitude();
+        area_times_two <= longest_edge * self.tolerance
+    }
+
+    /// Access the point with the given index
+    pub fn point(&self, index: usize) -> Point<3> {
+        self.points[index]
+    }
+
+    /// Return all points, in the order of their indices
+    pub fn into_points(self) -> Vec<Point<3>> {
+        self.points
+    }
+}
+
+/// Group connected triangles that lie in the same plane
+///
+/// Returns the indices of the triangles in each group.
+fn coplanar_groups(
+    points: &[Point<3>],
+    triangles: &[[usize; 3]],
+    max_distance_from_plane: Scalar,
+) -> Result<Vec<Vec<usize>>, FromTriangleMeshError> {
+    let mut triangles_by_edge = BTreeMap::new();
+    for (i, &[a, b, c]) in triangles.iter().enumerate() {
+        for [p, q] in [[a, b], [b, c], [c, a]] {
+            if triangles_by_edge.insert([p, q], i).is_some() {
+                return Err(FromTriangleMeshError::DuplicateEdge {
+                    a: points[p],
+                    b: points[q],
+                });
+            }
+        }
+    }
+
+    let normal_of = |[a, b, c]: [usize; 3]| {
+        (points[b] - points[a])
+            .cross(&(points[c] - points[a]))
+            .normalize()
+    };
+
+    let mut group_of_triangle = vec![None; triangles.len()];
+    let mut groups = Vec::new();
+
+    for (start, &triangle) in triangles.iter().enumerate() {
+        if group_of_triangle[start].is_some() {
+            continue;
+        }
+
+        let origin = points[triangle[0]];
+        let normal = normal_of(triangle);
+
+        let mut group = Vec::new();
+        let mut queue = VecDeque::from([start]);
+        group_of_triangle[start] = Some(groups.len());
+
+        while let Some(i) = queue.pop_front() {
+            group.push(i);
+
+            let [a, b, c] = triangles[i];
+            for [p, q] in [[a, b], [b, c], [c, a]] {
+                // The triangle on the other side of the edge, if any, must
+                // contain the edge in the opposite direction.
+                let Some(&j) = triangles_by_edge.get(&[q, p]) else {
+                    continue;
+                };
+                if group_of_triangle[j].is_some() {
+                    continue;
+                }
+
+                let faces_same_way =
+                    normal_of(triangles[j]).dot(&normal) > Scalar::ZERO;
+                let is_in_plane = triangles[j].iter().all(|&k| {
+                    (points[k] - origin).dot(&normal).abs()
+                        <= max_distance_from_plane
+                });
+
+                if faces_same_way && is_in_plane {
+                    group_of_triangle[j] = Some(groups.len());
+                    queue.push_back(j);
+                }
+            }
+        }
+
+        groups.push(group);
+    }
+
+    Ok(groups)
+}
+
+/// Find the closed loops of vertices that bound a group of triangles
+fn boundary_loops(
+    points: &[Point<3>],
+    triangles: &[[usize; 3]],
+    group: &[usize],
+) -> Result<Vec<Vec<usize>>, FromTriangleMeshError> {
+    let edges = group
+        .iter()
+        .flat_map(|&i| {
+            let [a, b, c] = triangles[i];
+            [[a, b], [b, c], [c, a]]
+        })
+        .collect::<BTreeSet<_>>();
+
+    // An edge is on the boundary, if the group doesn't contain the triangle on
+    // its other side.
+    let mut boundary_edges = BTreeMap::<_, Vec<_>>::new();
+    for &[a, b] in &edges {
+        if !edges.contains(&[b, a]) {
+            boundary_edges.entry(a).or_default().push(b);
+        }
+    }
+
+    let mut loops = Vec::new();
+    while let Some((&start, _)) = boundary_edges.first_key_value() {
+        let mut vertices = vec![start];
+        let mut current = start;
+
+        loop {
+            // Vertices are removed, once they don't have any boundary edges
+            // left. So if there's none here, the boundary is not closed.
+            let Some(end) = boundary_edges.get_mut(&current).and_then(Vec::pop)
+            else {
+                return Err(FromTriangleMeshError::InvalidBoundary {
+                    point: points[current],
+                });
+            };
+            if boundary_edges.get(&current).is_some_and(Vec::is_empty) {
+                boundary_edges.remove(&current);
+            }
+
+            if end == start {
+                break;
+            }
+
+            vertices.push(end);
+            current = end;
+        }
+
+        loops.push(vertices);
+    }
+
+    Ok(loops)
+}
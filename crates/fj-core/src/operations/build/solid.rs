@@ -1,11 +1,20 @@
-use fj_math::Point;
+use std::f64::consts::{PI, TAU};
+
+use fj_interop::mesh::{Color, Mesh};
+use fj_math::{Point, Scalar, Vector};
 
 use crate::{
-    objects::{Shell, Solid},
+    algorithms::approx::Tolerance,
+    objects::{Cycle, Region, Shell, Sketch, Solid},
     operations::{
-        build::{BuildShell, TetrahedronShell},
+        build::{
+            BuildCycle, BuildRegion, BuildShell, BuildSketch,
+            FromTriangleMeshError, TetrahedronShell,
+        },
         insert::{Insert, IsInsertedYes},
-        update::UpdateSolid,
+        reverse::Reverse,
+        sweep::SweepSketch,
+        update::{UpdateRegion, UpdateSketch, UpdateSolid},
     },
     services::Services,
 };
@@ -33,6 +42,226 @@ pub trait BuildSolid {
 
         Tetrahedron { solid, shell }
     }
+
+    /// Build a solid from a triangle mesh
+    ///
+    /// See [`BuildShell::from_triangle_mesh`] for more information.
+    fn from_triangle_mesh(
+        mesh: &Mesh<Point<3>>,
+        tolerance: impl Into<Tolerance>,
+        services: &mut Services,
+    ) -> Result<Solid, FromTriangleMeshError> {
+        let shell = Shell::from_triangle_mesh(mesh, tolerance, services)?
+            .insert(services);
+        Ok(Solid::empty().add_shells([shell]))
+    }
+
+    /// Build a cuboid with the provided edge lengths
+    ///
+    /// The cuboid stands on the xy-plane, centered on the z-axis.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the edge length along the x- or y-axis is zero, or if exactly
+    /// one of them is negative.
+    fn cuboid(size: impl Into<Vector<3>>, services: &mut Services) -> Solid {
+        let [x, y, z] = size.into().components;
+
+        let surface = services.objects.surfaces.xy_plane();
+        let region = Region::polygon(
+            [
+                [-x / 2., -y / 2.],
+                [x / 2., -y / 2.],
+                [x / 2., y / 2.],
+                [-x / 2., y / 2.],
+            ],
+            services,
+        )
+        .insert(services);
+
+        Sketch::empty()
+            .add_region(region)
+            .sweep_sketch(surface, [Scalar::ZERO, Scalar::ZERO, z], services)
+            .expect("Cuboid needs positive edge lengths")
+    }
+
+    /// Build a cylinder
+    ///
+    /// The cylinder stands on the xy-plane, centered on the z-axis.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the radius is zero.
+    fn cylinder(
+        radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Solid {
+        let surface = services.objects.surfaces.xy_plane();
+        let region =
+            Region::circle(Point::origin(), radius, services).insert(services);
+
+        Sketch::empty()
+            .add_region(region)
+            .sweep_sketch(
+                surface,
+                [Scalar::ZERO, Scalar::ZERO, height.into()],
+                services,
+            )
+            .expect("Cylinder needs a radius")
+    }
+
+    /// Build a faceted cone
+    ///
+    /// The base of the cone is on the xy-plane, centered on the z-axis, with
+    /// its tip on the z-axis at the provided height. The lateral surface of the
+    /// cone is divided into `num_segments` planar faces.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `num_segments` is less than 3.
+    fn faceted_cone(
+        radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+        num_segments: usize,
+        services: &mut Services,
+    ) -> Solid {
+        assert!(num_segments >= 3, "Cone needs at least 3 segments");
+
+        let radius = radius.into().into_f64();
+        let height = height.into().into_f64();
+
+        let point = |segment: usize| {
+            let phi =
+                TAU * (segment % num_segments) as f64 / num_segments as f64;
+            Point::from([radius * phi.cos(), radius * phi.sin(), 0.])
+        };
+        let tip = Point::from([0., 0., height]);
+        let center = Point::origin();
+
+        let mut mesh = Mesh::new();
+        for segment in 0..num_segments {
+            let next = segment + 1;
+
+            mesh.push_triangle(
+                [point(segment), point(next), tip],
+                Color::default(),
+            );
+            mesh.push_triangle(
+                [center, point(next), point(segment)],
+                Color::default(),
+            );
+        }
+
+        // See `faceted_sphere` for the choice of tolerance.
+        let tolerance = radius.max(height) * 1e-9;
+
+        Solid::from_triangle_mesh(&mesh, tolerance, services)
+            .expect("Mesh of faceted cone is closed and manifold")
+    }
+
+    /// Build a spacer, a cylinder with a cylindrical hole through it
+    ///
+    /// The spacer stands on the xy-plane, centered on the z-axis.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the outer radius is zero.
+    fn spacer(
+        outer_radius: impl Into<Scalar>,
+        inner_radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Solid {
+        let surface = services.objects.surfaces.xy_plane();
+
+        let hole = Cycle::circle(Point::origin(), inner_radius, services)
+            .reverse(services)
+            .insert(services);
+        let region = Region::circle(Point::origin(), outer_radius, services)
+            .add_interiors([hole])
+            .insert(services);
+
+        Sketch::empty()
+            .add_region(region)
+            .sweep_sketch(
+                surface,
+                [Scalar::ZERO, Scalar::ZERO, height.into()],
+                services,
+            )
+            .expect("Spacer needs an outer radius")
+    }
+
+    /// Build a faceted sphere, centered on the origin
+    ///
+    /// The sphere is divided into `num_segments` segments around the z-axis,
+    /// and half as many rings from pole to pole. Each facet of the sphere is a
+    /// separate planar face, which makes this a good stress test for code that
+    /// deals with many faces.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `num_segments` is less than 4.
+    fn faceted_sphere(
+        radius: impl Into<Scalar>,
+        num_segments: usize,
+        services: &mut Services,
+    ) -> Solid {
+        assert!(num_segments >= 4, "Sphere needs at least 4 segments");
+
+        let radius = radius.into().into_f64();
+        let num_rings = num_segments / 2;
+
+        let point = |ring: usize, segment: usize| {
+            let theta = PI * ring as f64 / num_rings as f64;
+            let phi =
+                TAU * (segment % num_segments) as f64 / num_segments as f64;
+
+            Point::from([
+                radius * theta.sin() * phi.cos(),
+                radius * theta.sin() * phi.sin(),
+                radius * theta.cos(),
+            ])
+        };
+        let top = Point::from([0., 0., radius]);
+        let bottom = Point::from([0., 0., -radius]);
+
+        let mut mesh = Mesh::new();
+        for segment in 0..num_segments {
+            let next = segment + 1;
+
+            mesh.push_triangle(
+                [top, point(1, segment), point(1, next)],
+                Color::default(),
+            );
+
+            for ring in 1..num_rings - 1 {
+                let [a, b, c, d] = [
+                    point(ring, segment),
+                    point(ring + 1, segment),
+                    point(ring + 1, next),
+                    point(ring, next),
+                ];
+
+                mesh.push_triangle([a, b, c], Color::default());
+                mesh.push_triangle([a, c, d], Color::default());
+            }
+
+            let last = num_rings - 1;
+            mesh.push_triangle(
+                [bottom, point(last, next), point(last, segment)],
+                Color::default(),
+            );
+        }
+
+        // Points of the mesh that are closer than this are merged. This needs
+        // to be smaller than the distance between any two distinct points, for
+        // any reasonable number of segments.
+        let tolerance = radius * 1e-9;
+
+        Solid::from_triangle_mesh(&mesh, tolerance, services)
+            .expect("Mesh of faceted sphere is closed and manifold")
+    }
 }
 
 impl BuildSolid for Solid {}
@@ -47,3 +276,155 @@ pub struct Tetrahedron {
     /// The shell of the tetrahedron
     pub shell: TetrahedronShell<IsInsertedYes>,
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::mesh::{Color, Mesh};
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::{approx::Tolerance, triangulate::Triangulate},
+        fixtures::{volume, volume_of_mesh},
+        objects::Solid,
+        operations::build::FromTriangleMeshError,
+        services::Services,
+        validate::Validate,
+    };
+
+    use super::BuildSolid;
+
+    #[test]
+    fn from_triangle_mesh() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        let spacer = Solid::spacer(1., 0.5, 1., &mut services);
+
+        let mesh = (&spacer, tolerance).triangulate()?;
+        let solid = Solid::from_triangle_mesh(&mesh, tolerance, &mut services)?;
+
+        solid.validate_and_return_first_error()?;
+        for shell in solid.shells() {
+            shell.validate_and_return_first_error()?;
+        }
+
+        // The top and bottom faces of the spacer are each imported as a single
+        // face, while its cylindrical walls remain faceted.
+        let volume = volume(&solid, tolerance)?;
+        let volume_of_mesh = volume_of_mesh(&mesh);
+        assert!((volume - volume_of_mesh).abs() < Scalar::from(1e-9));
+
+        let volume_of_spacer = Scalar::PI * (1. - 0.5 * 0.5);
+        assert!((volume - volume_of_spacer).abs() < volume_of_spacer * 0.05);
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_triangle_mesh_with_degenerate_triangle() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let [a, b, c, d] =
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]
+                .map(Point::from);
+
+        // The bottom face of the tetrahedron has a point right next to its
+        // edge from `a` to `b`. The sliver between that point and the edge is
+        // closed by a triangle that is too thin to have a normal.
+        let m = Point::from([0.5, 1e-12, 0.]);
+
+        let mut mesh = Mesh::new();
+        for triangle in [
+            [a, c, m],
+            [c, b, m],
+            [a, m, b],
+            [a, b, d],
+            [a, d, c],
+            [b, c, d],
+        ] {
+            mesh.push_triangle(triangle, Color::default());
+        }
+
+        let solid = Solid::from_triangle_mesh(&mesh, 1e-9, &mut services)?;
+        assert_eq!(solid.shells().only().faces().len(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_triangle_mesh_with_duplicate_edge() {
+        let mut services = Services::new();
+
+        let [a, b, c, d] =
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]
+                .map(Point::from);
+
+        // The bottom face of the tetrahedron is there twice.
+        let mut mesh = Mesh::new();
+        for triangle in [[a, c, b], [a, b, d], [a, d, c], [b, c, d], [a, c, b]]
+        {
+            mesh.push_triangle(triangle, Color::default());
+        }
+
+        assert_eq!(
+            Solid::from_triangle_mesh(&mesh, 1e-9, &mut services),
+            Err(FromTriangleMeshError::DuplicateEdge { a, b: c }),
+        );
+    }
+
+    #[test]
+    fn faceted_sphere() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        let sphere = Solid::faceted_sphere(1., 16, &mut services);
+
+        sphere.validate_and_return_first_error()?;
+        for shell in sphere.shells() {
+            shell.validate_and_return_first_error()?;
+        }
+
+        // The volume of the faceted sphere is a bit smaller than that of a
+        // real one.
+        let volume = volume(&sphere, tolerance)?;
+        let volume_of_sphere = Scalar::PI * 4. / 3.;
+        assert!(volume < volume_of_sphere);
+        assert!(volume > volume_of_sphere * 0.9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn primitives() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        let cuboid = Solid::cuboid([1., 2., 3.], &mut services);
+        let cylinder = Solid::cylinder(1., 2., &mut services);
+        let cone = Solid::faceted_cone(1., 2., 32, &mut services);
+
+        for solid in [&cuboid, &cylinder, &cone] {
+            solid.validate_and_return_first_error()?;
+            for shell in solid.shells() {
+                shell.validate_and_return_first_error()?;
+            }
+        }
+
+        let volume_of_cuboid = volume(&cuboid, tolerance)?;
+        assert!((volume_of_cuboid - 6.).abs() < Scalar::from(1e-9));
+
+        // The volumes of the approximated cylinder and the faceted cone are a
+        // bit smaller than those of the real ones.
+        let expected = Scalar::PI * 2.;
+        let volume_of_cylinder = volume(&cylinder, tolerance)?;
+        assert!(volume_of_cylinder < expected);
+        assert!(volume_of_cylinder > expected * 0.95);
+
+        let expected = Scalar::PI * 2. / 3.;
+        let volume_of_cone = volume(&cone, tolerance)?;
+        assert!(volume_of_cone < expected);
+        assert!(volume_of_cone > expected * 0.95);
+
+        Ok(())
+    }
+}
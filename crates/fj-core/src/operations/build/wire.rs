@@ -0,0 +1,43 @@
+use fj_math::Point;
+use itertools::Itertools;
+
+use crate::{
+    objects::{HalfEdge, Wire},
+    operations::{build::BuildHalfEdge, insert::Insert},
+    services::Services,
+};
+
+/// Build a [`Wire`]
+///
+/// See [module-level documentation] for context.
+///
+/// [module-level documentation]: super
+pub trait BuildWire {
+    /// Build an empty wire
+    fn empty() -> Wire {
+        Wire::new([])
+    }
+
+    /// Build a polyline
+    ///
+    /// Creates a line segment between each pair of neighboring points. Unlike
+    /// [`BuildCycle::polygon`], the last point is not connected to the first.
+    ///
+    /// [`BuildCycle::polygon`]: super::BuildCycle::polygon
+    fn polyline<P, Ps>(points: Ps, services: &mut Services) -> Wire
+    where
+        P: Into<Point<2>>,
+        Ps: IntoIterator<Item = P>,
+    {
+        let edges = points.into_iter().map(Into::into).tuple_windows().map(
+            |(start, end)| {
+                HalfEdge::line_segment([start, end], None, services)
+                    .insert(services)
+            },
+        );
+
+        Wire::new(edges)
+    }
+}
+
+impl BuildWire for Wire {}
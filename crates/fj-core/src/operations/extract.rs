@@ -0,0 +1,197 @@
+//! Extract faces of a solid into a shell
+//!
+//! See [`ShellFromFaces`].
+
+use crate::{
+    objects::{Face, Shell, Solid},
+    queries::AdjacencyIndex,
+    storage::Handle,
+};
+
+/// Extract some faces of a solid into an open [`Shell`]
+///
+/// This is useful, if only part of a solid is needed as a surface, for example
+/// to [thicken] it into a bracket.
+///
+/// [thicken]: crate::operations::thicken::Thicken
+pub trait ShellFromFaces {
+    /// Create a shell from the provided faces
+    ///
+    /// The faces are not copied, so faces that are adjacent in the solid are
+    /// still adjacent in the shell. The edges where the extracted faces border
+    /// faces that were not extracted, are the boundary of the shell. Use
+    /// [`SelectFaces`], to select the faces by their geometry.
+    ///
+    /// Faces that are provided multiple times, are only added once.
+    ///
+    /// Returns an error, if no faces are provided, if a face is not part of the
+    /// solid, or if the faces don't form a connected shell.
+    ///
+    /// [`SelectFaces`]: crate::queries::SelectFaces
+    fn shell_from_faces(
+        &self,
+        faces: impl IntoIterator<Item = Handle<Face>>,
+    ) -> Result<Shell, ShellFromFacesError>;
+}
+
+impl ShellFromFaces for Solid {
+    fn shell_from_faces(
+        &self,
+        faces: impl IntoIterator<Item = Handle<Face>>,
+    ) -> Result<Shell, ShellFromFacesError> {
+        let mut selected: Vec<Handle<Face>> = Vec::new();
+
+        for face in faces {
+            let is_part_of_solid = self
+                .shells()
+                .iter()
+                .any(|shell| shell.faces().contains(&face));
+            if !is_part_of_solid {
+                return Err(ShellFromFacesError::FaceNotInSolid(face));
+            }
+
+            if !selected.iter().any(|selected| selected.id() == face.id()) {
+                selected.push(face);
+            }
+        }
+
+        if selected.is_empty() {
+            return Err(ShellFromFacesError::NoFaces);
+        }
+
+        let shell = Shell::new(selected);
+
+        let components = connected_components(&shell);
+        if components.len() > 1 {
+            return Err(ShellFromFacesError::Disconnected { components });
+        }
+
+        Ok(shell)
+    }
+}
+
+/// Error returned by [`ShellFromFaces::shell_from_faces`]
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum ShellFromFacesError {
+    /// No faces were provided
+    #[error("Can't create shell without faces")]
+    NoFaces,
+
+    /// A face is not part of the solid
+    #[error("Face is not part of the solid: {0:#?}")]
+    FaceNotInSolid(Handle<Face>),
+
+    /// The faces don't form a connected shell
+    #[error(
+        "Faces don't form a connected shell ({} disconnected groups)",
+        .components.len()
+    )]
+    Disconnected {
+        /// The groups of faces that are connected to each other
+        ///
+        /// Faces in different groups don't share any edges.
+        components: Vec<Vec<Handle<Face>>>,
+    },
+}
+
+/// Group the faces of the shell by which faces they are connected to
+fn connected_components(shell: &Shell) -> Vec<Vec<Handle<Face>>> {
+    let adjacency = shell.adjacency();
+
+    let mut components: Vec<Vec<Handle<Face>>> = Vec::new();
+
+    for face in shell.faces() {
+        let is_visited = components
+            .iter()
+            .flatten()
+            .any(|visited| visited.id() == face.id());
+        if is_visited {
+            continue;
+        }
+
+        let mut component = vec![face.clone()];
+        let mut i = 0;
+
+        while let Some(current) = component.get(i).cloned() {
+            for neighbor in adjacency.neighbors_of_face(&current) {
+                if !component.iter().any(|face| face.id() == neighbor.id()) {
+                    component.push(neighbor);
+                }
+            }
+
+            i += 1;
+        }
+
+        components.push(component);
+    }
+
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::approx::Tolerance,
+        objects::Solid,
+        operations::build::BuildSolid,
+        queries::{AdjacencyIndex, SelectFaces},
+        services::Services,
+    };
+
+    use super::{ShellFromFaces, ShellFromFacesError};
+
+    #[test]
+    fn side_faces_of_box() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let cuboid = Solid::cuboid([1., 1., 1.], &mut services);
+        let sides =
+            cuboid.faces_where(Tolerance::from_scalar(0.001)?, |face| {
+                !face.faces_direction([0., 0., 1.], 1e-6)
+                    && !face.faces_direction([0., 0., -1.], 1e-6)
+            })?;
+        assert_eq!(sides.len(), 4);
+
+        let tube = cuboid.shell_from_faces(sides)?;
+        assert_eq!(tube.faces().len(), 4);
+
+        // Each side face borders two other side faces, like a tube. The edges
+        // at the top and bottom are open.
+        let adjacency = tube.adjacency();
+        let mut num_open_edges = 0;
+        for face in tube.faces() {
+            assert_eq!(adjacency.neighbors_of_face(face).len(), 2);
+
+            for half_edge in face.region().exterior().half_edges() {
+                if adjacency.faces_of_edge(half_edge).len() == 1 {
+                    num_open_edges += 1;
+                }
+            }
+        }
+        assert_eq!(num_open_edges, 8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn top_and_bottom_faces_of_box() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let cuboid = Solid::cuboid([1., 1., 1.], &mut services);
+        let top_and_bottom =
+            cuboid.faces_where(Tolerance::from_scalar(0.001)?, |face| {
+                face.faces_direction([0., 0., 1.], 1e-6)
+                    || face.faces_direction([0., 0., -1.], 1e-6)
+            })?;
+        assert_eq!(top_and_bottom.len(), 2);
+
+        let result = cuboid.shell_from_faces(top_and_bottom);
+        let Err(ShellFromFacesError::Disconnected { components }) = &result
+        else {
+            panic!("Expected faces to be disconnected: {result:?}");
+        };
+        assert_eq!(components.len(), 2);
+
+        Ok(())
+    }
+}
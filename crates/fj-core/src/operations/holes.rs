@@ -12,28 +12,32 @@ use super::{
     build::{BuildCycle, BuildHalfEdge, BuildRegion},
     insert::Insert,
     join::JoinCycle,
-    sweep::{SweepCache, SweepRegion},
+    sweep::{SweepCache, SweepError, SweepRegion},
     update::{UpdateCycle, UpdateFace, UpdateRegion, UpdateShell},
 };
 
 /// Add a hole to a [`Shell`]
-pub trait AddHole {
+pub trait AddHole: Sized {
     /// Add a blind hole at the provided location
+    ///
+    /// Returns an error, if the wall of the hole can't be swept.
     fn add_blind_hole(
         &self,
         location: HoleLocation,
         radius: impl Into<Scalar>,
         path: impl Into<Vector<3>>,
         services: &mut Services,
-    ) -> Self;
+    ) -> Result<Self, SweepError>;
 
     /// Add a through hole between the provided locations
+    ///
+    /// Returns an error, if the wall of the hole can't be swept.
     fn add_through_hole(
         &self,
         locations: [HoleLocation; 2],
         radius: impl Into<Scalar>,
         services: &mut Services,
-    ) -> Self;
+    ) -> Result<Self, SweepError>;
 }
 
 impl AddHole for Shell {
@@ -43,7 +47,7 @@ impl AddHole for Shell {
         radius: impl Into<Scalar>,
         path: impl Into<Vector<3>>,
         services: &mut Services,
-    ) -> Self {
+    ) -> Result<Self, SweepError> {
         let entry = HalfEdge::circle(location.position, radius, services)
             .insert(services);
         let hole = Region::empty(services)
@@ -57,25 +61,32 @@ impl AddHole for Shell {
                 path,
                 &mut SweepCache::default(),
                 services,
-            )
+            )?
             .all_faces()
             .map(|face| face.insert(services))
             .collect::<Vec<_>>();
 
-        self.update_face(location.face, |face| {
-            face.update_region(|region| {
-                region
-                    .add_interiors([Cycle::empty()
-                        .add_joined_edges(
-                            [(entry.clone(), entry.path(), entry.boundary())],
-                            services,
-                        )
-                        .insert(services)])
-                    .insert(services)
+        let shell = self
+            .update_face(location.face, |face| {
+                face.update_region(|region| {
+                    region
+                        .add_interiors([Cycle::empty()
+                            .add_joined_edges(
+                                [(
+                                    entry.clone(),
+                                    entry.path(),
+                                    entry.boundary(),
+                                )],
+                                services,
+                            )
+                            .insert(services)])
+                        .insert(services)
+                })
+                .insert(services)
             })
-            .insert(services)
-        })
-        .add_faces(hole)
+            .add_faces(hole);
+
+        Ok(shell)
     }
 
     fn add_through_hole(
@@ -83,7 +94,7 @@ impl AddHole for Shell {
         [entry_location, exit_location]: [HoleLocation; 2],
         radius: impl Into<Scalar>,
         services: &mut Services,
-    ) -> Self {
+    ) -> Result<Self, SweepError> {
         let radius = radius.into();
 
         let entry = HalfEdge::circle(entry_location.position, radius, services)
@@ -115,7 +126,7 @@ impl AddHole for Shell {
                 path,
                 &mut SweepCache::default(),
                 services,
-            );
+            )?;
 
         let hole = swept_region
             .side_faces
@@ -130,33 +141,40 @@ impl AddHole for Shell {
             .half_edges()
             .only();
 
-        self.update_face(entry_location.face, |face| {
-            face.update_region(|region| {
-                region
-                    .add_interiors([Cycle::empty()
-                        .add_joined_edges(
-                            [(entry.clone(), entry.path(), entry.boundary())],
-                            services,
-                        )
-                        .insert(services)])
-                    .insert(services)
+        let shell = self
+            .update_face(entry_location.face, |face| {
+                face.update_region(|region| {
+                    region
+                        .add_interiors([Cycle::empty()
+                            .add_joined_edges(
+                                [(
+                                    entry.clone(),
+                                    entry.path(),
+                                    entry.boundary(),
+                                )],
+                                services,
+                            )
+                            .insert(services)])
+                        .insert(services)
+                })
+                .insert(services)
             })
-            .insert(services)
-        })
-        .update_face(exit_location.face, |face| {
-            face.update_region(|region| {
-                region
-                    .add_interiors([Cycle::empty()
-                        .add_joined_edges(
-                            [(exit.clone(), exit.path(), exit.boundary())],
-                            services,
-                        )
-                        .insert(services)])
-                    .insert(services)
+            .update_face(exit_location.face, |face| {
+                face.update_region(|region| {
+                    region
+                        .add_interiors([Cycle::empty()
+                            .add_joined_edges(
+                                [(exit.clone(), exit.path(), exit.boundary())],
+                                services,
+                            )
+                            .insert(services)])
+                        .insert(services)
+                })
+                .insert(services)
             })
-            .insert(services)
-        })
-        .add_faces(hole)
+            .add_faces(hole);
+
+        Ok(shell)
     }
 }
 
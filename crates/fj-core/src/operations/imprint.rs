@@ -0,0 +1,497 @@
+//! Imprint curves onto faces
+//!
+//! See [`Imprint`].
+
+use fj_math::{Point, Scalar};
+
+use crate::{
+    geometry::SurfacePath,
+    objects::{Cycle, Face, HalfEdge, Region, Shell},
+    services::Services,
+    storage::Handle,
+};
+
+use super::{
+    build::BuildHalfEdge,
+    insert::Insert,
+    reverse::Reverse,
+    section::{
+        is_same_point, polygon_contains_point, polygon_from_cycle, signed_area,
+    },
+    split::SplitFace,
+    update::{UpdateFace, UpdateRegion, UpdateShell},
+};
+
+/// Imprint curves onto a face of a [`Shell`]
+///
+/// Imprinting splits a face into multiple faces, along curves that lie on its
+/// surface. This is useful, if different parts of a face need to be treated
+/// differently, for example to give them different colors, or to apply an
+/// operation to only part of the face.
+pub trait Imprint {
+    /// Imprint the curves onto the face
+    ///
+    /// Returns the updated shell, and all faces that replaced the original
+    /// face. Each curve results in one additional face:
+    ///
+    /// - An [`ImprintCurve::Line`] splits the face it crosses in two.
+    /// - An [`ImprintCurve::Loop`] results in a new face, that fills the loop.
+    ///   The face that contains the loop gets a hole in its place.
+    ///
+    /// Lines are imprinted before loops, and loops are imprinted from the
+    /// outermost to the innermost, so a loop within another loop ends up in
+    /// the face that fills the outer one.
+    ///
+    /// The curves must not intersect each other, or the boundary of the face,
+    /// including its holes, other than where lines start and end. If a line
+    /// splits a face with holes, each hole ends up in the part of the face that
+    /// contains it.
+    ///
+    /// Returns an error, if the face is not part of the shell, if a line
+    /// doesn't start and end in the interior of edges on the exterior boundary
+    /// of the face, or if a loop is not within the face.
+    fn imprint(
+        &self,
+        face: &Handle<Face>,
+        curves: impl IntoIterator<Item = ImprintCurve>,
+        services: &mut Services,
+    ) -> Result<(Shell, Vec<Handle<Face>>), ImprintError>;
+}
+
+impl Imprint for Shell {
+    fn imprint(
+        &self,
+        face: &Handle<Face>,
+        curves: impl IntoIterator<Item = ImprintCurve>,
+        services: &mut Services,
+    ) -> Result<(Shell, Vec<Handle<Face>>), ImprintError> {
+        if !self.faces().contains(face) {
+            return Err(ImprintError::FaceNotInShell { face: face.clone() });
+        }
+
+        let mut lines = Vec::new();
+        let mut loops = Vec::new();
+
+        for curve in curves {
+            match curve {
+                ImprintCurve::Line(line) => lines.push(line),
+                ImprintCurve::Loop(cycle) => loops.push(cycle),
+            }
+        }
+
+        let mut shell = self.clone();
+        let mut faces = vec![face.clone()];
+
+        for line in lines {
+            let (i, [a, b]) = faces
+                .iter()
+                .enumerate()
+                .find_map(|(i, face)| {
+                    let [a, b] =
+                        line.map(|point| point_on_exterior(face, point));
+                    Some((i, [a?, b?]))
+                })
+                .ok_or(ImprintError::LineNotOnExterior { line })?;
+
+            let face = faces.remove(i);
+            let (updated, split) =
+                shell.split_face(&face, [(&a.0, a.1), (&b.0, b.1)], services);
+            shell = updated;
+
+            // Splitting the face only takes care of its exterior. Each of its
+            // holes needs to be carried over to the part that contains it.
+            for part in split {
+                let interiors = face
+                    .region()
+                    .interiors()
+                    .iter()
+                    .filter(|interior| {
+                        let point =
+                            interior.half_edges().first().start_position();
+                        face_contains_point(&part, point)
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                if interiors.is_empty() {
+                    faces.push(part);
+                    continue;
+                }
+
+                let with_holes = part
+                    .update_region(|region| {
+                        region.add_interiors(interiors).insert(services)
+                    })
+                    .insert(services);
+                shell = shell.update_face(&part, |_| with_holes.clone());
+                faces.push(with_holes);
+            }
+        }
+
+        // An outer loop needs to be imprinted first, so loops within it end up
+        // in the face that fills it.
+        let mut loops = loops
+            .into_iter()
+            .map(|cycle| {
+                let area = signed_area(&polygon_from_cycle(&cycle)).abs();
+                (cycle, area)
+            })
+            .collect::<Vec<_>>();
+        loops.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+        for (cycle, _) in loops {
+            let Some(winding) = cycle.try_winding() else {
+                return Err(ImprintError::DegenerateLoop { cycle });
+            };
+
+            let point = cycle.half_edges().first().start_position();
+            let Some(i) = faces
+                .iter()
+                .position(|face| face_contains_point(face, point))
+            else {
+                return Err(ImprintError::LoopOutsideFace { cycle });
+            };
+
+            let face = faces.remove(i);
+            let face_winding =
+                face.region().exterior().try_winding().ok_or_else(|| {
+                    ImprintError::DegenerateFace { face: face.clone() }
+                })?;
+
+            // The new face must have the same orientation as the one it's
+            // imprinted on, and the hole in that face the opposite one.
+            let exterior = if winding == face_winding {
+                cycle
+            } else {
+                cycle.reverse(services)
+            };
+            let hole = siblings_of(&exterior, services).insert(services);
+            let exterior = exterior.insert(services);
+
+            let with_hole = face
+                .update_region(|region| {
+                    region.add_interiors([hole]).insert(services)
+                })
+                .insert(services);
+            let filling = Face::new(
+                face.surface().clone(),
+                Region::new(exterior, [], face.region().color())
+                    .insert(services),
+            )
+            .insert(services);
+
+            let replacements = [with_hole, filling];
+            shell = shell.replace_face(&face, |_| replacements.clone());
+            faces.extend(replacements);
+        }
+
+        Ok((shell, faces))
+    }
+}
+
+/// Error imprinting curves onto a face
+///
+/// See [`Imprint::imprint`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum ImprintError {
+    /// The face is not part of the shell
+    #[error("Face to imprint onto is not part of the shell")]
+    FaceNotInShell {
+        /// The face that is not part of the shell
+        face: Handle<Face>,
+    },
+
+    /// A line doesn't connect two edges of the face's exterior
+    #[error("Line doesn't connect two edges of the face's exterior: {line:?}")]
+    LineNotOnExterior {
+        /// The line, in surface coordinates
+        line: [Point<2>; 2],
+    },
+
+    /// A loop is not within the face
+    #[error("Loop is not within the face")]
+    LoopOutsideFace {
+        /// The loop that is not within the face
+        cycle: Cycle,
+    },
+
+    /// A loop encloses no area, so its orientation is undefined
+    #[error("Loop encloses no area")]
+    DegenerateLoop {
+        /// The loop that encloses no area
+        cycle: Cycle,
+    },
+
+    /// The exterior of the face encloses no area
+    #[error("Exterior of face to imprint onto encloses no area")]
+    DegenerateFace {
+        /// The face whose exterior encloses no area
+        face: Handle<Face>,
+    },
+}
+
+/// A curve that can be imprinted onto a face
+///
+/// See [`Imprint`].
+#[derive(Clone, Debug)]
+pub enum ImprintCurve {
+    /// A straight line between two points on the boundary of the face
+    ///
+    /// The points are in surface coordinates.
+    Line([Point<2>; 2]),
+
+    /// A closed loop within the face
+    ///
+    /// The cycle is defined in surface coordinates. Its orientation doesn't
+    /// matter.
+    Loop(Cycle),
+}
+
+/// Find the half-edge on the exterior of the face that the point is on
+///
+/// Returns the half-edge and the position of the point on its curve, if the
+/// point is in the interior of the half-edge.
+fn point_on_exterior(
+    face: &Face,
+    point: Point<2>,
+) -> Option<(Handle<HalfEdge>, Point<1>)> {
+    face.region()
+        .exterior()
+        .half_edges()
+        .iter()
+        .find_map(|half_edge| {
+            let [start, end] = half_edge.boundary().inner.map(|point| point.t);
+            let [min, max] = [start.min(end), start.max(end)];
+
+            let t = match half_edge.path() {
+                SurfacePath::Line(line) => line.point_to_line_coords(point).t,
+                SurfacePath::Circle(circle) => {
+                    // Circle coordinates repeat every full turn. We need the
+                    // one that falls into the boundary, if any.
+                    let angle = circle.point_to_circle_coords(point).t;
+                    let turns = ((min - angle) / Scalar::TAU).ceil();
+                    angle + Scalar::TAU * turns
+                }
+                SurfacePath::Spiral(_) => return None,
+            };
+
+            let is_within = t > min && t < max;
+            let is_on_path = is_same_point(
+                half_edge.path().point_from_path_coords([t]),
+                point,
+            );
+            let is_at_vertex = is_same_point(
+                half_edge.path().point_from_path_coords([start]),
+                point,
+            ) || is_same_point(
+                half_edge.path().point_from_path_coords([end]),
+                point,
+            );
+
+            (is_within && is_on_path && !is_at_vertex)
+                .then(|| (half_edge.clone(), Point::from([t])))
+        })
+}
+
+/// Determine whether the point is within the face, not counting its holes
+fn face_contains_point(face: &Face, point: Point<2>) -> bool {
+    let mut cycles = face.region().all_cycles();
+
+    let in_exterior = cycles.next().is_some_and(|exterior| {
+        polygon_contains_point(&polygon_from_cycle(exterior), point)
+    });
+
+    in_exterior
+        && !cycles.any(|interior| {
+            polygon_contains_point(&polygon_from_cycle(interior), point)
+        })
+}
+
+/// Build the cycle that runs along the other side of the provided one
+///
+/// Each half-edge of the new cycle is the sibling of a half-edge of the
+/// provided cycle, so the new cycle has the opposite orientation.
+fn siblings_of(cycle: &Cycle, services: &mut Services) -> Cycle {
+    let siblings = cycle
+        .half_edges()
+        .pairs()
+        .map(|(half_edge, next)| {
+            HalfEdge::from_sibling(half_edge, next.start_vertex().clone())
+                .insert(services)
+        })
+        .collect::<Vec<_>>();
+
+    Cycle::new(siblings.into_iter().rev())
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::{
+        algorithms::approx::Tolerance,
+        objects::{Cycle, Face, Solid},
+        operations::build::{BuildCycle, BuildSolid},
+        queries::SelectFaces,
+        services::Services,
+        storage::Handle,
+        validate::Validate,
+    };
+
+    use super::{Imprint, ImprintCurve, ImprintError};
+
+    #[test]
+    fn line_across_square_face() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let cuboid = Solid::cuboid([1., 1., 1.], &mut services);
+        let shell = cuboid.shells().only();
+        let top = top_face(&cuboid)?;
+
+        // A line between the midpoints of two opposite edges of the face.
+        let [a, b] = [0, 2].map(|i| {
+            let half_edge =
+                top.region().exterior().half_edges().nth_circular(i);
+            let [start, end] = half_edge.boundary().inner;
+            half_edge
+                .path()
+                .point_from_path_coords([(start.t + end.t) / 2.])
+        });
+
+        let (shell, faces) =
+            shell.imprint(&top, [ImprintCurve::Line([a, b])], &mut services)?;
+
+        assert_eq!(faces.len(), 2);
+        assert_eq!(shell.faces().len(), 7);
+        for face in &faces {
+            assert!(shell.faces().contains(face));
+            assert_eq!(face.region().exterior().half_edges().len(), 4);
+        }
+        shell.validate_and_return_first_error()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn loop_within_square_face() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let cuboid = Solid::cuboid([1., 1., 1.], &mut services);
+        let shell = cuboid.shells().only();
+        let top = top_face(&cuboid)?;
+
+        // A small square around the center of the face.
+        let half_edges = top.region().exterior().half_edges();
+        let center = half_edges
+            .iter()
+            .fold(Point::origin(), |center, half_edge| {
+                center + half_edge.start_position().coords / 4.
+            });
+        let square = Cycle::polygon(
+            [[-0.1, -0.1], [0.1, -0.1], [0.1, 0.1], [-0.1, 0.1]]
+                .map(|offset| center + Point::from(offset).coords),
+            &mut services,
+        );
+
+        let (shell, faces) =
+            shell.imprint(&top, [ImprintCurve::Loop(square)], &mut services)?;
+
+        assert_eq!(faces.len(), 2);
+        assert_eq!(shell.faces().len(), 7);
+
+        let [with_hole, filling] = [0, 1].map(|i| &faces[i]);
+        assert_eq!(with_hole.region().interiors().len(), 1);
+        assert_eq!(filling.region().interiors().len(), 0);
+        assert_eq!(
+            filling.region().exterior().winding(),
+            top.region().exterior().winding()
+        );
+        shell.validate_and_return_first_error()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn line_across_face_with_hole() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let cuboid = Solid::cuboid([1., 1., 1.], &mut services);
+        let shell = cuboid.shells().only();
+        let top = top_face(&cuboid)?;
+
+        // A small square off to the side of the center of the face, so the
+        // line below doesn't cross it.
+        let half_edges = top.region().exterior().half_edges();
+        let center = half_edges
+            .iter()
+            .fold(Point::origin(), |center, half_edge| {
+                center + half_edge.start_position().coords / 4.
+            });
+        let square = Cycle::polygon(
+            [[0.15, 0.15], [0.35, 0.15], [0.35, 0.35], [0.15, 0.35]]
+                .map(|offset| center + Point::from(offset).coords),
+            &mut services,
+        );
+        let (shell, faces) =
+            shell.imprint(&top, [ImprintCurve::Loop(square)], &mut services)?;
+        let with_hole = &faces[0];
+
+        // A line between the midpoints of two opposite edges of the face.
+        let [a, b] = [0, 2].map(|i| {
+            let half_edge =
+                with_hole.region().exterior().half_edges().nth_circular(i);
+            let [start, end] = half_edge.boundary().inner;
+            half_edge
+                .path()
+                .point_from_path_coords([(start.t + end.t) / 2.])
+        });
+
+        let (shell, faces) = shell.imprint(
+            with_hole,
+            [ImprintCurve::Line([a, b])],
+            &mut services,
+        )?;
+
+        assert_eq!(faces.len(), 2);
+        assert_eq!(shell.faces().len(), 8);
+        let num_holes = faces
+            .iter()
+            .map(|face| face.region().interiors().len())
+            .collect::<Vec<_>>();
+        assert!(num_holes == [0, 1] || num_holes == [1, 0]);
+        for face in &faces {
+            assert!(shell.faces().contains(face));
+        }
+        shell.validate_and_return_first_error()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn loop_outside_face() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let cuboid = Solid::cuboid([1., 1., 1.], &mut services);
+        let shell = cuboid.shells().only();
+        let top = top_face(&cuboid)?;
+
+        let square = Cycle::polygon(
+            [[2., 2.], [3., 2.], [3., 3.], [2., 3.]],
+            &mut services,
+        );
+
+        let result =
+            shell.imprint(&top, [ImprintCurve::Loop(square)], &mut services);
+        assert!(matches!(result, Err(ImprintError::LoopOutsideFace { .. })));
+
+        Ok(())
+    }
+
+    fn top_face(cuboid: &Solid) -> anyhow::Result<Handle<Face>> {
+        let faces = cuboid
+            .faces_where(Tolerance::from_scalar(0.001)?, |face| {
+                face.faces_direction([0., 0., 1.], 1e-6)
+            })?;
+        Ok(faces[0].clone())
+    }
+}
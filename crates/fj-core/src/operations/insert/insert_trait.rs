@@ -1,7 +1,7 @@
 use crate::{
     objects::{
         Curve, Cycle, Face, HalfEdge, Region, Shell, Sketch, Solid, Surface,
-        Vertex,
+        Vertex, Wire,
     },
     operations::build::{Polygon, TetrahedronShell},
     services::Services,
@@ -59,6 +59,7 @@ impl_insert!(
     Solid, solids;
     Surface, surfaces;
     Vertex, vertices;
+    Wire, wires;
 );
 
 impl<const D: usize> Insert for Polygon<D, IsInsertedNo> {
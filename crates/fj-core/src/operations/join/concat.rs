@@ -0,0 +1,156 @@
+use fj_math::{Point, Scalar};
+
+use crate::{
+    objects::{Cycle, HalfEdge},
+    operations::update::UpdateCycle,
+};
+
+/// Chain the half-edges of two [`Cycle`]s end-to-end
+///
+/// This is useful for building a profile piece by piece, for example from arcs
+/// and lines that have been computed separately. Unlike [`JoinCycle`], which
+/// joins coincident half-edges of neighboring cycles, this connects the end of
+/// one chain of half-edges to the start of another.
+///
+/// [`JoinCycle`]: super::JoinCycle
+pub trait ConcatCycle: Sized {
+    /// Append the half-edges of another cycle to the end of this one
+    ///
+    /// The cycles are expected to be open chains of half-edges. The other cycle
+    /// must start where this one ends, within the provided tolerance. If either
+    /// cycle is empty, the other one is returned unchanged.
+    ///
+    /// The half-edges are appended as they are, without adjusting their
+    /// geometry. To result in a valid cycle, the tolerance should not exceed
+    /// the one used by validation.
+    ///
+    /// Returns an error, if the other cycle doesn't start where this one ends.
+    fn append_cycle(
+        &self,
+        other: &Cycle,
+        tolerance: impl Into<Scalar>,
+    ) -> Result<Self, ConcatCycleError>;
+}
+
+impl ConcatCycle for Cycle {
+    fn append_cycle(
+        &self,
+        other: &Cycle,
+        tolerance: impl Into<Scalar>,
+    ) -> Result<Self, ConcatCycleError> {
+        let (Some(last), Some(first)) = (
+            self.half_edges().iter().last(),
+            other.half_edges().iter().next(),
+        ) else {
+            return Ok(self.add_half_edges(other.half_edges().iter().cloned()));
+        };
+
+        let end = end_position(last);
+        let start = first.start_position();
+        let distance = end.distance_to(&start);
+
+        if distance > tolerance.into() {
+            return Err(ConcatCycleError::EndpointsDontMeet {
+                end,
+                start,
+                distance,
+            });
+        }
+
+        Ok(self.add_half_edges(other.half_edges().iter().cloned()))
+    }
+}
+
+/// Error chaining two cycles together
+///
+/// Returned by [`ConcatCycle::append_cycle`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum ConcatCycleError {
+    /// The other cycle doesn't start where the cycle ends
+    #[error(
+        "Can't append cycle that starts at {start:?} to cycle that ends at \
+        {end:?} (distance: {distance})"
+    )]
+    EndpointsDontMeet {
+        /// The end of the cycle that is appended to
+        end: Point<2>,
+
+        /// The start of the cycle that is appended
+        start: Point<2>,
+
+        /// The distance between both points
+        distance: Scalar,
+    },
+}
+
+fn end_position(half_edge: &HalfEdge) -> Point<2> {
+    let [_, end] = half_edge.boundary().inner;
+    half_edge.path().point_from_path_coords(end)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Winding};
+
+    use crate::{
+        objects::{Cycle, HalfEdge},
+        operations::{
+            build::{BuildCycle, BuildHalfEdge},
+            insert::Insert,
+            update::UpdateCycle,
+        },
+        services::Services,
+        validate::Validate,
+    };
+
+    use super::{ConcatCycle, ConcatCycleError};
+
+    #[test]
+    fn half_circles_to_circle() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let [upper, lower] = [([1., 0.], [-1., 0.]), ([-1., 0.], [1., 0.])]
+            .map(|(start, end)| {
+                Cycle::empty().add_half_edges([HalfEdge::arc(
+                    start,
+                    end,
+                    Scalar::PI,
+                    &mut services,
+                )
+                .insert(&mut services)])
+            });
+
+        let circle = upper.append_cycle(&lower, 1e-9)?;
+
+        assert_eq!(circle.half_edges().len(), 2);
+        assert!(circle.is_closed(1e-9));
+        assert_eq!(circle.winding(), Winding::Ccw);
+        circle.validate_and_return_first_error()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn endpoints_dont_meet() {
+        let mut services = Services::new();
+
+        let [a, b] =
+            [[[0., 0.], [1., 0.]], [[2., 0.], [3., 0.]]].map(|points| {
+                Cycle::empty().add_half_edges([HalfEdge::line_segment(
+                    points,
+                    None,
+                    &mut services,
+                )
+                .insert(&mut services)])
+            });
+
+        assert_eq!(
+            a.append_cycle(&b, 1e-9).err(),
+            Some(ConcatCycleError::EndpointsDontMeet {
+                end: Point::from([1., 0.]),
+                start: Point::from([2., 0.]),
+                distance: Scalar::ONE,
+            })
+        );
+    }
+}
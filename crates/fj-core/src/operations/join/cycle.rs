@@ -13,10 +13,11 @@ use crate::{
     },
     services::Services,
     storage::Handle,
+    trace::operation_span,
 };
 
 /// Join a [`Cycle`] to another
-pub trait JoinCycle {
+pub trait JoinCycle: Sized {
     /// Add half-edges to the cycle that are joined to the provided ones
     #[must_use]
     fn add_joined_edges<Es>(&self, edges: Es, services: &mut Services) -> Self
@@ -35,9 +36,7 @@ pub trait JoinCycle {
     /// of 3 edges, indices `0` and `3` refer to the same edge. This allows for
     /// specifying a range that crosses the "seam" of the cycle.
     ///
-    /// # Panics
-    ///
-    /// Panics, if the ranges have different lengths.
+    /// Returns an error, if the ranges have different lengths.
     ///
     /// # Assumptions
     ///
@@ -67,14 +66,13 @@ pub trait JoinCycle {
     ///
     /// Maybe a custom trait that is implemented for `usize` and all range types
     /// would be the best solution.
-    #[must_use]
     fn join_to(
         &self,
         other: &Cycle,
         range: RangeInclusive<usize>,
         other_range: RangeInclusive<usize>,
         services: &mut Services,
-    ) -> Self;
+    ) -> Result<Self, JoinCycleError>;
 }
 
 impl JoinCycle for Cycle {
@@ -85,7 +83,10 @@ impl JoinCycle for Cycle {
         >,
         Es::IntoIter: Clone + ExactSizeIterator,
     {
-        self.add_half_edges(edges.into_iter().circular_tuple_windows().map(
+        let edges = edges.into_iter();
+        operation_span!("add_joined_edges", edges = edges.len());
+
+        self.add_half_edges(edges.circular_tuple_windows().map(
             |((prev_half_edge, _, _), (half_edge, curve, boundary))| {
                 HalfEdge::unjoined(curve, boundary, services)
                     .update_curve(|_| half_edge.curve().clone())
@@ -103,14 +104,19 @@ impl JoinCycle for Cycle {
         range: RangeInclusive<usize>,
         range_other: RangeInclusive<usize>,
         services: &mut Services,
-    ) -> Self {
-        assert_eq!(
-            range.end() - range.start(),
-            range_other.end() - range_other.start(),
-            "Ranges have different lengths",
-        );
+    ) -> Result<Self, JoinCycleError> {
+        let len = range.clone().count();
+        let len_other = range_other.clone().count();
+        if len != len_other {
+            return Err(JoinCycleError::RangesHaveDifferentLengths {
+                len,
+                len_other,
+            });
+        }
+
+        operation_span!("join_to", edges = len);
 
-        range.zip(range_other).fold(
+        let cycle = range.zip(range_other).fold(
             self.clone(),
             |cycle, (index, index_other)| {
                 let edge_other = other.half_edges().nth_circular(index_other);
@@ -140,6 +146,50 @@ impl JoinCycle for Cycle {
                         },
                     )
             },
-        )
+        );
+
+        Ok(cycle)
+    }
+}
+
+/// Error joining a [`Cycle`] to another
+///
+/// Returned by [`JoinCycle::join_to`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum JoinCycleError {
+    /// The ranges of half-edges to join have different lengths
+    #[error("Ranges to join have different lengths ({len} and {len_other})")]
+    RangesHaveDifferentLengths {
+        /// The length of the range in the cycle that is being joined
+        len: usize,
+
+        /// The length of the range in the cycle it is joined to
+        len_other: usize,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        objects::Cycle, operations::build::BuildCycle, services::Services,
+    };
+
+    use super::{JoinCycle, JoinCycleError};
+
+    #[test]
+    fn join_ranges_of_different_lengths() {
+        let mut services = Services::new();
+
+        let a = Cycle::polygon([[0., 0.], [1., 0.], [0., 1.]], &mut services);
+        let b = Cycle::polygon([[1., 0.], [0., 0.], [0., -1.]], &mut services);
+
+        let result = a.join_to(&b, 0..=1, 0..=0, &mut services);
+        assert_eq!(
+            result.err(),
+            Some(JoinCycleError::RangesHaveDifferentLengths {
+                len: 2,
+                len_other: 1,
+            })
+        );
     }
 }
@@ -1,8 +1,11 @@
 //! # Operations to join objects together
 //!
-//! See [`JoinCycle`], which is currently the only trait in this module, for
-//! more information.
+//! See [`JoinCycle`] and [`ConcatCycle`], for more information.
 
+mod concat;
 mod cycle;
 
-pub use self::cycle::JoinCycle;
+pub use self::{
+    concat::{ConcatCycle, ConcatCycleError},
+    cycle::{JoinCycle, JoinCycleError},
+};
@@ -38,13 +38,24 @@
 //! assume that the code in question is outdated. Feel free to open an issue or
 //! send a pull request!
 
+pub mod boolean;
 pub mod build;
+pub mod extract;
 pub mod holes;
+pub mod imprint;
 pub mod insert;
 pub mod join;
 pub mod merge;
+pub mod offset;
+pub mod pad;
+pub mod pocket;
+pub mod profile;
+pub mod repair;
 pub mod replace;
 pub mod reverse;
+pub mod section;
+pub mod sheet_metal;
 pub mod split;
 pub mod sweep;
+pub mod thicken;
 pub mod update;
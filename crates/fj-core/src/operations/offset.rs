@@ -0,0 +1,844 @@
+//! Offset 2D profiles
+//!
+//! See [`OffsetRegion`].
+
+use fj_math::{Circle, Point, Scalar, Vector, Winding};
+
+use crate::{
+    geometry::{CurveBoundary, SurfacePath},
+    objects::{Cycle, HalfEdge, Region},
+    operations::{build::BuildHalfEdge, insert::Insert},
+    services::Services,
+    storage::Handle,
+    validate::ValidationConfig,
+};
+
+/// Offset a [`Region`]
+pub trait OffsetRegion {
+    /// Offset the region by a signed distance
+    ///
+    /// Each boundary edge is moved along its normal by `distance`. A positive
+    /// distance grows the region, a negative one shrinks it. As a result, the
+    /// interior cycles of the region are offset in the opposite direction of
+    /// the exterior one.
+    ///
+    /// Where offsetting the edges of a corner opens a gap between them, that
+    /// gap is closed as specified by `join`. Where the offset edges overlap
+    /// instead, they are trimmed at their intersection. Edges that vanish as a
+    /// result of that, which happens at concave corners for large distances,
+    /// are clipped from the result.
+    ///
+    /// Arcs are offset by changing their radius. Where an arc meets its
+    /// neighbor tangentially, the offset edges still meet.
+    ///
+    /// Returns `None`, if the exterior cycle vanishes. Interior cycles that
+    /// vanish are left out of the result. Returns an error, if any of the
+    /// cycles encloses no area to begin with.
+    ///
+    /// # Limitations
+    ///
+    /// In cycles that contain arcs, only corners that are tangential, or that
+    /// are joined with [`OffsetJoin::Round`], are supported, unless both edges
+    /// at the corner are line segments. Edges of such cycles can't be clipped
+    /// either. An error is returned in those cases.
+    ///
+    /// Clipping only removes edges that vanish. Offset cycles that intersect
+    /// themselves elsewhere, or that intersect other cycles of the region, are
+    /// not detected.
+    fn offset(
+        &self,
+        distance: impl Into<Scalar>,
+        join: OffsetJoin,
+        services: &mut Services,
+    ) -> Result<Option<Region>, OffsetError>;
+}
+
+impl OffsetRegion for Region {
+    fn offset(
+        &self,
+        distance: impl Into<Scalar>,
+        join: OffsetJoin,
+        services: &mut Services,
+    ) -> Result<Option<Region>, OffsetError> {
+        let distance = distance.into();
+
+        let Some(exterior) = offset_cycle(
+            self.exterior(),
+            distance,
+            Winding::Ccw,
+            join,
+            services,
+        )?
+        else {
+            return Ok(None);
+        };
+
+        let mut interiors = Vec::new();
+        for interior in self.interiors() {
+            interiors.extend(offset_cycle(
+                interior,
+                distance,
+                Winding::Cw,
+                join,
+                services,
+            )?);
+        }
+
+        Ok(Some(Region::new(exterior, interiors, self.color())))
+    }
+}
+
+/// How [`OffsetRegion`] closes the gap at corners, where offset edges separate
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OffsetJoin {
+    /// Extend both edges until they meet
+    Miter,
+
+    /// Connect the edges with an arc around the original corner
+    Round,
+}
+
+/// Error offsetting a [`Region`]
+///
+/// See [`OffsetRegion::offset`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum OffsetError {
+    /// A cycle contains an edge that is neither a line segment nor an arc
+    #[error("Only cycles of line segments and arcs can be offset")]
+    UnsupportedPath,
+
+    /// An arc would shrink to a radius of zero or less
+    #[error("Offsetting the region makes an arc vanish")]
+    ArcVanishes,
+
+    /// An edge of a cycle that contains arcs would vanish
+    #[error("Offsetting the region makes an edge vanish")]
+    EdgeVanishes,
+
+    /// The offset edges at a corner next to an arc can't be joined
+    #[error(
+        "Can only join offset edges next to an arc, if they meet tangentially, \
+        or with a round join that fills a gap between them"
+    )]
+    UnsupportedCorner,
+
+    /// A cycle encloses no area, so the side to offset it to is undefined
+    #[error("Can't offset a cycle that encloses no area")]
+    DegenerateCycle,
+}
+
+/// Offset a cycle
+///
+/// `winding` is the winding that a cycle in this position would have, if the
+/// region were on the left side of its half-edges.
+fn offset_cycle(
+    cycle: &Cycle,
+    distance: Scalar,
+    winding: Winding,
+    join: OffsetJoin,
+    services: &mut Services,
+) -> Result<Option<Handle<Cycle>>, OffsetError> {
+    let cycle_winding =
+        cycle.try_winding().ok_or(OffsetError::DegenerateCycle)?;
+
+    // From here on, we offset to the right side of the half-edges.
+    let distance = if cycle_winding == winding {
+        distance
+    } else {
+        -distance
+    };
+
+    if cycle.half_edges().len() == 1 {
+        let half_edge = cycle.half_edges().only();
+
+        if let SurfacePath::Circle(circle) = half_edge.path() {
+            return Ok(offset_circle(
+                half_edge,
+                circle,
+                cycle_winding,
+                distance,
+                services,
+            ));
+        }
+    }
+
+    let mut has_arcs = false;
+    for half_edge in cycle.half_edges() {
+        match half_edge.path() {
+            SurfacePath::Line(_) => {}
+            SurfacePath::Circle(_) => has_arcs = true,
+            SurfacePath::Spiral(_) => return Err(OffsetError::UnsupportedPath),
+        }
+    }
+    if has_arcs {
+        return offset_cycle_with_arcs(cycle, distance, join, services)
+            .map(Some);
+    }
+
+    let mut lines = cycle
+        .half_edges()
+        .iter()
+        .map(|half_edge| {
+            let [start, end] = half_edge
+                .boundary()
+                .inner
+                .map(|point| half_edge.path().point_from_path_coords(point));
+
+            OffsetLine::new(start, end, distance)
+        })
+        .collect::<Vec<_>>();
+
+    let min_length = ValidationConfig::default().distinct_min_distance;
+
+    // Trimming the offset lines against each other can make some of them
+    // vanish. Remove those, and trim the remaining ones again, until none are
+    // left that vanish.
+    let corners = loop {
+        if lines.len() < 3 {
+            return Ok(None);
+        }
+
+        let corners = (0..lines.len())
+            .map(|i| {
+                let next = (i + 1) % lines.len();
+                Corner::new(&lines[i], &lines[next], distance, join)
+            })
+            .collect::<Vec<_>>();
+
+        let vanished = (0..lines.len()).find(|&i| {
+            let prev = (i + lines.len() - 1) % lines.len();
+            let start = corners[prev].start_of_next;
+            let end = corners[i].end_of_prev;
+
+            end - start < min_length
+        });
+
+        match vanished {
+            Some(i) => {
+                lines.remove(i);
+            }
+            None => break corners,
+        }
+    };
+
+    let mut half_edges = Vec::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        let prev = (i + lines.len() - 1) % lines.len();
+        let start = line.point(corners[prev].start_of_next);
+        let end = line.point(corners[i].end_of_prev);
+
+        half_edges.push(HalfEdge::line_segment([start, end], None, services));
+
+        if let Some(angle) = corners[i].arc {
+            let next = &lines[(i + 1) % lines.len()];
+            let start_of_next = next.point(corners[i].start_of_next);
+
+            half_edges.push(HalfEdge::arc(end, start_of_next, angle, services));
+        }
+    }
+
+    let half_edges = half_edges
+        .into_iter()
+        .map(|half_edge| half_edge.insert(services))
+        .collect::<Vec<_>>();
+
+    Ok(Some(Cycle::new(half_edges).insert(services)))
+}
+
+/// Offset a cycle that contains arcs
+///
+/// Works like [`offset_cycle`], except that edges are never clipped, and only
+/// some kinds of corners are supported. See [`OffsetRegion::offset`].
+fn offset_cycle_with_arcs(
+    cycle: &Cycle,
+    distance: Scalar,
+    join: OffsetJoin,
+    services: &mut Services,
+) -> Result<Handle<Cycle>, OffsetError> {
+    let mut edges = cycle
+        .half_edges()
+        .iter()
+        .map(|half_edge| OffsetEdge::new(half_edge, distance))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // The corners are where each edge meets the next one. Any arcs that join
+    // the edges at a corner are collected here.
+    let mut joins = vec![None; edges.len()];
+
+    let min_length = ValidationConfig::default().distinct_min_distance;
+    let tangent_tolerance = min_length;
+
+    for i in 0..edges.len() {
+        let next = (i + 1) % edges.len();
+        let [prev_direction, next_direction] =
+            [edges[i].directions[1], edges[next].directions[0]];
+
+        // The directions of arcs are computed, so they can be slightly off
+        // from the direction of a neighboring edge that they meet
+        // tangentially.
+        let cross = prev_direction.cross2d(&next_direction);
+        if cross.abs() < tangent_tolerance {
+            if prev_direction.dot(&next_direction) > Scalar::ZERO {
+                // The edges meet tangentially, so their offset versions still
+                // meet.
+                continue;
+            }
+
+            // The cycle turns back on itself here.
+            return Err(OffsetError::UnsupportedCorner);
+        }
+
+        let is_open = cross * distance > Scalar::ZERO;
+
+        if is_open && join == OffsetJoin::Round {
+            let [prev_offset, next_offset] =
+                [edges[i].offsets[1], edges[next].offsets[0]];
+            let angle = prev_offset
+                .cross2d(&next_offset)
+                .atan2(prev_offset.dot(&next_offset));
+
+            joins[i] = Some((edges[i].end(), edges[next].start(), angle));
+            continue;
+        }
+
+        // Lines can be trimmed or extended to where they intersect. That's
+        // not supported for arcs.
+        let (
+            OffsetEdgePath::Line {
+                start: prev_start, ..
+            },
+            OffsetEdgePath::Line { end: next_end, .. },
+        ) = (edges[i].path, edges[next].path)
+        else {
+            return Err(OffsetError::UnsupportedCorner);
+        };
+
+        let [t, _] = intersect(
+            edges[i].end(),
+            prev_direction,
+            edges[next].start(),
+            next_direction,
+        );
+        let corner = edges[i].end() + prev_direction * t;
+
+        edges[i].path = OffsetEdgePath::Line {
+            start: prev_start,
+            end: corner,
+        };
+        edges[next].path = OffsetEdgePath::Line {
+            start: corner,
+            end: next_end,
+        };
+    }
+
+    let mut half_edges = Vec::new();
+
+    for (edge, join) in edges.iter().zip(joins) {
+        let half_edge = match edge.path {
+            OffsetEdgePath::Line { start, end } => {
+                // Trimming a line against both of its neighbors can make it
+                // vanish, or even flip it around.
+                if (end - start).dot(&edge.directions[0]) < min_length {
+                    return Err(OffsetError::EdgeVanishes);
+                }
+
+                HalfEdge::line_segment([start, end], None, services)
+            }
+            OffsetEdgePath::Arc { circle, boundary } => HalfEdge::unjoined(
+                SurfacePath::Circle(circle),
+                boundary,
+                services,
+            ),
+        };
+        half_edges.push(half_edge);
+
+        if let Some((start, end, angle)) = join {
+            half_edges.push(HalfEdge::arc(start, end, angle, services));
+        }
+    }
+
+    let half_edges = half_edges
+        .into_iter()
+        .map(|half_edge| half_edge.insert(services))
+        .collect::<Vec<_>>();
+
+    Ok(Cycle::new(half_edges).insert(services))
+}
+
+fn offset_circle(
+    half_edge: &HalfEdge,
+    circle: Circle<2>,
+    winding: Winding,
+    distance: Scalar,
+    services: &mut Services,
+) -> Option<Handle<Cycle>> {
+    // The right side of a circle is its outside, if it is traversed
+    // counter-clockwise.
+    let radius = match winding {
+        Winding::Ccw => circle.radius() + distance,
+        Winding::Cw => circle.radius() - distance,
+    };
+    if radius <= Scalar::ZERO {
+        return None;
+    }
+
+    let scale = radius / circle.radius();
+    let circle =
+        Circle::new(circle.center(), circle.a() * scale, circle.b() * scale);
+
+    let half_edge = HalfEdge::unjoined(
+        SurfacePath::Circle(circle),
+        half_edge.boundary(),
+        services,
+    )
+    .insert(services);
+
+    Some(Cycle::new([half_edge]).insert(services))
+}
+
+/// An edge of a cycle that contains arcs, offset to its right side
+struct OffsetEdge {
+    path: OffsetEdgePath,
+
+    /// The direction of the edge at its start and end
+    directions: [Vector<2>; 2],
+
+    /// The vectors by which the start and end of the edge were offset
+    offsets: [Vector<2>; 2],
+}
+
+impl OffsetEdge {
+    fn new(
+        half_edge: &HalfEdge,
+        distance: Scalar,
+    ) -> Result<Self, OffsetError> {
+        let boundary = half_edge.boundary();
+        let [start, end] = boundary
+            .inner
+            .map(|point| half_edge.path().point_from_path_coords(point));
+
+        let (path, directions) = match half_edge.path() {
+            SurfacePath::Line(_) => {
+                let direction = (end - start).normalize();
+                let offset = right_of(direction) * distance;
+
+                let path = OffsetEdgePath::Line {
+                    start: start + offset,
+                    end: end + offset,
+                };
+
+                (path, [direction; 2])
+            }
+            SurfacePath::Circle(circle) => {
+                let [a, b] = boundary.inner;
+                let sign = if a < b { Scalar::ONE } else { -Scalar::ONE };
+                let directions = boundary
+                    .inner
+                    .map(|point| tangent_of_circle(&circle, point.t, sign));
+
+                // The right side of an arc is either its outside or its inside,
+                // depending on the direction it is traversed in.
+                let to_start = (start - circle.center()) / circle.radius();
+                let radius = circle.radius()
+                    + right_of(directions[0]).dot(&to_start) * distance;
+                if radius <= Scalar::ZERO {
+                    return Err(OffsetError::ArcVanishes);
+                }
+
+                let scale = radius / circle.radius();
+                let path = OffsetEdgePath::Arc {
+                    circle: Circle::new(
+                        circle.center(),
+                        circle.a() * scale,
+                        circle.b() * scale,
+                    ),
+                    boundary,
+                };
+
+                (path, directions)
+            }
+            SurfacePath::Spiral(_) => return Err(OffsetError::UnsupportedPath),
+        };
+
+        Ok(Self {
+            path,
+            directions,
+            offsets: directions.map(|direction| right_of(direction) * distance),
+        })
+    }
+
+    fn start(&self) -> Point<2> {
+        match self.path {
+            OffsetEdgePath::Line { start, .. } => start,
+            OffsetEdgePath::Arc { circle, boundary } => {
+                let [start, _] = boundary.inner;
+                circle.point_from_circle_coords(start)
+            }
+        }
+    }
+
+    fn end(&self) -> Point<2> {
+        match self.path {
+            OffsetEdgePath::Line { end, .. } => end,
+            OffsetEdgePath::Arc { circle, boundary } => {
+                let [_, end] = boundary.inner;
+                circle.point_from_circle_coords(end)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum OffsetEdgePath {
+    Line {
+        start: Point<2>,
+        end: Point<2>,
+    },
+    Arc {
+        circle: Circle<2>,
+        boundary: CurveBoundary<Point<1>>,
+    },
+}
+
+/// The direction of travel along a circle, at the provided circle coordinate
+///
+/// `sign` is positive, if the circle is traversed in the direction of growing
+/// coordinates.
+fn tangent_of_circle(circle: &Circle<2>, t: Scalar, sign: Scalar) -> Vector<2> {
+    let (sin, cos) = t.sin_cos();
+    ((circle.b() * cos - circle.a() * sin) * sign).normalize()
+}
+
+/// The direction to the right of the provided one
+fn right_of(direction: Vector<2>) -> Vector<2> {
+    Vector::from([direction.v, -direction.u])
+}
+
+/// A line segment of a cycle, offset to its right side
+struct OffsetLine {
+    /// The start of the line segment, before it was offset
+    original: Point<2>,
+
+    /// The direction of the line
+    direction: Vector<2>,
+
+    /// The vector by which the line was offset
+    offset: Vector<2>,
+}
+
+impl OffsetLine {
+    fn new(start: Point<2>, end: Point<2>, distance: Scalar) -> Self {
+        let direction = (end - start).normalize();
+
+        Self {
+            original: start,
+            direction,
+            offset: right_of(direction) * distance,
+        }
+    }
+
+    fn origin(&self) -> Point<2> {
+        self.original + self.offset
+    }
+
+    fn point(&self, t: Scalar) -> Point<2> {
+        self.origin() + self.direction * t
+    }
+}
+
+/// The corner between two neighboring offset lines
+struct Corner {
+    /// The coordinate along the previous line, where it ends
+    end_of_prev: Scalar,
+
+    /// The coordinate along the next line, where it starts
+    start_of_next: Scalar,
+
+    /// The angle of the arc that connects both lines, if there is one
+    arc: Option<Scalar>,
+}
+
+impl Corner {
+    fn new(
+        prev: &OffsetLine,
+        next: &OffsetLine,
+        distance: Scalar,
+        join: OffsetJoin,
+    ) -> Self {
+        let cross = prev.direction.cross2d(&next.direction);
+
+        if cross.abs() < Scalar::default_epsilon() {
+            // The lines are parallel. If they are neighbors in the original
+            // cycle, they are collinear too, and the next one starts where the
+            // previous one ends.
+            return Self {
+                end_of_prev: (next.origin() - prev.origin())
+                    .dot(&prev.direction),
+                start_of_next: Scalar::ZERO,
+                arc: None,
+            };
+        }
+
+        // Where the offset lines separate, they need to be joined. Where they
+        // overlap, they are trimmed to where they intersect, which is what a
+        // miter join comes down to, too.
+        let is_open = cross * distance > Scalar::ZERO;
+
+        if is_open && join == OffsetJoin::Round {
+            // The arc is centered on the intersection of the original lines,
+            // which is the original corner, if the lines are neighbors there.
+            let [t, _] = intersect(
+                prev.original,
+                prev.direction,
+                next.original,
+                next.direction,
+            );
+            let center = prev.original + prev.direction * t;
+
+            let to_start = center + prev.offset - prev.origin();
+            let to_end = center + next.offset - next.origin();
+
+            return Self {
+                end_of_prev: to_start.dot(&prev.direction),
+                start_of_next: to_end.dot(&next.direction),
+                arc: Some(
+                    prev.offset
+                        .cross2d(&next.offset)
+                        .atan2(prev.offset.dot(&next.offset)),
+                ),
+            };
+        }
+
+        let [t, s] = intersect(
+            prev.origin(),
+            prev.direction,
+            next.origin(),
+            next.direction,
+        );
+
+        Self {
+            end_of_prev: t,
+            start_of_next: s,
+            arc: None,
+        }
+    }
+}
+
+/// Intersect two lines, returning the coordinates along each of them
+fn intersect(
+    origin_a: Point<2>,
+    direction_a: Vector<2>,
+    origin_b: Point<2>,
+    direction_b: Vector<2>,
+) -> [Scalar; 2] {
+    let cross = direction_a.cross2d(&direction_b);
+    let between = origin_b - origin_a;
+
+    let t = between.cross2d(&direction_b) / cross;
+    let s = between.cross2d(&direction_a) / cross;
+
+    [t, s]
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        geometry::SurfacePath,
+        objects::{Cycle, HalfEdge, Region},
+        operations::{
+            build::{BuildHalfEdge, BuildRegion},
+            insert::Insert,
+        },
+        services::Services,
+    };
+
+    use super::{OffsetError, OffsetJoin, OffsetRegion};
+
+    #[test]
+    fn offset_square_inward() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let square = Region::polygon(
+            [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+            &mut services,
+        );
+
+        let offset = square
+            .offset(-0.1, OffsetJoin::Miter, &mut services)?
+            .unwrap();
+
+        let starts = offset
+            .exterior()
+            .half_edges()
+            .iter()
+            .map(|half_edge| half_edge.start_position())
+            .collect::<Vec<_>>();
+        let expected = [[0.1, 0.1], [1.9, 0.1], [1.9, 1.9], [0.1, 1.9]];
+
+        assert_eq!(starts.len(), expected.len());
+        for (start, expected) in starts.into_iter().zip(expected) {
+            let distance = (start - Point::from(expected)).magnitude();
+            assert!(distance < Scalar::from(1e-12));
+        }
+
+        // Shrinking the square by more than half its size makes it vanish.
+        assert!(square
+            .offset(-1.5, OffsetJoin::Miter, &mut services)?
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn offset_square_outward() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let square = Region::polygon(
+            [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+            &mut services,
+        );
+
+        // Round joins add an arc at each corner.
+        let offset = square
+            .offset(0.1, OffsetJoin::Round, &mut services)?
+            .unwrap();
+        let num_arcs = offset
+            .exterior()
+            .half_edges()
+            .iter()
+            .filter(|half_edge| {
+                matches!(half_edge.path(), SurfacePath::Circle(_))
+            })
+            .count();
+        assert_eq!(offset.exterior().half_edges().len(), 8);
+        assert_eq!(num_arcs, 4);
+
+        // Miter joins extend the edges instead.
+        let offset = square
+            .offset(0.1, OffsetJoin::Miter, &mut services)?
+            .unwrap();
+        assert_eq!(offset.exterior().half_edges().len(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn offset_slot() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        // A slot, with round ends that meet its sides tangentially.
+        let slot = region(
+            [
+                HalfEdge::line_segment(
+                    [[-1., -1.], [1., -1.]],
+                    None,
+                    &mut services,
+                ),
+                HalfEdge::arc([1., -1.], [1., 1.], Scalar::PI, &mut services),
+                HalfEdge::line_segment(
+                    [[1., 1.], [-1., 1.]],
+                    None,
+                    &mut services,
+                ),
+                HalfEdge::arc([-1., 1.], [-1., -1.], Scalar::PI, &mut services),
+            ],
+            &mut services,
+        );
+
+        // The offset edges still meet, so no joins are required.
+        let offset =
+            slot.offset(0.5, OffsetJoin::Miter, &mut services)?.unwrap();
+        assert_eq!(offset.exterior().half_edges().len(), 4);
+        for radius in arc_radii(&offset) {
+            assert!((radius - Scalar::from(1.5)).abs() < Scalar::from(1e-12));
+        }
+
+        // Shrinking the slot by more than the radius of its ends makes them
+        // vanish.
+        assert_eq!(
+            slot.offset(-1.5, OffsetJoin::Miter, &mut services),
+            Err(OffsetError::ArcVanishes),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn offset_half_disc() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        // The arc meets the line at right angles.
+        let half_disc = region(
+            [
+                HalfEdge::arc([1., 0.], [-1., 0.], Scalar::PI, &mut services),
+                HalfEdge::line_segment(
+                    [[-1., 0.], [1., 0.]],
+                    None,
+                    &mut services,
+                ),
+            ],
+            &mut services,
+        );
+
+        // Round joins fill the gaps that open up at the corners.
+        let offset = half_disc
+            .offset(0.5, OffsetJoin::Round, &mut services)?
+            .unwrap();
+        assert_eq!(offset.exterior().half_edges().len(), 4);
+        assert_eq!(arc_radii(&offset).len(), 3);
+
+        // Extending an arc to a miter is not supported, and neither is
+        // trimming it.
+        assert_eq!(
+            half_disc.offset(0.5, OffsetJoin::Miter, &mut services),
+            Err(OffsetError::UnsupportedCorner),
+        );
+        assert_eq!(
+            half_disc.offset(-0.25, OffsetJoin::Round, &mut services),
+            Err(OffsetError::UnsupportedCorner),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn offset_degenerate_region() {
+        let mut services = Services::new();
+
+        let degenerate =
+            Region::polygon([[0., 0.], [1., 0.], [2., 0.]], &mut services);
+
+        assert_eq!(
+            degenerate.offset(0.1, OffsetJoin::Miter, &mut services),
+            Err(OffsetError::DegenerateCycle),
+        );
+    }
+
+    fn region(
+        half_edges: impl IntoIterator<Item = HalfEdge>,
+        services: &mut Services,
+    ) -> Region {
+        let half_edges = half_edges
+            .into_iter()
+            .map(|half_edge| half_edge.insert(services))
+            .collect::<Vec<_>>();
+        let exterior = Cycle::new(half_edges).insert(services);
+
+        Region::new(exterior, [], None)
+    }
+
+    fn arc_radii(region: &Region) -> Vec<Scalar> {
+        region
+            .exterior()
+            .half_edges()
+            .iter()
+            .filter_map(|half_edge| match half_edge.path() {
+                SurfacePath::Circle(circle) => Some(circle.radius()),
+                SurfacePath::Line(_) | SurfacePath::Spiral(_) => None,
+            })
+            .collect()
+    }
+}
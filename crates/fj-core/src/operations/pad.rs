@@ -0,0 +1,226 @@
+//! Add bosses to shapes
+
+use fj_math::Scalar;
+
+use crate::{
+    objects::{Face, Region, Shell, Sketch},
+    services::Services,
+    storage::Handle,
+    trace::operation_span,
+};
+
+use super::{
+    boolean::{contains_region, BooleanError},
+    insert::Insert,
+    pocket::add_interiors,
+    reverse::Reverse,
+    sweep::{SweepCache, SweepError, SweepRegion},
+    update::UpdateShell,
+};
+
+/// Add a pad to a [`Shell`]
+///
+/// A pad, or boss, is the volume that results from extruding a sketch away
+/// from a face of the shell. Adding it joins that volume to the shape, which
+/// is the inverse of cutting a pocket into the face.
+pub trait Pad: Sized {
+    /// Add a pad to the shell, starting at the provided face
+    ///
+    /// The sketch is placed on the face, as with [`SketchOnFace`], and extruded
+    /// along the face's normal by `height`. Each region of the sketch results
+    /// in a separate pad. Interior cycles of a region result in holes through
+    /// the pad, which end at the face.
+    ///
+    /// # Overhanging Sketches
+    ///
+    /// The sketch must lie within the boundary of the face. A pad that
+    /// overhangs the edge of the face would require splitting the faces that
+    /// are adjacent to it, which needs boolean operations on solids. Those are
+    /// not available yet. An error is returned for such a pad, as well as for
+    /// one that touches the boundary of the face, or covers a hole in it.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the face is not part of the shell.
+    ///
+    /// [`SketchOnFace`]: super::profile::SketchOnFace
+    fn pad(
+        &self,
+        face: &Handle<Face>,
+        sketch: &Sketch,
+        height: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Result<Self, PadError>;
+}
+
+impl Pad for Shell {
+    fn pad(
+        &self,
+        face: &Handle<Face>,
+        sketch: &Sketch,
+        height: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Result<Self, PadError> {
+        operation_span!("pad", regions = sketch.regions().len());
+
+        let normal = face.normal().ok_or(PadError::CurvedSurface)?;
+        let path = normal * height.into();
+
+        // Check all regions up front, so we don't insert any objects for a pad
+        // that we can't add.
+        for region in sketch.regions() {
+            if !contains_region(face.region(), region)? {
+                return Err(PadError::Overhang {
+                    region: region.clone(),
+                });
+            }
+        }
+
+        let exterior_winding = face.region().exterior().winding();
+        let mut cache = SweepCache::default();
+
+        let mut interiors = Vec::new();
+        let mut pad_faces = Vec::new();
+
+        for region in sketch.regions() {
+            // The region needs to face away from the path, for the faces of the
+            // sweep to face outward. This is also the right winding for its
+            // exterior to become a hole in the face.
+            let region = if region.exterior().winding() == exterior_winding {
+                region.reverse(services).insert(services)
+            } else {
+                region.clone()
+            };
+
+            // Holes in the pad go through it, and end at the face. What's left
+            // of the face there, is the bottom of the hole.
+            for interior in region.interiors() {
+                let region =
+                    Region::new(interior.clone(), [], face.region().color())
+                        .insert(services);
+                let bottom = Face::new(face.surface().clone(), region);
+                pad_faces.push(bottom.insert(services));
+            }
+
+            let swept = region.sweep_region(
+                face.surface(),
+                path,
+                &mut cache,
+                services,
+            )?;
+
+            interiors.push(region.exterior().clone());
+            pad_faces
+                .extend(swept.all_faces().map(|face| face.insert(services)));
+        }
+
+        Ok(add_interiors(self, face, interiors, services).add_faces(pad_faces))
+    }
+}
+
+/// Error adding a pad to a [`Shell`]
+///
+/// See [`Pad::pad`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum PadError {
+    /// The surface of the face is curved
+    #[error("Can't add pad to face with curved surface")]
+    CurvedSurface,
+
+    /// A region of the sketch doesn't lie within the boundary of the face
+    #[error("Pad overhangs the boundary of the face")]
+    Overhang {
+        /// The region that overhangs the face
+        region: Handle<Region>,
+    },
+
+    /// Error checking whether a region of the sketch overhangs the face
+    #[error("Error checking whether pad overhangs face")]
+    Boolean(#[from] BooleanError),
+
+    /// Error sweeping a region of the sketch
+    #[error("Error sweeping region")]
+    Sweep(#[from] SweepError),
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::approx::Tolerance,
+        fixtures::volume,
+        objects::{Region, Sketch, Solid},
+        operations::{
+            build::{BuildRegion, BuildSketch, BuildSolid},
+            insert::Insert,
+            update::UpdateSketch,
+        },
+        queries::SelectFaces,
+        services::Services,
+        validate::Validate,
+    };
+
+    use super::{Pad, PadError};
+
+    #[test]
+    fn cylindrical_boss_on_box() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let cuboid = Solid::cuboid([2., 2., 1.], &mut services);
+        let shell = cuboid.shells().only();
+        let top = cuboid
+            .faces_where(tolerance, |face| {
+                face.faces_direction([0., 0., 1.], 1e-6)
+            })?
+            .remove(0);
+
+        let sketch = Sketch::empty().add_region(
+            Region::circle(Point::origin(), 0.5, &mut services)
+                .insert(&mut services),
+        );
+        let shell = shell
+            .pad(&top, &sketch, 1., &mut services)?
+            .insert(&mut services);
+        shell.validate_and_return_first_error()?;
+
+        // The boss is approximated the same way as a cylinder of the same
+        // size, so the volumes match exactly.
+        let cylinder = Solid::cylinder(0.5, 1., &mut services);
+        let expected = Scalar::from(4.) + volume(&cylinder, tolerance)?;
+
+        let solid = Solid::new([shell]);
+        assert!(
+            (volume(&solid, tolerance)? - expected).abs() < Scalar::from(1e-9)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn overhanging_boss_on_box() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let cuboid = Solid::cuboid([2., 2., 1.], &mut services);
+        let shell = cuboid.shells().only();
+        let top = cuboid
+            .faces_where(tolerance, |face| {
+                face.faces_direction([0., 0., 1.], 1e-6)
+            })?
+            .remove(0);
+
+        // The circle reaches past the edge of the top face, at `x = 1`.
+        let region = Region::circle([0.75, 0.], 0.5, &mut services)
+            .insert(&mut services);
+        let sketch = Sketch::empty().add_region(region.clone());
+
+        assert_eq!(
+            shell.pad(&top, &sketch, 1., &mut services),
+            Err(PadError::Overhang { region })
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,400 @@
+//! Cut pockets into shapes
+
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{
+    objects::{Cycle, Face, Region, Shell, Sketch},
+    services::Services,
+    storage::Handle,
+    trace::operation_span,
+};
+
+use super::{
+    boolean::{contains_region, BooleanError},
+    insert::Insert,
+    reverse::Reverse,
+    sweep::{SweepCache, SweepError, SweepRegion},
+    update::{UpdateFace, UpdateRegion, UpdateShell},
+};
+
+/// Cut a pocket into a [`Shell`]
+///
+/// A pocket is the volume that results from extruding a sketch into a face of
+/// the shell. Cutting it removes that volume from the shape, which is the
+/// inverse of extruding a boss from the face.
+pub trait Pocket: Sized {
+    /// Cut a pocket into the shell, starting at the provided face
+    ///
+    /// The sketch is placed on the face, as with [`SketchOnFace`]. Each region
+    /// of the sketch results in a separate pocket.
+    ///
+    /// # Overhanging Sketches
+    ///
+    /// The sketch must lie within the boundary of the face. A pocket that
+    /// overhangs the edge of the face would require splitting the faces that
+    /// are adjacent to it, which needs boolean operations on solids. Those are
+    /// not available yet. An error is returned for such a pocket, as well as
+    /// for one that touches the boundary of the face, or covers a hole in it.
+    ///
+    /// # Islands
+    ///
+    /// Pockets that leave islands of material behind are not supported. An
+    /// error is returned, if a region of the sketch has interior cycles.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the face is not part of the shell.
+    ///
+    /// [`SketchOnFace`]: super::profile::SketchOnFace
+    fn pocket(
+        &self,
+        face: &Handle<Face>,
+        sketch: &Sketch,
+        depth: PocketDepth,
+        services: &mut Services,
+    ) -> Result<Self, PocketError>;
+}
+
+impl Pocket for Shell {
+    fn pocket(
+        &self,
+        face: &Handle<Face>,
+        sketch: &Sketch,
+        depth: PocketDepth,
+        services: &mut Services,
+    ) -> Result<Self, PocketError> {
+        operation_span!("pocket", regions = sketch.regions().len());
+
+        let normal = face.normal().ok_or(PocketError::CurvedSurface)?;
+
+        let (path, exit_face) = match depth {
+            PocketDepth::Blind(depth) => (normal * -depth, None),
+            PocketDepth::ThroughAll => {
+                let (exit_face, distance) = find_exit_face(self, face, normal)
+                    .ok_or(PocketError::NoExitFace)?;
+                (normal * -distance, Some(exit_face))
+            }
+        };
+
+        // Check all regions up front, so we don't insert any objects for a
+        // pocket that we can't cut.
+        for region in sketch.regions() {
+            if !region.interiors().is_empty() {
+                return Err(PocketError::Islands {
+                    region: region.clone(),
+                });
+            }
+            if !contains_region(face.region(), region)? {
+                return Err(PocketError::Overhang {
+                    region: region.clone(),
+                });
+            }
+
+            if let Some(exit_face) = &exit_face {
+                // The cycles at the bottom of the pocket are defined in the
+                // coordinates of the entry face, translated along the path.
+                // We're going to use them on the surface of the exit face.
+                let entry = face.surface().geometry();
+                let exit = exit_face.surface().geometry();
+
+                let is_compatible =
+                    region.exterior().half_edges().iter().all(|half_edge| {
+                        let point = half_edge.start_position();
+                        (entry.point_from_surface_coords(point) + path)
+                            .distance_to(&exit.point_from_surface_coords(point))
+                            < Scalar::from(1e-9)
+                    });
+                if !is_compatible {
+                    return Err(PocketError::IncompatibleExitFace {
+                        face: exit_face.clone(),
+                    });
+                }
+            }
+        }
+
+        let exterior_winding = face.region().exterior().winding();
+        let mut cache = SweepCache::default();
+
+        let mut entry_cycles = Vec::new();
+        let mut exit_cycles = Vec::new();
+        let mut pocket_faces = Vec::new();
+
+        for region in sketch.regions() {
+            // The faces of the pocket need to face into the pocket, which is
+            // the opposite of how the faces of a sweep are oriented. We get
+            // there by sweeping a region that faces along the path, which also
+            // gives it the right winding to become a hole in the face.
+            let region = if region.exterior().winding() == exterior_winding {
+                region.reverse(services).insert(services)
+            } else {
+                region.clone()
+            };
+
+            let swept = region.sweep_region(
+                face.surface(),
+                path,
+                &mut cache,
+                services,
+            )?;
+
+            entry_cycles.push(region.exterior().clone());
+            pocket_faces.extend(
+                swept
+                    .side_faces
+                    .into_iter()
+                    .map(|face| face.insert(services)),
+            );
+
+            if exit_face.is_some() {
+                exit_cycles.push(swept.top_face.region().exterior().clone());
+            } else {
+                pocket_faces.push(swept.top_face.insert(services));
+            }
+        }
+
+        let shell = add_interiors(self, face, entry_cycles, services);
+        let shell = match exit_face {
+            Some(exit_face) => {
+                add_interiors(&shell, &exit_face, exit_cycles, services)
+            }
+            None => shell,
+        };
+
+        Ok(shell.add_faces(pocket_faces))
+    }
+}
+
+/// Error cutting a pocket into a [`Shell`]
+///
+/// See [`Pocket::pocket`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum PocketError {
+    /// The surface of the face is curved
+    #[error("Can't cut pocket into face with curved surface")]
+    CurvedSurface,
+
+    /// A region of the sketch has interior cycles
+    #[error("Pockets with islands are not supported")]
+    Islands {
+        /// The region with interior cycles
+        region: Handle<Region>,
+    },
+
+    /// A region of the sketch doesn't lie within the boundary of the face
+    #[error("Pocket overhangs the boundary of the face")]
+    Overhang {
+        /// The region that overhangs the face
+        region: Handle<Region>,
+    },
+
+    /// No face was found for a [`PocketDepth::ThroughAll`] pocket to exit
+    #[error("Found no face for pocket to exit through")]
+    NoExitFace,
+
+    /// The exit face's surface coordinates are incompatible with the entry's
+    ///
+    /// See [`PocketDepth::ThroughAll`].
+    #[error("Exit face has incompatible surface coordinates")]
+    IncompatibleExitFace {
+        /// The face that the pocket would exit through
+        face: Handle<Face>,
+    },
+
+    /// Error checking whether a region of the sketch overhangs the face
+    #[error("Error checking whether pocket overhangs face")]
+    Boolean(#[from] BooleanError),
+
+    /// Error sweeping a region of the sketch
+    #[error("Error sweeping region")]
+    Sweep(#[from] SweepError),
+}
+
+/// The depth of a pocket
+#[derive(Clone, Copy, Debug)]
+pub enum PocketDepth {
+    /// The pocket ends at the provided depth, below the face it starts at
+    Blind(Scalar),
+
+    /// The pocket goes all the way through the shape
+    ///
+    /// It ends at the nearest face on the other side that is parallel to the
+    /// face that it starts at. The surface coordinates of both faces must be
+    /// compatible, meaning that one surface is the other, translated along
+    /// the normal.
+    ThroughAll,
+}
+
+/// Find the face that a through-all pocket exits through
+///
+/// Returns the face, and its distance from the entry face, if there is one.
+fn find_exit_face(
+    shell: &Shell,
+    entry_face: &Handle<Face>,
+    normal: Vector<3>,
+) -> Option<(Handle<Face>, Scalar)> {
+    let origin_of = |face: &Face| {
+        face.surface()
+            .geometry()
+            .point_from_surface_coords(Point::origin())
+    };
+    let entry_origin = origin_of(entry_face);
+
+    shell
+        .faces()
+        .iter()
+        .filter(|face| face.id() != entry_face.id())
+        .filter_map(|face| {
+            let exit_normal = face.normal()?;
+
+            // The exit face faces away from the entry face.
+            let is_parallel =
+                (exit_normal + normal).magnitude() < Scalar::from(1e-9);
+            if !is_parallel {
+                return None;
+            }
+
+            let distance = (origin_of(face) - entry_origin).dot(&-normal);
+            (distance > Scalar::ZERO).then(|| (face.clone(), distance))
+        })
+        .min_by_key(|(_, distance)| *distance)
+}
+
+/// Add interior cycles to a face of the shell
+pub(super) fn add_interiors(
+    shell: &Shell,
+    face: &Handle<Face>,
+    interiors: Vec<Handle<Cycle>>,
+    services: &mut Services,
+) -> Shell {
+    shell.update_face(face, |face| {
+        face.update_region(|region| {
+            region.add_interiors(interiors).insert(services)
+        })
+        .insert(services)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::approx::Tolerance,
+        fixtures::volume,
+        objects::{Cycle, Region, Sketch, Solid},
+        operations::{
+            build::{BuildCycle, BuildRegion, BuildSketch, BuildSolid},
+            insert::Insert,
+            reverse::Reverse,
+            update::{UpdateRegion, UpdateSketch},
+        },
+        queries::SelectFaces,
+        services::Services,
+        validate::Validate,
+    };
+
+    use super::{Pocket, PocketDepth, PocketError};
+
+    #[test]
+    fn square_pocket_in_box() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let cuboid = Solid::cuboid([2., 2., 1.], &mut services);
+        let shell = cuboid.shells().only();
+        let top = cuboid
+            .faces_where(tolerance, |face| {
+                face.faces_direction([0., 0., 1.], 1e-6)
+            })?
+            .remove(0);
+
+        let sketch = Sketch::empty().add_region(
+            Region::polygon(
+                [[-0.5, -0.5], [0.5, -0.5], [0.5, 0.5], [-0.5, 0.5]],
+                &mut services,
+            )
+            .insert(&mut services),
+        );
+
+        for (depth, pocket_volume) in [
+            (PocketDepth::Blind(Scalar::from(0.5)), 0.5),
+            (PocketDepth::ThroughAll, 1.),
+        ] {
+            let shell = shell
+                .pocket(&top, &sketch, depth, &mut services)?
+                .insert(&mut services);
+            shell.validate_and_return_first_error()?;
+
+            let solid = Solid::new([shell]);
+            let expected = Scalar::from(4. - pocket_volume);
+            assert!(
+                (volume(&solid, tolerance)? - expected).abs()
+                    < Scalar::from(1e-9)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn overhanging_pocket_in_box() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let cuboid = Solid::cuboid([2., 2., 1.], &mut services);
+        let top = cuboid
+            .faces_where(tolerance, |face| {
+                face.faces_direction([0., 0., 1.], 1e-6)
+            })?
+            .remove(0);
+
+        // The circle reaches past the edge of the top face, at `x = 1`.
+        let region = Region::circle([0.75, 0.], 0.5, &mut services)
+            .insert(&mut services);
+        let sketch = Sketch::empty().add_region(region.clone());
+
+        assert_eq!(
+            cuboid.shells().only().pocket(
+                &top,
+                &sketch,
+                PocketDepth::ThroughAll,
+                &mut services
+            ),
+            Err(PocketError::Overhang { region })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn pocket_with_island() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let cuboid = Solid::cuboid([2., 2., 1.], &mut services);
+        let top = cuboid
+            .faces_where(tolerance, |face| {
+                face.faces_direction([0., 0., 1.], 1e-6)
+            })?
+            .remove(0);
+
+        let region = Region::circle([0., 0.], 0.5, &mut services)
+            .add_interiors([Cycle::circle([0., 0.], 0.25, &mut services)
+                .reverse(&mut services)
+                .insert(&mut services)])
+            .insert(&mut services);
+        let sketch = Sketch::empty().add_region(region.clone());
+
+        assert_eq!(
+            cuboid.shells().only().pocket(
+                &top,
+                &sketch,
+                PocketDepth::Blind(Scalar::from(0.5)),
+                &mut services
+            ),
+            Err(PocketError::Islands { region })
+        );
+
+        Ok(())
+    }
+}
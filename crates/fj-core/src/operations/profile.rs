@@ -0,0 +1,282 @@
+//! Reuse faces as profiles for other operations
+
+use fj_math::{Scalar, Vector, Winding};
+
+use crate::{
+    objects::{Cycle, Face, HalfEdge, Region, Sketch, Solid, Surface},
+    services::Services,
+    storage::Handle,
+};
+
+use super::{
+    build::BuildHalfEdge,
+    insert::Insert,
+    reverse::Reverse,
+    sweep::{SweepSketch, SweepSketchError},
+};
+
+/// Extract the boundary of a [`Face`] as a standalone [`Region`]
+///
+/// This enables workflows that select a face of an existing shape, and use it
+/// as the starting point for a new one. For example, to extrude a boss on top
+/// of a face, extract its region, and sweep it from the face's surface.
+pub trait ToRegion {
+    /// Extract the boundary of the face as a region
+    ///
+    /// The region is defined in the surface coordinates of the face. Interior
+    /// cycles of the face become interior cycles of the region.
+    ///
+    /// The region doesn't share any half-edges, curves, or vertices with the
+    /// face, so using it doesn't affect the shape that the face belongs to.
+    /// Its exterior is wound counter-clockwise, as expected by [`SweepSketch`],
+    /// regardless of which side of its surface the face is facing.
+    ///
+    /// [`SweepSketch`]: crate::operations::sweep::SweepSketch
+    #[must_use]
+    fn to_region(&self, services: &mut Services) -> Handle<Region>;
+}
+
+impl ToRegion for Face {
+    fn to_region(&self, services: &mut Services) -> Handle<Region> {
+        let region = self.region();
+
+        let exterior = copy_cycle(region.exterior(), services);
+        let interiors = region
+            .interiors()
+            .iter()
+            .map(|interior| copy_cycle(interior, services))
+            .collect::<Vec<_>>();
+
+        let region = Region::new(exterior, interiors, region.color());
+
+        match region.exterior().winding() {
+            Winding::Ccw => region.insert(services),
+            Winding::Cw => region.reverse(services).insert(services),
+        }
+    }
+}
+
+/// Place a [`Sketch`] on a [`Face`]
+///
+/// This is the starting point for features that are built on existing faces,
+/// like a boss that is extruded from a face.
+pub trait SketchOnFace {
+    /// Place the sketch on the face
+    ///
+    /// The sketch is interpreted in the surface coordinates of the face. It is
+    /// not checked whether it actually lies within the boundary of the face.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the surface of the face is curved. Sketches can only be
+    /// placed on planar faces.
+    fn sketch_on_face(&self, sketch: Sketch) -> FaceSketch;
+}
+
+impl SketchOnFace for Face {
+    fn sketch_on_face(&self, sketch: Sketch) -> FaceSketch {
+        let normal = self
+            .normal()
+            .expect("Can't place sketch on face with curved surface");
+
+        FaceSketch {
+            sketch,
+            surface: self.surface().clone(),
+            normal,
+        }
+    }
+}
+
+/// A [`Sketch`] that has been placed on a [`Face`]
+///
+/// Returned by [`SketchOnFace::sketch_on_face`].
+#[derive(Clone, Debug)]
+pub struct FaceSketch {
+    /// The sketch, in the surface coordinates of the face
+    pub sketch: Sketch,
+
+    /// The surface of the face
+    pub surface: Handle<Surface>,
+
+    /// The normal of the face, pointing away from its front side
+    ///
+    /// For a face that is part of a shell, this points outward.
+    pub normal: Vector<3>,
+}
+
+impl FaceSketch {
+    /// Extrude the sketch along the normal of the face
+    ///
+    /// A positive distance extrudes the sketch away from the face, a negative
+    /// one extrudes it into the shape that the face belongs to.
+    ///
+    /// See [`SweepSketch::sweep_sketch`] for the errors that can be returned.
+    pub fn extrude(
+        &self,
+        distance: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Result<Solid, SweepSketchError> {
+        self.sketch.sweep_sketch(
+            self.surface.clone(),
+            self.normal * distance.into(),
+            services,
+        )
+    }
+}
+
+/// Copy the geometry of a cycle, without sharing any objects with it
+fn copy_cycle(cycle: &Cycle, services: &mut Services) -> Handle<Cycle> {
+    let half_edges = cycle
+        .half_edges()
+        .iter()
+        .map(|half_edge| {
+            HalfEdge::unjoined(half_edge.path(), half_edge.boundary(), services)
+                .insert(services)
+        })
+        .collect::<Vec<_>>();
+
+    Cycle::new(half_edges).insert(services)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Vector, Winding};
+
+    use crate::{
+        algorithms::{
+            approx::{Approx, Tolerance},
+            bounding_volume::BoundingVolume,
+        },
+        objects::{Cycle, Region, Sketch, Solid},
+        operations::{
+            build::{BuildCycle, BuildRegion, BuildSketch, BuildSolid},
+            insert::Insert,
+            reverse::Reverse,
+            sweep::SweepSketch,
+            update::{UpdateRegion, UpdateSketch},
+        },
+        queries::{ContainsPoint, SelectFaces},
+        services::Services,
+    };
+
+    use super::{SketchOnFace, ToRegion};
+
+    #[test]
+    fn spacer_top_face() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let spacer = Sketch::empty()
+            .add_region(
+                Region::circle(Point::origin(), 1., &mut services)
+                    .add_interiors([Cycle::circle(
+                        Point::origin(),
+                        0.5,
+                        &mut services,
+                    )
+                    .reverse(&mut services)
+                    .insert(&mut services)])
+                    .insert(&mut services),
+            )
+            .sweep_sketch(
+                services.objects.surfaces.xy_plane(),
+                Vector::from([0., 0., 1.]),
+                &mut services,
+            )?;
+        let [bottom, top] = [-1., 1.].map(|z| {
+            spacer
+                .faces_where(tolerance, |face| {
+                    face.faces_direction([0., 0., z], 1e-6)
+                })
+                .map(|mut faces| faces.remove(0))
+        });
+        let [bottom, top] = [bottom?, top?];
+
+        let expected_area = Scalar::PI * (1. - 0.5 * 0.5);
+
+        for face in [bottom, top] {
+            let region = face.to_region(&mut services);
+
+            assert_eq!(region.exterior().winding(), Winding::Ccw);
+            assert_eq!(region.interiors().len(), 1);
+
+            let region_area = area(&region, tolerance);
+            assert!((region_area - expected_area).abs() < Scalar::from(0.01));
+
+            for (a, b) in face.region().all_cycles().zip(region.all_cycles()) {
+                assert!(a
+                    .half_edges()
+                    .iter()
+                    .zip(b.half_edges())
+                    .all(|(a, b)| a.curve().id() != b.curve().id()
+                        && a.start_vertex().id() != b.start_vertex().id()));
+            }
+        }
+
+        // The top face is already oriented the right way, so its region keeps
+        // the same area, including its sign.
+        assert!(
+            (area(top.region(), tolerance) - expected_area).abs()
+                < Scalar::from(0.01)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn peg_on_box() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let cuboid = Solid::cuboid([2., 2., 1.], &mut services);
+        let top = cuboid
+            .faces_where(tolerance, |face| {
+                face.faces_direction([0., 0., 1.], 1e-6)
+            })?
+            .remove(0);
+
+        let peg = top
+            .sketch_on_face(
+                Sketch::empty().add_region(
+                    Region::circle([0.5, 0.5], 0.25, &mut services)
+                        .insert(&mut services),
+                ),
+            )
+            .extrude(0.5, &mut services)?;
+
+        assert!(peg.contains_point([0.5, 0.5, 1.25], tolerance)?);
+        assert!(!peg.contains_point([0.5, 0.5, 0.75], tolerance)?);
+        assert!(!peg.contains_point([0., 0., 1.25], tolerance)?);
+
+        let aabb = peg.aabb().unwrap();
+        assert!((aabb.min.z - 1.).abs() < Scalar::from(1e-12));
+        assert!((aabb.max.z - 1.5).abs() < Scalar::from(1e-12));
+
+        Ok(())
+    }
+
+    /// Compute the signed area of a region, using its approximated boundary
+    fn area(region: &Region, tolerance: Tolerance) -> Scalar {
+        let mut area = Scalar::ZERO;
+
+        for cycle in region.all_cycles() {
+            let mut points = Vec::new();
+            for half_edge in cycle.half_edges() {
+                points.push(half_edge.start_position());
+                points.extend(
+                    (&half_edge.path(), half_edge.boundary())
+                        .approx(tolerance)
+                        .into_iter()
+                        .map(|(_, point)| point),
+                );
+            }
+
+            for (i, a) in points.iter().enumerate() {
+                let b = points[(i + 1) % points.len()];
+                area += (a.u * b.v - b.u * a.v) / 2.;
+            }
+        }
+
+        area
+    }
+}
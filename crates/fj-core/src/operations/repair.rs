@@ -0,0 +1,254 @@
+//! Repair shapes
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use fj_math::{Point, Scalar};
+
+use crate::{
+    algorithms::{
+        approx::{ApproxError, Tolerance},
+        triangulate::Triangulate,
+    },
+    geometry::CurveBoundary,
+    objects::{Curve, Shell},
+    queries::CoincidentFaces,
+    services::Services,
+    storage::HandleWrapper,
+    validate::ValidationConfig,
+};
+
+use super::{insert::Insert, reverse::Reverse};
+
+/// Repair the orientation of the faces of a [`Shell`]
+pub trait RepairOrientation: Sized {
+    /// Orient all faces consistently, with their front sides facing outward
+    ///
+    /// Two adjacent faces are oriented consistently, if their half-edges along
+    /// the shared edge run in opposite directions. Starting from a seed face,
+    /// this operation visits all faces across shared edges, and reverses any
+    /// face that is not oriented consistently with the face it was reached
+    /// from.
+    ///
+    /// Afterwards, the volume enclosed by the shell is computed from its
+    /// triangulation, using the provided tolerance. If that volume is negative,
+    /// the faces are facing inward, and all of them are reversed.
+    ///
+    /// Assumes that the shell is connected. For a shell that consists of
+    /// multiple disconnected parts, each part is oriented consistently, but
+    /// only the shell as a whole is oriented outward.
+    ///
+    /// Returns an error, if the shell can't be approximated.
+    fn repair_orientation(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        services: &mut Services,
+    ) -> Result<Self, ApproxError>;
+}
+
+impl RepairOrientation for Shell {
+    fn repair_orientation(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        services: &mut Services,
+    ) -> Result<Self, ApproxError> {
+        let faces = self.faces().iter().collect::<Vec<_>>();
+
+        // Find the half-edges along each edge, and which face they belong to.
+        let mut half_edges_by_edge = BTreeMap::<
+            (HandleWrapper<Curve>, CurveBoundary<Point<1>>),
+            Vec<(usize, CurveBoundary<Point<1>>)>,
+        >::new();
+        for (i, face) in faces.iter().enumerate() {
+            for cycle in face.region().all_cycles() {
+                for half_edge in cycle.half_edges() {
+                    let boundary = half_edge.boundary();
+                    let key = (
+                        half_edge.curve().clone().into(),
+                        boundary.normalize(),
+                    );
+
+                    half_edges_by_edge
+                        .entry(key)
+                        .or_default()
+                        .push((i, boundary));
+                }
+            }
+        }
+
+        // For each face, its neighbors, and whether the half-edges along the
+        // shared edge run in the same direction.
+        let mut neighbors = vec![Vec::new(); faces.len()];
+        for half_edges in half_edges_by_edge.values() {
+            for &(i, boundary_i) in half_edges {
+                for &(j, boundary_j) in half_edges {
+                    if i != j {
+                        neighbors[i].push((j, boundary_i == boundary_j));
+                    }
+                }
+            }
+        }
+
+        // Flood-fill from seed faces, deciding which faces need to be
+        // reversed. A face needs to be reversed, if the face it is reached from
+        // does and the half-edges run in opposite directions, or if the face it
+        // is reached from doesn't and the half-edges run in the same direction.
+        let mut reverse = vec![None; faces.len()];
+        for seed in 0..faces.len() {
+            if reverse[seed].is_some() {
+                continue;
+            }
+
+            reverse[seed] = Some(false);
+            let mut queue = VecDeque::from([seed]);
+
+            while let Some(i) = queue.pop_front() {
+                let reverse_i = reverse[i] == Some(true);
+
+                for &(j, same_direction) in &neighbors[i] {
+                    if reverse[j].is_none() {
+                        reverse[j] = Some(reverse_i != same_direction);
+                        queue.push_back(j);
+                    }
+                }
+            }
+        }
+
+        let consistent = Shell::new(faces.into_iter().zip(reverse).map(
+            |(face, reverse)| {
+                if reverse == Some(true) {
+                    face.reverse(services).insert(services)
+                } else {
+                    face.clone()
+                }
+            },
+        ));
+
+        // Sum up the signed volumes of the tetrahedra formed by the origin and
+        // each triangle. This is positive, if the triangles face outward.
+        let mesh = (&consistent, tolerance.into()).triangulate()?;
+        let volume = mesh.triangles().fold(Scalar::ZERO, |volume, triangle| {
+            let [a, b, c] = triangle.inner.points().map(|point| point.coords);
+            volume + a.dot(&b.cross(&c))
+        });
+
+        if volume < Scalar::ZERO {
+            Ok(consistent.reverse(services))
+        } else {
+            Ok(consistent)
+        }
+    }
+}
+
+/// Remove redundant faces from a [`Shell`]
+pub trait RemoveCoincidentFaces {
+    /// Remove faces that coincide with another face of the shell
+    ///
+    /// Of each group of coincident faces, the one that comes first in the shell
+    /// is kept. Faces are considered to be coincident, as determined by
+    /// [`CoincidentFaces`], using the default
+    /// [`ValidationConfig::distinct_min_distance`].
+    #[must_use]
+    fn remove_coincident_faces(&self) -> Self;
+}
+
+impl RemoveCoincidentFaces for Shell {
+    fn remove_coincident_faces(&self) -> Self {
+        let max_distance = ValidationConfig::default().distinct_min_distance;
+
+        // The pairs are ordered by their first face. So by the time we get to
+        // a face as the first of a pair, we already know whether it's going to
+        // be kept. If it's not, the second one coincides with the face that is
+        // kept instead, and has been marked for removal already.
+        let mut redundant = BTreeSet::new();
+        for [face, other] in self.coincident_faces(max_distance) {
+            if !redundant.contains(&HandleWrapper::from(face)) {
+                redundant.insert(HandleWrapper::from(other));
+            }
+        }
+
+        Shell::new(
+            self.faces()
+                .iter()
+                .filter(|face| {
+                    !redundant.contains(&HandleWrapper::from((*face).clone()))
+                })
+                .cloned(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        objects::Shell,
+        operations::{
+            build::BuildShell, insert::Insert, reverse::Reverse,
+            update::UpdateShell,
+        },
+        services::Services,
+        validate::Validate,
+    };
+
+    use super::{RemoveCoincidentFaces, RepairOrientation};
+
+    #[test]
+    fn reversed_face() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let shell = tetrahedron(&mut services);
+        let broken =
+            Shell::new(shell.faces().iter().enumerate().map(|(i, face)| {
+                if i == 1 {
+                    face.reverse(&mut services).insert(&mut services)
+                } else {
+                    face.clone()
+                }
+            }));
+        assert!(broken.validate_and_return_first_error().is_err());
+
+        let repaired = broken.repair_orientation(0.01, &mut services)?;
+        repaired.validate_and_return_first_error()?;
+        assert_eq!(repaired, shell);
+
+        Ok(())
+    }
+
+    #[test]
+    fn inward_facing_shell() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let shell = tetrahedron(&mut services);
+        let inward = shell.reverse(&mut services);
+
+        let repaired = inward.repair_orientation(0.01, &mut services)?;
+        repaired.validate_and_return_first_error()?;
+        assert_eq!(repaired, shell);
+
+        Ok(())
+    }
+
+    #[test]
+    fn duplicate_face() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let shell = tetrahedron(&mut services);
+        let face = shell.faces().first().clone();
+        let duplicate = face.clone_object().insert(&mut services);
+        let broken = shell.add_faces([duplicate]);
+        assert!(broken.validate_and_return_first_error().is_err());
+
+        let repaired = broken.remove_coincident_faces();
+        repaired.validate_and_return_first_error()?;
+        assert_eq!(repaired, shell);
+
+        Ok(())
+    }
+
+    fn tetrahedron(services: &mut Services) -> Shell {
+        Shell::tetrahedron(
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]],
+            services,
+        )
+        .shell
+    }
+}
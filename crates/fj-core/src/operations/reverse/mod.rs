@@ -6,6 +6,9 @@ mod cycle;
 mod edge;
 mod face;
 mod region;
+mod shell;
+mod solid;
+mod surface;
 
 /// Reverse the direction/orientation of an object
 pub trait Reverse {
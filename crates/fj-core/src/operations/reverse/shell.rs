@@ -0,0 +1,54 @@
+use crate::{objects::Shell, operations::insert::Insert, services::Services};
+
+use super::Reverse;
+
+impl Reverse for Shell {
+    fn reverse(&self, services: &mut Services) -> Self {
+        let faces = self
+            .faces()
+            .iter()
+            .map(|face| face.reverse(services).insert(services));
+
+        Shell::new(faces)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        objects::Shell,
+        operations::{build::BuildShell, reverse::Reverse},
+        services::Services,
+        validate::Validate,
+    };
+
+    #[test]
+    fn reversed_shell_is_valid() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let shell = Shell::tetrahedron(
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]],
+            &mut services,
+        )
+        .shell;
+        let reversed = shell.reverse(&mut services);
+
+        reversed.validate_and_return_first_error()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn double_reverse_is_identity() {
+        let mut services = Services::new();
+
+        let shell = Shell::tetrahedron(
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]],
+            &mut services,
+        )
+        .shell;
+        let reversed = shell.reverse(&mut services).reverse(&mut services);
+
+        assert_eq!(reversed, shell);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{objects::Solid, operations::insert::Insert, services::Services};
+
+use super::Reverse;
+
+impl Reverse for Solid {
+    fn reverse(&self, services: &mut Services) -> Self {
+        let shells = self
+            .shells()
+            .iter()
+            .map(|shell| shell.reverse(services).insert(services));
+
+        Solid::new(shells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fixtures, objects::Solid, operations::reverse::Reverse,
+        services::Services, validate::validate_all,
+    };
+
+    #[test]
+    fn reversed_solid_is_valid() {
+        let mut services = Services::new();
+
+        let solid = fixtures::cube(&mut services);
+        let reversed = solid.reverse(&mut services);
+
+        let report = validate_all(&reversed);
+        assert!(report.is_valid(), "{report}");
+    }
+
+    #[test]
+    fn double_reverse_restores_face_orientation() {
+        let mut services = Services::new();
+
+        let solid = fixtures::cube(&mut services);
+        let reversed = solid.reverse(&mut services);
+        let restored = reversed.reverse(&mut services);
+
+        let faces = |solid: &Solid| {
+            solid
+                .shells()
+                .iter()
+                .flat_map(|shell| shell.faces().iter().cloned())
+                .collect::<Vec<_>>()
+        };
+
+        for ((original, reversed), restored) in faces(&solid)
+            .into_iter()
+            .zip(faces(&reversed))
+            .zip(faces(&restored))
+        {
+            assert_ne!(
+                original.coord_handedness(),
+                reversed.coord_handedness()
+            );
+            assert_eq!(
+                original.coord_handedness(),
+                restored.coord_handedness()
+            );
+        }
+        assert_eq!(restored, solid);
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{geometry::SurfaceGeometry, objects::Surface, services::Services};
+
+use super::Reverse;
+
+/// Reverse the orientation of a [`Surface`]
+///
+/// The v-axis of the surface is flipped, which flips its normal. Points on the
+/// surface don't move, but their surface coordinates change: A point at `[u,
+/// v]` on the original surface is at `[u, -v]` on the reversed one.
+///
+/// Whether a face points towards one side of its surface or the other, is
+/// defined by the winding of its exterior cycle in surface coordinates.
+/// Flipping the v-axis mirrors those coordinates, which flips the winding too.
+/// So to move a face to the reversed surface, without changing its position
+/// or orientation in space, the v-coordinates of its half-edges must be negated
+/// too.
+impl Reverse for Surface {
+    fn reverse(&self, _: &mut Services) -> Self {
+        let SurfaceGeometry { u, v } = self.geometry();
+        Surface::new(SurfaceGeometry { u, v: -v })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{operations::reverse::Reverse, services::Services};
+
+    #[test]
+    fn reverse() {
+        let mut services = Services::new();
+
+        let surface = services.objects.surfaces.xz_plane();
+        let reversed = surface.reverse(&mut services);
+
+        assert_ne!(reversed, *surface);
+        assert_eq!(reversed.reverse(&mut services), *surface);
+
+        let [u, v] = [0.5, 2.];
+        assert_eq!(
+            reversed.geometry().point_from_surface_coords([u, -v]),
+            surface.geometry().point_from_surface_coords([u, v]),
+        );
+    }
+}
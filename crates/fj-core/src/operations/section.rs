@@ -0,0 +1,607 @@
+//! Compute the cross-section of shapes
+
+use fj_math::{Circle, Line, Plane, Point, Scalar, Vector};
+
+use crate::{
+    algorithms::intersect::{
+        CurveFaceIntersection, SurfaceSurfaceIntersection,
+    },
+    geometry::{CurveBoundary, GlobalPath, SurfacePath},
+    objects::{
+        Curve, Cycle, Face, HalfEdge, Region, Sketch, Solid, Surface, Vertex,
+    },
+    services::Services,
+    storage::Handle,
+    validate::ValidationConfig,
+};
+
+use super::{insert::Insert, reverse::Reverse};
+
+/// Compute the cross-section of a [`Solid`]
+pub trait Section {
+    /// Compute the intersection of the solid with a plane
+    ///
+    /// Returns a sketch in the coordinates of the provided surface, which must
+    /// be a plane. The regions of the sketch are the areas where the plane
+    /// intersects the material of the solid. If the plane misses the solid, the
+    /// sketch is empty.
+    ///
+    /// Faces that lie within the plane don't contribute to the section. This
+    /// means that, if the plane only touches the solid, the sketch is empty.
+    ///
+    /// # Implementation Note
+    ///
+    /// Currently, only the intersection with planar faces, and with faces that
+    /// are swept from a circle along the plane's normal, is supported. Returns
+    /// an error, if the solid has any other curved face that isn't parallel to
+    /// the plane.
+    fn section(
+        &self,
+        surface: Handle<Surface>,
+        services: &mut Services,
+    ) -> Result<Sketch, SectionError>;
+}
+
+impl Section for Solid {
+    fn section(
+        &self,
+        surface: Handle<Surface>,
+        services: &mut Services,
+    ) -> Result<Sketch, SectionError> {
+        let plane = plane_from_surface(&surface)
+            .ok_or(SectionError::SurfaceIsNotAPlane)?;
+
+        let mut edges = Vec::new();
+        for shell in self.shells() {
+            for face in shell.faces() {
+                edges.extend(section_face(face, &surface, &plane)?);
+            }
+        }
+
+        let cycles = connect_edges(edges)?
+            .into_iter()
+            .map(|cycle| build_cycle(cycle, services))
+            .collect::<Vec<_>>();
+
+        Ok(build_sketch(cycles, services))
+    }
+}
+
+/// Error computing a section
+///
+/// See [`Section::section`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum SectionError {
+    /// The surface that the solid is sectioned with is not a plane
+    #[error("Can only compute the section with a plane")]
+    SurfaceIsNotAPlane,
+
+    /// The plane intersects a swept circle at an angle
+    #[error(
+        "Section of a swept circle is only supported, if the sweep is \
+        perpendicular to the plane"
+    )]
+    UnsupportedSweptCircle,
+
+    /// The edges of the section don't form closed cycles
+    ///
+    /// This can only happen, if the solid is not valid.
+    #[error("Section edges don't form closed cycles")]
+    OpenCycle,
+}
+
+/// An edge of the section, in the coordinates of the section plane
+#[derive(Clone, Copy)]
+pub(super) struct SectionEdge {
+    pub(super) path: SurfacePath,
+    pub(super) boundary: CurveBoundary<Point<1>>,
+}
+
+impl SectionEdge {
+    pub(super) fn start(&self) -> Point<2> {
+        let [start, _] = self.boundary.inner;
+        self.path.point_from_path_coords(start)
+    }
+
+    pub(super) fn end(&self) -> Point<2> {
+        let [_, end] = self.boundary.inner;
+        self.path.point_from_path_coords(end)
+    }
+
+    pub(super) fn reverse(self) -> Self {
+        Self {
+            path: self.path,
+            boundary: self.boundary.reverse(),
+        }
+    }
+
+    pub(super) fn is_closed(&self) -> bool {
+        is_same_point(self.start(), self.end())
+    }
+}
+
+fn section_face(
+    face: &Face,
+    surface: &Handle<Surface>,
+    plane: &Plane,
+) -> Result<Vec<SectionEdge>, SectionError> {
+    let (path_on_face, path_on_plane) = match face.surface().geometry().u {
+        GlobalPath::Line(_) => {
+            let face_plane = plane_from_surface(face.surface())
+                .expect("Surface with a line as `u` is a plane");
+
+            // The intersection line of nearly parallel planes is numerically
+            // meaningless, so we treat those as parallel.
+            let sine_of_angle = face_plane
+                .normal()
+                .normalize()
+                .cross(&plane.normal().normalize())
+                .magnitude();
+            if sine_of_angle < parallel_tolerance() {
+                return Ok(Vec::new());
+            }
+
+            let Some(intersection) = SurfaceSurfaceIntersection::compute([
+                face.surface().clone(),
+                surface.clone(),
+            ]) else {
+                // The face is parallel to the plane.
+                return Ok(Vec::new());
+            };
+
+            let [path_on_face, path_on_plane] =
+                intersection.intersection_curves;
+
+            (path_on_face, path_on_plane)
+        }
+        GlobalPath::Circle(circle) => {
+            let path = face.surface().geometry().v;
+            match section_swept_circle(circle, path, plane)? {
+                Some(paths) => paths,
+                None => return Ok(Vec::new()),
+            }
+        }
+    };
+
+    let edges = CurveFaceIntersection::compute(&path_on_face, face)
+        .intervals
+        .into_iter()
+        // If the intersection touches the face in a single point, or lies on
+        // its boundary, we can end up with empty intervals here. Those don't
+        // contribute to the section.
+        .filter(|interval| interval.start < interval.end)
+        .map(|interval| SectionEdge {
+            path: path_on_plane,
+            boundary: CurveBoundary::from([interval.start, interval.end]),
+        })
+        .collect();
+
+    Ok(edges)
+}
+
+/// Compute the section of a surface that was swept from a circle
+///
+/// Returns the intersection curve in surface coordinates of the swept surface,
+/// and the same curve in the coordinates of the plane. Both share the same curve
+/// coordinates.
+///
+/// Returns `None`, if the surface is parallel to the plane.
+fn section_swept_circle(
+    circle: Circle<3>,
+    path: Vector<3>,
+    plane: &Plane,
+) -> Result<Option<(SurfacePath, SurfacePath)>, SectionError> {
+    let (distance, normal) = plane.constant_normal_form();
+    let tolerance = ValidationConfig::default().distinct_min_distance;
+
+    // The components of the circle's axes and of the path, that are
+    // perpendicular to the plane, measure how far the surface deviates from
+    // the plane along them. As long as that's too small to matter, we consider
+    // the surface to be parallel to the plane.
+    let circle_is_parallel_to_plane = normal.dot(&circle.a()).abs() < tolerance
+        && normal.dot(&circle.b()).abs() < tolerance;
+    let path_is_parallel_to_plane = normal.dot(&path).abs() < tolerance;
+    let path_is_perpendicular_to_plane =
+        normal.cross(&path).magnitude() < tolerance;
+
+    if circle_is_parallel_to_plane && path_is_parallel_to_plane {
+        return Ok(None);
+    }
+    if !(circle_is_parallel_to_plane && path_is_perpendicular_to_plane) {
+        return Err(SectionError::UnsupportedSweptCircle);
+    }
+
+    // The surface intersects the plane at a single `v` coordinate. Where
+    // exactly, we can compute from the condition that the point on the surface
+    // must be on the plane: `normal * (center + v * path) = distance`.
+    let v =
+        (distance - normal.dot(&circle.center().coords)) / normal.dot(&path);
+
+    // The circle's coordinates are the surface's `u` coordinates, so the curve
+    // in surface coordinates is a line along `u`.
+    let path_on_surface = SurfacePath::Line(Line::from_origin_and_direction(
+        Point::from([Scalar::ZERO, v]),
+        Vector::unit_u(),
+    ));
+    let path_on_plane = SurfacePath::Circle(Circle::new(
+        plane.project_point(circle.center() + path * v),
+        plane.project_vector(circle.a()),
+        plane.project_vector(circle.b()),
+    ));
+
+    Ok(Some((path_on_surface, path_on_plane)))
+}
+
+/// Connect the edges of the section into closed cycles
+fn connect_edges(
+    mut edges: Vec<SectionEdge>,
+) -> Result<Vec<Vec<SectionEdge>>, SectionError> {
+    let mut cycles = Vec::new();
+
+    while let Some(first) = edges.pop() {
+        let mut cycle = vec![first];
+
+        while !is_same_point(
+            cycle.last().expect("Cycle has at least one edge").end(),
+            first.start(),
+        ) {
+            let end = cycle.last().expect("Cycle has at least one edge").end();
+
+            // The section edges come from different faces, so they don't have
+            // a consistent direction. We need to consider both.
+            let next = edges.iter().position(|edge| {
+                is_same_point(edge.start(), end)
+                    || is_same_point(edge.end(), end)
+            });
+            let next = next
+                .map(|index| edges.swap_remove(index))
+                .ok_or(SectionError::OpenCycle)?;
+
+            if is_same_point(next.start(), end) {
+                cycle.push(next);
+            } else {
+                cycle.push(next.reverse());
+            }
+        }
+
+        cycles.push(cycle);
+    }
+
+    Ok(cycles)
+}
+
+pub(super) fn build_cycle(
+    edges: Vec<SectionEdge>,
+    services: &mut Services,
+) -> Cycle {
+    if let [edge] = edges.as_slice() {
+        if edge.is_closed() {
+            let half_edge = HalfEdge::new(
+                edge.path,
+                edge.boundary,
+                Curve::new().insert(services),
+                Vertex::new().insert(services),
+            )
+            .insert(services);
+
+            return Cycle::new([half_edge]);
+        }
+    }
+
+    // The start points of the edges are the points where they connect to the
+    // previous edge. We use those as the end points of line segments, to make
+    // sure that neighboring edges match exactly.
+    let start_points =
+        edges.iter().map(|edge| edge.start()).collect::<Vec<_>>();
+
+    let half_edges = edges
+        .iter()
+        .enumerate()
+        .map(|(i, edge)| {
+            let (path, boundary) = match edge.path {
+                SurfacePath::Line(_) => {
+                    let start = start_points[i];
+                    let end = start_points[(i + 1) % start_points.len()];
+
+                    let (path, boundary) =
+                        SurfacePath::line_from_points([start, end]);
+                    (path, CurveBoundary::from(boundary))
+                }
+                SurfacePath::Circle(_) | SurfacePath::Spiral(_) => {
+                    (edge.path, edge.boundary)
+                }
+            };
+
+            HalfEdge::new(
+                path,
+                boundary,
+                Curve::new().insert(services),
+                Vertex::new().insert(services),
+            )
+            .insert(services)
+        })
+        .collect::<Vec<_>>();
+
+    Cycle::new(half_edges)
+}
+
+/// Assemble the cycles of the section into the regions of a sketch
+///
+/// Cycles that are contained in an even number of other cycles are the
+/// exteriors of regions. All others are interiors of the smallest cycle that
+/// contains them.
+pub(super) fn build_sketch(
+    cycles: Vec<Cycle>,
+    services: &mut Services,
+) -> Sketch {
+    let polygons = cycles.iter().map(polygon_from_cycle).collect::<Vec<_>>();
+
+    let containers = polygons
+        .iter()
+        .enumerate()
+        .map(|(i, polygon)| {
+            polygons
+                .iter()
+                .enumerate()
+                .filter(|&(j, other)| {
+                    i != j && polygon_contains_point(other, polygon[0])
+                })
+                .map(|(j, _)| j)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut regions = Vec::new();
+
+    for (i, cycle) in cycles.iter().enumerate() {
+        if containers[i].len() % 2 == 1 {
+            continue;
+        }
+
+        let exterior = if signed_area(&polygons[i]) > Scalar::ZERO {
+            cycle.clone()
+        } else {
+            cycle.reverse(services)
+        };
+
+        // The interiors of this region are those cycles, whose innermost
+        // container is this cycle. That's the container that has the most
+        // containers itself.
+        let interiors = cycles
+            .iter()
+            .enumerate()
+            .filter(|&(j, _)| {
+                containers[j].len() % 2 == 1
+                    && containers[j]
+                        .iter()
+                        .max_by_key(|&&k| containers[k].len())
+                        == Some(&i)
+            })
+            .map(|(j, interior)| {
+                let interior = if signed_area(&polygons[j]) < Scalar::ZERO {
+                    interior.clone()
+                } else {
+                    interior.reverse(services)
+                };
+
+                interior.insert(services)
+            })
+            .collect::<Vec<_>>();
+
+        let region = Region::new(exterior.insert(services), interiors, None)
+            .insert(services);
+        regions.push(region);
+    }
+
+    Sketch::new(regions)
+}
+
+/// Approximate a cycle as a polygon, for the purpose of determining its
+/// orientation and the cycles that contain it
+pub(super) fn polygon_from_cycle(cycle: &Cycle) -> Vec<Point<2>> {
+    // This doesn't need to be an accurate approximation. The cycles of a
+    // section don't intersect, so a coarse approximation is enough to decide
+    // which cycle is inside which.
+    const NUM_POINTS_PER_ARC: u32 = 16;
+
+    let mut points = Vec::new();
+
+    for half_edge in cycle.half_edges() {
+        let [start, end] = half_edge.boundary().inner;
+
+        let num_points = match half_edge.path() {
+            SurfacePath::Line(_) => 1,
+            SurfacePath::Circle(_) | SurfacePath::Spiral(_) => {
+                NUM_POINTS_PER_ARC
+            }
+        };
+
+        for i in 0..num_points {
+            let t = start.t
+                + (end.t - start.t) * f64::from(i) / f64::from(num_points);
+            points.push(half_edge.path().point_from_path_coords([t]));
+        }
+    }
+
+    points
+}
+
+pub(super) fn signed_area(polygon: &[Point<2>]) -> Scalar {
+    let mut area = Scalar::ZERO;
+
+    for (i, a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+        area += a.coords.cross2d(&b.coords);
+    }
+
+    area / Scalar::TWO
+}
+
+pub(super) fn polygon_contains_point(
+    polygon: &[Point<2>],
+    point: Point<2>,
+) -> bool {
+    let mut contains = false;
+
+    for (i, a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+
+        if (a.v > point.v) != (b.v > point.v) {
+            let u = a.u + (b.u - a.u) * (point.v - a.v) / (b.v - a.v);
+            if point.u < u {
+                contains = !contains;
+            }
+        }
+    }
+
+    contains
+}
+
+fn plane_from_surface(surface: &Surface) -> Option<Plane> {
+    match surface.geometry().u {
+        GlobalPath::Line(line) => Some(Plane::from_parametric(
+            line.origin(),
+            line.direction(),
+            surface.geometry().v,
+        )),
+        GlobalPath::Circle(_) => None,
+    }
+}
+
+/// The tolerance below which the sine of the angle between two directions is
+/// considered zero
+///
+/// Over a unit length, directions that are closer than that diverge by less
+/// than the minimum distance between distinct points.
+fn parallel_tolerance() -> Scalar {
+    ValidationConfig::default().distinct_min_distance
+}
+
+pub(super) fn is_same_point(a: Point<2>, b: Point<2>) -> bool {
+    a.distance_to(&b) < ValidationConfig::default().distinct_min_distance
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Circle, Point, Scalar, Transform};
+
+    use crate::{
+        algorithms::transform::TransformObject,
+        fixtures::spacer,
+        geometry::{GlobalPath, SurfacePath},
+        objects::Cycle,
+        services::Services,
+    };
+
+    use super::{Section, SectionError};
+
+    #[test]
+    fn spacer_through_middle() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let spacer = spacer(&mut services);
+        let surface = services
+            .objects
+            .surfaces
+            .xy_plane()
+            .transform(&Transform::translation([0., 0., 0.5]), &mut services);
+
+        let sketch = spacer.section(surface, &mut services)?;
+
+        assert_eq!(sketch.regions().len(), 1);
+        let region = sketch.regions().first();
+
+        assert_eq!(region.exterior().half_edges().len(), 1);
+        let exterior = circle(region.exterior());
+        assert_eq!(exterior.center(), Point::origin());
+        assert_eq!(exterior.radius(), Scalar::from(1.));
+
+        assert_eq!(region.interiors().len(), 1);
+        let interior = circle(region.interiors().first());
+        assert_eq!(region.interiors().first().half_edges().len(), 1);
+        assert_eq!(interior.center(), Point::origin());
+        assert_eq!(interior.radius(), Scalar::from(0.5));
+
+        Ok(())
+    }
+
+    #[test]
+    fn plane_misses_solid() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let spacer = spacer(&mut services);
+        let surface = services
+            .objects
+            .surfaces
+            .xy_plane()
+            .transform(&Transform::translation([0., 0., 2.]), &mut services);
+
+        let sketch = spacer.section(surface, &mut services)?;
+
+        assert!(sketch.regions().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn plane_touches_solid() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let spacer = spacer(&mut services);
+        let surface = services
+            .objects
+            .surfaces
+            .xy_plane()
+            .transform(&Transform::translation([0., 0., 1.]), &mut services);
+
+        let sketch = spacer.section(surface, &mut services)?;
+
+        assert!(sketch.regions().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn surface_is_not_a_plane() {
+        let mut services = Services::new();
+
+        let spacer = spacer(&mut services);
+        let surface = spacer
+            .shells()
+            .only()
+            .faces()
+            .iter()
+            .map(|face| face.surface().clone())
+            .find(|surface| {
+                matches!(surface.geometry().u, GlobalPath::Circle(_))
+            })
+            .expect("Spacer has curved faces");
+
+        assert_eq!(
+            spacer.section(surface, &mut services),
+            Err(SectionError::SurfaceIsNotAPlane),
+        );
+    }
+
+    #[test]
+    fn plane_intersects_swept_circle_at_an_angle() {
+        let mut services = Services::new();
+
+        let spacer = spacer(&mut services);
+        let surface = services.objects.surfaces.xz_plane();
+
+        assert_eq!(
+            spacer.section(surface, &mut services),
+            Err(SectionError::UnsupportedSweptCircle),
+        );
+    }
+
+    fn circle(cycle: &Cycle) -> Circle<2> {
+        match cycle.half_edges().first().path() {
+            SurfacePath::Circle(circle) => circle,
+            SurfacePath::Line(_) | SurfacePath::Spiral(_) => {
+                panic!("Expected circle")
+            }
+        }
+    }
+}
@@ -0,0 +1,315 @@
+//! Sheet-metal parts with single-axis bends
+//!
+//! See [`SheetMetal`].
+
+use fj_interop::drawing::Drawing;
+use fj_math::{Point, Scalar, Vector};
+use itertools::Itertools;
+
+use crate::{
+    objects::{Cycle, HalfEdge, Region, Sketch, Solid},
+    operations::{
+        build::BuildHalfEdge,
+        insert::Insert,
+        sweep::{SweepSketch, SweepSketchError},
+    },
+    services::Services,
+};
+
+/// A sheet-metal part, made up of flanges that are connected by bends
+///
+/// All bends share the same axis, which is the z-axis. The part is described
+/// by its flat length in the direction that is perpendicular to that axis, and
+/// its width along it. [`SheetMetal::fold`] creates the bent part, while
+/// [`SheetMetal::unfold`] and [`SheetMetal::flat_pattern`] create the flat
+/// sheet it is made from.
+///
+/// A bend stretches the material on its outer side, and compresses it on its
+/// inner side. The length of the bend zone in the flat sheet, the *bend
+/// allowance*, is the length of the neutral layer in between, which keeps its
+/// length. The position of that layer is defined by the k-factor, its distance
+/// from the inner side relative to the thickness of the sheet.
+#[derive(Clone, Debug)]
+pub struct SheetMetal {
+    thickness: Scalar,
+    width: Scalar,
+    k_factor: Scalar,
+    first_flange: Scalar,
+    bends: Vec<(Bend, Scalar)>,
+}
+
+impl SheetMetal {
+    /// Create a flat sheet that consists of a single flange
+    ///
+    /// The k-factor defaults to `0.5`, placing the neutral layer in the middle
+    /// of the sheet. Use [`SheetMetal::with_k_factor`] to change that.
+    pub fn new(
+        thickness: impl Into<Scalar>,
+        width: impl Into<Scalar>,
+        length: impl Into<Scalar>,
+    ) -> Self {
+        Self {
+            thickness: thickness.into(),
+            width: width.into(),
+            k_factor: Scalar::from(0.5),
+            first_flange: length.into(),
+            bends: Vec::new(),
+        }
+    }
+
+    /// Set the k-factor that is used to compute bend allowances
+    pub fn with_k_factor(mut self, k_factor: impl Into<Scalar>) -> Self {
+        self.k_factor = k_factor.into();
+        self
+    }
+
+    /// Add a bend, followed by a flange of the given length
+    ///
+    /// The length of a flange doesn't include any of the adjacent bends. It
+    /// must be positive.
+    pub fn bend(mut self, bend: Bend, length: impl Into<Scalar>) -> Self {
+        self.bends.push((bend, length.into()));
+        self
+    }
+
+    /// Compute the bend allowance of the provided bend
+    pub fn bend_allowance(&self, bend: &Bend) -> Scalar {
+        bend.angle.abs() * (bend.inner_radius + self.k_factor * self.thickness)
+    }
+
+    /// Compute the length of the flat sheet
+    ///
+    /// This is the sum of the lengths of all flanges and the allowances of all
+    /// bends.
+    pub fn flat_length(&self) -> Scalar {
+        self.bends
+            .iter()
+            .fold(self.first_flange, |length, (bend, flange)| {
+                length + self.bend_allowance(bend) + *flange
+            })
+    }
+
+    /// Create the bent part
+    ///
+    /// The outer side of the first flange starts at the origin, and runs along
+    /// the x-axis, while the sheet extends into the positive y direction.
+    /// Bends with positive angles bend toward that side.
+    ///
+    /// Returns an error, if the profile of the sheet doesn't enclose any area,
+    /// which is the case if its thickness is zero.
+    pub fn fold(
+        &self,
+        services: &mut Services,
+    ) -> Result<Solid, SweepSketchError> {
+        let thickness = self.thickness;
+
+        // We track the sheet along one of its sides, and offset the other side
+        // from it, using the left-hand normal of the current direction.
+        let normal =
+            |direction: Vector<2>| Vector::from([-direction.v, direction.u]);
+
+        let mut position = Point::from([0., 0.]);
+        let mut direction = Vector::from([1., 0.]);
+
+        // Each segment of the sheet is a flange or a bend. For each, we create
+        // a pair of edges, one on either side of the sheet.
+        let mut segments = Vec::new();
+
+        let flange =
+            |position: Point<2>, direction: Vector<2>, length: Scalar| {
+                let offset = normal(direction) * thickness;
+                let end = position + direction * length;
+
+                ([position, end], [position + offset, end + offset], None)
+            };
+
+        segments.push(flange(position, direction, self.first_flange));
+        position = position + direction * self.first_flange;
+
+        for (bend, length) in &self.bends {
+            // The center of the bend is on the inner side of the sheet.
+            let center = if bend.angle > Scalar::ZERO {
+                position + normal(direction) * (thickness + bend.inner_radius)
+            } else {
+                position - normal(direction) * bend.inner_radius
+            };
+
+            let (sin, cos) = bend.angle.sin_cos();
+            let rotate = |v: Vector<2>| {
+                Vector::from([v.u * cos - v.v * sin, v.u * sin + v.v * cos])
+            };
+
+            let start_offset = position + normal(direction) * thickness;
+            let end = center + rotate(position - center);
+            let end_offset = center + rotate(start_offset - center);
+
+            segments.push((
+                [position, end],
+                [start_offset, end_offset],
+                Some(bend.angle),
+            ));
+
+            position = end;
+            direction = rotate(direction);
+
+            segments.push(flange(position, direction, *length));
+            position = position + direction * *length;
+        }
+
+        // The boundary of the profile runs forward along one side of the
+        // sheet, and back along the other one. The ends of the sheet close it.
+        let ([start_a, _], [start_b, _], _) = segments[0];
+        let ([_, end_a], [_, end_b], _) = segments[segments.len() - 1];
+
+        let boundary = segments
+            .iter()
+            .map(|&(side_a, _, angle)| (side_a, angle))
+            .chain([([end_a, end_b], None)])
+            .chain(segments.iter().rev().map(|&([_, _], [b0, b1], angle)| {
+                ([b1, b0], angle.map(|angle: Scalar| -angle))
+            }))
+            .chain([([start_b, start_a], None)])
+            .collect::<Vec<_>>();
+
+        let half_edges = boundary
+            .into_iter()
+            .map(|([start, end], angle)| {
+                let half_edge = match angle {
+                    Some(angle) => HalfEdge::arc(start, end, angle, services),
+                    None => {
+                        HalfEdge::line_segment([start, end], None, services)
+                    }
+                };
+                half_edge.insert(services)
+            })
+            .collect::<Vec<_>>();
+
+        let exterior = Cycle::new(half_edges).insert(services);
+        let region = Region::new(exterior, [], None).insert(services);
+
+        let surface = services.objects.surfaces.xy_plane();
+        Sketch::new([region]).sweep_sketch(
+            surface,
+            [0., 0., self.width.into_f64()],
+            services,
+        )
+    }
+
+    /// Create the flat sheet that the part is made from
+    ///
+    /// The sheet is placed like the first flange of the bent part.
+    ///
+    /// See [`SheetMetal::fold`] for the errors that can be returned.
+    pub fn unfold(
+        &self,
+        services: &mut Services,
+    ) -> Result<Solid, SweepSketchError> {
+        Self::new(self.thickness, self.width, self.flat_length())
+            .with_k_factor(self.k_factor)
+            .fold(services)
+    }
+
+    /// Create a drawing of the flat pattern
+    ///
+    /// The drawing shows the outline of the flat sheet, as seen from its outer
+    /// side, with its length along the x-axis and its width along the y-axis.
+    /// The boundaries of the bend zones are added as hidden segments.
+    pub fn flat_pattern(&self) -> Drawing {
+        let length = self.flat_length();
+        let width = self.width;
+
+        let mut drawing = Drawing::new();
+
+        let corners = [[0., 0.], [1., 0.], [1., 1.], [0., 1.]]
+            .map(|[u, v]| Point::from([length * u, width * v]));
+        for (a, b) in corners.into_iter().circular_tuple_windows() {
+            drawing.push_segment([a, b]);
+        }
+
+        let mut position = self.first_flange;
+        for (bend, flange) in &self.bends {
+            let bend_end = position + self.bend_allowance(bend);
+
+            for x in [position, bend_end] {
+                drawing.push_hidden_segment([
+                    Point::from([x, Scalar::ZERO]),
+                    Point::from([x, width]),
+                ]);
+            }
+
+            position = bend_end + *flange;
+        }
+
+        drawing
+    }
+}
+
+/// A bend between two flanges of a [`SheetMetal`] part
+#[derive(Clone, Copy, Debug)]
+pub struct Bend {
+    /// The angle of the bend, in radians
+    ///
+    /// Positive angles bend counter-clockwise, when looking at the part from
+    /// the positive end of the bend axis.
+    pub angle: Scalar,
+
+    /// The radius of the inner side of the bend
+    pub inner_radius: Scalar,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::bounding_volume::BoundingVolume, services::Services,
+        validate::Validate,
+    };
+
+    use super::{Bend, SheetMetal};
+
+    #[test]
+    fn fold_and_unfold() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let bend = Bend {
+            angle: Scalar::from(FRAC_PI_2),
+            inner_radius: Scalar::from(1.),
+        };
+        let part = SheetMetal::new(0.5, 3., 10.)
+            .with_k_factor(0.4)
+            .bend(bend, 5.);
+
+        let bend_allowance = FRAC_PI_2 * (1. + 0.4 * 0.5);
+        assert_eq!(part.bend_allowance(&bend), Scalar::from(bend_allowance));
+        assert_eq!(part.flat_length(), Scalar::from(10. + bend_allowance + 5.));
+
+        // The second flange points up, with its inner side at a distance of the
+        // inner radius from the end of the first flange.
+        let folded = part.fold(&mut services)?;
+        folded.validate_and_return_first_error()?;
+        let aabb = folded.aabb().unwrap();
+        assert_points_approx_eq(aabb.min, [0., 0., 0.]);
+        assert_points_approx_eq(aabb.max, [11.5, 6.5, 3.]);
+
+        // Unfolding recovers the flat sheet, including the bend allowance.
+        let unfolded = part.unfold(&mut services)?;
+        unfolded.validate_and_return_first_error()?;
+        let aabb = unfolded.aabb().unwrap();
+        assert_points_approx_eq(aabb.min, [0., 0., 0.]);
+        assert_points_approx_eq(aabb.max, [15. + bend_allowance, 0.5, 3.]);
+
+        let flat_pattern = part.flat_pattern();
+        assert_eq!(flat_pattern.segments().count(), 4);
+        assert_eq!(flat_pattern.hidden_segments().count(), 2);
+
+        Ok(())
+    }
+
+    fn assert_points_approx_eq(a: Point<3>, b: impl Into<Point<3>>) {
+        let b = b.into();
+        assert!((a - b).magnitude() < Scalar::from(1e-9), "{a:?} != {b:?}");
+    }
+}
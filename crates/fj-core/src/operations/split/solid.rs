@@ -0,0 +1,408 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use fj_interop::mesh::{Color, Mesh};
+use fj_math::{Plane, Point, Scalar, Triangle, Vector};
+
+use crate::{
+    algorithms::{
+        approx::{
+            cycle::CycleApprox, edge::HalfEdgeApprox, face::FaceApprox,
+            ApproxError, ApproxPoint, Tolerance,
+        },
+        triangulate::Triangulate,
+    },
+    geometry::GlobalPath,
+    objects::{Handedness, Solid, Surface},
+    operations::build::{BuildSolid, FromTriangleMeshError},
+    services::Services,
+    storage::Handle,
+};
+
+/// Split a solid into two
+pub trait SplitSolid: Sized {
+    /// Split the solid into two, along a plane
+    ///
+    /// Returns the part of the solid that is in front of the plane (on the
+    /// side its normal points to) first, and the part behind it second. The
+    /// cut is capped on both sides, so both parts are closed solids. If the
+    /// plane doesn't intersect the solid, one of the parts is empty.
+    ///
+    /// The solid is approximated using the provided tolerance, and both parts
+    /// are rebuilt from that approximation, using
+    /// [`BuildSolid::from_triangle_mesh`]. Planar faces are preserved, but
+    /// curved faces end up faceted.
+    ///
+    /// Returns an error, if the surface is not a plane, or if the cut through
+    /// the solid doesn't form closed loops. The latter can only happen, if the
+    /// solid isn't closed to begin with. See [`SplitSolidError`] for the other
+    /// errors that can be returned.
+    fn split_solid(
+        &self,
+        surface: &Handle<Surface>,
+        tolerance: impl Into<Tolerance>,
+        services: &mut Services,
+    ) -> Result<(Self, Self), SplitSolidError>;
+}
+
+impl SplitSolid for Solid {
+    fn split_solid(
+        &self,
+        surface: &Handle<Surface>,
+        tolerance: impl Into<Tolerance>,
+        services: &mut Services,
+    ) -> Result<(Self, Self), SplitSolidError> {
+        let tolerance = tolerance.into();
+
+        let plane = match surface.geometry().u {
+            GlobalPath::Line(line) => Plane::from_parametric(
+                line.origin(),
+                line.direction(),
+                surface.geometry().v,
+            ),
+            GlobalPath::Circle(_) => {
+                return Err(SplitSolidError::NonPlanarCutter);
+            }
+        };
+        let (distance, normal) = plane.constant_normal_form();
+
+        let signed_distance = |point: Point<3>| {
+            let signed_distance = normal.dot(&point.coords) - distance;
+
+            // Points that are this close to the plane are considered to be on
+            // it. Otherwise, we'd end up with slivers that are narrower than
+            // the tolerance.
+            if signed_distance.abs() < tolerance.inner() {
+                Scalar::ZERO
+            } else {
+                signed_distance
+            }
+        };
+
+        let mut halves = [Vec::new(), Vec::new()];
+
+        for triangle in self.to_mesh(tolerance)?.triangles() {
+            let points = triangle.inner.points();
+            let distances = points.map(signed_distance);
+
+            if distances.iter().all(|&d| d == Scalar::ZERO) {
+                // The triangle is within the plane. It bounds the half that it
+                // faces away from.
+                let side =
+                    if triangle.inner.normal().dot(&normal) < Scalar::ZERO {
+                        0
+                    } else {
+                        1
+                    };
+                halves[side].push((points, triangle.color));
+                continue;
+            }
+
+            for (side, sign) in
+                [Scalar::ONE, -Scalar::ONE].into_iter().enumerate()
+            {
+                let mut polygon = Vec::new();
+
+                for i in 0..3 {
+                    let j = (i + 1) % 3;
+
+                    if distances[i] * sign >= Scalar::ZERO {
+                        polygon.push(points[i]);
+                    }
+                    if distances[i] * distances[j] < Scalar::ZERO {
+                        polygon.push(intersection(
+                            (points[i], distances[i]),
+                            (points[j], distances[j]),
+                        ));
+                    }
+                }
+
+                // Clipping a triangle always results in a convex polygon, so a
+                // simple fan is enough to triangulate it.
+                for i in 1..polygon.len().saturating_sub(1) {
+                    let points = [polygon[0], polygon[i], polygon[i + 1]];
+
+                    // Points that were snapped to the plane can result in
+                    // triangles that don't span any area. Those can be skipped.
+                    if Triangle::from_points(points).is_ok() {
+                        halves[side].push((points, triangle.color));
+                    }
+                }
+            }
+        }
+
+        let [front, back] = [(0, -normal), (1, normal)].map(
+            |(side, outward)| -> Result<_, SplitSolidError> {
+                let mut triangles = halves[side].clone();
+                if triangles.is_empty() {
+                    return Ok(Solid::empty());
+                }
+
+                let cap = cap(&triangles, &plane, outward, surface)?;
+                triangles.extend(cap);
+
+                let mut mesh = Mesh::new();
+                for (points, color) in triangles {
+                    mesh.push_triangle(points, color);
+                }
+
+                Ok(Solid::from_triangle_mesh(&mesh, tolerance, services)?)
+            },
+        );
+
+        Ok((front?, back?))
+    }
+}
+
+/// Error splitting a solid
+///
+/// See [`SplitSolid::split_solid`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum SplitSolidError {
+    /// The surface to split the solid with is not a plane
+    #[error("Can only split a solid with a plane")]
+    NonPlanarCutter,
+
+    /// The cut through the solid doesn't form closed loops
+    #[error("Cut through solid doesn't form closed loops")]
+    OpenCut,
+
+    /// Error approximating the solid or the cut
+    #[error("Error approximating solid")]
+    Approx(#[from] ApproxError),
+
+    /// Error rebuilding one of the parts from its triangle mesh
+    #[error("Error rebuilding part of solid")]
+    Mesh(#[from] FromTriangleMeshError),
+}
+
+/// Compute where the line between two points intersects the plane
+///
+/// The points are ordered before the computation, so the two triangles that
+/// share an edge get the exact same intersection point for it.
+fn intersection(a: (Point<3>, Scalar), b: (Point<3>, Scalar)) -> Point<3> {
+    let ((a, distance_a), (b, distance_b)) =
+        if a.0 < b.0 { (a, b) } else { (b, a) };
+    a + (b - a) * (distance_a / (distance_a - distance_b))
+}
+
+/// Triangulate the cap that closes the cut through one half of the solid
+fn cap(
+    triangles: &[([Point<3>; 3], Color)],
+    plane: &Plane,
+    outward: Vector<3>,
+    surface: &Handle<Surface>,
+) -> Result<Vec<([Point<3>; 3], Color)>, SplitSolidError> {
+    let mut edges = BTreeSet::new();
+    for ([a, b, c], _) in triangles {
+        edges.extend([[*a, *b], [*b, *c], [*c, *a]]);
+    }
+
+    // The mesh of the whole solid is closed, so any edge of the half that has
+    // no counterpart going the other way, must be on the cut.
+    let mut boundary = edges
+        .iter()
+        .filter(|[a, b]| !edges.contains(&[*b, *a]))
+        .map(|[a, b]| (*a, *b))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut loops = Vec::new();
+    while let Some((start, mut next)) = boundary.pop_first() {
+        let mut points = vec![start];
+
+        while next != start {
+            points.push(next);
+            next = boundary.remove(&next).ok_or(SplitSolidError::OpenCut)?;
+        }
+
+        loops.push(
+            points
+                .into_iter()
+                .map(|point| {
+                    ApproxPoint::new(plane.project_point(point), point)
+                })
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    // Loops that are nested within an odd number of other loops are holes in
+    // the cap. Each of them belongs to the innermost loop that contains it.
+    let depths = loops
+        .iter()
+        .map(|inner| {
+            loops.iter().filter(|outer| contains(outer, inner)).count()
+        })
+        .collect::<Vec<_>>();
+
+    let mut cap = Vec::new();
+    for (i, exterior) in loops.iter().enumerate() {
+        if depths[i] % 2 == 1 {
+            continue;
+        }
+
+        let interiors = loops
+            .iter()
+            .enumerate()
+            .filter(|&(j, interior)| {
+                depths[j] == depths[i] + 1 && contains(exterior, interior)
+            })
+            .map(|(_, interior)| cycle_approx(interior))
+            .collect();
+
+        let approx = FaceApprox {
+            exterior: cycle_approx(exterior),
+            interiors,
+            color: None,
+            coord_handedness: Handedness::RightHanded,
+            surface: *surface.geometry(),
+        };
+
+        for triangle in approx.triangulate()?.triangles() {
+            let [a, b, c] = triangle.inner.points();
+
+            let points = if triangle.inner.normal().dot(&outward) < Scalar::ZERO
+            {
+                [a, c, b]
+            } else {
+                [a, b, c]
+            };
+            cap.push((points, triangle.color));
+        }
+    }
+
+    Ok(cap)
+}
+
+fn cycle_approx(points: &[ApproxPoint<2>]) -> CycleApprox {
+    CycleApprox {
+        half_edges: vec![HalfEdgeApprox {
+            points: points.to_vec(),
+        }],
+    }
+}
+
+/// Determine whether a loop is within another one
+///
+/// Loops of the cut don't cross each other, so it's enough to check the
+/// midpoint of one edge of the inner loop.
+fn contains(outer: &[ApproxPoint<2>], inner: &[ApproxPoint<2>]) -> bool {
+    if std::ptr::eq(outer, inner) {
+        return false;
+    }
+
+    let [a, b] = [inner[0], inner[1]].map(|point| point.local_form);
+    let point = a + (b - a) / 2.;
+
+    let ends = outer.iter().cycle().skip(1);
+    outer
+        .iter()
+        .zip(ends)
+        .filter(|(a, b)| {
+            let [a, b] = [a.local_form, b.local_form];
+
+            // Count the edges that a ray in positive u-direction crosses.
+            (a.v > point.v) != (b.v > point.v)
+                && point.u < a.u + (point.v - a.v) / (b.v - a.v) * (b.u - a.u)
+        })
+        .count()
+        % 2
+        == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Scalar, Vector};
+
+    use crate::{
+        algorithms::{approx::Tolerance, bounding_volume::BoundingVolume},
+        fixtures::volume,
+        geometry::{GlobalPath, SurfaceGeometry},
+        objects::{Solid, Surface},
+        operations::{
+            build::{BuildSolid, BuildSurface},
+            insert::Insert,
+        },
+        services::Services,
+        validate::Validate,
+    };
+
+    use super::{SplitSolid, SplitSolidError};
+
+    #[test]
+    fn split_cuboid_in_half() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let cuboid = Solid::cuboid([1., 1., 1.], &mut services);
+        let (surface, _) = Surface::plane_from_points([
+            [0., 0., 0.5],
+            [1., 0., 0.5],
+            [0., 1., 0.5],
+        ]);
+        let surface = surface.insert(&mut services);
+
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        let (top, bottom) =
+            cuboid.split_solid(&surface, tolerance, &mut services)?;
+
+        for half in [&top, &bottom] {
+            assert_eq!(half.shells().len(), 1);
+            for shell in half.shells() {
+                shell.validate_and_return_first_error()?;
+            }
+
+            let volume = volume(half, tolerance)?;
+            assert!((volume - Scalar::from(0.5)).abs() < Scalar::from(1e-9));
+        }
+
+        let [top, bottom] =
+            [top, bottom].map(|half| half.aabb().expect("Half is not empty"));
+        assert_eq!(top.min.z, Scalar::from(0.5));
+        assert_eq!(bottom.max.z, Scalar::from(0.5));
+
+        Ok(())
+    }
+
+    #[test]
+    fn plane_misses_solid() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let cuboid = Solid::cuboid([1., 1., 1.], &mut services);
+        let (surface, _) = Surface::plane_from_points([
+            [0., 0., 2.],
+            [1., 0., 2.],
+            [0., 1., 2.],
+        ]);
+        let surface = surface.insert(&mut services);
+
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        let (above, below) =
+            cuboid.split_solid(&surface, tolerance, &mut services)?;
+
+        assert!(above.shells().is_empty());
+        assert!(
+            (volume(&below, tolerance)? - Scalar::ONE).abs()
+                < Scalar::from(1e-9)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn split_with_curved_surface() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let cuboid = Solid::cuboid([1., 1., 1.], &mut services);
+        let surface = Surface::new(SurfaceGeometry {
+            u: GlobalPath::circle_from_radius(0.5),
+            v: Vector::unit_z(),
+        })
+        .insert(&mut services);
+
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        assert_eq!(
+            cuboid.split_solid(&surface, tolerance, &mut services),
+            Err(SplitSolidError::NonPlanarCutter),
+        );
+
+        Ok(())
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
     services::Services,
 };
 
-use super::SweepCache;
+use super::{SweepCache, SweepError};
 
 /// # Sweep a [`Cycle`]
 ///
@@ -35,6 +35,8 @@ pub trait SweepCycle {
     /// For the same reason, there also is no "bottom" face. Additionally,
     /// whether a bottom face is even desirable depends on the context this
     /// operation is called in, and therefore falls outside of its scope.
+    ///
+    /// Returns an error, if any of the half-edges is a spiral.
     fn sweep_cycle(
         &self,
         surface: &Surface,
@@ -42,7 +44,7 @@ pub trait SweepCycle {
         path: impl Into<Vector<3>>,
         cache: &mut SweepCache,
         services: &mut Services,
-    ) -> SweptCycle;
+    ) -> Result<SweptCycle, SweepError>;
 }
 
 impl SweepCycle for Cycle {
@@ -53,7 +55,7 @@ impl SweepCycle for Cycle {
         path: impl Into<Vector<3>>,
         cache: &mut SweepCache,
         services: &mut Services,
-    ) -> SweptCycle {
+    ) -> Result<SweptCycle, SweepError> {
         let path = path.into();
 
         let mut faces = Vec::new();
@@ -70,7 +72,7 @@ impl SweepCycle for Cycle {
                 path,
                 cache,
                 services,
-            );
+            )?;
 
             faces.push(side_face);
 
@@ -83,7 +85,7 @@ impl SweepCycle for Cycle {
 
         let top_cycle = Cycle::empty().add_joined_edges(top_edges, services);
 
-        SweptCycle { faces, top_cycle }
+        Ok(SweptCycle { faces, top_cycle })
     }
 }
 
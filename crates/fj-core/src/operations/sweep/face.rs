@@ -1,13 +1,14 @@
-use fj_math::Vector;
+use fj_math::{Scalar, Vector};
 
 use crate::{
-    objects::{Face, Shell},
-    operations::insert::Insert,
+    objects::{Face, Shell, Solid},
+    operations::{insert::Insert, reverse::Reverse},
     services::Services,
     storage::Handle,
+    trace::operation_span,
 };
 
-use super::{SweepCache, SweepRegion};
+use super::{SweepCache, SweepError, SweepRegion};
 
 /// # Sweep a [`Face`]
 ///
@@ -16,12 +17,31 @@ use super::{SweepCache, SweepRegion};
 /// [module documentation]: super
 pub trait SweepFace {
     /// # Sweep the [`Face`]
+    ///
+    /// Returns an error, if any of the face's half-edges is a spiral.
     fn sweep_face(
         &self,
         path: impl Into<Vector<3>>,
         cache: &mut SweepCache,
         services: &mut Services,
-    ) -> Shell;
+    ) -> Result<Shell, SweepError>;
+
+    /// # Sweep the [`Face`] into a [`Solid`]
+    ///
+    /// Unlike [`SweepFace::sweep_face`], this works regardless of the face's
+    /// orientation relative to the path. The face is reversed as necessary,
+    /// so that it ends up facing outward, as the bottom face of the solid.
+    ///
+    /// Interior cycles of the face result in holes through the solid.
+    ///
+    /// Returns an error, if the face's surface is curved, as its orientation
+    /// relative to the path can't be determined then. See
+    /// [`SweepFace::sweep_face`] for the other errors that can be returned.
+    fn sweep_face_into_solid(
+        &self,
+        path: impl Into<Vector<3>>,
+        services: &mut Services,
+    ) -> Result<Solid, SweepError>;
 }
 
 impl SweepFace for Handle<Face> {
@@ -30,7 +50,7 @@ impl SweepFace for Handle<Face> {
         path: impl Into<Vector<3>>,
         cache: &mut SweepCache,
         services: &mut Services,
-    ) -> Shell {
+    ) -> Result<Shell, SweepError> {
         // Please note that this function uses the words "bottom" and "top" in a
         // specific sense:
         //
@@ -44,6 +64,11 @@ impl SweepFace for Handle<Face> {
         // locations, and depending on the direction of `path`, the two meanings
         // might actually be opposite.
 
+        operation_span!(
+            "sweep_face",
+            cycles = self.region().all_cycles().count()
+        );
+
         let path = path.into();
 
         let mut faces = Vec::new();
@@ -53,11 +78,129 @@ impl SweepFace for Handle<Face> {
 
         let side_faces = bottom_face
             .region()
-            .sweep_region(bottom_face.surface(), path, cache, services)
+            .sweep_region(bottom_face.surface(), path, cache, services)?
             .all_faces()
             .map(|side_face| side_face.insert(services));
         faces.extend(side_faces);
 
-        Shell::new(faces)
+        Ok(Shell::new(faces))
+    }
+
+    fn sweep_face_into_solid(
+        &self,
+        path: impl Into<Vector<3>>,
+        services: &mut Services,
+    ) -> Result<Solid, SweepError> {
+        operation_span!(
+            "sweep_face_into_solid",
+            cycles = self.region().all_cycles().count()
+        );
+
+        let path = path.into();
+
+        let normal = self.normal().ok_or(SweepError::CurvedSurface)?;
+        let is_facing_along_path = normal.dot(&path) > Scalar::ZERO;
+
+        // The original face becomes the bottom face of the solid, which needs
+        // to face away from the path.
+        let bottom_face = if is_facing_along_path {
+            self.reverse(services).insert(services)
+        } else {
+            self.clone()
+        };
+
+        let shell = bottom_face
+            .sweep_face(path, &mut SweepCache::default(), services)?
+            .insert(services);
+
+        Ok(Solid::new([shell]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::{
+        algorithms::approx::Tolerance,
+        fixtures,
+        geometry::GlobalPath,
+        objects::{Cycle, Face, Region},
+        operations::{
+            build::{BuildCycle, BuildRegion},
+            insert::Insert,
+            reverse::Reverse,
+            update::UpdateRegion,
+        },
+        queries::ContainsPoint,
+        services::Services,
+        validate::Validate,
+    };
+
+    use super::{SweepError, SweepFace};
+
+    #[test]
+    fn sweep_face_into_solid() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        // The annular face of the spacer.
+        let face = {
+            let surface = services.objects.surfaces.xy_plane();
+            let region = Region::circle(Point::origin(), 1., &mut services)
+                .add_interiors([Cycle::circle(
+                    Point::origin(),
+                    0.5,
+                    &mut services,
+                )
+                .reverse(&mut services)
+                .insert(&mut services)])
+                .insert(&mut services);
+
+            Face::new(surface, region).insert(&mut services)
+        };
+
+        // The face points up, so this checks both the case where it must be
+        // reversed and the case where it must not.
+        for direction in [1., -1.] {
+            let solid =
+                face.sweep_face_into_solid([0., 0., direction], &mut services)?;
+
+            solid.validate_and_return_first_error()?;
+            assert_eq!(solid.shells().len(), 1);
+            for shell in solid.shells() {
+                shell.validate_and_return_first_error()?;
+                assert_eq!(shell.faces().len(), 4);
+            }
+
+            // A point in the wall is within the material, a point in the hole
+            // is not.
+            let z = direction / 2.;
+            assert!(solid.contains_point([0.75, 0., z], tolerance)?);
+            assert!(!solid.contains_point([0., 0., z], tolerance)?);
+        }
+
+        Ok(())
+    }
+    #[test]
+    fn sweep_curved_face_into_solid() {
+        let mut services = Services::new();
+
+        let spacer = fixtures::spacer(&mut services);
+        let face = spacer
+            .shells()
+            .only()
+            .faces()
+            .iter()
+            .find(|face| {
+                matches!(face.surface().geometry().u, GlobalPath::Circle(_))
+            })
+            .expect("Spacer has curved faces")
+            .clone();
+
+        assert_eq!(
+            face.sweep_face_into_solid([0., 0., 1.], &mut services),
+            Err(SweepError::CurvedSurface),
+        );
     }
 }
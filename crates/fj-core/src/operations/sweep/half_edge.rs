@@ -12,7 +12,7 @@ use crate::{
     storage::Handle,
 };
 
-use super::{vertex::SweepVertex, SweepCache, SweepSurfacePath};
+use super::{vertex::SweepVertex, SweepCache, SweepError, SweepSurfacePath};
 
 /// # Sweep a [`HalfEdge`]
 ///
@@ -33,6 +33,8 @@ pub trait SweepHalfEdge {
     ///   vertex of the next half-edge in the cycle.
     /// - The `surface` that the half-edge is defined on.
     /// - The `color` of the resulting face, if applicable
+    ///
+    /// Returns an error, if the half-edge is a spiral.
     fn sweep_half_edge(
         &self,
         end_vertex: Handle<Vertex>,
@@ -41,7 +43,7 @@ pub trait SweepHalfEdge {
         path: impl Into<Vector<3>>,
         cache: &mut SweepCache,
         services: &mut Services,
-    ) -> (Face, Handle<HalfEdge>);
+    ) -> Result<(Face, Handle<HalfEdge>), SweepError>;
 }
 
 impl SweepHalfEdge for HalfEdge {
@@ -53,12 +55,12 @@ impl SweepHalfEdge for HalfEdge {
         path: impl Into<Vector<3>>,
         cache: &mut SweepCache,
         services: &mut Services,
-    ) -> (Face, Handle<HalfEdge>) {
+    ) -> Result<(Face, Handle<HalfEdge>), SweepError> {
         let path = path.into();
 
         let surface = self
             .path()
-            .sweep_surface_path(surface, path)
+            .sweep_surface_path(surface, path)?
             .insert(services);
 
         // Next, we need to define the boundaries of the face. Let's start with
@@ -140,6 +142,6 @@ impl SweepHalfEdge for HalfEdge {
         let region = Region::new(exterior, [], color).insert(services);
         let face = Face::new(surface, region);
 
-        (face, edge_top)
+        Ok((face, edge_top))
     }
 }
@@ -1,7 +1,9 @@
 //! Sweep objects along a path to create new objects
 //!
 //! Sweeps 1D or 2D objects along a straight path, creating a 2D or 3D object,
-//! respectively.
+//! respectively. Open 1D objects, like [`Wire`]s, result in open shells.
+//!
+//! [`Wire`]: crate::objects::Wire
 
 mod cycle;
 mod face;
@@ -11,6 +13,7 @@ mod region;
 mod shell_face;
 mod sketch;
 mod vertex;
+mod wire;
 
 pub use self::{
     cycle::{SweepCycle, SweptCycle},
@@ -19,8 +22,9 @@ pub use self::{
     path::SweepSurfacePath,
     region::{SweepRegion, SweptRegion},
     shell_face::SweepFaceOfShell,
-    sketch::SweepSketch,
+    sketch::{SweepSketch, SweepSketchError},
     vertex::SweepVertex,
+    wire::SweepWire,
 };
 
 use std::collections::BTreeMap;
@@ -30,6 +34,25 @@ use crate::{
     storage::{Handle, ObjectId},
 };
 
+/// Error sweeping an object
+///
+/// Returned by the sweep operations in this module.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum SweepError {
+    /// A spiral was swept
+    ///
+    /// The surface that results from sweeping a spiral can't be represented
+    /// by [`GlobalPath`] yet.
+    ///
+    /// [`GlobalPath`]: crate::geometry::GlobalPath
+    #[error("Sweeping a spiral is not supported yet")]
+    Spiral,
+
+    /// A face with a curved surface was swept into a solid
+    #[error("Sweeping a face with a curved surface is not supported yet")]
+    CurvedSurface,
+}
+
 /// A cache used for sweeping
 #[derive(Default)]
 pub struct SweepCache {
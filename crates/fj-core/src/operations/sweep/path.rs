@@ -5,6 +5,8 @@ use crate::{
     objects::Surface,
 };
 
+use super::SweepError;
+
 /// # Sweep a [`SurfacePath`]
 ///
 /// See [module documentation] for more information.
@@ -15,6 +17,8 @@ pub trait SweepSurfacePath {
     ///
     /// Requires a reference to the surface that the path is defined on.
     ///
+    /// Returns an error, if the path is a spiral.
+    ///
     ///
     /// ## Implementation Note
     ///
@@ -25,7 +29,7 @@ pub trait SweepSurfacePath {
         &self,
         surface: &Surface,
         path: impl Into<Vector<3>>,
-    ) -> Surface;
+    ) -> Result<Surface, SweepError>;
 }
 
 impl SweepSurfacePath for SurfacePath {
@@ -33,7 +37,7 @@ impl SweepSurfacePath for SurfacePath {
         &self,
         surface: &Surface,
         path: impl Into<Vector<3>>,
-    ) -> Surface {
+    ) -> Result<Surface, SweepError> {
         match surface.geometry().u {
             GlobalPath::Circle(_) => {
                 // Sweeping a `Curve` creates a `Surface`. The u-axis of that
@@ -83,8 +87,9 @@ impl SweepSurfacePath for SurfacePath {
 
                 GlobalPath::Line(line)
             }
+            SurfacePath::Spiral(_) => return Err(SweepError::Spiral),
         };
 
-        Surface::new(SurfaceGeometry { u, v: path.into() })
+        Ok(Surface::new(SurfaceGeometry { u, v: path.into() }))
     }
 }
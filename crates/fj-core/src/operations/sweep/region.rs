@@ -9,7 +9,7 @@ use crate::{
     storage::Handle,
 };
 
-use super::{SweepCache, SweepCycle};
+use super::{SweepCache, SweepCycle, SweepError};
 
 /// # Sweep a [`Region`]
 ///
@@ -28,13 +28,15 @@ pub trait SweepRegion {
     /// There no "bottom" face. Whether having one is desirable depends on the
     /// context of the caller of this operation, and falls outside of this
     /// operation's scope.
+    ///
+    /// Returns an error, if any of the region's half-edges is a spiral.
     fn sweep_region(
         &self,
         surface: &Surface,
         path: impl Into<Vector<3>>,
         cache: &mut SweepCache,
         services: &mut Services,
-    ) -> SweptRegion;
+    ) -> Result<SweptRegion, SweepError>;
 }
 
 impl SweepRegion for Region {
@@ -44,7 +46,7 @@ impl SweepRegion for Region {
         path: impl Into<Vector<3>>,
         cache: &mut SweepCache,
         services: &mut Services,
-    ) -> SweptRegion {
+    ) -> Result<SweptRegion, SweepError> {
         let path = path.into();
 
         let mut faces = Vec::new();
@@ -57,7 +59,7 @@ impl SweepRegion for Region {
             path,
             cache,
             services,
-        );
+        )?;
 
         let mut top_interiors = Vec::new();
 
@@ -70,7 +72,7 @@ impl SweepRegion for Region {
                 path,
                 cache,
                 services,
-            );
+            )?;
 
             top_interiors.push(top_cycle);
         }
@@ -85,10 +87,10 @@ impl SweepRegion for Region {
             Face::new(top_surface, top_region)
         };
 
-        SweptRegion {
+        Ok(SweptRegion {
             top_face,
             side_faces: faces,
-        }
+        })
     }
 }
 
@@ -100,18 +102,18 @@ fn sweep_cycle(
     path: Vector<3>,
     cache: &mut SweepCache,
     services: &mut Services,
-) -> Handle<Cycle> {
+) -> Result<Handle<Cycle>, SweepError> {
     let swept_cycle = bottom_cycle.reverse(services).sweep_cycle(
         bottom_surface,
         color,
         path,
         cache,
         services,
-    );
+    )?;
 
     faces.extend(swept_cycle.faces);
 
-    swept_cycle.top_cycle.insert(services)
+    Ok(swept_cycle.top_cycle.insert(services))
 }
 
 /// The result of sweeping a [`Region`]
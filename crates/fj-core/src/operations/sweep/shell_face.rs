@@ -5,7 +5,7 @@ use crate::{
     operations::{
         insert::Insert,
         reverse::Reverse,
-        sweep::{SweepCache, SweepRegion},
+        sweep::{SweepCache, SweepError, SweepRegion},
         update::UpdateShell,
     },
     services::Services,
@@ -17,11 +17,13 @@ use crate::{
 /// See [module documentation] for more information.
 ///
 /// [module documentation]: super
-pub trait SweepFaceOfShell {
+pub trait SweepFaceOfShell: Sized {
     /// # Sweep the [`Face`] of the [`Shell`]
     ///
     /// Extends the shell, adding the new faces to it.
     ///
+    /// Returns an error, if any of the face's half-edges is a spiral.
+    ///
     /// # Panics
     ///
     /// Panics, if the face has interior cycles. This is not a fundamental
@@ -31,7 +33,7 @@ pub trait SweepFaceOfShell {
         face: Handle<Face>,
         path: impl Into<Vector<3>>,
         services: &mut Services,
-    ) -> Self;
+    ) -> Result<Self, SweepError>;
 }
 
 impl SweepFaceOfShell for Shell {
@@ -40,7 +42,7 @@ impl SweepFaceOfShell for Shell {
         face: Handle<Face>,
         path: impl Into<Vector<3>>,
         services: &mut Services,
-    ) -> Self {
+    ) -> Result<Self, SweepError> {
         let path = path.into();
 
         if !face.region().interiors().is_empty() {
@@ -56,10 +58,10 @@ impl SweepFaceOfShell for Shell {
             face.region().exterior().reverse(services).insert(services);
         let region = Region::new(exterior, [], face.region().color());
         let faces = region
-            .sweep_region(face.surface(), path, &mut cache, services)
+            .sweep_region(face.surface(), path, &mut cache, services)?
             .all_faces()
             .map(|face| face.insert(services));
 
-        self.remove_face(&face).add_faces(faces)
+        Ok(self.remove_face(&face).add_faces(faces))
     }
 }
@@ -1,14 +1,20 @@
 use fj_math::{Scalar, Vector};
 
 use crate::{
-    geometry::GlobalPath,
-    objects::{Face, Sketch, Solid, Surface},
-    operations::{insert::Insert, reverse::Reverse},
+    algorithms::approx::{ApproxError, Tolerance},
+    geometry::{GlobalPath, SurfacePath},
+    objects::{Cycle, Face, HalfEdge, Region, Shell, Sketch, Solid, Surface},
+    operations::{
+        build::BuildHalfEdge, insert::Insert, reverse::Reverse,
+        update::UpdateHalfEdge,
+    },
+    queries::SelfIntersections,
     services::Services,
     storage::Handle,
+    trace::operation_span,
 };
 
-use super::{face::SweepFace, SweepCache};
+use super::{face::SweepFace, SweepCache, SweepError, SweepRegion};
 
 /// # Sweep a [`Sketch`]
 ///
@@ -17,12 +23,78 @@ use super::{face::SweepFace, SweepCache};
 /// [module documentation]: super
 pub trait SweepSketch {
     /// # Sweep the [`Sketch`]
+    ///
+    /// The resulting solid is not checked for faces that intersect each other,
+    /// which happens if regions of the sketch overlap, or if a region's
+    /// boundary intersects itself. Use [`SweepSketch::sweep_sketch_checked`]
+    /// to check for that.
+    ///
+    /// Returns an error, if a region of the sketch can't be swept. See
+    /// [`SweepSketchError`] for the cases in which that happens.
     fn sweep_sketch(
         &self,
         surface: Handle<Surface>,
         path: impl Into<Vector<3>>,
         services: &mut Services,
-    ) -> Solid;
+    ) -> Result<Solid, SweepSketchError>;
+
+    /// # Sweep the [`Sketch`], checking the result for self-intersections
+    ///
+    /// Works like [`SweepSketch::sweep_sketch`], but then checks the resulting
+    /// solid for faces that intersect each other, using [`SelfIntersections`]
+    /// with the provided tolerance. Returns
+    /// [`SweepSketchError::SelfIntersecting`], if it finds any.
+    fn sweep_sketch_checked(
+        &self,
+        surface: Handle<Surface>,
+        path: impl Into<Vector<3>>,
+        tolerance: impl Into<Tolerance>,
+        services: &mut Services,
+    ) -> Result<Solid, SweepSketchError>;
+
+    /// # Sweep the [`Sketch`], welding vertices that are close to each other
+    ///
+    /// Works like [`SweepSketch::sweep_sketch`], but first welds the vertices
+    /// of each region, that are closer to each other than `weld_tolerance`.
+    /// Each line segment that is shorter than that is removed from its cycle,
+    /// and a neighboring line segment is extended to close the gap. This
+    /// prevents the sweep from creating degenerate side faces for tiny
+    /// features of the sketch.
+    ///
+    /// Edges that aren't line segments are never removed, and neither are
+    /// edges of cycles that have no more than three of them. Distances are
+    /// measured in the coordinates of `surface`.
+    ///
+    /// ## Interaction with Validation
+    ///
+    /// Edges that are shorter than [`ValidationConfig::distinct_min_distance`]
+    /// don't pass validation in the first place. Welding is meant for the edges
+    /// that are valid, but too short to be useful, so `weld_tolerance` should
+    /// be larger than that. The sketch itself is not modified, so any
+    /// validation errors it has are still reported.
+    ///
+    /// [`ValidationConfig::distinct_min_distance`]:
+    ///     crate::validate::ValidationConfig::distinct_min_distance
+    fn sweep_sketch_welded(
+        &self,
+        surface: Handle<Surface>,
+        path: impl Into<Vector<3>>,
+        weld_tolerance: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Result<Solid, SweepSketchError>;
+
+    /// # Sweep the [`Sketch`], without adding faces at the start and end
+    ///
+    /// Returns a shell that only consists of the side faces created by the
+    /// sweep. This shell is open, and will not pass validation as a result.
+    /// It is meant to be used as a building block for other shells, for
+    /// example by adding faces that close it.
+    fn sweep_sketch_open(
+        &self,
+        surface: Handle<Surface>,
+        path: impl Into<Vector<3>>,
+        services: &mut Services,
+    ) -> Result<Shell, SweepSketchError>;
 }
 
 impl SweepSketch for Sketch {
@@ -31,46 +103,497 @@ impl SweepSketch for Sketch {
         surface: Handle<Surface>,
         path: impl Into<Vector<3>>,
         services: &mut Services,
-    ) -> Solid {
+    ) -> Result<Solid, SweepSketchError> {
+        operation_span!("sweep_sketch", regions = self.regions().len());
+
+        sweep_regions(self.regions().iter().cloned(), surface, path, services)
+    }
+
+    fn sweep_sketch_checked(
+        &self,
+        surface: Handle<Surface>,
+        path: impl Into<Vector<3>>,
+        tolerance: impl Into<Tolerance>,
+        services: &mut Services,
+    ) -> Result<Solid, SweepSketchError> {
+        operation_span!("sweep_sketch_checked", regions = self.regions().len());
+
+        let solid = sweep_regions(
+            self.regions().iter().cloned(),
+            surface,
+            path,
+            services,
+        )?;
+
+        let faces = solid.self_intersections(tolerance)?;
+        if !faces.is_empty() {
+            return Err(SweepSketchError::SelfIntersecting { faces });
+        }
+
+        Ok(solid)
+    }
+
+    fn sweep_sketch_welded(
+        &self,
+        surface: Handle<Surface>,
+        path: impl Into<Vector<3>>,
+        weld_tolerance: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Result<Solid, SweepSketchError> {
+        operation_span!("sweep_sketch_welded", regions = self.regions().len());
+
+        let weld_tolerance = weld_tolerance.into();
+
+        let regions = self
+            .regions()
+            .iter()
+            .map(|region| weld_region(region, weld_tolerance, services))
+            .collect::<Vec<_>>();
+
+        sweep_regions(regions, surface, path, services)
+    }
+
+    fn sweep_sketch_open(
+        &self,
+        surface: Handle<Surface>,
+        path: impl Into<Vector<3>>,
+        services: &mut Services,
+    ) -> Result<Shell, SweepSketchError> {
+        operation_span!("sweep_sketch_open", regions = self.regions().len());
+
         let path = path.into();
         let mut cache = SweepCache::default();
 
-        let mut shells = Vec::new();
+        let mut faces = Vec::new();
         for region in self.regions() {
-            let region = {
-                // The following code assumes that the sketch is winded counter-
-                // clockwise. Let's check that real quick.
-                assert!(region.exterior().winding().is_ccw());
-
-                let is_negative_sweep = {
-                    let u = match surface.geometry().u {
-                        GlobalPath::Circle(_) => todo!(
-                            "Sweeping sketch from a rounded surfaces is not \
-                            supported"
-                        ),
-                        GlobalPath::Line(line) => line.direction(),
-                    };
-                    let v = surface.geometry().v;
-
-                    let normal = u.cross(&v);
-
-                    normal.dot(&path) < Scalar::ZERO
-                };
-
-                if is_negative_sweep {
-                    region.clone()
-                } else {
-                    region.reverse(services).insert(services)
-                }
-            };
-
-            let face =
-                Face::new(surface.clone(), region.clone()).insert(services);
-            let shell =
-                face.sweep_face(path, &mut cache, services).insert(services);
-            shells.push(shell);
+            let region = orient_region(region, &surface, path, services)?;
+
+            let side_faces = region
+                .sweep_region(&surface, path, &mut cache, services)?
+                .side_faces
+                .into_iter()
+                .map(|side_face| side_face.insert(services));
+            faces.extend(side_faces);
+        }
+
+        Ok(Shell::new(faces))
+    }
+}
+
+/// Error sweeping a [`Sketch`]
+///
+/// See [`SweepSketch::sweep_sketch`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum SweepSketchError {
+    /// The exterior of a region doesn't enclose any area
+    ///
+    /// This happens, if all vertices of the exterior lie on a line, or if it
+    /// is a circle without radius. Sweeping such a region would only result in
+    /// degenerate faces.
+    #[error("Can't sweep region whose exterior doesn't enclose any area")]
+    RegionWithoutArea {
+        /// The region that doesn't enclose any area
+        region: Handle<Region>,
+    },
+
+    /// The exterior of a region is winded clockwise
+    #[error("Can't sweep region whose exterior is winded clockwise")]
+    ClockwiseExterior {
+        /// The region whose exterior is winded clockwise
+        region: Handle<Region>,
+    },
+
+    /// The sketch is placed on a curved surface
+    #[error("Sweeping sketch from a curved surface is not supported")]
+    CurvedSurface,
+
+    /// Error sweeping a region of the sketch
+    #[error("Error sweeping region")]
+    Sweep(#[from] SweepError),
+
+    /// Faces of the swept solid intersect each other
+    ///
+    /// See [`SweepSketch::sweep_sketch_checked`].
+    #[error("Faces of the swept solid intersect each other")]
+    SelfIntersecting {
+        /// The pairs of faces that intersect each other
+        faces: Vec<[Handle<Face>; 2]>,
+    },
+
+    /// Error approximating the swept solid, to check it for self-intersections
+    #[error("Error approximating swept solid")]
+    Approx(#[from] ApproxError),
+}
+
+fn sweep_regions(
+    regions: impl IntoIterator<Item = Handle<Region>>,
+    surface: Handle<Surface>,
+    path: impl Into<Vector<3>>,
+    services: &mut Services,
+) -> Result<Solid, SweepSketchError> {
+    let path = path.into();
+    let mut cache = SweepCache::default();
+
+    let mut shells = Vec::new();
+    for region in regions {
+        let region = orient_region(&region, &surface, path, services)?;
+
+        let face = Face::new(surface.clone(), region.clone()).insert(services);
+        let shell = face
+            .sweep_face(path, &mut cache, services)?
+            .insert(services);
+        shells.push(shell);
+    }
+
+    Ok(Solid::new(shells))
+}
+
+/// Weld the vertices of the region's cycles, that are close to each other
+///
+/// Returns the original region, if there was nothing to weld.
+fn weld_region(
+    region: &Handle<Region>,
+    tolerance: Scalar,
+    services: &mut Services,
+) -> Handle<Region> {
+    let exterior = weld_cycle(region.exterior(), tolerance, services);
+    let interiors = region
+        .interiors()
+        .iter()
+        .map(|interior| weld_cycle(interior, tolerance, services))
+        .collect::<Vec<_>>();
+
+    if exterior.is_none() && interiors.iter().all(Option::is_none) {
+        return region.clone();
+    }
+
+    let exterior = exterior.unwrap_or_else(|| region.exterior().clone());
+    let interiors = interiors
+        .into_iter()
+        .zip(region.interiors().iter())
+        .map(|(welded, original)| welded.unwrap_or_else(|| original.clone()));
+
+    Region::new(exterior, interiors, region.color()).insert(services)
+}
+
+/// Weld the vertices of the cycle, that are close to each other
+///
+/// Returns `None`, if there was nothing to weld.
+fn weld_cycle(
+    cycle: &Handle<Cycle>,
+    tolerance: Scalar,
+    services: &mut Services,
+) -> Option<Handle<Cycle>> {
+    let is_line =
+        |half_edge: &HalfEdge| matches!(half_edge.path(), SurfacePath::Line(_));
+
+    let mut half_edges = cycle.half_edges().iter().cloned().collect::<Vec<_>>();
+    let mut welded_any = false;
+
+    // Removing a half-edge from a cycle of three would leave a degenerate
+    // cycle behind, so we stop there.
+    while half_edges.len() > 3 {
+        let n = half_edges.len();
+
+        let short = (0..n).find(|&i| {
+            let half_edge = &half_edges[i];
+            let prev = &half_edges[(i + n - 1) % n];
+            let next = &half_edges[(i + 1) % n];
+
+            let length = (next.start_position() - half_edge.start_position())
+                .magnitude();
+
+            // We can only weld, if one of the neighbors can be extended to
+            // close the gap. A circle can't be extended without changing its
+            // shape, so one of them needs to be a line segment.
+            is_line(half_edge)
+                && length < tolerance
+                && (is_line(prev) || is_line(next))
+        });
+        let Some(i) = short else {
+            break;
+        };
+
+        let [i_prev, i_next, i_after_next] =
+            [n - 1, 1, 2].map(|offset| (i + offset) % n);
+        let short = half_edges[i].clone();
+        let prev = half_edges[i_prev].clone();
+        let next = half_edges[i_next].clone();
+
+        // One of the neighbors of the short half-edge is extended to take its
+        // place. The short half-edge's vertices become one.
+        if is_line(&next) {
+            let end = half_edges[i_after_next].start_position();
+            half_edges[i_next] = HalfEdge::line_segment(
+                [short.start_position(), end],
+                None,
+                services,
+            )
+            .update_start_vertex(|_| short.start_vertex().clone())
+            .insert(services);
+        } else {
+            half_edges[i_prev] = HalfEdge::line_segment(
+                [prev.start_position(), next.start_position()],
+                None,
+                services,
+            )
+            .update_start_vertex(|_| prev.start_vertex().clone())
+            .insert(services);
+        }
+
+        half_edges.remove(i);
+        welded_any = true;
+    }
+
+    welded_any.then(|| Cycle::new(half_edges).insert(services))
+}
+
+/// Orient the region, so it faces away from the path
+///
+/// This is the orientation expected for the bottom face of a sweep, and makes
+/// sure that all faces created by the sweep face outward.
+fn orient_region(
+    region: &Handle<Region>,
+    surface: &Handle<Surface>,
+    path: Vector<3>,
+    services: &mut Services,
+) -> Result<Handle<Region>, SweepSketchError> {
+    // The following code assumes that the sketch is winded counter-clockwise.
+    // Let's check that real quick. A region without area has no winding.
+    let winding = region.exterior().try_winding().ok_or_else(|| {
+        SweepSketchError::RegionWithoutArea {
+            region: region.clone(),
+        }
+    })?;
+    if !winding.is_ccw() {
+        return Err(SweepSketchError::ClockwiseExterior {
+            region: region.clone(),
+        });
+    }
+
+    let is_negative_sweep = {
+        let u = match surface.geometry().u {
+            GlobalPath::Circle(_) => {
+                return Err(SweepSketchError::CurvedSurface);
+            }
+            GlobalPath::Line(line) => line.direction(),
+        };
+        let v = surface.geometry().v;
+
+        let normal = u.cross(&v);
+
+        normal.dot(&path) < Scalar::ZERO
+    };
+
+    if is_negative_sweep {
+        Ok(region.clone())
+    } else {
+        Ok(region.reverse(services).insert(services))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Deref;
+
+    use fj_interop::mesh::Color;
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::{
+            approx::{Approx, Tolerance},
+            triangulate::Triangulate,
+        },
+        assert_contains_err,
+        geometry::GlobalPath,
+        objects::{Region, Sketch},
+        operations::{
+            build::{BuildRegion, BuildSketch},
+            insert::Insert,
+            update::{UpdateRegion, UpdateSketch},
+        },
+        services::Services,
+        validate::{ShellValidationError, Validate, ValidationError},
+    };
+
+    use super::{SweepSketch, SweepSketchError};
+
+    #[test]
+    fn sweep_sketch_welded() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        // A square with a tiny notch in one of its corners.
+        let surface = services.objects.surfaces.xy_plane();
+        let solid = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [[0., 0.], [1., 0.], [1., 1.], [0., 1.], [1e-6, 1. - 1e-6]],
+                    &mut services,
+                )
+                .insert(&mut services),
+            )
+            .sweep_sketch_welded(surface, [0., 0., 1.], 1e-3, &mut services)?;
+
+        solid.validate_and_return_first_error()?;
+
+        // Without welding, the notch would have resulted in a seventh face.
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        for shell in solid.shells() {
+            assert_eq!(shell.faces().len(), 6);
+
+            for face in shell.faces() {
+                let area = face
+                    .deref()
+                    .approx(tolerance)?
+                    .triangulate()?
+                    .triangles()
+                    .map(|triangle| {
+                        let [a, b, c] = triangle.inner.points();
+                        (b - a).cross(&(c - a)).magnitude() / 2.
+                    })
+                    .fold(Scalar::ZERO, |sum, area| sum + area);
+
+                assert!(area > Scalar::from(0.5));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_sketch_open() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let surface = services.objects.surfaces.xy_plane();
+        let tube = Sketch::empty()
+            .add_region(
+                Region::circle(Point::origin(), 1., &mut services)
+                    .insert(&mut services),
+            )
+            .sweep_sketch_open(surface, [0., 0., 1.], &mut services)?;
+
+        // Sweeping the circle results in a single, cylindrical face.
+        assert_eq!(tube.faces().len(), 1);
+        for face in tube.faces() {
+            assert!(matches!(
+                face.surface().geometry().u,
+                GlobalPath::Circle(_)
+            ));
+        }
+
+        // The shell is open at both ends.
+        assert_contains_err!(
+            tube,
+            ValidationError::Shell(
+                ShellValidationError::HalfEdgeHasNoSibling { .. }
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_region_without_area() {
+        let mut services = Services::new();
+
+        // A triangle that has collapsed into a line.
+        let surface = services.objects.surfaces.xy_plane();
+        let region =
+            Region::polygon([[0., 0.], [1., 0.], [2., 0.]], &mut services)
+                .insert(&mut services);
+        let result = Sketch::empty().add_region(region.clone()).sweep_sketch(
+            surface,
+            [0., 0., 1.],
+            &mut services,
+        );
+
+        assert_eq!(
+            result.map(|_| ()),
+            Err(SweepSketchError::RegionWithoutArea { region })
+        );
+    }
+
+    #[test]
+    fn sweep_two_colored_sketch() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let surface = services.objects.surfaces.xy_plane();
+        let [left, right] =
+            [(0., Color::BLUE), (2., Color::YELLOW)].map(|(x, color)| {
+                Region::polygon(
+                    [[x, 0.], [x + 1., 0.], [x + 1., 1.], [x, 1.]],
+                    &mut services,
+                )
+                .set_color(color)
+                .insert(&mut services)
+            });
+        let solid = Sketch::empty()
+            .add_region(left)
+            .add_region(right)
+            .sweep_sketch(surface, [0., 0., 1.], &mut services)?;
+
+        solid.validate_and_return_first_error()?;
+
+        // Every face that results from a region, including the side faces,
+        // has the region's color.
+        let mesh = (&solid, Tolerance::from_scalar(0.01)?).triangulate()?;
+        let mut num_triangles = [0, 0];
+        for triangle in mesh.triangles() {
+            let points = triangle.inner.points();
+
+            if points.iter().all(|point| point.x <= Scalar::ONE) {
+                assert_eq!(triangle.color, Color::BLUE);
+                num_triangles[0] += 1;
+            } else {
+                assert!(points.iter().all(|point| point.x >= Scalar::TWO));
+                assert_eq!(triangle.color, Color::YELLOW);
+                num_triangles[1] += 1;
+            }
         }
+        assert_eq!(num_triangles, [12, 12]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_sketch_checked() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        let surface = services.objects.surfaces.xy_plane();
+
+        let square = Region::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut services,
+        )
+        .insert(&mut services);
+        Sketch::empty().add_region(square).sweep_sketch_checked(
+            surface.clone(),
+            [0., 0., 1.],
+            tolerance,
+            &mut services,
+        )?;
+
+        // The boundary of this region crosses itself, so two of the side faces
+        // that result from sweeping it intersect each other.
+        let bow_tie = Region::polygon(
+            [[0., 1.], [3., 0.], [3., 3.], [0., 0.]],
+            &mut services,
+        )
+        .insert(&mut services);
+        let result = Sketch::empty().add_region(bow_tie).sweep_sketch_checked(
+            surface,
+            [0., 0., 1.],
+            tolerance,
+            &mut services,
+        );
+
+        assert!(matches!(
+            result,
+            Err(SweepSketchError::SelfIntersecting { faces })
+                if faces.len() == 1
+        ));
 
-        Solid::new(shells)
+        Ok(())
     }
 }
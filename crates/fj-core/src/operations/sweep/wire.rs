@@ -0,0 +1,124 @@
+use fj_math::Vector;
+
+use crate::{
+    objects::{Shell, Surface, Vertex, Wire},
+    operations::insert::Insert,
+    services::Services,
+    storage::Handle,
+};
+
+use super::{SweepCache, SweepError, SweepHalfEdge};
+
+/// # Sweep a [`Wire`]
+///
+/// See [module documentation] for more information.
+///
+/// [module documentation]: super
+pub trait SweepWire {
+    /// # Sweep the [`Wire`]
+    ///
+    /// Each half-edge of the wire is swept into a face. Neighboring faces share
+    /// the edge that their shared vertex is swept into, so the result is a
+    /// connected sheet.
+    ///
+    /// The returned shell is open along the wire itself, its swept copy, and
+    /// the two edges swept from the wire's ends. It will not pass validation
+    /// as a result. It is meant to be used as a thin-walled surface, or as a
+    /// building block for other shells.
+    ///
+    /// The normal of each face points in the direction of the cross product of
+    /// its half-edge's direction and the sweep path.
+    ///
+    /// Returns an error, if any of the half-edges is a spiral.
+    fn sweep_wire(
+        &self,
+        surface: Handle<Surface>,
+        path: impl Into<Vector<3>>,
+        services: &mut Services,
+    ) -> Result<Shell, SweepError>;
+}
+
+impl SweepWire for Wire {
+    fn sweep_wire(
+        &self,
+        surface: Handle<Surface>,
+        path: impl Into<Vector<3>>,
+        services: &mut Services,
+    ) -> Result<Shell, SweepError> {
+        let path = path.into();
+        let mut cache = SweepCache::default();
+
+        let mut faces = Vec::new();
+
+        for (i, half_edge) in self.half_edges().iter().enumerate() {
+            // The end vertex of a half-edge is the start vertex of the next
+            // one. The last half-edge of a wire has no next one though, so its
+            // end needs a new vertex.
+            let end_vertex = match self.half_edges().nth(i + 1) {
+                Some(next) => next.start_vertex().clone(),
+                None => Vertex::new().insert(services),
+            };
+
+            let (face, _) = half_edge.sweep_half_edge(
+                end_vertex, &surface, None, path, &mut cache, services,
+            )?;
+
+            faces.push(face.insert(services));
+        }
+
+        Ok(Shell::new(faces))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        objects::Wire,
+        operations::build::BuildWire,
+        queries::AllEdges,
+        services::Services,
+        validate::{ShellValidationError, Validate, ValidationError},
+    };
+
+    use super::SweepWire;
+
+    #[test]
+    fn sweep_l_shaped_wire() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let surface = services.objects.surfaces.xy_plane();
+        let sheet =
+            Wire::polyline([[0., 0.], [2., 0.], [2., 1.]], &mut services)
+                .sweep_wire(surface, [0., 0., 1.], &mut services)?;
+
+        // One face per half-edge, bent by 90 degrees where they meet.
+        assert_eq!(sheet.faces().len(), 2);
+        let [a, b] = [0, 1].map(|i| {
+            let face = sheet.faces().nth(i).unwrap();
+            face.surface()
+                .geometry()
+                .vector_from_surface_coords([1., 0.])
+                .normalize()
+        });
+        assert_eq!(a.dot(&b), Scalar::ZERO);
+
+        // Each face has four edges, one of which is shared between them.
+        assert_eq!(sheet.all_edges().len(), 7);
+
+        // All other edges are on the boundary of the sheet, which is why the
+        // shell doesn't pass validation.
+        let mut errors = Vec::new();
+        sheet.validate(&mut errors);
+        assert_eq!(errors.len(), 6);
+        assert!(errors.iter().all(|error| matches!(
+            error,
+            ValidationError::Shell(
+                ShellValidationError::HalfEdgeHasNoSibling { .. }
+            )
+        )));
+
+        Ok(())
+    }
+}
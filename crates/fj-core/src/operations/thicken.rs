@@ -0,0 +1,433 @@
+//! Thicken surfaces into solids
+//!
+//! See [`Thicken`].
+
+use std::collections::BTreeMap;
+
+use fj_interop::mesh::Mesh;
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{
+    algorithms::{
+        approx::{ApproxError, Tolerance},
+        transform::TransformObject,
+        triangulate::Triangulate,
+    },
+    geometry::SurfacePath,
+    objects::{Face, Shell, Solid},
+    operations::{
+        build::{BuildShell, FromTriangleMeshError, MergedPoints},
+        insert::Insert,
+        sweep::{SweepError, SweepFace},
+    },
+    services::Services,
+    storage::Handle,
+    validate::ValidationConfig,
+};
+
+/// Thicken a surface into a solid
+///
+/// This is the operation that turns a thin-walled surface, like one created by
+/// [`SweepWire`], into a sheet-metal-like part.
+///
+/// [`SweepWire`]: crate::operations::sweep::SweepWire
+pub trait Thicken {
+    /// Thicken the surface into a solid
+    ///
+    /// The surface is offset by `thickness`, to the side that `side` specifies,
+    /// and its boundary is closed by side faces.
+    ///
+    /// Where faces meet, their shared vertices are offset along the average of
+    /// the faces' normals, scaled so that each offset face keeps `thickness`
+    /// as its distance from the original one. If the surface is closed, the
+    /// result is a hollow solid, with one shell on either side of the surface.
+    ///
+    /// Returns an error, if any face of the surface is not planar, or is
+    /// bounded by a curved edge. Returns an error too, if the faces that meet
+    /// at a vertex can't all be offset by `thickness` at once, as can happen,
+    /// if more than three faces meet there.
+    fn thicken(
+        &self,
+        thickness: impl Into<Scalar>,
+        side: ThickenSide,
+        services: &mut Services,
+    ) -> Result<Solid, ThickenError>;
+}
+
+impl Thicken for Handle<Face> {
+    fn thicken(
+        &self,
+        thickness: impl Into<Scalar>,
+        side: ThickenSide,
+        services: &mut Services,
+    ) -> Result<Solid, ThickenError> {
+        let thickness = thickness.into();
+        let normal = self.normal().ok_or(ThickenError::CurvedFace)?;
+
+        let (offset, path) = match side {
+            ThickenSide::Front => (None, normal),
+            ThickenSide::Back => (None, -normal),
+            ThickenSide::Both => (Some(-normal * thickness / 2.), normal),
+        };
+
+        let face = match offset {
+            Some(offset) => self.clone().translate(offset, services),
+            None => self.clone(),
+        };
+
+        Ok(face.sweep_face_into_solid(path * thickness, services)?)
+    }
+}
+
+impl Thicken for Shell {
+    fn thicken(
+        &self,
+        thickness: impl Into<Scalar>,
+        side: ThickenSide,
+        services: &mut Services,
+    ) -> Result<Solid, ThickenError> {
+        // A single face can be swept, which keeps any curved edges intact.
+        if self.faces().len() == 1 {
+            return self.faces().only().thicken(thickness, side, services);
+        }
+
+        let thickness = thickness.into();
+
+        if self.faces().iter().any(|face| face.normal().is_none()) {
+            return Err(ThickenError::CurvedFace);
+        }
+        for face in self.faces() {
+            let has_curved_edge = face
+                .region()
+                .all_cycles()
+                .flat_map(|cycle| cycle.half_edges())
+                .any(|half_edge| {
+                    !matches!(half_edge.path(), SurfacePath::Line(_))
+                });
+            if has_curved_edge {
+                return Err(ThickenError::CurvedEdge);
+            }
+        }
+
+        // All faces are planar polygons, so their triangulation is exact, no
+        // matter the tolerance.
+        let tolerance =
+            Tolerance::from(ValidationConfig::default().distinct_min_distance);
+        let mesh = (self, tolerance).triangulate()?;
+
+        let mut points = MergedPoints::new(tolerance);
+        let triangles = mesh
+            .triangles()
+            .map(|triangle| {
+                let indices =
+                    triangle.inner.points().map(|point| points.index_of(point));
+                (indices, triangle.color)
+            })
+            .collect::<Vec<_>>();
+        let points = points.into_points();
+
+        // The normals of the faces that meet at each point. Triangles that
+        // are part of the same face have the same normal, which is only
+        // stored once.
+        let mut normals = vec![Vec::<Vector<3>>::new(); points.len()];
+        for &([a, b, c], _) in &triangles {
+            let normal = (points[b] - points[a])
+                .cross(&(points[c] - points[a]))
+                .normalize();
+
+            for i in [a, b, c] {
+                let is_known = normals[i].iter().any(|known| {
+                    (*known - normal).magnitude() < tolerance.inner()
+                });
+                if !is_known {
+                    normals[i].push(normal);
+                }
+            }
+        }
+
+        let offsets = points
+            .iter()
+            .zip(&normals)
+            .map(|(&point, normals)| {
+                offset_direction(normals, tolerance.inner())
+                    .ok_or(ThickenError::InconsistentOffset { point })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let [lower, upper] = match side {
+            ThickenSide::Front => [0., 1.],
+            ThickenSide::Back => [-1., 0.],
+            ThickenSide::Both => [-0.5, 0.5],
+        }
+        .map(|factor| {
+            points
+                .iter()
+                .zip(&offsets)
+                .map(|(&point, &offset)| point + offset * thickness * factor)
+                .collect::<Vec<_>>()
+        });
+
+        let mut edges = BTreeMap::new();
+        for &([a, b, c], color) in &triangles {
+            for edge in [[a, b], [b, c], [c, a]] {
+                edges.insert(edge, color);
+            }
+        }
+        let boundary = edges
+            .iter()
+            .filter(|(edge, _)| {
+                let [a, b] = **edge;
+                !edges.contains_key(&[b, a])
+            })
+            .collect::<Vec<_>>();
+
+        // The upper copy of the surface faces outward. The lower copy faces
+        // the other way, so it needs to be reversed. If the surface is closed,
+        // the copies don't get connected, and each one becomes a shell.
+        let mut meshes = [Mesh::new(), Mesh::new()];
+        let lower_mesh = usize::from(boundary.is_empty());
+
+        for &([a, b, c], color) in &triangles {
+            meshes[0].push_triangle([upper[a], upper[b], upper[c]], color);
+            meshes[lower_mesh]
+                .push_triangle([lower[a], lower[c], lower[b]], color);
+        }
+
+        // The side faces need to contain the boundary edges in the opposite
+        // direction from the upper and lower faces.
+        for (&[a, b], &color) in boundary {
+            meshes[0].push_triangle([upper[b], upper[a], lower[a]], color);
+            meshes[0].push_triangle([upper[b], lower[a], lower[b]], color);
+        }
+
+        let shells = meshes
+            .into_iter()
+            .filter(|mesh| mesh.triangles().next().is_some())
+            .map(|mesh| {
+                Ok(Shell::from_triangle_mesh(&mesh, tolerance, services)?
+                    .insert(services))
+            })
+            .collect::<Result<Vec<_>, ThickenError>>()?;
+
+        Ok(Solid::new(shells))
+    }
+}
+
+/// The side of a surface that [`Thicken`] adds material to
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ThickenSide {
+    /// Add material to the front side of the surface
+    ///
+    /// The front side of a face is the one its normal points to. See
+    /// [`Face::coord_handedness`].
+    Front,
+
+    /// Add material to the back side of the surface
+    Back,
+
+    /// Add half of the material to either side of the surface
+    Both,
+}
+
+/// Error thickening a surface
+///
+/// See [`Thicken::thicken`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum ThickenError {
+    /// A face of the surface is not planar
+    #[error("Thickening a face on a rounded surface is not supported")]
+    CurvedFace,
+
+    /// A face of the surface is bounded by a curved edge
+    ///
+    /// This is only supported, if the surface consists of a single face.
+    #[error("Thickening faces with curved edges is not supported")]
+    CurvedEdge,
+
+    /// The faces that meet at a point can't all be offset by the thickness
+    #[error("Can't offset all faces that meet at {point:?}")]
+    InconsistentOffset {
+        /// The point where the faces meet
+        point: Point<3>,
+    },
+
+    /// Error approximating the surface
+    #[error("Error approximating surface")]
+    Approx(#[from] ApproxError),
+
+    /// Error sweeping a face of the surface
+    #[error("Error sweeping face")]
+    Sweep(#[from] SweepError),
+
+    /// Error building a shell from the offset surface
+    #[error("Error building shell from offset surface")]
+    Mesh(#[from] FromTriangleMeshError),
+}
+
+/// Compute the direction that a point is offset in, per unit of thickness
+///
+/// Moving the point along that direction must move it by the same distance
+/// from the planes of all faces that meet there. That's only possible, if
+/// there's a single solution for at most three linearly independent normals.
+fn offset_direction(
+    normals: &[Vector<3>],
+    tolerance: Scalar,
+) -> Option<Vector<3>> {
+    let (&first, rest) = normals.split_first()?;
+
+    let direction = match rest.iter().max_by_key(|n| first.cross(n).magnitude())
+    {
+        None => first,
+        Some(&second) => {
+            let perpendicular = first.cross(&second);
+            let third = rest
+                .iter()
+                .max_by_key(|n| n.dot(&perpendicular).abs())
+                .copied()
+                .unwrap_or(second);
+            let det = third.dot(&perpendicular);
+
+            if det.abs() < tolerance {
+                // The normals all lie in a plane. The direction that moves the
+                // point away from both planes by the same distance is along
+                // their average.
+                let cos = first.dot(&second);
+                if Scalar::ONE + cos < tolerance {
+                    return None;
+                }
+
+                (first + second) / (Scalar::ONE + cos)
+            } else {
+                // Solve `n * direction = 1` for the three normals, using
+                // Cramer's rule.
+                (second.cross(&third)
+                    + third.cross(&first)
+                    + first.cross(&second))
+                    / det
+            }
+        }
+    };
+
+    let is_consistent = normals
+        .iter()
+        .all(|normal| (normal.dot(&direction) - Scalar::ONE).abs() < tolerance);
+
+    is_consistent.then_some(direction)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::bounding_volume::BoundingVolume,
+        fixtures,
+        geometry::GlobalPath,
+        objects::{Face, Region, Wire},
+        operations::{
+            build::{BuildRegion, BuildWire},
+            insert::Insert,
+            sweep::SweepWire,
+        },
+        services::Services,
+        validate::Validate,
+    };
+
+    use super::{Thicken, ThickenError, ThickenSide};
+
+    #[test]
+    fn thicken_planar_face() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let surface = services.objects.surfaces.xy_plane();
+        let region = Region::polygon(
+            [[0., 0.], [2., 0.], [2., 1.], [0., 1.]],
+            &mut services,
+        )
+        .insert(&mut services);
+        let face = Face::new(surface, region).insert(&mut services);
+
+        for (side, [min_z, max_z]) in [
+            (ThickenSide::Front, [0., 0.5]),
+            (ThickenSide::Back, [-0.5, 0.]),
+            (ThickenSide::Both, [-0.25, 0.25]),
+        ] {
+            let solid = face.thicken(0.5, side, &mut services)?;
+            solid.validate_and_return_first_error()?;
+
+            // The result is a box with the thickness that we asked for.
+            assert_eq!(solid.shells().len(), 1);
+            for shell in solid.shells() {
+                shell.validate_and_return_first_error()?;
+                assert_eq!(shell.faces().len(), 6);
+            }
+
+            let aabb = solid.aabb().unwrap();
+            assert_eq!(aabb.min, Point::from([0., 0., min_z]));
+            assert_eq!(aabb.max, Point::from([2., 1., max_z]));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn thicken_bent_sheet() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        // Two faces, meeting at a right angle. The normal of the first one
+        // points towards negative y, the normal of the second one towards
+        // positive x.
+        let surface = services.objects.surfaces.xy_plane();
+        let sheet =
+            Wire::polyline([[0., 0.], [2., 0.], [2., 1.]], &mut services)
+                .sweep_wire(surface, [0., 0., 1.], &mut services)?;
+
+        for (side, [min, max]) in [
+            (ThickenSide::Front, [[0., -0.25, 0.], [2.25, 1., 1.]]),
+            (ThickenSide::Back, [[0., 0., 0.], [2., 1., 1.]]),
+            (ThickenSide::Both, [[0., -0.125, 0.], [2.125, 1., 1.]]),
+        ] {
+            let solid = sheet.thicken(0.25, side, &mut services)?;
+            solid.validate_and_return_first_error()?;
+
+            // Both copies of the sheet, the L-shaped bottom and top, and one
+            // side at each end.
+            assert_eq!(solid.shells().len(), 1);
+            assert_eq!(solid.shells().only().faces().len(), 8);
+
+            let aabb = solid.aabb().unwrap();
+            assert!(
+                aabb.min.distance_to(&Point::from(min)) < Scalar::from(1e-9)
+            );
+            assert!(
+                aabb.max.distance_to(&Point::from(max)) < Scalar::from(1e-9)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn thicken_curved_face() {
+        let mut services = Services::new();
+
+        let spacer = fixtures::spacer(&mut services);
+        let shell = spacer.shells().only();
+        let curved_face = shell
+            .faces()
+            .iter()
+            .find(|face| {
+                matches!(face.surface().geometry().u, GlobalPath::Circle(_))
+            })
+            .expect("Spacer has curved faces");
+
+        assert_eq!(
+            curved_face.thicken(0.1, ThickenSide::Front, &mut services),
+            Err(ThickenError::CurvedFace),
+        );
+        assert_eq!(
+            shell.thicken(0.1, ThickenSide::Front, &mut services),
+            Err(ThickenError::CurvedFace),
+        );
+    }
+}
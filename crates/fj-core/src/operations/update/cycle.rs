@@ -1,10 +1,14 @@
+use fj_math::{Arc, InvalidArc, Point, Vector};
+
 use crate::{
     objects::{Cycle, HalfEdge},
+    operations::{build::BuildHalfEdge, insert::Insert},
+    services::Services,
     storage::Handle,
 };
 
 /// Update a [`Cycle`]
-pub trait UpdateCycle {
+pub trait UpdateCycle: Sized {
     /// Add edges to the cycle
     #[must_use]
     fn add_half_edges(
@@ -12,6 +16,25 @@ pub trait UpdateCycle {
         edges: impl IntoIterator<Item = Handle<HalfEdge>>,
     ) -> Self;
 
+    /// Add an arc that continues the cycle tangentially
+    ///
+    /// The arc starts at the end of the last edge of the cycle, in the
+    /// direction that edge ends in, and ends at `end`. Its radius and center
+    /// follow from that.
+    ///
+    /// Returns an error, if `end` coincides with the end of the last edge, or
+    /// lies on the tangent at that point. An arc can't be constructed in those
+    /// cases.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the cycle is empty.
+    fn add_tangent_arc(
+        &self,
+        end: impl Into<Point<2>>,
+        services: &mut Services,
+    ) -> Result<Self, InvalidArc>;
+
     /// Update an edge of the cycle
     ///
     /// # Panics
@@ -53,6 +76,34 @@ impl UpdateCycle for Cycle {
         Cycle::new(edges)
     }
 
+    fn add_tangent_arc(
+        &self,
+        end: impl Into<Point<2>>,
+        services: &mut Services,
+    ) -> Result<Self, InvalidArc> {
+        let previous = self
+            .half_edges()
+            .iter()
+            .last()
+            .expect("Can't continue empty cycle with tangent arc");
+
+        let end = end.into();
+
+        let [_, previous_end] = previous.boundary().inner;
+        let start = previous.path().point_from_path_coords(previous_end);
+        let tangent = tangent_of_half_edge_at(previous, previous_end);
+
+        let arc = Arc::from_start_tangent_and_end(start, tangent, end)?;
+        let half_edge = HalfEdge::arc(
+            start,
+            end,
+            arc.end_angle - arc.start_angle,
+            services,
+        );
+
+        Ok(self.add_half_edges([half_edge.insert(services)]))
+    }
+
     fn update_half_edge(
         &self,
         handle: &Handle<HalfEdge>,
@@ -77,3 +128,79 @@ impl UpdateCycle for Cycle {
         Cycle::new(edges)
     }
 }
+
+/// Compute the tangent of a half-edge, in the direction of the half-edge
+fn tangent_of_half_edge_at(half_edge: &HalfEdge, point: Point<1>) -> Vector<2> {
+    let tangent = half_edge.path().tangent_at(point);
+
+    let [start, end] = half_edge.boundary().inner;
+    if start <= end {
+        tangent
+    } else {
+        -tangent
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{InvalidArc, Scalar};
+
+    use crate::{
+        objects::{Cycle, HalfEdge},
+        operations::{
+            build::{BuildCycle, BuildHalfEdge},
+            insert::Insert,
+        },
+        services::Services,
+    };
+
+    use super::{tangent_of_half_edge_at, UpdateCycle};
+
+    #[test]
+    fn add_tangent_arc() -> Result<(), InvalidArc> {
+        let mut services = Services::new();
+
+        let line =
+            HalfEdge::line_segment([[0., 0.], [1., 0.]], None, &mut services)
+                .insert(&mut services);
+        let cycle = Cycle::empty()
+            .add_half_edges([line])
+            .add_tangent_arc([2., 1.], &mut services)?
+            .add_tangent_arc([1., 2.], &mut services)?;
+
+        let epsilon = Scalar::from(1e-10);
+
+        for (previous, next) in cycle.half_edges().pairs().take(2) {
+            let [_, previous_end] = previous.boundary().inner;
+            let [next_start, _] = next.boundary().inner;
+
+            let end_of_previous =
+                previous.path().point_from_path_coords(previous_end);
+            let start_of_next = next.path().point_from_path_coords(next_start);
+            assert!(end_of_previous.distance_to(&start_of_next) < epsilon);
+
+            let end_tangent =
+                tangent_of_half_edge_at(previous, previous_end).normalize();
+            let start_tangent =
+                tangent_of_half_edge_at(next, next_start).normalize();
+            assert!((end_tangent - start_tangent).magnitude() < epsilon);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn add_tangent_arc_to_point_on_tangent() {
+        let mut services = Services::new();
+
+        let line =
+            HalfEdge::line_segment([[0., 0.], [1., 0.]], None, &mut services)
+                .insert(&mut services);
+        let cycle = Cycle::empty().add_half_edges([line]);
+
+        assert_eq!(
+            cycle.add_tangent_arc([2., 0.], &mut services),
+            Err(InvalidArc::CollinearPoints)
+        );
+    }
+}
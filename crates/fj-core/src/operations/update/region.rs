@@ -1,3 +1,5 @@
+use fj_interop::mesh::Color;
+
 use crate::{
     objects::{Cycle, Region},
     storage::Handle,
@@ -49,6 +51,14 @@ pub trait UpdateRegion {
         handle: &Handle<Cycle>,
         replace: impl FnOnce(&Handle<Cycle>) -> [Handle<Cycle>; N],
     ) -> Self;
+
+    /// Set the color of the region
+    ///
+    /// The color is carried over to any faces that are created from the
+    /// region, for example by sweeping it, and from there into the triangle
+    /// meshes of those faces.
+    #[must_use]
+    fn set_color(&self, color: impl Into<Option<Color>>) -> Self;
 }
 
 impl UpdateRegion for Region {
@@ -91,4 +101,12 @@ impl UpdateRegion for Region {
             .expect("Cycle not found");
         Region::new(self.exterior().clone(), interiors, self.color())
     }
+
+    fn set_color(&self, color: impl Into<Option<Color>>) -> Self {
+        Region::new(
+            self.exterior().clone(),
+            self.interiors().iter().cloned(),
+            color.into(),
+        )
+    }
 }
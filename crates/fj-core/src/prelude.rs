@@ -0,0 +1,32 @@
+//! # Commonly used items
+//!
+//! Defining a model requires items from many different modules. This module
+//! re-exports the ones that most models need, so they can be imported all at
+//! once:
+//!
+//! ``` rust
+//! use fj_core::prelude::*;
+//! ```
+//!
+//! This includes the traits that are needed to create, update, and sweep
+//! sketches, the object types that those traits are implemented for, as well
+//! as the basic math types. Anything more specialized must be imported from
+//! its respective module.
+
+pub use fj_math::{Point, Scalar, Vector};
+
+pub use crate::{
+    objects::{Cycle, Face, HalfEdge, Region, Shell, Sketch, Solid},
+    operations::{
+        build::{
+            BuildCycle, BuildFace, BuildHalfEdge, BuildRegion, BuildShell,
+            BuildSketch, BuildSolid,
+        },
+        insert::Insert,
+        reverse::Reverse,
+        sweep::SweepSketch,
+        update::{UpdateRegion, UpdateSketch},
+    },
+    services::Services,
+    storage::Handle,
+};
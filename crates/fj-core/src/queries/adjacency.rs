@@ -0,0 +1,233 @@
+use std::collections::BTreeMap;
+
+use fj_math::Point;
+
+use crate::{
+    geometry::CurveBoundary,
+    objects::{Curve, Face, HalfEdge, Shell, Solid, Vertex},
+    storage::{Handle, HandleWrapper},
+};
+
+/// Build an index of which faces, edges, and vertices are adjacent
+///
+/// Building the index requires visiting every half-edge of the object. Once it
+/// is built, the index can answer adjacency queries without doing that again.
+pub trait AdjacencyIndex {
+    /// Build the adjacency index for the object
+    fn adjacency(&self) -> Adjacency;
+}
+
+impl AdjacencyIndex for Shell {
+    fn adjacency(&self) -> Adjacency {
+        Adjacency::from_faces(self.faces().iter())
+    }
+}
+
+impl AdjacencyIndex for Solid {
+    fn adjacency(&self) -> Adjacency {
+        Adjacency::from_faces(
+            self.shells().iter().flat_map(|shell| shell.faces().iter()),
+        )
+    }
+}
+
+/// An index of which faces, edges, and vertices are adjacent
+///
+/// An edge is represented by a pair of sibling half-edges. Queries that take an
+/// edge accept either of its half-edges. Queries that return edges return one
+/// of the two half-edges of each edge.
+///
+/// Use [`AdjacencyIndex`] to build an instance of this struct.
+#[derive(Default)]
+pub struct Adjacency {
+    faces_by_edge: BTreeMap<EdgeKey, Vec<Handle<Face>>>,
+    edges_by_face: BTreeMap<HandleWrapper<Face>, Vec<EdgeKey>>,
+    edges_by_vertex: BTreeMap<HandleWrapper<Vertex>, Vec<EdgeKey>>,
+    half_edges: BTreeMap<EdgeKey, Handle<HalfEdge>>,
+}
+
+impl Adjacency {
+    fn from_faces<'r>(
+        faces: impl IntoIterator<Item = &'r Handle<Face>>,
+    ) -> Self {
+        let mut adjacency = Self::default();
+
+        for face in faces {
+            for cycle in face.region().all_cycles() {
+                for (half_edge, next) in cycle.half_edges().pairs() {
+                    let key = EdgeKey::from_half_edge(half_edge);
+
+                    adjacency
+                        .half_edges
+                        .entry(key.clone())
+                        .or_insert_with(|| half_edge.clone());
+
+                    push_unique(
+                        adjacency.faces_by_edge.entry(key.clone()).or_default(),
+                        face.clone(),
+                        |a, b| a.id() == b.id(),
+                    );
+                    push_unique(
+                        adjacency
+                            .edges_by_face
+                            .entry(face.clone().into())
+                            .or_default(),
+                        key.clone(),
+                        |a, b| a == b,
+                    );
+
+                    // The end vertex of a half-edge is the start vertex of the
+                    // next one.
+                    for vertex in
+                        [half_edge.start_vertex(), next.start_vertex()]
+                    {
+                        push_unique(
+                            adjacency
+                                .edges_by_vertex
+                                .entry(vertex.clone().into())
+                                .or_default(),
+                            key.clone(),
+                            |a, b| a == b,
+                        );
+                    }
+                }
+            }
+        }
+
+        adjacency
+    }
+
+    /// Access the faces that are bounded by the given edge
+    ///
+    /// For a valid shell, this returns two faces, or one, if the edge is a seam
+    /// that bounds the same face on both sides.
+    pub fn faces_of_edge(
+        &self,
+        half_edge: &Handle<HalfEdge>,
+    ) -> Vec<Handle<Face>> {
+        self.faces_by_edge
+            .get(&EdgeKey::from_half_edge(half_edge))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Access the edges that are bounded by the given vertex
+    pub fn edges_of_vertex(
+        &self,
+        vertex: &Handle<Vertex>,
+    ) -> Vec<Handle<HalfEdge>> {
+        self.edges_by_vertex
+            .get(&HandleWrapper::from(vertex.clone()))
+            .into_iter()
+            .flatten()
+            .map(|key| self.half_edges[key].clone())
+            .collect()
+    }
+
+    /// Access the faces that share an edge with the given face
+    ///
+    /// The face itself is not included, even if it shares an edge with itself.
+    pub fn neighbors_of_face(&self, face: &Handle<Face>) -> Vec<Handle<Face>> {
+        let mut neighbors = Vec::new();
+
+        let edges = self
+            .edges_by_face
+            .get(&HandleWrapper::from(face.clone()))
+            .into_iter()
+            .flatten();
+
+        for key in edges {
+            for neighbor in &self.faces_by_edge[key] {
+                if neighbor.id() == face.id() {
+                    continue;
+                }
+
+                push_unique(&mut neighbors, neighbor.clone(), |a, b| {
+                    a.id() == b.id()
+                });
+            }
+        }
+
+        neighbors
+    }
+}
+
+/// Identifies an edge, regardless of which of its half-edges is used
+///
+/// Sibling half-edges refer to the same curve, and have reversed boundaries.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+struct EdgeKey {
+    curve: HandleWrapper<Curve>,
+    boundary: CurveBoundary<Point<1>>,
+}
+
+impl EdgeKey {
+    fn from_half_edge(half_edge: &HalfEdge) -> Self {
+        Self {
+            curve: half_edge.curve().clone().into(),
+            boundary: half_edge.boundary().normalize(),
+        }
+    }
+}
+
+fn push_unique<T>(items: &mut Vec<T>, item: T, eq: impl Fn(&T, &T) -> bool) {
+    if !items.iter().any(|existing| eq(existing, &item)) {
+        items.push(item);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        objects::{Region, Sketch},
+        operations::{
+            build::{BuildRegion, BuildSketch},
+            insert::Insert,
+            sweep::SweepSketch,
+            update::UpdateSketch,
+        },
+        queries::AllEdges,
+        services::Services,
+    };
+
+    use super::AdjacencyIndex;
+
+    #[test]
+    fn cuboid() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let surface = services.objects.surfaces.xy_plane();
+        let solid = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                    &mut services,
+                )
+                .insert(&mut services),
+            )
+            .sweep_sketch(surface, [0., 0., 1.], &mut services)?;
+
+        let adjacency = solid.adjacency();
+
+        let edges = solid.all_edges();
+        assert_eq!(edges.len(), 12);
+        for (half_edge, _) in &edges {
+            assert_eq!(adjacency.faces_of_edge(half_edge).len(), 2);
+        }
+
+        for shell in solid.shells() {
+            for face in shell.faces() {
+                assert_eq!(adjacency.neighbors_of_face(face).len(), 4);
+
+                for cycle in face.region().all_cycles() {
+                    for half_edge in cycle.half_edges() {
+                        let vertex = half_edge.start_vertex();
+                        assert_eq!(adjacency.edges_of_vertex(vertex).len(), 3);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::BTreeSet;
+
+use fj_math::PolyChain;
+
+use crate::{
+    algorithms::approx::{
+        edge::HalfEdgeApproxCache, Approx, ApproxError, Tolerance,
+    },
+    objects::{HalfEdge, Shell, Solid, Surface},
+    storage::{Handle, HandleWrapper},
+};
+
+use super::BoundingVerticesOfHalfEdge;
+
+/// Access all edges of an object, and the surface they're on
+///
+/// An edge is represented by a pair of sibling half-edges, one for each of the
+/// faces that share it. Unlike [`AllHalfEdgesWithSurface`], this query returns
+/// only one half-edge of each such pair, so each edge appears once.
+///
+/// [`AllHalfEdgesWithSurface`]: super::AllHalfEdgesWithSurface
+pub trait AllEdges {
+    /// Access all edges of the object, and the surface they're on
+    ///
+    /// Half-edges that don't have a sibling (which is only possible, if the
+    /// object is invalid) are returned too.
+    fn all_edges(&self) -> Vec<(Handle<HalfEdge>, Handle<Surface>)>;
+
+    /// Approximate all edges of the object as polylines
+    ///
+    /// Returns one polyline per edge, as returned by [`AllEdges::all_edges`],
+    /// in global coordinates.
+    ///
+    /// Returns an error, if any of the edges can't be approximated.
+    fn all_edges_approx(
+        &self,
+        tolerance: impl Into<Tolerance>,
+    ) -> Result<Vec<PolyChain<3>>, ApproxError> {
+        let tolerance = tolerance.into();
+        let mut cache = HalfEdgeApproxCache::default();
+
+        self.all_edges()
+            .into_iter()
+            .map(|(half_edge, surface)| {
+                let approx = (&*half_edge, &*surface)
+                    .approx_with_cache(tolerance, &mut cache)?;
+
+                // The approximation of a half-edge leaves out its end vertex.
+                // We need it here, to get the full polyline.
+                let [_, end] = half_edge.boundary().inner;
+                let end = surface.geometry().point_from_surface_coords(
+                    half_edge.path().point_from_path_coords(end),
+                );
+
+                Ok(PolyChain::from_points(
+                    approx
+                        .points
+                        .into_iter()
+                        .map(|point| point.global_form)
+                        .chain([end]),
+                ))
+            })
+            .collect()
+    }
+}
+
+impl AllEdges for Shell {
+    fn all_edges(&self) -> Vec<(Handle<HalfEdge>, Handle<Surface>)> {
+        let mut visited = BTreeSet::new();
+        let mut edges = Vec::new();
+
+        for face in self.faces() {
+            for cycle in face.region().all_cycles() {
+                for half_edge in cycle.half_edges() {
+                    let curve = HandleWrapper::from(half_edge.curve().clone());
+                    let boundary = half_edge.boundary();
+                    let vertices =
+                        cycle.bounding_vertices_of_half_edge(half_edge).expect(
+                            "`half_edge` came from `cycle`, must exist there",
+                        );
+
+                    let key = (curve.clone(), boundary, vertices.clone());
+                    let key_reversed =
+                        (curve, boundary.reverse(), vertices.reverse());
+
+                    // If we've already seen the sibling of this half-edge, the
+                    // edge has already been added.
+                    if visited.contains(&key_reversed) {
+                        continue;
+                    }
+
+                    visited.insert(key);
+                    edges.push((half_edge.clone(), face.surface().clone()));
+                }
+            }
+        }
+
+        edges
+    }
+}
+
+impl AllEdges for Solid {
+    fn all_edges(&self) -> Vec<(Handle<HalfEdge>, Handle<Surface>)> {
+        self.shells()
+            .iter()
+            .flat_map(|shell| shell.all_edges())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::approx::Tolerance,
+        objects::{Region, Shell, Sketch},
+        operations::{
+            build::{BuildRegion, BuildShell, BuildSketch},
+            insert::Insert,
+            sweep::SweepSketch,
+            update::UpdateSketch,
+        },
+        services::Services,
+    };
+
+    use super::AllEdges;
+
+    #[test]
+    fn tetrahedron() {
+        let mut services = Services::new();
+
+        let tetrahedron = Shell::tetrahedron(
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]],
+            &mut services,
+        );
+
+        assert_eq!(tetrahedron.shell.all_edges().len(), 6);
+    }
+
+    #[test]
+    fn cuboid() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let surface = services.objects.surfaces.xy_plane();
+        let solid = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                    &mut services,
+                )
+                .insert(&mut services),
+            )
+            .sweep_sketch(surface, [0., 0., 1.], &mut services)?;
+
+        assert_eq!(solid.all_edges().len(), 12);
+
+        let polylines =
+            solid.all_edges_approx(Tolerance::from_scalar(0.01)?)?;
+        assert_eq!(polylines.len(), 12);
+        for polyline in polylines {
+            // All edges of the cuboid are straight lines.
+            assert_eq!(polyline.segments().len(), 1);
+        }
+
+        Ok(())
+    }
+}
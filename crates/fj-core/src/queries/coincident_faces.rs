@@ -0,0 +1,132 @@
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{
+    objects::{Face, Shell},
+    storage::Handle,
+};
+
+/// Find faces of an object that coincide with each other
+///
+/// Coincident faces occupy the same region of the same surface. They can be
+/// left over by operations that combine shapes, and make an object
+/// non-manifold.
+pub trait CoincidentFaces {
+    /// Find all pairs of faces of the object that coincide
+    ///
+    /// Two faces coincide, if the half-edges that bound them coincide, in the
+    /// same or the opposite direction, and if a point within the boundary is
+    /// the same on both surfaces. Points that are no further apart than
+    /// `max_distance` are considered to be the same.
+    ///
+    /// Each pair is returned once, with the face that comes first in the
+    /// object coming first in the pair.
+    fn coincident_faces(
+        &self,
+        max_distance: impl Into<Scalar>,
+    ) -> Vec<[Handle<Face>; 2]>;
+}
+
+impl CoincidentFaces for Shell {
+    fn coincident_faces(
+        &self,
+        max_distance: impl Into<Scalar>,
+    ) -> Vec<[Handle<Face>; 2]> {
+        let max_distance = max_distance.into();
+
+        let faces = self
+            .faces()
+            .iter()
+            .map(|face| (face, FaceSamples::new(face)))
+            .collect::<Vec<_>>();
+
+        let mut coincident = Vec::new();
+
+        for (i, (face_a, samples_a)) in faces.iter().enumerate() {
+            for (face_b, samples_b) in &faces[i + 1..] {
+                if samples_a.coincide_with(samples_b, max_distance) {
+                    coincident.push([(*face_a).clone(), (*face_b).clone()]);
+                }
+            }
+        }
+
+        coincident
+    }
+}
+
+/// Points sampled from a face, in global coordinates
+pub(super) struct FaceSamples {
+    /// The start, middle, and end point of each half-edge
+    half_edges: Vec<[Point<3>; 3]>,
+
+    /// A point on the surface, surrounded by the boundary of the face
+    ///
+    /// This is the centroid of the half-edge samples, in surface coordinates.
+    /// For concave boundaries, it might be outside of the face. That doesn't
+    /// matter, as long as coincident surfaces share it.
+    pub(super) inner: Point<3>,
+}
+
+impl FaceSamples {
+    pub(super) fn new(face: &Face) -> Self {
+        let surface = face.surface().geometry();
+
+        let mut half_edges = Vec::new();
+        let mut sum = Vector::from([0., 0.]);
+        let mut num_points = 0.;
+
+        for cycle in face.region().all_cycles() {
+            for half_edge in cycle.half_edges() {
+                let [start, end] = half_edge.boundary().inner;
+                let middle = start + (end - start) * 0.5;
+
+                let points_surface = [start, middle, end].map(|point| {
+                    half_edge.path().point_from_path_coords(point)
+                });
+
+                for point in &points_surface[..2] {
+                    sum = sum + point.coords;
+                    num_points += 1.;
+                }
+
+                half_edges.push(
+                    points_surface
+                        .map(|point| surface.point_from_surface_coords(point)),
+                );
+            }
+        }
+
+        let inner = surface
+            .point_from_surface_coords(Point::origin() + sum / num_points);
+
+        Self { half_edges, inner }
+    }
+
+    pub(super) fn coincide_with(
+        &self,
+        other: &Self,
+        max_distance: Scalar,
+    ) -> bool {
+        let is_same_point =
+            |a: Point<3>, b: Point<3>| a.distance_to(&b) <= max_distance;
+
+        if self.half_edges.len() != other.half_edges.len() {
+            return false;
+        }
+        if !is_same_point(self.inner, other.inner) {
+            return false;
+        }
+
+        self.half_edges.iter().all(|&[a0, a1, a2]| {
+            other.half_edges.iter().any(|&[b0, b1, b2]| {
+                let same_direction = is_same_point(a0, b0)
+                    && is_same_point(a1, b1)
+                    && is_same_point(a2, b2);
+                let opposite_direction = is_same_point(a0, b2)
+                    && is_same_point(a1, b1)
+                    && is_same_point(a2, b0);
+
+                same_direction || opposite_direction
+            })
+        })
+    }
+}
@@ -0,0 +1,125 @@
+use fj_math::{Point, Scalar};
+
+use crate::{
+    algorithms::{
+        approx::{ApproxError, Tolerance},
+        triangulate::Triangulate,
+    },
+    objects::Solid,
+};
+
+/// Determine whether an object contains a point
+pub trait ContainsPoint {
+    /// Determine whether the object contains the point
+    ///
+    /// The object is approximated using the provided tolerance. Points whose
+    /// distance to the boundary of the object is within that tolerance are
+    /// considered to be on the boundary, and are always reported as contained.
+    ///
+    /// Returns an error, if the object can't be approximated.
+    fn contains_point(
+        &self,
+        point: impl Into<Point<3>>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Result<bool, ApproxError>;
+}
+
+impl ContainsPoint for Solid {
+    fn contains_point(
+        &self,
+        point: impl Into<Point<3>>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Result<bool, ApproxError> {
+        let point = point.into();
+        let tolerance = tolerance.into();
+
+        let mesh = (self, tolerance).triangulate()?;
+
+        // The approximation can't tell us where exactly the boundary is, only
+        // where it is within the tolerance. Deciding this case up front also
+        // makes sure that we don't run into numerical trouble below, as the
+        // solid angle of a triangle is not defined for points on it.
+        let is_on_boundary = mesh.triangles().any(|triangle| {
+            triangle.inner.distance_to_point(point) <= tolerance.inner()
+        });
+        if is_on_boundary {
+            return Ok(true);
+        }
+
+        // Instead of casting a ray and counting the faces it passes through,
+        // which would require careful handling of rays that pass through edges
+        // or vertices, we compute the winding number of the mesh around the
+        // point. We do that by adding up the signed solid angles of all
+        // triangles, as seen from the point.
+        //
+        // For a closed mesh, the result is a multiple of the full solid angle
+        // (`4π`). It is zero, if the point is outside of the mesh, which
+        // includes points within cavities or holes.
+        let mut solid_angle = Scalar::ZERO;
+        for triangle in mesh.triangles() {
+            let [a, b, c] =
+                triangle.inner.points().map(|vertex| vertex - point);
+            let [la, lb, lc] = [a, b, c].map(|v| v.magnitude());
+
+            // See Van Oosterom and Strackee, "The Solid Angle of a Plane
+            // Triangle".
+            let numerator = a.dot(&b.cross(&c));
+            let denominator =
+                la * lb * lc + a.dot(&b) * lc + a.dot(&c) * lb + b.dot(&c) * la;
+
+            solid_angle += numerator.atan2(denominator) * 2.;
+        }
+
+        // The sign of the winding number depends on the orientation of the
+        // mesh, which we don't need to care about here.
+        let winding_number = solid_angle / (Scalar::TAU * 2.);
+        Ok(winding_number.abs() > Scalar::from(0.5))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::approx::Tolerance, fixtures::spacer, services::Services,
+    };
+
+    use super::ContainsPoint;
+
+    #[test]
+    fn spacer() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let solid = spacer(&mut services);
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        // A point in the wall is within the material.
+        assert!(solid.contains_point([0.75, 0., 0.5], tolerance)?);
+
+        // A point in the central hole is not.
+        assert!(!solid.contains_point([0., 0., 0.5], tolerance)?);
+
+        // Neither are points outside of the spacer.
+        assert!(!solid.contains_point([2., 0., 0.5], tolerance)?);
+        assert!(!solid.contains_point([0.75, 0., 2.], tolerance)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn spacer_boundary() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let solid = spacer(&mut services);
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        // Points on the top and bottom faces.
+        assert!(solid.contains_point([0.75, 0., 0.], tolerance)?);
+        assert!(solid.contains_point([0.75, 0., 1.], tolerance)?);
+
+        // Points on the outer and inner cylinder.
+        assert!(solid.contains_point([1., 0., 0.5], tolerance)?);
+        assert!(solid.contains_point([0.5, 0., 0.5], tolerance)?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,293 @@
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{
+    geometry::GlobalPath,
+    objects::{Face, HalfEdge, Handedness, Shell},
+    storage::Handle,
+};
+
+use super::SiblingOfHalfEdge;
+
+/// Determine how smoothly two faces meet along their shared edge
+///
+/// This is useful for checking the quality of a model, for example to verify
+/// that a fillet blends into its neighboring faces without a crease.
+pub trait EdgeContinuity {
+    /// Determine the continuity along the edge that the half-edge is part of
+    ///
+    /// Samples the edge at evenly spaced points, including its vertices. At
+    /// each point, the position and normal of the face that the half-edge
+    /// bounds are compared to those of the face that its sibling bounds.
+    ///
+    /// The faces are considered to be positionally continuous at a point, if
+    /// they are no further apart than `max_distance`. They are considered to
+    /// be tangentially continuous, if the angle between their normals doesn't
+    /// exceed `max_angle`, in radians.
+    ///
+    /// Returns `None`, if the half-edge is not part of the object, or if it
+    /// has no sibling there.
+    fn edge_continuity(
+        &self,
+        half_edge: &Handle<HalfEdge>,
+        max_distance: impl Into<Scalar>,
+        max_angle: impl Into<Scalar>,
+    ) -> Option<ContinuityReport>;
+}
+
+impl EdgeContinuity for Shell {
+    fn edge_continuity(
+        &self,
+        half_edge: &Handle<HalfEdge>,
+        max_distance: impl Into<Scalar>,
+        max_angle: impl Into<Scalar>,
+    ) -> Option<ContinuityReport> {
+        let max_distance = max_distance.into();
+        let max_angle = max_angle.into();
+
+        let sibling = self.get_sibling_of(half_edge)?;
+
+        let [face, sibling_face] = [half_edge, &sibling]
+            .map(|half_edge| face_of_half_edge(self, half_edge));
+        let (face, sibling_face) = (face?, sibling_face?);
+
+        let mut report = ContinuityReport {
+            continuity: Continuity::G1,
+            breaks: Vec::new(),
+        };
+
+        // Sibling half-edges share a curve, so a point on that curve has the
+        // same coordinates for both of them.
+        let [a, b] = half_edge.boundary().inner;
+        for i in 0..NUM_SAMPLES {
+            let t = a.t + (b.t - a.t) * (i as f64 / (NUM_SAMPLES - 1) as f64);
+
+            let [(position, normal), (sibling_position, sibling_normal)] =
+                [(face, half_edge), (sibling_face, &sibling)].map(
+                    |(face, half_edge)| {
+                        let point_surface =
+                            half_edge.path().point_from_path_coords([t]);
+                        let position = face
+                            .surface()
+                            .geometry()
+                            .point_from_surface_coords(point_surface);
+
+                        (position, normal_at(face, point_surface))
+                    },
+                );
+
+            let gap = position.distance_to(&sibling_position);
+
+            // Rounding errors could push the cosine slightly outside the
+            // domain of `acos`.
+            let angle = normal
+                .dot(&sibling_normal)
+                .max(-Scalar::ONE)
+                .min(Scalar::ONE)
+                .acos();
+
+            let continuity = if gap > max_distance {
+                Continuity::None
+            } else if angle > max_angle {
+                Continuity::G0
+            } else {
+                Continuity::G1
+            };
+
+            if continuity < Continuity::G1 {
+                report.continuity = report.continuity.min(continuity);
+                report.breaks.push(ContinuityBreak {
+                    position,
+                    continuity,
+                    gap,
+                    angle,
+                });
+            }
+        }
+
+        Some(report)
+    }
+}
+
+/// The continuity along an edge, as determined by [`EdgeContinuity`]
+#[derive(Clone, Debug)]
+pub struct ContinuityReport {
+    /// The lowest continuity that was found along the edge
+    pub continuity: Continuity,
+
+    /// The sampled points at which the faces are not tangentially continuous
+    ///
+    /// This is empty, if `continuity` is [`Continuity::G1`].
+    pub breaks: Vec<ContinuityBreak>,
+}
+
+/// A point on an edge, at which the faces don't meet smoothly
+///
+/// See [`ContinuityReport`].
+#[derive(Clone, Copy, Debug)]
+pub struct ContinuityBreak {
+    /// The position of the point, on the face that the half-edge bounds
+    pub position: Point<3>,
+
+    /// The continuity at the point
+    pub continuity: Continuity,
+
+    /// The distance between the faces at the point
+    pub gap: Scalar,
+
+    /// The angle between the normals of the faces at the point, in radians
+    pub angle: Scalar,
+}
+
+/// How smoothly two faces meet
+///
+/// Each level of continuity implies the ones below it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Continuity {
+    /// The faces don't meet
+    None,
+
+    /// The faces meet, but at an angle (positional continuity)
+    G0,
+
+    /// The faces meet, and their normals match (tangential continuity)
+    G1,
+}
+
+/// The number of points at which an edge is sampled
+const NUM_SAMPLES: usize = 9;
+
+fn face_of_half_edge<'r>(
+    shell: &'r Shell,
+    half_edge: &Handle<HalfEdge>,
+) -> Option<&'r Handle<Face>> {
+    shell.faces().iter().find(|face| {
+        face.region()
+            .all_cycles()
+            .any(|cycle| cycle.half_edges().contains(half_edge))
+    })
+}
+
+fn normal_at(face: &Handle<Face>, point_surface: Point<2>) -> Vector<3> {
+    let surface = face.surface().geometry();
+
+    let u = match surface.u {
+        GlobalPath::Circle(circle) => {
+            // The derivative of a circle is the same circle, a quarter turn
+            // ahead.
+            circle
+                .vector_from_circle_coords([point_surface.u + Scalar::PI / 2.])
+        }
+        GlobalPath::Line(line) => line.direction(),
+    };
+    let normal = u.cross(&surface.v).normalize();
+
+    match face.coord_handedness() {
+        Handedness::RightHanded => normal,
+        Handedness::LeftHanded => -normal,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fixtures,
+        geometry::GlobalPath,
+        objects::{Region, Shell, Sketch},
+        operations::{
+            build::{BuildRegion, BuildSketch},
+            insert::Insert,
+            sweep::SweepSketch,
+            update::UpdateSketch,
+        },
+        services::Services,
+    };
+
+    use super::{Continuity, EdgeContinuity};
+
+    #[test]
+    fn fillet_is_tangentially_continuous() {
+        let mut services = Services::new();
+
+        let solid = fixtures::filleted_cube(&mut services);
+        let shell = solid.shells().only();
+
+        // The fillet blends smoothly into the two sides it connects. It meets
+        // the top and bottom faces at a right angle.
+        let continuities = continuities_of_curved_face(shell);
+        assert_eq!(continuities.len(), 4);
+        assert_eq!(count(&continuities, Continuity::G1), 2);
+        assert_eq!(count(&continuities, Continuity::G0), 2);
+    }
+
+    #[test]
+    fn chamfer_is_only_positionally_continuous() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        // A cube like the filleted one, but with a chamfer instead of the
+        // fillet.
+        let surface = services.objects.surfaces.xy_plane();
+        let solid = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [[0., 0.], [1., 0.], [1., 0.75], [0.75, 1.], [0., 1.]],
+                    &mut services,
+                )
+                .insert(&mut services),
+            )
+            .sweep_sketch(surface, [0., 0., 1.], &mut services)?;
+        let shell = solid.shells().only();
+
+        let chamfer = shell
+            .faces()
+            .iter()
+            .find(|face| {
+                let v = face.surface().geometry().v;
+                let GlobalPath::Line(u) = face.surface().geometry().u else {
+                    return false;
+                };
+                let normal = u.direction().cross(&v).normalize();
+
+                (normal.x.abs() - normal.y.abs()).abs().into_f64() < 1e-9
+                    && normal.x.abs().into_f64() > 0.5
+            })
+            .expect("Chamfer face must exist");
+
+        for half_edge in chamfer.region().exterior().half_edges() {
+            let report = shell.edge_continuity(half_edge, 1e-9, 1e-6).unwrap();
+            assert_eq!(report.continuity, Continuity::G0);
+            assert!(!report.breaks.is_empty());
+        }
+
+        Ok(())
+    }
+
+    fn continuities_of_curved_face(shell: &Shell) -> Vec<Continuity> {
+        let curved = shell
+            .faces()
+            .iter()
+            .find(|face| {
+                matches!(face.surface().geometry().u, GlobalPath::Circle(_))
+            })
+            .expect("Curved face must exist");
+
+        curved
+            .region()
+            .exterior()
+            .half_edges()
+            .iter()
+            .map(|half_edge| {
+                let report =
+                    shell.edge_continuity(half_edge, 1e-9, 1e-6).unwrap();
+                assert_eq!(
+                    report.breaks.is_empty(),
+                    report.continuity == Continuity::G1
+                );
+                report.continuity
+            })
+            .collect()
+    }
+
+    fn count(continuities: &[Continuity], continuity: Continuity) -> usize {
+        continuities.iter().filter(|&&c| c == continuity).count()
+    }
+}
@@ -0,0 +1,383 @@
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{
+    objects::{Face, HalfEdge, Solid, Surface},
+    storage::Handle,
+};
+
+use super::{coincident_faces::FaceSamples, AllEdges};
+
+/// Compute the difference between two versions of an object
+///
+/// This is useful for parametric editing, where a model is rebuilt after a
+/// parameter changed. Knowing which parts of the model actually changed allows
+/// for re-triangulating only those, or for highlighting them to the user.
+pub trait Diff {
+    /// Compute which faces and edges changed between `self` and `new`
+    ///
+    /// Faces and edges are matched in two passes:
+    ///
+    /// 1. Objects that are shared by both versions are matched by identity.
+    /// 2. The remaining objects are matched by geometry. Objects that coincide
+    ///    are unchanged, even if they are not the same objects. Points that are
+    ///    no further apart than `max_distance` are considered to be the same.
+    ///
+    /// Any objects that are still unmatched after that, are paired up with the
+    /// nearest unmatched object of the other version that has the same
+    /// orientation. Those pairs are considered modified. What's left over, was
+    /// added or removed.
+    fn diff(&self, new: &Self, max_distance: impl Into<Scalar>) -> SolidDiff;
+}
+
+impl Diff for Solid {
+    fn diff(&self, new: &Self, max_distance: impl Into<Scalar>) -> SolidDiff {
+        let max_distance = max_distance.into();
+
+        let faces = |solid: &Solid| {
+            solid
+                .shells()
+                .iter()
+                .flat_map(|shell| shell.faces().iter().cloned())
+                .map(FaceSignature::new)
+                .collect::<Vec<_>>()
+        };
+        let edges = |solid: &Solid| {
+            solid
+                .all_edges()
+                .into_iter()
+                .map(|(half_edge, surface)| {
+                    EdgeSignature::new(half_edge, &surface)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        SolidDiff {
+            faces: Changes::compute(faces(self), faces(new), max_distance),
+            edges: Changes::compute(edges(self), edges(new), max_distance),
+        }
+    }
+}
+
+/// The difference between two versions of a solid
+///
+/// Returned by [`Diff::diff`].
+#[derive(Clone, Debug)]
+pub struct SolidDiff {
+    /// The changes to the faces of the solid
+    pub faces: Changes<Face>,
+
+    /// The changes to the edges of the solid
+    ///
+    /// Each edge is represented by one of its half-edges, as returned by
+    /// [`AllEdges::all_edges`].
+    pub edges: Changes<HalfEdge>,
+}
+
+impl SolidDiff {
+    /// Indicate whether nothing changed between the two versions
+    pub fn is_empty(&self) -> bool {
+        self.faces.is_empty() && self.edges.is_empty()
+    }
+}
+
+/// The changes to one kind of object, as part of a [`SolidDiff`]
+///
+/// Pairs of objects have the object from the old version first, and the one
+/// from the new version second.
+#[derive(Clone, Debug)]
+pub struct Changes<T> {
+    /// The objects that only exist in the new version
+    pub added: Vec<Handle<T>>,
+
+    /// The objects that only exist in the old version
+    pub removed: Vec<Handle<T>>,
+
+    /// The objects that exist in both versions, but with different geometry
+    pub modified: Vec<[Handle<T>; 2]>,
+
+    /// The objects that exist in both versions, with the same geometry
+    ///
+    /// These can be the same objects, or different objects that coincide.
+    pub unchanged: Vec<[Handle<T>; 2]>,
+}
+
+impl<T> Changes<T> {
+    /// Indicate whether no object was added, removed, or modified
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.modified.is_empty()
+    }
+
+    fn compute<S>(old: Vec<S>, new: Vec<S>, max_distance: Scalar) -> Self
+    where
+        S: Signature<Object = T>,
+    {
+        let mut old = old.into_iter().map(Some).collect::<Vec<_>>();
+        let mut new = new.into_iter().map(Some).collect::<Vec<_>>();
+
+        let mut changes = Self {
+            added: Vec::new(),
+            removed: Vec::new(),
+            modified: Vec::new(),
+            unchanged: Vec::new(),
+        };
+
+        let identical = take_matches(&mut old, &mut new, |a, b| {
+            a.is_identical_to(b).then_some(Scalar::ZERO)
+        });
+        let coincident = take_matches(&mut old, &mut new, |a, b| {
+            a.coincides_with(b, max_distance).then_some(Scalar::ZERO)
+        });
+        for [a, b] in identical.into_iter().chain(coincident) {
+            changes.unchanged.push([a.object(), b.object()]);
+        }
+
+        for [a, b] in take_matches(&mut old, &mut new, |a, b| {
+            a.distance_if_comparable(b, max_distance)
+        }) {
+            changes.modified.push([a.object(), b.object()]);
+        }
+
+        changes
+            .removed
+            .extend(old.into_iter().flatten().map(S::object));
+        changes
+            .added
+            .extend(new.into_iter().flatten().map(S::object));
+
+        changes
+    }
+}
+
+/// Remove pairs of matching objects from `old` and `new`
+///
+/// `distance` returns `None` for objects that don't match. Each object from
+/// `new` is matched with the nearest remaining object from `old`.
+fn take_matches<S>(
+    old: &mut [Option<S>],
+    new: &mut [Option<S>],
+    distance: impl Fn(&S, &S) -> Option<Scalar>,
+) -> Vec<[S; 2]> {
+    let mut matches = Vec::new();
+
+    for slot in new.iter_mut() {
+        let Some(b) = slot.as_ref() else {
+            continue;
+        };
+
+        let nearest = old
+            .iter()
+            .enumerate()
+            .filter_map(|(i, a)| Some((i, distance(a.as_ref()?, b)?)))
+            .min_by_key(|&(_, distance)| distance);
+
+        if let Some((i, _)) = nearest {
+            let a = old[i].take().expect("Found `a` in `old`");
+            let b = slot.take().expect("Just checked that `b` exists");
+            matches.push([a, b]);
+        }
+    }
+
+    matches
+}
+
+/// The information about an object that [`Diff`] uses to match it
+trait Signature {
+    type Object;
+
+    fn object(self) -> Handle<Self::Object>;
+    fn is_identical_to(&self, other: &Self) -> bool;
+    fn coincides_with(&self, other: &Self, max_distance: Scalar) -> bool;
+
+    /// Compute the distance to another object of the same orientation
+    ///
+    /// Returns `None`, if the objects don't have the same orientation.
+    fn distance_if_comparable(
+        &self,
+        other: &Self,
+        max_distance: Scalar,
+    ) -> Option<Scalar>;
+}
+
+struct FaceSignature {
+    face: Handle<Face>,
+    samples: FaceSamples,
+
+    /// The normal of the face, if it's planar
+    normal: Option<Vector<3>>,
+}
+
+impl FaceSignature {
+    fn new(face: Handle<Face>) -> Self {
+        let samples = FaceSamples::new(&face);
+        let normal = face.normal();
+
+        Self {
+            face,
+            samples,
+            normal,
+        }
+    }
+}
+
+impl Signature for FaceSignature {
+    type Object = Face;
+
+    fn object(self) -> Handle<Face> {
+        self.face
+    }
+
+    fn is_identical_to(&self, other: &Self) -> bool {
+        self.face.id() == other.face.id()
+    }
+
+    fn coincides_with(&self, other: &Self, max_distance: Scalar) -> bool {
+        self.normal.is_some() == other.normal.is_some()
+            && self.samples.coincide_with(&other.samples, max_distance)
+    }
+
+    fn distance_if_comparable(
+        &self,
+        other: &Self,
+        max_distance: Scalar,
+    ) -> Option<Scalar> {
+        match (self.normal, other.normal) {
+            (Some(a), Some(b)) if (a - b).magnitude() > max_distance => {
+                return None;
+            }
+            (Some(_), None) | (None, Some(_)) => return None,
+            _ => {}
+        }
+
+        Some(self.samples.inner.distance_to(&other.samples.inner))
+    }
+}
+
+struct EdgeSignature {
+    half_edge: Handle<HalfEdge>,
+
+    /// The start, middle, and end point of the half-edge, in global coordinates
+    points: [Point<3>; 3],
+}
+
+impl EdgeSignature {
+    fn new(half_edge: Handle<HalfEdge>, surface: &Surface) -> Self {
+        let [start, end] = half_edge.boundary().inner;
+        let middle = start + (end - start) * 0.5;
+
+        let points = [start, middle, end].map(|point| {
+            surface.geometry().point_from_surface_coords(
+                half_edge.path().point_from_path_coords(point),
+            )
+        });
+
+        Self { half_edge, points }
+    }
+
+    fn direction(&self) -> Vector<3> {
+        let [start, _, end] = self.points;
+        (end - start).normalize()
+    }
+}
+
+impl Signature for EdgeSignature {
+    type Object = HalfEdge;
+
+    fn object(self) -> Handle<HalfEdge> {
+        self.half_edge
+    }
+
+    fn is_identical_to(&self, other: &Self) -> bool {
+        // Sibling half-edges share a curve. Which one of them represents the
+        // edge, depends on the order of the faces.
+        let [a, b] = [&self.half_edge, &other.half_edge];
+        a.curve().id() == b.curve().id()
+            && (a.boundary() == b.boundary()
+                || a.boundary() == b.boundary().reverse())
+    }
+
+    fn coincides_with(&self, other: &Self, max_distance: Scalar) -> bool {
+        let is_same_point =
+            |a: Point<3>, b: Point<3>| a.distance_to(&b) <= max_distance;
+
+        let [a0, a1, a2] = self.points;
+        let [b0, b1, b2] = other.points;
+
+        is_same_point(a1, b1)
+            && ((is_same_point(a0, b0) && is_same_point(a2, b2))
+                || (is_same_point(a0, b2) && is_same_point(a2, b0)))
+    }
+
+    fn distance_if_comparable(
+        &self,
+        other: &Self,
+        max_distance: Scalar,
+    ) -> Option<Scalar> {
+        // Edges can run in either direction.
+        let [a, b] = [self.direction(), other.direction()];
+        if (a - b).magnitude() > max_distance
+            && (a + b).magnitude() > max_distance
+        {
+            return None;
+        }
+
+        let [_, a, _] = self.points;
+        let [_, b, _] = other.points;
+        Some(a.distance_to(&b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        objects::Solid, operations::build::BuildSolid, services::Services,
+    };
+
+    use super::Diff;
+
+    #[test]
+    fn changing_height_of_cuboid() {
+        let mut services = Services::new();
+
+        let old = Solid::cuboid([1., 1., 1.], &mut services);
+        let new = Solid::cuboid([1., 1., 2.], &mut services);
+
+        let diff = old.diff(&old, 1e-9);
+        assert!(diff.is_empty());
+        assert_eq!(diff.faces.unchanged.len(), 6);
+        assert_eq!(diff.edges.unchanged.len(), 12);
+
+        // Only the bottom face stays the same. The top face moves, and the
+        // side faces get taller.
+        let diff = old.diff(&new, 1e-9);
+        assert!(diff.faces.added.is_empty());
+        assert!(diff.faces.removed.is_empty());
+        assert_eq!(diff.faces.modified.len(), 5);
+        assert_eq!(diff.faces.unchanged.len(), 1);
+
+        let [bottom_old, bottom_new] = &diff.faces.unchanged[0];
+        for bottom in [bottom_old, bottom_new] {
+            let is_bottom = bottom.region().exterior().half_edges().iter().all(
+                |half_edge| {
+                    bottom
+                        .surface()
+                        .geometry()
+                        .point_from_surface_coords(half_edge.start_position())
+                        .z
+                        == Scalar::ZERO
+                },
+            );
+            assert!(is_bottom);
+        }
+
+        // The edges of the bottom face stay the same. The vertical edges get
+        // longer, and the ones at the top move.
+        assert!(diff.edges.added.is_empty());
+        assert!(diff.edges.removed.is_empty());
+        assert_eq!(diff.edges.modified.len(), 8);
+        assert_eq!(diff.edges.unchanged.len(), 4);
+    }
+}
@@ -9,12 +9,34 @@
 //! This module provides traits express such non-trivial queries, and implements
 //! them for various objects that have the information to answer the query.
 
+mod adjacency;
+mod all_edges;
 mod all_half_edges_with_surface;
 mod bounding_vertices_of_half_edge;
+mod coincident_faces;
+mod contains_point;
+mod continuity;
+mod diff;
+mod position_of_vertex;
+mod select_edges;
+mod select_faces;
+mod self_intersections;
 mod sibling_of_half_edge;
 
 pub use self::{
+    adjacency::{Adjacency, AdjacencyIndex},
+    all_edges::AllEdges,
     all_half_edges_with_surface::AllHalfEdgesWithSurface,
     bounding_vertices_of_half_edge::BoundingVerticesOfHalfEdge,
+    coincident_faces::CoincidentFaces,
+    contains_point::ContainsPoint,
+    continuity::{
+        Continuity, ContinuityBreak, ContinuityReport, EdgeContinuity,
+    },
+    diff::{Changes, Diff, SolidDiff},
+    position_of_vertex::{GlobalPositionOfVertex, PositionOfVertex},
+    select_edges::{EdgeGeometry, SelectEdges},
+    select_faces::{FaceGeometry, SelectFaces},
+    self_intersections::SelfIntersections,
     sibling_of_half_edge::SiblingOfHalfEdge,
 };
@@ -0,0 +1,165 @@
+use fj_math::Point;
+
+use crate::{
+    objects::{Face, HalfEdge, Shell, Vertex},
+    storage::Handle,
+};
+
+use super::BoundingVerticesOfHalfEdge;
+
+/// Determine the position of a vertex, relative to a half-edge it bounds
+///
+/// Vertices don't have a position themselves. Their position is defined by the
+/// half-edges they bound, in the coordinates of the curve and the surface that
+/// each half-edge is defined on. Since a half-edge only refers to the vertex it
+/// starts at, the object that contains the half-edge is required to also
+/// consider the vertex it ends at.
+///
+/// See [`GlobalPositionOfVertex`] for global coordinates, which additionally
+/// require the surface that the half-edge is defined on.
+pub trait PositionOfVertex {
+    /// Determine the position of the vertex on the curve of the half-edge
+    ///
+    /// Returns `None`, if the half-edge is not part of the object, or if the
+    /// vertex doesn't bound it.
+    fn vertex_position_on_curve(
+        &self,
+        half_edge: &Handle<HalfEdge>,
+        vertex: &Handle<Vertex>,
+    ) -> Option<Point<1>>;
+
+    /// Determine the position of the vertex on the surface of the half-edge
+    ///
+    /// Returns `None` in the same cases as
+    /// [`PositionOfVertex::vertex_position_on_curve`].
+    fn vertex_position_on_surface(
+        &self,
+        half_edge: &Handle<HalfEdge>,
+        vertex: &Handle<Vertex>,
+    ) -> Option<Point<2>> {
+        let point_curve = self.vertex_position_on_curve(half_edge, vertex)?;
+        Some(half_edge.path().point_from_path_coords(point_curve))
+    }
+}
+
+impl<T: BoundingVerticesOfHalfEdge> PositionOfVertex for T {
+    fn vertex_position_on_curve(
+        &self,
+        half_edge: &Handle<HalfEdge>,
+        vertex: &Handle<Vertex>,
+    ) -> Option<Point<1>> {
+        let vertices = self.bounding_vertices_of_half_edge(half_edge)?;
+
+        // If the half-edge is closed, it starts and ends at the same vertex.
+        // Then we return the start of its boundary.
+        let index = vertices
+            .inner
+            .iter()
+            .position(|bounding| bounding.id() == vertex.id())?;
+
+        Some(half_edge.boundary().inner[index])
+    }
+}
+
+/// Determine the position of a vertex in global coordinates
+///
+/// This requires the surface that the half-edge is defined on, and thus is
+/// only available for objects that provide it.
+pub trait GlobalPositionOfVertex: PositionOfVertex {
+    /// Determine the global position of the vertex
+    ///
+    /// Returns `None` in the same cases as
+    /// [`PositionOfVertex::vertex_position_on_curve`].
+    fn global_vertex_position(
+        &self,
+        half_edge: &Handle<HalfEdge>,
+        vertex: &Handle<Vertex>,
+    ) -> Option<Point<3>>;
+}
+
+impl GlobalPositionOfVertex for Face {
+    fn global_vertex_position(
+        &self,
+        half_edge: &Handle<HalfEdge>,
+        vertex: &Handle<Vertex>,
+    ) -> Option<Point<3>> {
+        let point_surface =
+            self.vertex_position_on_surface(half_edge, vertex)?;
+
+        Some(
+            self.surface()
+                .geometry()
+                .point_from_surface_coords(point_surface),
+        )
+    }
+}
+
+impl GlobalPositionOfVertex for Shell {
+    fn global_vertex_position(
+        &self,
+        half_edge: &Handle<HalfEdge>,
+        vertex: &Handle<Vertex>,
+    ) -> Option<Point<3>> {
+        self.faces()
+            .iter()
+            .find_map(|face| face.global_vertex_position(half_edge, vertex))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::{
+        objects::{Cycle, Face, Region},
+        operations::{build::BuildCycle, insert::Insert},
+        services::Services,
+    };
+
+    use super::{GlobalPositionOfVertex, PositionOfVertex};
+
+    #[test]
+    fn position_of_vertex() {
+        let mut services = Services::new();
+
+        let cycle = Cycle::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut services,
+        )
+        .insert(&mut services);
+
+        let mut half_edges = cycle.half_edges().iter();
+        let first = half_edges.next().unwrap();
+        let second = half_edges.next().unwrap();
+
+        // The vertex where the second half-edge starts, is where the first one
+        // ends.
+        let vertex = second.start_vertex();
+        assert_eq!(
+            cycle.vertex_position_on_curve(second, vertex),
+            Some(second.boundary().inner[0]),
+        );
+        assert_eq!(
+            cycle.vertex_position_on_curve(first, vertex),
+            Some(first.boundary().inner[1]),
+        );
+        for half_edge in [first, second] {
+            assert_eq!(
+                cycle.vertex_position_on_surface(half_edge, vertex),
+                Some(Point::from([1., 0.])),
+            );
+        }
+
+        // The third half-edge isn't bounded by the vertex.
+        let third = half_edges.next().unwrap();
+        assert_eq!(cycle.vertex_position_on_curve(third, vertex), None);
+
+        let surface = services.objects.surfaces.xz_plane();
+        let region = Region::new(cycle.clone(), [], None).insert(&mut services);
+        let face = Face::new(surface, region);
+        assert_eq!(
+            face.global_vertex_position(second, vertex),
+            Some(Point::from([1., 0., 0.])),
+        );
+    }
+}
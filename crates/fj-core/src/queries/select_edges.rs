@@ -0,0 +1,158 @@
+use fj_math::{Aabb, PolyChain, Scalar, Vector};
+
+use crate::{
+    algorithms::approx::{ApproxError, Tolerance},
+    objects::{HalfEdge, Surface},
+    storage::Handle,
+};
+
+use super::AllEdges;
+
+/// Select the edges of an object by their geometry
+///
+/// This is useful for operations that apply to many edges at once, like
+/// rounding off all vertical edges of a shape. Instead of collecting the
+/// half-edges manually, they can be selected by a predicate.
+pub trait SelectEdges: AllEdges {
+    /// Select all edges of the object that match the predicate
+    ///
+    /// Each edge is represented by one of its half-edges and the surface it's
+    /// on, as returned by [`AllEdges::all_edges`]. The predicate receives the
+    /// geometry of each edge, approximated using the provided tolerance. See
+    /// [`EdgeGeometry`] for convenience predicates.
+    ///
+    /// Returns an error, if any of the edges can't be approximated.
+    fn edges_where(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        mut predicate: impl FnMut(&EdgeGeometry) -> bool,
+    ) -> Result<Vec<(Handle<HalfEdge>, Handle<Surface>)>, ApproxError> {
+        let polylines = self.all_edges_approx(tolerance)?;
+
+        let edges = self
+            .all_edges()
+            .into_iter()
+            .zip(polylines)
+            .filter_map(|((half_edge, surface), polyline)| {
+                let edge = EdgeGeometry {
+                    half_edge,
+                    surface,
+                    polyline,
+                };
+                predicate(&edge).then_some((edge.half_edge, edge.surface))
+            })
+            .collect();
+
+        Ok(edges)
+    }
+}
+
+impl<T: AllEdges> SelectEdges for T {}
+
+/// The geometry of an edge, as passed to [`SelectEdges::edges_where`]
+#[derive(Clone, Debug)]
+pub struct EdgeGeometry {
+    /// The half-edge that represents the edge
+    pub half_edge: Handle<HalfEdge>,
+
+    /// The surface that the half-edge is defined on
+    pub surface: Handle<Surface>,
+
+    /// The approximation of the edge, in global coordinates
+    pub polyline: PolyChain<3>,
+}
+
+impl EdgeGeometry {
+    /// Compute the length of the edge
+    ///
+    /// For curved edges, this is the length of the approximation.
+    pub fn length(&self) -> Scalar {
+        self.polyline.segments().into_iter().fold(
+            Scalar::ZERO,
+            |length, segment| {
+                let [a, b] = segment.points();
+                length + a.distance_to(&b)
+            },
+        )
+    }
+
+    /// Determine whether the edge is straight and parallel to a direction
+    ///
+    /// The edge may run in either sense of the direction, and deviate from it
+    /// by up to `max_angle`, in radians. Curved edges are never parallel to a
+    /// direction, unless their approximation is a single straight segment.
+    pub fn is_parallel_to(
+        &self,
+        direction: impl Into<Vector<3>>,
+        max_angle: impl Into<Scalar>,
+    ) -> bool {
+        let direction = direction.into().normalize();
+        let max_angle = max_angle.into();
+
+        self.polyline.segments().into_iter().all(|segment| {
+            let [a, b] = segment.points();
+            let cos = (b - a).normalize().dot(&direction).abs();
+
+            // Rounding errors could push the cosine slightly above one, which
+            // is outside of the domain of `acos`.
+            cos.min(Scalar::ONE).acos() <= max_angle
+        })
+    }
+
+    /// Determine whether the edge is fully within a bounding box
+    pub fn is_within(&self, aabb: &Aabb<3>) -> bool {
+        self.polyline.segments().into_iter().all(|segment| {
+            segment
+                .points()
+                .into_iter()
+                .all(|point| aabb.contains(point))
+        })
+    }
+
+    /// Determine whether the edge is shorter than the provided length
+    pub fn is_shorter_than(&self, length: impl Into<Scalar>) -> bool {
+        self.length() < length.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Scalar};
+
+    use crate::{
+        algorithms::approx::Tolerance, objects::Solid,
+        operations::build::BuildSolid, services::Services,
+    };
+
+    use super::SelectEdges;
+
+    #[test]
+    fn select_vertical_edges_of_box() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let cuboid = Solid::cuboid([1., 2., 3.], &mut services);
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let vertical = cuboid.edges_where(tolerance, |edge| {
+            let is_vertical = edge.is_parallel_to([0., 0., 1.], 1e-6);
+            if is_vertical {
+                assert_eq!(edge.length(), Scalar::from(3.));
+            }
+            is_vertical
+        })?;
+        assert_eq!(vertical.len(), 4);
+
+        let short =
+            cuboid.edges_where(tolerance, |edge| edge.is_shorter_than(1.5))?;
+        assert_eq!(short.len(), 4);
+
+        let top = Aabb {
+            min: [-1., -2., 2.5].into(),
+            max: [1., 2., 3.5].into(),
+        };
+        let top = cuboid.edges_where(tolerance, |edge| edge.is_within(&top))?;
+        assert_eq!(top.len(), 4);
+
+        Ok(())
+    }
+}
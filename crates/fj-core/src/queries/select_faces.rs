@@ -0,0 +1,188 @@
+use fj_interop::mesh::Mesh;
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{
+    algorithms::{
+        approx::{Approx, ApproxError, Tolerance},
+        triangulate::Triangulate,
+    },
+    geometry::GlobalPath,
+    objects::{Face, Shell, Solid},
+    storage::Handle,
+};
+
+/// Select the faces of an object by their geometry
+///
+/// This is useful for operations that are placed on a face, like pockets or
+/// pads. Instead of finding the face manually, it can be selected by a
+/// predicate, like "the face that points up".
+///
+/// See [`SelectEdges`] for the equivalent query for edges.
+///
+/// [`SelectEdges`]: super::SelectEdges
+pub trait SelectFaces {
+    /// Select all faces of the object that match the predicate
+    ///
+    /// The predicate receives the geometry of each face, triangulated using the
+    /// provided tolerance. See [`FaceGeometry`] for convenience predicates.
+    ///
+    /// Returns an error, if any of the faces can't be approximated.
+    fn faces_where(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        predicate: impl FnMut(&FaceGeometry) -> bool,
+    ) -> Result<Vec<Handle<Face>>, ApproxError>;
+}
+
+impl SelectFaces for Shell {
+    fn faces_where(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        mut predicate: impl FnMut(&FaceGeometry) -> bool,
+    ) -> Result<Vec<Handle<Face>>, ApproxError> {
+        let tolerance = tolerance.into();
+        let mut faces = Vec::new();
+
+        for face in self.faces() {
+            let mesh = face.approx(tolerance)?.triangulate()?;
+            let geometry = FaceGeometry {
+                face: face.clone(),
+                mesh,
+            };
+
+            if predicate(&geometry) {
+                faces.push(geometry.face);
+            }
+        }
+
+        Ok(faces)
+    }
+}
+
+impl SelectFaces for Solid {
+    fn faces_where(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        mut predicate: impl FnMut(&FaceGeometry) -> bool,
+    ) -> Result<Vec<Handle<Face>>, ApproxError> {
+        let tolerance = tolerance.into();
+        let mut faces = Vec::new();
+
+        for shell in self.shells() {
+            faces.extend(shell.faces_where(tolerance, &mut predicate)?);
+        }
+
+        Ok(faces)
+    }
+}
+
+/// The geometry of a face, as passed to [`SelectFaces::faces_where`]
+#[derive(Clone, Debug)]
+pub struct FaceGeometry {
+    /// The face
+    pub face: Handle<Face>,
+
+    /// The triangulation of the face, in global coordinates
+    pub mesh: Mesh<Point<3>>,
+}
+
+impl FaceGeometry {
+    /// Determine whether the face is planar
+    pub fn is_planar(&self) -> bool {
+        matches!(self.face.surface().geometry().u, GlobalPath::Line(_))
+    }
+
+    /// Compute the normal of the face, pointing outward from its front side
+    ///
+    /// Returns `None`, if the face is curved, and thus has no single normal.
+    ///
+    /// See [`Face::normal`].
+    pub fn normal(&self) -> Option<Vector<3>> {
+        self.face.normal()
+    }
+
+    /// Determine whether the face is planar and faces a direction
+    ///
+    /// The normal of the face may deviate from the direction by up to
+    /// `max_angle`, in radians.
+    pub fn faces_direction(
+        &self,
+        direction: impl Into<Vector<3>>,
+        max_angle: impl Into<Scalar>,
+    ) -> bool {
+        let Some(normal) = self.normal() else {
+            return false;
+        };
+        let cos = normal.dot(&direction.into().normalize());
+
+        // Rounding errors could push the cosine slightly outside the domain of
+        // `acos`.
+        cos.max(-Scalar::ONE).min(Scalar::ONE).acos() <= max_angle.into()
+    }
+
+    /// Compute the area of the face
+    ///
+    /// For curved faces, this is the area of the triangulation.
+    pub fn area(&self) -> Scalar {
+        self.mesh.triangles().fold(Scalar::ZERO, |area, triangle| {
+            let [a, b, c] = triangle.inner.points();
+            area + (b - a).cross(&(c - a)).magnitude() / 2.
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::approx::Tolerance, objects::Solid,
+        operations::build::BuildSolid, services::Services,
+    };
+
+    use super::SelectFaces;
+
+    #[test]
+    fn select_top_face_of_box() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let cuboid = Solid::cuboid([1., 2., 3.], &mut services);
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let top = cuboid.faces_where(tolerance, |face| {
+            face.faces_direction([0., 0., 1.], 1e-6)
+        })?;
+        assert_eq!(top.len(), 1);
+
+        let largest = cuboid.faces_where(tolerance, |face| {
+            (face.area() - Scalar::from(6.)).abs() < Scalar::from(1e-9)
+        })?;
+        assert_eq!(largest.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn select_flat_faces_of_spacer() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let spacer = Solid::spacer(1., 0.5, 1., &mut services);
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let flat = spacer.faces_where(tolerance, |face| face.is_planar())?;
+        assert_eq!(flat.len(), 2);
+
+        let curved = spacer.faces_where(tolerance, |face| !face.is_planar())?;
+        assert_eq!(curved.len(), 2);
+
+        let up = spacer.faces_where(tolerance, |face| {
+            face.faces_direction([0., 0., 1.], 1e-6)
+        })?;
+        let down = spacer.faces_where(tolerance, |face| {
+            face.faces_direction([0., 0., -1.], 1e-6)
+        })?;
+        assert_eq!((up.len(), down.len()), (1, 1));
+
+        Ok(())
+    }
+}
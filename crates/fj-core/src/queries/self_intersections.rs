@@ -0,0 +1,225 @@
+use std::ops::Deref;
+
+use fj_math::{Aabb, Point, Scalar, Triangle};
+
+use crate::{
+    algorithms::{
+        approx::{edge::HalfEdgeApproxCache, Approx, ApproxError, Tolerance},
+        triangulate::Triangulate,
+    },
+    objects::{Face, Shell, Solid},
+    storage::Handle,
+};
+
+/// Find faces of an object that intersect each other
+///
+/// Faces that intersect each other make an object invalid. This isn't detected
+/// by validation, as it requires an approximation of the faces' geometry. Such
+/// intersections can result from sweeping a profile that intersects itself,
+/// for example, or from sweeping overlapping profiles.
+/// [`SweepSketch::sweep_sketch_checked`] runs this check after sweeping.
+///
+/// [`SweepSketch::sweep_sketch_checked`]:
+///     crate::operations::sweep::SweepSketch::sweep_sketch_checked
+pub trait SelfIntersections {
+    /// Find all pairs of faces of the object that intersect each other
+    ///
+    /// The faces are approximated using the provided tolerance, and those
+    /// approximations are checked for intersections. Faces that merely touch,
+    /// like adjacent faces do at their shared edges, are not considered to
+    /// intersect. Neither are faces that overlap within the same plane.
+    ///
+    /// Returns an error, if any of the faces can't be approximated.
+    fn self_intersections(
+        &self,
+        tolerance: impl Into<Tolerance>,
+    ) -> Result<Vec<[Handle<Face>; 2]>, ApproxError>;
+}
+
+impl SelfIntersections for Shell {
+    fn self_intersections(
+        &self,
+        tolerance: impl Into<Tolerance>,
+    ) -> Result<Vec<[Handle<Face>; 2]>, ApproxError> {
+        intersecting_faces(self.faces(), tolerance.into())
+    }
+}
+
+impl SelfIntersections for Solid {
+    fn self_intersections(
+        &self,
+        tolerance: impl Into<Tolerance>,
+    ) -> Result<Vec<[Handle<Face>; 2]>, ApproxError> {
+        // Faces of different shells must not intersect either, so we need to
+        // check all of them together.
+        let faces = self.shells().iter().flat_map(|shell| shell.faces());
+        intersecting_faces(faces, tolerance.into())
+    }
+}
+
+fn intersecting_faces<'r>(
+    faces: impl IntoIterator<Item = &'r Handle<Face>>,
+    tolerance: Tolerance,
+) -> Result<Vec<[Handle<Face>; 2]>, ApproxError> {
+    let mut cache = HalfEdgeApproxCache::default();
+
+    let faces = faces
+        .into_iter()
+        .map(|face| {
+            let triangles = face
+                .deref()
+                .approx_with_cache(tolerance, &mut cache)?
+                .triangulate()?
+                .triangles()
+                .map(|triangle| triangle.inner)
+                .collect::<Vec<_>>();
+
+            if triangles.is_empty() {
+                return Ok(None);
+            }
+
+            let aabb = Aabb::<3>::from_points(
+                triangles.iter().flat_map(|triangle| triangle.points()),
+            );
+
+            Ok(Some((face, triangles, aabb)))
+        })
+        .filter_map(Result::transpose)
+        .collect::<Result<Vec<_>, ApproxError>>()?;
+
+    let mut intersections = Vec::new();
+
+    for (i, (face_a, triangles_a, aabb_a)) in faces.iter().enumerate() {
+        for (face_b, triangles_b, aabb_b) in &faces[i + 1..] {
+            if !aabbs_overlap(aabb_a, aabb_b) {
+                continue;
+            }
+
+            let faces_intersect = triangles_a
+                .iter()
+                .any(|a| triangles_b.iter().any(|b| triangles_intersect(a, b)));
+
+            if faces_intersect {
+                intersections.push([(*face_a).clone(), (*face_b).clone()]);
+            }
+        }
+    }
+
+    Ok(intersections)
+}
+
+fn aabbs_overlap(a: &Aabb<3>, b: &Aabb<3>) -> bool {
+    (0..3).all(|i| {
+        a.min.coords.components[i] <= b.max.coords.components[i]
+            && b.min.coords.components[i] <= a.max.coords.components[i]
+    })
+}
+
+/// Determine whether two triangles cross each other
+///
+/// If two triangles that are not coplanar cross each other, the segment where
+/// they do starts and ends on edges of the triangles. So it is enough to check
+/// whether any edge of one triangle passes through the interior of the other.
+fn triangles_intersect(a: &Triangle<3>, b: &Triangle<3>) -> bool {
+    let edges = |triangle: &Triangle<3>| {
+        let [a, b, c] = triangle.points();
+        [[a, b], [b, c], [c, a]]
+    };
+
+    edges(a)
+        .into_iter()
+        .any(|edge| edge_passes_through_triangle(edge, b))
+        || edges(b)
+            .into_iter()
+            .any(|edge| edge_passes_through_triangle(edge, a))
+}
+
+fn edge_passes_through_triangle(
+    [p, q]: [Point<3>; 2],
+    triangle: &Triangle<3>,
+) -> bool {
+    // Coordinates that are this close to the boundary of the edge or the
+    // triangle, are considered to be on the boundary. This prevents triangles
+    // that touch at their edges from being reported.
+    let margin = Scalar::from(1e-9);
+
+    let [a, b, c] = triangle.points();
+    let normal = (b - a).cross(&(c - a));
+
+    // The edge must start and end on different sides of the triangle's plane.
+    let distance_p = (p - a).dot(&normal);
+    let distance_q = (q - a).dot(&normal);
+    if distance_p * distance_q >= Scalar::ZERO {
+        return false;
+    }
+
+    let t = distance_p / (distance_p - distance_q);
+    if t < margin || t > Scalar::ONE - margin {
+        return false;
+    }
+
+    // The point where the edge passes through the plane must be in the
+    // interior of the triangle. We check that by computing the barycentric
+    // coordinate of that point for each vertex of the triangle.
+    let point = p + (q - p) * t;
+    [[a, b], [b, c], [c, a]].into_iter().all(|[u, v]| {
+        let coordinate =
+            (v - u).cross(&(point - u)).dot(&normal) / normal.dot(&normal);
+        coordinate > margin
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Vector;
+
+    use crate::{
+        algorithms::approx::Tolerance,
+        objects::{Region, Sketch, Solid},
+        operations::{
+            build::{BuildRegion, BuildSketch, BuildSolid},
+            insert::Insert,
+            sweep::SweepSketch,
+            update::UpdateSketch,
+        },
+        services::Services,
+    };
+
+    use super::SelfIntersections;
+
+    #[test]
+    fn spacer() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let solid = Solid::spacer(1., 0.5, 1., &mut services);
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        assert!(solid.self_intersections(tolerance)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn self_intersecting_profile() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        // The boundary of this profile crosses itself, at `[0.75, 0.75]`.
+        let surface = services.objects.surfaces.xy_plane();
+        let solid = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [[0., 1.], [3., 0.], [3., 3.], [0., 0.]],
+                    &mut services,
+                )
+                .insert(&mut services),
+            )
+            .sweep_sketch(surface, Vector::from([0., 0., 1.]), &mut services)?;
+
+        // The two side faces that result from the crossing edges intersect
+        // each other. All other faces only touch at their shared edges.
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        assert_eq!(solid.self_intersections(tolerance)?.len(), 1);
+
+        Ok(())
+    }
+}
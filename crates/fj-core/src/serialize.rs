@@ -0,0 +1,588 @@
+//! Serialization of the object graph
+//!
+//! Objects reference each other through [`Handle`]s, and an object can be
+//! referenced from multiple places. Two half-edges can refer to the same curve,
+//! for example, and adjacent faces share vertices. Serializing each object
+//! along with everything it references would duplicate those shared objects,
+//! losing the information that they were shared.
+//!
+//! [`SerializedSolid`] avoids this, by storing one table per object type.
+//! Objects refer to each other by their index in those tables. On
+//! deserialization, each entry in a table becomes one object, and all
+//! references to it become handles to that same object.
+//!
+//! [`SerializedSolid`] implements [`Serialize`] and [`Deserialize`], and can be
+//! used with any format supported by `serde`.
+
+use std::collections::BTreeMap;
+
+use fj_interop::mesh::Color;
+use fj_math::{Circle, Line, Point, Scalar, Spiral, Vector};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::{GlobalPath, SurfaceGeometry, SurfacePath},
+    objects::{
+        Curve, Cycle, Face, HalfEdge, Region, Shell, Solid, Surface, Vertex,
+    },
+    operations::insert::Insert,
+    services::Services,
+    storage::{Handle, HandleWrapper},
+};
+
+/// A serializable representation of a [`Solid`]
+///
+/// See [module-level documentation] for context.
+///
+/// [module-level documentation]: self
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SerializedSolid {
+    /// The number of vertices
+    ///
+    /// Vertices carry no data, only identity.
+    vertices: usize,
+
+    /// The number of curves
+    ///
+    /// Curves carry no data, only identity.
+    curves: usize,
+
+    surfaces: Vec<SurfaceData>,
+    half_edges: Vec<HalfEdgeData>,
+    cycles: Vec<Vec<usize>>,
+    regions: Vec<RegionData>,
+    faces: Vec<FaceData>,
+    shells: Vec<Vec<usize>>,
+    solid: Vec<usize>,
+}
+
+impl SerializedSolid {
+    /// Create the serializable representation of a solid
+    pub fn from_solid(solid: &Solid) -> Self {
+        let mut serializer = Serializer::default();
+
+        serializer.serialized.solid = solid
+            .shells()
+            .iter()
+            .map(|shell| serializer.shell(shell))
+            .collect();
+
+        serializer.serialized
+    }
+
+    /// Create a solid from its serializable representation
+    ///
+    /// All created objects are inserted into `services`.
+    pub fn to_solid(
+        &self,
+        services: &mut Services,
+    ) -> Result<Solid, DeserializeError> {
+        let vertices = (0..self.vertices)
+            .map(|_| Vertex::new().insert(services))
+            .collect::<Vec<_>>();
+        let curves = (0..self.curves)
+            .map(|_| Curve::new().insert(services))
+            .collect::<Vec<_>>();
+
+        let surfaces = self
+            .surfaces
+            .iter()
+            .map(|surface| {
+                let geometry = SurfaceGeometry {
+                    u: surface.u.to_global_path()?,
+                    v: Vector::from(scalars(surface.v)?),
+                };
+                Ok(Surface::new(geometry).insert(services))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let half_edges = self
+            .half_edges
+            .iter()
+            .map(|half_edge| {
+                Ok(HalfEdge::new(
+                    half_edge.path.to_surface_path()?,
+                    scalars(half_edge.boundary)?.map(|t| Point::from([t])),
+                    get(&curves, half_edge.curve)?,
+                    get(&vertices, half_edge.start_vertex)?,
+                )
+                .insert(services))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let cycles = self
+            .cycles
+            .iter()
+            .map(|cycle| {
+                Ok(Cycle::new(get_all(&half_edges, cycle)?).insert(services))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let regions = self
+            .regions
+            .iter()
+            .map(|region| {
+                Ok(Region::new(
+                    get(&cycles, region.exterior)?,
+                    get_all(&cycles, &region.interiors)?,
+                    region.color.map(Color),
+                )
+                .insert(services))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let faces = self
+            .faces
+            .iter()
+            .map(|face| {
+                Ok(Face::new(
+                    get(&surfaces, face.surface)?,
+                    get(&regions, face.region)?,
+                )
+                .insert(services))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let shells = self
+            .shells
+            .iter()
+            .map(|shell| {
+                Ok(Shell::new(get_all(&faces, shell)?).insert(services))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Solid::new(get_all(&shells, &self.solid)?))
+    }
+}
+
+/// Error deserializing a [`SerializedSolid`]
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum DeserializeError {
+    /// An object references another object that doesn't exist
+    #[error("Reference to non-existing object at index {index}")]
+    InvalidIndex {
+        /// The index of the object that doesn't exist
+        index: usize,
+    },
+
+    /// A number is NaN or infinite
+    #[error("Number is not finite")]
+    InvalidNumber,
+
+    /// A circle is degenerate or its axes are not perpendicular
+    #[error("Invalid circle")]
+    InvalidCircle,
+
+    /// A line has a zero direction
+    #[error("Invalid line")]
+    InvalidLine,
+
+    /// A spiral is degenerate, or used where spirals are not supported
+    #[error("Invalid spiral")]
+    InvalidSpiral,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+struct SurfaceData {
+    u: PathData<[f64; 3]>,
+    v: [f64; 3],
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+struct HalfEdgeData {
+    path: PathData<[f64; 2]>,
+    boundary: [f64; 2],
+    curve: usize,
+    start_vertex: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct RegionData {
+    exterior: usize,
+    interiors: Vec<usize>,
+    color: Option<[u8; 4]>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+struct FaceData {
+    surface: usize,
+    region: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+enum PathData<V> {
+    Circle {
+        center: V,
+        a: V,
+        b: V,
+    },
+    Line {
+        origin: V,
+        direction: V,
+    },
+    Spiral {
+        center: V,
+        a: V,
+        b: V,
+        radius: f64,
+        growth: f64,
+    },
+}
+
+impl<const D: usize> PathData<[f64; D]> {
+    fn from_circle(circle: &Circle<D>) -> Self {
+        Self::Circle {
+            center: circle.center().coords.into(),
+            a: circle.a().into(),
+            b: circle.b().into(),
+        }
+    }
+
+    fn from_line(line: &Line<D>) -> Self {
+        Self::Line {
+            origin: line.origin().coords.into(),
+            direction: line.direction().into(),
+        }
+    }
+
+    fn from_spiral(spiral: &Spiral<D>) -> Self {
+        Self::Spiral {
+            center: spiral.center().coords.into(),
+            a: spiral.a().into(),
+            b: spiral.b().into(),
+            radius: spiral.radius().into_f64(),
+            growth: spiral.growth().into_f64(),
+        }
+    }
+
+    fn to_circle(self) -> Result<Circle<D>, DeserializeError> {
+        let Self::Circle { center, a, b } = self else {
+            unreachable!("Only called on circles");
+        };
+        let [a, b] = [scalars(a)?, scalars(b)?].map(Vector::from);
+
+        // These are the conditions that `Circle::new` asserts. Check them here,
+        // to return an error instead of panicking.
+        let is_valid = a.magnitude() == b.magnitude()
+            && a.magnitude() != Scalar::ZERO
+            && a.dot(&b) < Scalar::from(f64::EPSILON);
+        if !is_valid {
+            return Err(DeserializeError::InvalidCircle);
+        }
+
+        Ok(Circle::new(scalars(center)?, a, b))
+    }
+
+    fn to_line(self) -> Result<Line<D>, DeserializeError> {
+        let Self::Line { origin, direction } = self else {
+            unreachable!("Only called on lines");
+        };
+        let direction = Vector::from(scalars(direction)?);
+
+        if direction.magnitude() == Scalar::ZERO {
+            return Err(DeserializeError::InvalidLine);
+        }
+
+        Ok(Line::from_origin_and_direction(scalars(origin)?, direction))
+    }
+
+    fn to_spiral(self) -> Result<Spiral<D>, DeserializeError> {
+        let Self::Spiral {
+            center,
+            a,
+            b,
+            radius,
+            growth,
+        } = self
+        else {
+            unreachable!("Only called on spirals");
+        };
+        let [a, b] = [scalars(a)?, scalars(b)?].map(Vector::from);
+        let [radius, growth] = scalars([radius, growth])?;
+
+        Spiral::try_new(scalars(center)?, a, b, radius, growth)
+            .map_err(|_| DeserializeError::InvalidSpiral)
+    }
+}
+
+impl PathData<[f64; 2]> {
+    fn from_surface_path(path: &SurfacePath) -> Self {
+        match path {
+            SurfacePath::Circle(circle) => Self::from_circle(circle),
+            SurfacePath::Line(line) => Self::from_line(line),
+            SurfacePath::Spiral(spiral) => Self::from_spiral(spiral),
+        }
+    }
+
+    fn to_surface_path(self) -> Result<SurfacePath, DeserializeError> {
+        match self {
+            Self::Circle { .. } => self.to_circle().map(SurfacePath::Circle),
+            Self::Line { .. } => self.to_line().map(SurfacePath::Line),
+            Self::Spiral { .. } => self.to_spiral().map(SurfacePath::Spiral),
+        }
+    }
+}
+
+impl PathData<[f64; 3]> {
+    fn from_global_path(path: &GlobalPath) -> Self {
+        match path {
+            GlobalPath::Circle(circle) => Self::from_circle(circle),
+            GlobalPath::Line(line) => Self::from_line(line),
+        }
+    }
+
+    fn to_global_path(self) -> Result<GlobalPath, DeserializeError> {
+        match self {
+            Self::Circle { .. } => self.to_circle().map(GlobalPath::Circle),
+            Self::Line { .. } => self.to_line().map(GlobalPath::Line),
+            Self::Spiral { .. } => Err(DeserializeError::InvalidSpiral),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Serializer {
+    serialized: SerializedSolid,
+    vertices: BTreeMap<HandleWrapper<Vertex>, usize>,
+    curves: BTreeMap<HandleWrapper<Curve>, usize>,
+    surfaces: BTreeMap<HandleWrapper<Surface>, usize>,
+    half_edges: BTreeMap<HandleWrapper<HalfEdge>, usize>,
+    cycles: BTreeMap<HandleWrapper<Cycle>, usize>,
+    regions: BTreeMap<HandleWrapper<Region>, usize>,
+    faces: BTreeMap<HandleWrapper<Face>, usize>,
+    shells: BTreeMap<HandleWrapper<Shell>, usize>,
+}
+
+impl Serializer {
+    fn vertex(&mut self, vertex: &Handle<Vertex>) -> usize {
+        *self
+            .vertices
+            .entry(vertex.clone().into())
+            .or_insert_with(|| next(&mut self.serialized.vertices))
+    }
+
+    fn curve(&mut self, curve: &Handle<Curve>) -> usize {
+        *self
+            .curves
+            .entry(curve.clone().into())
+            .or_insert_with(|| next(&mut self.serialized.curves))
+    }
+
+    fn surface(&mut self, surface: &Handle<Surface>) -> usize {
+        if let Some(&index) = self.surfaces.get(&surface.clone().into()) {
+            return index;
+        }
+
+        let geometry = surface.geometry();
+        let data = SurfaceData {
+            u: PathData::from_global_path(&geometry.u),
+            v: geometry.v.into(),
+        };
+
+        push(
+            &mut self.surfaces,
+            &mut self.serialized.surfaces,
+            surface,
+            data,
+        )
+    }
+
+    fn half_edge(&mut self, half_edge: &Handle<HalfEdge>) -> usize {
+        if let Some(&index) = self.half_edges.get(&half_edge.clone().into()) {
+            return index;
+        }
+
+        let data = HalfEdgeData {
+            path: PathData::from_surface_path(&half_edge.path()),
+            boundary: half_edge.boundary().inner.map(|point| point.t.into()),
+            curve: self.curve(half_edge.curve()),
+            start_vertex: self.vertex(half_edge.start_vertex()),
+        };
+
+        push(
+            &mut self.half_edges,
+            &mut self.serialized.half_edges,
+            half_edge,
+            data,
+        )
+    }
+
+    fn cycle(&mut self, cycle: &Handle<Cycle>) -> usize {
+        if let Some(&index) = self.cycles.get(&cycle.clone().into()) {
+            return index;
+        }
+
+        let data = cycle
+            .half_edges()
+            .iter()
+            .map(|half_edge| self.half_edge(half_edge))
+            .collect();
+
+        push(&mut self.cycles, &mut self.serialized.cycles, cycle, data)
+    }
+
+    fn region(&mut self, region: &Handle<Region>) -> usize {
+        if let Some(&index) = self.regions.get(&region.clone().into()) {
+            return index;
+        }
+
+        let data = RegionData {
+            exterior: self.cycle(region.exterior()),
+            interiors: region
+                .interiors()
+                .iter()
+                .map(|cycle| self.cycle(cycle))
+                .collect(),
+            color: region.color().map(|color| color.0),
+        };
+
+        push(
+            &mut self.regions,
+            &mut self.serialized.regions,
+            region,
+            data,
+        )
+    }
+
+    fn face(&mut self, face: &Handle<Face>) -> usize {
+        if let Some(&index) = self.faces.get(&face.clone().into()) {
+            return index;
+        }
+
+        let data = FaceData {
+            surface: self.surface(face.surface()),
+            region: self.region(face.region()),
+        };
+
+        push(&mut self.faces, &mut self.serialized.faces, face, data)
+    }
+
+    fn shell(&mut self, shell: &Handle<Shell>) -> usize {
+        if let Some(&index) = self.shells.get(&shell.clone().into()) {
+            return index;
+        }
+
+        let data = shell.faces().iter().map(|face| self.face(face)).collect();
+
+        push(&mut self.shells, &mut self.serialized.shells, shell, data)
+    }
+}
+
+fn next(count: &mut usize) -> usize {
+    let index = *count;
+    *count += 1;
+    index
+}
+
+fn push<T, D>(
+    indices: &mut BTreeMap<HandleWrapper<T>, usize>,
+    table: &mut Vec<D>,
+    handle: &Handle<T>,
+    data: D,
+) -> usize {
+    let index = table.len();
+    table.push(data);
+    indices.insert(handle.clone().into(), index);
+    index
+}
+
+fn get<T>(
+    objects: &[Handle<T>],
+    index: usize,
+) -> Result<Handle<T>, DeserializeError> {
+    objects
+        .get(index)
+        .cloned()
+        .ok_or(DeserializeError::InvalidIndex { index })
+}
+
+fn get_all<T>(
+    objects: &[Handle<T>],
+    indices: &[usize],
+) -> Result<Vec<Handle<T>>, DeserializeError> {
+    indices.iter().map(|&index| get(objects, index)).collect()
+}
+
+fn scalars<const D: usize>(
+    values: [f64; D],
+) -> Result<[Scalar; D], DeserializeError> {
+    if values.iter().any(|value| !value.is_finite()) {
+        return Err(DeserializeError::InvalidNumber);
+    }
+
+    Ok(values.map(Scalar::from))
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Vector};
+
+    use crate::{
+        objects::{Cycle, Region, Sketch, Solid},
+        operations::{
+            build::{BuildCycle, BuildRegion, BuildSketch},
+            insert::Insert,
+            reverse::Reverse,
+            sweep::SweepSketch,
+            update::{UpdateRegion, UpdateSketch},
+        },
+        services::Services,
+    };
+
+    use super::{DeserializeError, SerializedSolid};
+
+    #[test]
+    fn spacer_round_trip() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let surface = services.objects.surfaces.xy_plane();
+        let solid = Sketch::empty()
+            .add_region(
+                Region::circle(Point::origin(), 1., &mut services)
+                    .add_interiors([Cycle::circle(
+                        Point::origin(),
+                        0.5,
+                        &mut services,
+                    )
+                    .reverse(&mut services)
+                    .insert(&mut services)])
+                    .insert(&mut services),
+            )
+            .sweep_sketch(surface, Vector::from([0., 0., 1.]), &mut services)?;
+
+        let serialized = SerializedSolid::from_solid(&solid);
+        let json = serde_json::to_string(&serialized)?;
+
+        let deserialized = serde_json::from_str::<SerializedSolid>(&json)?;
+        let restored = deserialized.to_solid(&mut services)?;
+
+        // Curves and vertices only have identity, so the restored solid can't
+        // be equal to the original one. But if its structure, including which
+        // objects are shared, is the same, it serializes to the same result.
+        assert_eq!(SerializedSolid::from_solid(&restored), serialized);
+
+        // Siblings share curves and vertices. If that sharing is preserved,
+        // there are far fewer of them than half-edges.
+        assert!(serialized.curves < serialized.half_edges.len());
+        assert!(serialized.vertices < serialized.half_edges.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_index() {
+        let mut services = Services::new();
+
+        let serialized = SerializedSolid {
+            solid: vec![0],
+            ..SerializedSolid::default()
+        };
+        assert_eq!(
+            serialized.to_solid(&mut services).err(),
+            Some(DeserializeError::InvalidIndex { index: 0 }),
+        );
+
+        let serialized = SerializedSolid::from_solid(&Solid::new([]));
+        assert!(serialized.to_solid(&mut services).is_ok());
+    }
+}
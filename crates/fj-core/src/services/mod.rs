@@ -6,7 +6,10 @@ mod objects;
 mod service;
 mod validation;
 
+use fj_math::{Point, Scalar};
+
 use crate::{
+    geometry::SnapGrid,
     objects::{Object, Objects, WithHandle},
     validate::ValidationErrors,
 };
@@ -28,6 +31,11 @@ pub struct Services {
     ///
     /// Validates objects that are inserted using the objects service.
     pub validation: Service<Validation>,
+
+    /// The grid that points are snapped to, when building geometry
+    ///
+    /// Snapping is disabled, if this is `None`. See [`Services::with_snap`].
+    pub snap: Option<SnapGrid>,
 }
 
 impl Services {
@@ -39,6 +47,30 @@ impl Services {
         Self {
             objects,
             validation,
+            snap: None,
+        }
+    }
+
+    /// Snap points to a grid with the provided resolution
+    ///
+    /// The builders in [`operations::build`] snap the points they are given to
+    /// the grid, before they build geometry from them. This reduces
+    /// floating-point drift between points that are meant to be the same,
+    /// which would otherwise fail validation checks for coincidence.
+    ///
+    /// [`operations::build`]: crate::operations::build
+    pub fn with_snap(mut self, resolution: impl Into<Scalar>) -> Self {
+        self.snap = Some(SnapGrid::new(resolution));
+        self
+    }
+
+    /// Snap the point to the grid, if snapping is enabled
+    pub fn snap<const D: usize>(&self, point: impl Into<Point<D>>) -> Point<D> {
+        let point = point.into();
+
+        match &self.snap {
+            Some(grid) => grid.snap(point),
+            None => point,
         }
     }
 
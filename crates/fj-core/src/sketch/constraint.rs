@@ -0,0 +1,109 @@
+use fj_math::{Point, Scalar};
+
+use super::{
+    parametric::{CircleId, PointId},
+    solver::Equation,
+};
+
+/// A constraint on the geometry of a [`ParametricSketch`]
+///
+/// [`ParametricSketch`]: super::ParametricSketch
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Constraint {
+    /// The two points are at the same position
+    Coincident(PointId, PointId),
+
+    /// The point is at the given position
+    Fixed(PointId, Point<2>),
+
+    /// The two points are on a horizontal line
+    ///
+    /// Applied to the two points of a line segment, this makes the line
+    /// segment horizontal.
+    Horizontal(PointId, PointId),
+
+    /// The two points are on a vertical line
+    ///
+    /// Applied to the two points of a line segment, this makes the line
+    /// segment vertical.
+    Vertical(PointId, PointId),
+
+    /// The two points are at the given distance from each other
+    Distance(PointId, PointId, Scalar),
+
+    /// The circle has the given radius
+    Radius(CircleId, Scalar),
+}
+
+impl Constraint {
+    /// Compute the equations that represent this constraint
+    ///
+    /// The constraint is satisfied, if the residuals of all equations are zero.
+    pub(super) fn equations(
+        &self,
+        variables: &[f64],
+        equations: &mut Vec<Equation>,
+    ) {
+        match *self {
+            Self::Coincident(a, b) => {
+                for i in 0..2 {
+                    equations.push(difference(variables, a.var(i), b.var(i)));
+                }
+            }
+            Self::Fixed(point, position) => {
+                for i in 0..2 {
+                    let var = point.var(i);
+                    equations.push(Equation {
+                        residual: variables[var]
+                            - position.coords.components[i].into_f64(),
+                        gradient: vec![(var, 1.)],
+                    });
+                }
+            }
+            Self::Horizontal(a, b) => {
+                equations.push(difference(variables, a.var(1), b.var(1)));
+            }
+            Self::Vertical(a, b) => {
+                equations.push(difference(variables, a.var(0), b.var(0)));
+            }
+            Self::Distance(a, b, distance) => {
+                let [du, dv] =
+                    [0, 1].map(|i| variables[a.var(i)] - variables[b.var(i)]);
+                let actual = du.hypot(dv);
+
+                // The gradient is not defined, if the points coincide. The
+                // solver will still make progress, as long as other equations
+                // move the points apart.
+                let [gu, gv] = if actual == 0. {
+                    [0., 0.]
+                } else {
+                    [du / actual, dv / actual]
+                };
+
+                equations.push(Equation {
+                    residual: actual - distance.into_f64(),
+                    gradient: vec![
+                        (a.var(0), gu),
+                        (a.var(1), gv),
+                        (b.var(0), -gu),
+                        (b.var(1), -gv),
+                    ],
+                });
+            }
+            Self::Radius(circle, radius) => {
+                let var = circle.radius_var();
+                equations.push(Equation {
+                    residual: variables[var] - radius.into_f64(),
+                    gradient: vec![(var, 1.)],
+                });
+            }
+        }
+    }
+}
+
+fn difference(variables: &[f64], a: usize, b: usize) -> Equation {
+    Equation {
+        residual: variables[a] - variables[b],
+        gradient: vec![(a, 1.), (b, -1.)],
+    }
+}
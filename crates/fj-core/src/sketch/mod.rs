@@ -0,0 +1,25 @@
+//! Parametric sketches
+//!
+//! A [`ParametricSketch`] describes the geometry of a sketch in terms of
+//! points, profiles that are built from those points, and [`Constraint`]s that
+//! the points must satisfy. Solving the constraints moves the points into
+//! positions that are consistent with them. Afterwards, a [`Sketch`] can be
+//! built from the solved geometry.
+//!
+//! The solver is minimal. It finds a solution close to the initial positions of
+//! the points, using the Gauss-Newton algorithm, and reports how many degrees
+//! of freedom the constraints leave undetermined. It does not detect
+//! conflicting constraints, beyond reporting the remaining residual of an
+//! over-determined system.
+//!
+//! [`Sketch`]: crate::objects::Sketch
+
+mod constraint;
+mod parametric;
+mod solver;
+
+pub use self::{
+    constraint::Constraint,
+    parametric::{CircleId, ParametricSketch, PointId},
+    solver::{Solution, SolveError},
+};
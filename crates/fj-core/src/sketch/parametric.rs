@@ -0,0 +1,274 @@
+use fj_math::{Point, Scalar};
+
+use crate::{
+    objects::{Region, Sketch},
+    operations::{
+        build::{BuildRegion, BuildSketch},
+        insert::Insert,
+        update::UpdateSketch,
+    },
+    services::Services,
+};
+
+use super::{
+    constraint::Constraint,
+    solver::{self, Solution, SolveError},
+};
+
+/// A sketch whose geometry is defined by constraints
+///
+/// See [module-level documentation] for context.
+///
+/// [module-level documentation]: super
+#[derive(Clone, Debug, Default)]
+pub struct ParametricSketch {
+    variables: Vec<f64>,
+    profiles: Vec<Profile>,
+    constraints: Vec<Constraint>,
+}
+
+impl ParametricSketch {
+    /// Construct an empty instance of `ParametricSketch`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a point at the given initial position
+    ///
+    /// The initial position is where the solver starts. If the point is not
+    /// fully determined by constraints, it will stay close to that position.
+    pub fn add_point(&mut self, position: impl Into<Point<2>>) -> PointId {
+        let position = position.into();
+
+        let id = PointId(self.variables.len());
+        self.variables
+            .extend(position.coords.components.map(Scalar::into_f64));
+
+        id
+    }
+
+    /// Add a polygon that connects the given points
+    ///
+    /// The polygon becomes a region, when building a [`Sketch`].
+    pub fn add_polygon(&mut self, points: impl IntoIterator<Item = PointId>) {
+        self.profiles
+            .push(Profile::Polygon(points.into_iter().collect()));
+    }
+
+    /// Add a circle around the given center point
+    ///
+    /// The circle becomes a region, when building a [`Sketch`]. The radius is
+    /// an initial value, like the position of a point. Use
+    /// [`Constraint::Radius`] to fix it.
+    pub fn add_circle(
+        &mut self,
+        center: PointId,
+        radius: impl Into<Scalar>,
+    ) -> CircleId {
+        let id = CircleId {
+            center,
+            radius: self.variables.len(),
+        };
+        self.variables.push(radius.into().into_f64());
+        self.profiles.push(Profile::Circle(id));
+
+        id
+    }
+
+    /// Add a constraint
+    pub fn add_constraint(&mut self, constraint: Constraint) {
+        self.constraints.push(constraint);
+    }
+
+    /// Access the current position of a point
+    pub fn point(&self, id: PointId) -> Point<2> {
+        Point::from([id.var(0), id.var(1)].map(|var| self.variables[var]))
+    }
+
+    /// Access the current radius of a circle
+    pub fn radius(&self, id: CircleId) -> Scalar {
+        Scalar::from(self.variables[id.radius_var()])
+    }
+
+    /// Solve the constraints
+    ///
+    /// Updates the positions of the points and the radii of the circles, so
+    /// they satisfy the constraints. If that is not possible, because the
+    /// constraints are in conflict, they are satisfied as well as possible, in
+    /// the least-squares sense. Check [`Solution::residual`] to detect this.
+    pub fn solve(&mut self) -> Result<Solution, SolveError> {
+        let constraints = &self.constraints;
+
+        solver::solve(&mut self.variables, |variables| {
+            let mut equations = Vec::new();
+            for constraint in constraints {
+                constraint.equations(variables, &mut equations);
+            }
+            equations
+        })
+    }
+
+    /// Build a [`Sketch`] from the current geometry
+    ///
+    /// Each profile becomes a region of the sketch. Call
+    /// [`ParametricSketch::solve`] before, to build a sketch from the solved
+    /// geometry.
+    pub fn build_sketch(&self, services: &mut Services) -> Sketch {
+        let mut sketch = Sketch::empty();
+
+        for profile in &self.profiles {
+            let region = match profile {
+                Profile::Polygon(points) => Region::polygon(
+                    points.iter().map(|&point| self.point(point)),
+                    services,
+                ),
+                Profile::Circle(circle) => Region::circle(
+                    self.point(circle.center),
+                    self.radius(*circle),
+                    services,
+                ),
+            };
+
+            sketch = sketch.add_region(region.insert(services));
+        }
+
+        sketch
+    }
+}
+
+/// Identifies a point in a [`ParametricSketch`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct PointId(usize);
+
+impl PointId {
+    /// Access the index of the variable that holds the given coordinate
+    pub(super) fn var(&self, coord: usize) -> usize {
+        self.0 + coord
+    }
+}
+
+/// Identifies a circle in a [`ParametricSketch`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct CircleId {
+    center: PointId,
+    radius: usize,
+}
+
+impl CircleId {
+    /// Access the index of the variable that holds the radius
+    pub(super) fn radius_var(&self) -> usize {
+        self.radius
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Profile {
+    Polygon(Vec<PointId>),
+    Circle(CircleId),
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        services::Services,
+        sketch::{Constraint, ParametricSketch, PointId},
+    };
+
+    #[test]
+    fn rectangle_fully_constrained() -> anyhow::Result<()> {
+        let (mut sketch, [a, b, c, d]) = rectangle();
+        sketch.add_constraint(Constraint::Fixed(a, Point::origin()));
+
+        let solution = sketch.solve()?;
+        assert_eq!(solution.degrees_of_freedom, 0);
+        assert!(solution.residual < Scalar::from(1e-9));
+
+        for (id, expected) in
+            [(a, [0., 0.]), (b, [2., 0.]), (c, [2., 1.]), (d, [0., 1.])]
+        {
+            let distance = sketch.point(id).distance_to(&expected.into());
+            assert!(distance < Scalar::from(1e-9));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn rectangle_under_constrained() -> anyhow::Result<()> {
+        let (mut sketch, _) = rectangle();
+
+        // Nothing determines the position of the rectangle.
+        let solution = sketch.solve()?;
+        assert_eq!(solution.degrees_of_freedom, 2);
+        assert!(solution.residual < Scalar::from(1e-9));
+
+        Ok(())
+    }
+
+    #[test]
+    fn rectangle_over_determined() -> anyhow::Result<()> {
+        let (mut sketch, [a, _, c, _]) = rectangle();
+        sketch.add_constraint(Constraint::Fixed(a, Point::origin()));
+
+        // This is implied by the other constraints, and thus consistent with
+        // them.
+        sketch.add_constraint(Constraint::Distance(
+            a,
+            c,
+            Scalar::from(5_f64.sqrt()),
+        ));
+
+        let solution = sketch.solve()?;
+        assert_eq!(solution.degrees_of_freedom, 0);
+        assert!(solution.residual < Scalar::from(1e-9));
+
+        Ok(())
+    }
+
+    #[test]
+    fn circle() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let mut sketch = ParametricSketch::new();
+        let center = sketch.add_point([0.1, -0.1]);
+        let circle = sketch.add_circle(center, 0.9);
+        sketch.add_constraint(Constraint::Fixed(center, Point::from([1., 1.])));
+        sketch.add_constraint(Constraint::Radius(circle, Scalar::from(0.5)));
+
+        let solution = sketch.solve()?;
+        assert_eq!(solution.degrees_of_freedom, 0);
+        assert!(
+            (sketch.radius(circle) - Scalar::from(0.5)).abs()
+                < Scalar::from(1e-9)
+        );
+
+        let sketch = sketch.build_sketch(&mut services);
+        assert_eq!(sketch.regions().len(), 1);
+
+        Ok(())
+    }
+
+    /// A rectangle that is constrained in size, but not position
+    ///
+    /// The initial positions are slightly off.
+    fn rectangle() -> (ParametricSketch, [PointId; 4]) {
+        let mut sketch = ParametricSketch::new();
+
+        let a = sketch.add_point([0.1, -0.1]);
+        let b = sketch.add_point([1.8, 0.2]);
+        let c = sketch.add_point([2.1, 1.1]);
+        let d = sketch.add_point([-0.2, 0.9]);
+        sketch.add_polygon([a, b, c, d]);
+
+        sketch.add_constraint(Constraint::Horizontal(a, b));
+        sketch.add_constraint(Constraint::Vertical(b, c));
+        sketch.add_constraint(Constraint::Horizontal(c, d));
+        sketch.add_constraint(Constraint::Vertical(d, a));
+        sketch.add_constraint(Constraint::Distance(a, b, Scalar::from(2.)));
+        sketch.add_constraint(Constraint::Distance(b, c, Scalar::from(1.)));
+
+        (sketch, [a, b, c, d])
+    }
+}
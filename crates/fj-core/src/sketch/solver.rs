@@ -0,0 +1,226 @@
+use fj_math::Scalar;
+
+/// The maximum number of Gauss-Newton iterations
+const MAX_ITERATIONS: usize = 100;
+
+/// The maximum number of times a step is halved, if it doesn't improve things
+const MAX_STEP_HALVINGS: usize = 32;
+
+/// The residual below which the system is considered to be solved
+const RESIDUAL_TOLERANCE: f64 = 1e-10;
+
+/// The step size below which the solver considers itself to be stuck
+const STEP_TOLERANCE: f64 = 1e-12;
+
+/// Damping that keeps the linear system regular, if it is under-determined
+///
+/// This is small enough to not noticeably affect convergence, but makes sure
+/// that variables that are not determined by any equation are left alone.
+const DAMPING: f64 = 1e-6;
+
+/// Threshold for considering a pivot zero, when computing the rank
+const RANK_TOLERANCE: f64 = 1e-9;
+
+/// An equation that the solver drives towards zero
+pub struct Equation {
+    /// The value of the equation, for the current values of the variables
+    pub residual: f64,
+
+    /// The partial derivatives of the equation, by variable index
+    pub gradient: Vec<(usize, f64)>,
+}
+
+/// The result of successfully solving the constraints of a sketch
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Solution {
+    /// The number of degrees of freedom that are not determined by constraints
+    ///
+    /// This is zero for a fully constrained sketch. Please note that this
+    /// includes the freedom to move or rotate the whole sketch, unless the
+    /// constraints determine its position.
+    pub degrees_of_freedom: usize,
+
+    /// The remaining residual of the constraint equations
+    ///
+    /// This is (close to) zero, if all constraints could be satisfied. It is
+    /// larger, if the constraints are in conflict with each other, in which
+    /// case the solution satisfies them in the least-squares sense.
+    pub residual: Scalar,
+}
+
+/// Error solving the constraints of a sketch
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum SolveError {
+    /// The solver did not converge within the maximum number of iterations
+    #[error("Constraint solver did not converge")]
+    DidNotConverge,
+}
+
+/// Solve the equations, starting from the current values of the variables
+///
+/// Updates the variables in place.
+pub fn solve(
+    variables: &mut [f64],
+    equations: impl Fn(&[f64]) -> Vec<Equation>,
+) -> Result<Solution, SolveError> {
+    let mut current = equations(variables);
+
+    for _ in 0..MAX_ITERATIONS {
+        let error = sum_of_squares(&current);
+        if error.sqrt() <= RESIDUAL_TOLERANCE {
+            return Ok(solution(variables.len(), &current));
+        }
+
+        let step = gauss_newton_step(variables.len(), &current);
+
+        // Gauss-Newton can overshoot, if the equations are far from linear.
+        // Reduce the step, until it actually improves things.
+        let mut scale = 1.;
+        let mut improved = None;
+        for _ in 0..MAX_STEP_HALVINGS {
+            let candidate = variables
+                .iter()
+                .zip(&step)
+                .map(|(variable, step)| variable + step * scale)
+                .collect::<Vec<_>>();
+            let equations_at_candidate = equations(&candidate);
+
+            if sum_of_squares(&equations_at_candidate) < error {
+                improved = Some((candidate, equations_at_candidate));
+                break;
+            }
+
+            scale /= 2.;
+        }
+
+        let Some((candidate, equations_at_candidate)) = improved else {
+            // We can't improve on the current state, meaning we've reached a
+            // least-squares solution of an over-determined system.
+            return Ok(solution(variables.len(), &current));
+        };
+
+        variables.copy_from_slice(&candidate);
+        current = equations_at_candidate;
+
+        let step_size =
+            step.iter().map(|step| step * step).sum::<f64>().sqrt() * scale;
+        if step_size <= STEP_TOLERANCE {
+            return Ok(solution(variables.len(), &current));
+        }
+    }
+
+    Err(SolveError::DidNotConverge)
+}
+
+fn sum_of_squares(equations: &[Equation]) -> f64 {
+    equations
+        .iter()
+        .map(|equation| equation.residual * equation.residual)
+        .sum()
+}
+
+fn solution(num_variables: usize, equations: &[Equation]) -> Solution {
+    let jacobian = equations
+        .iter()
+        .map(|equation| {
+            let mut row = vec![0.; num_variables];
+            for &(i, derivative) in &equation.gradient {
+                row[i] += derivative;
+            }
+            row
+        })
+        .collect();
+
+    Solution {
+        degrees_of_freedom: num_variables - rank(jacobian),
+        residual: Scalar::from(sum_of_squares(equations).sqrt()),
+    }
+}
+
+/// Compute the Gauss-Newton step, by solving the normal equations
+fn gauss_newton_step(num_variables: usize, equations: &[Equation]) -> Vec<f64> {
+    let mut a = vec![vec![0.; num_variables]; num_variables];
+    let mut b = vec![0.; num_variables];
+
+    for (i, row) in a.iter_mut().enumerate() {
+        row[i] = DAMPING;
+    }
+
+    for equation in equations {
+        for &(i, derivative_i) in &equation.gradient {
+            for &(j, derivative_j) in &equation.gradient {
+                a[i][j] += derivative_i * derivative_j;
+            }
+            b[i] -= derivative_i * equation.residual;
+        }
+    }
+
+    solve_linear_system(a, b)
+}
+
+/// Solve a regular linear system, using Gaussian elimination
+fn solve_linear_system(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Vec<f64> {
+    let n = b.len();
+
+    for column in 0..n {
+        let pivot = (column..n)
+            .max_by(|&i, &j| a[i][column].abs().total_cmp(&a[j][column].abs()))
+            .expect("Range is not empty");
+        a.swap(column, pivot);
+        b.swap(column, pivot);
+
+        let (a_upper, a_lower) = a.split_at_mut(column + 1);
+        let (b_upper, b_lower) = b.split_at_mut(column + 1);
+        let pivot_row = &a_upper[column];
+
+        for (row, b_row) in a_lower.iter_mut().zip(b_lower) {
+            let factor = row[column] / pivot_row[column];
+            subtract_scaled(&mut row[column..], &pivot_row[column..], factor);
+            *b_row -= factor * b_upper[column];
+        }
+    }
+
+    let mut x = vec![0.; n];
+    for row in (0..n).rev() {
+        let sum = (row + 1..n).map(|k| a[row][k] * x[k]).sum::<f64>();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+
+    x
+}
+
+/// Compute the rank of a matrix, using Gaussian elimination
+fn rank(mut matrix: Vec<Vec<f64>>) -> usize {
+    let num_columns = matrix.first().map(Vec::len).unwrap_or(0);
+    let mut rank = 0;
+
+    for column in 0..num_columns {
+        let Some(pivot) = (rank..matrix.len()).max_by(|&i, &j| {
+            matrix[i][column].abs().total_cmp(&matrix[j][column].abs())
+        }) else {
+            break;
+        };
+        if matrix[pivot][column].abs() <= RANK_TOLERANCE {
+            continue;
+        }
+        matrix.swap(rank, pivot);
+
+        let (upper, lower) = matrix.split_at_mut(rank + 1);
+        let pivot_row = &upper[rank];
+
+        for row in lower {
+            let factor = row[column] / pivot_row[column];
+            subtract_scaled(&mut row[column..], &pivot_row[column..], factor);
+        }
+
+        rank += 1;
+    }
+
+    rank
+}
+
+fn subtract_scaled(row: &mut [f64], other: &[f64], factor: f64) {
+    for (value, other) in row.iter_mut().zip(other) {
+        *value -= factor * other;
+    }
+}
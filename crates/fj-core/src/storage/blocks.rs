@@ -24,10 +24,13 @@ impl<T> Blocks<T> {
             match current_block.reserve() {
                 Ok((object_index, ptr)) => {
                     let block_index = BlockIndex(self.inner.len());
+                    let position =
+                        block_index.0 * self.block_size + object_index.0;
                     break (
                         Index {
                             block_index,
                             object_index,
+                            position: position as u64,
                         },
                         ptr,
                     );
@@ -50,6 +53,14 @@ impl<T> Blocks<T> {
         block.insert(index.object_index, object);
     }
 
+    pub fn num_reserved(&self) -> usize {
+        self.inner.iter().map(|block| block.len()).sum()
+    }
+
+    pub fn num_slots(&self) -> usize {
+        self.inner.len() * self.block_size
+    }
+
     pub fn get_and_inc(&self, index: &mut Index) -> Option<&Option<T>> {
         let block = self.inner.get(index.block_index.0)?;
         let object = block.get(index.object_index);
@@ -112,6 +123,13 @@ impl<T> Block<T> {
 pub struct Index {
     block_index: BlockIndex,
     object_index: ObjectIndex,
+
+    /// The position of the slot, counting from the first slot of the first
+    /// block
+    ///
+    /// Slots are reserved in order, so this is the number of slots that were
+    /// reserved before this one.
+    position: u64,
 }
 
 impl Index {
@@ -119,10 +137,16 @@ impl Index {
         Self {
             block_index: BlockIndex(0),
             object_index: ObjectIndex(0),
+            position: 0,
         }
     }
 
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
     pub fn inc<T>(&mut self, block: &Block<T>) {
+        self.position += 1;
         self.object_index.0 += 1;
         if self.object_index.0 >= block.len() {
             self.block_index.0 += 1;
@@ -1,8 +1,17 @@
 use std::{
-    any::type_name, borrow::Borrow, cmp::Ordering, fmt, hash::Hash, ops::Deref,
+    any::type_name,
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt,
+    hash::Hash,
+    ops::Deref,
+    sync::{Arc, Weak},
 };
 
-use super::{blocks::Index, store::StoreInner};
+use super::{
+    blocks::Index,
+    store::{StoreInner, WeakStoreInner},
+};
 
 /// A handle for an object
 ///
@@ -21,8 +30,11 @@ use super::{blocks::Index, store::StoreInner};
 /// a relevant distinction when validating objects, as equal but not identical
 /// objects might be a sign of a bug.
 ///
-/// You can compare the identity of two objects through their `Handle`s, by
-/// comparing the values returned by [`Handle::id`].
+/// You can compare the identity of two objects through their `Handle`s, using
+/// [`Handle::same_object`], or by comparing the values returned by
+/// [`Handle::id`]. Unlike the equality comparison, this doesn't need to look at
+/// the objects themselves, which makes it cheap, regardless of how large those
+/// objects are.
 pub struct Handle<T> {
     pub(super) store: StoreInner<T>,
     pub(super) index: Index,
@@ -31,8 +43,27 @@ pub struct Handle<T> {
 
 impl<T> Handle<T> {
     /// Access this pointer's unique id
+    ///
+    /// See [`ObjectId`] for the guarantees that the id provides.
     pub fn id(&self) -> ObjectId {
-        ObjectId::from_ptr(self.ptr)
+        ObjectId::new::<T>(self.index, Arc::as_ptr(&self.store).cast())
+    }
+
+    /// Indicate whether two handles refer to the same object
+    ///
+    /// This compares the identity of the objects, not their equality. See
+    /// [`Handle`]'s documentation for the distinction.
+    pub fn same_object(&self, other: &Self) -> bool {
+        self.ptr == other.ptr
+    }
+
+    /// Create a [`WeakHandle`] that refers to the same object
+    pub fn downgrade(&self) -> WeakHandle<T> {
+        WeakHandle {
+            store: Arc::downgrade(&self.store),
+            index: self.index,
+            ptr: self.ptr,
+        }
     }
 
     /// Return a clone of the object this handle refers to
@@ -150,7 +181,7 @@ where
                 None => type_name,
             }
         };
-        let id = self.id().0;
+        let id = self.id().index;
         let object = self.deref();
 
         if f.alternate() {
@@ -172,22 +203,132 @@ impl<T> From<HandleWrapper<T>> for Handle<T> {
 unsafe impl<T> Send for Handle<T> {}
 unsafe impl<T> Sync for Handle<T> {}
 
+/// A weak reference to an object
+///
+/// A `WeakHandle` can be used for back-references (like from a curve to the
+/// faces that use it), which would otherwise keep objects alive that are no
+/// longer needed.
+///
+/// # Validity
+///
+/// Objects are stored in an append-only arena, their [`Store`]. They are never
+/// removed from it individually, so an object stays alive for as long as its
+/// store does. A store, in turn, stays alive for as long as the [`Services`]
+/// that own it, or any [`Handle`] into it, exist.
+///
+/// A `WeakHandle` does not keep the store alive. It can be upgraded to a
+/// [`Handle`] using [`WeakHandle::upgrade`], as long as the store is still
+/// alive. Once the store has been dropped, upgrading fails.
+///
+/// [`Store`]: super::Store
+/// [`Services`]: crate::services::Services
+pub struct WeakHandle<T> {
+    store: WeakStoreInner<T>,
+    index: Index,
+    ptr: *const Option<T>,
+}
+
+impl<T> WeakHandle<T> {
+    /// Access the unique id of the referenced object
+    ///
+    /// This is the same id that [`Handle::id`] returns for the same object.
+    pub fn id(&self) -> ObjectId {
+        ObjectId::new::<T>(self.index, Weak::as_ptr(&self.store).cast())
+    }
+
+    /// Attempt to upgrade to a [`Handle`]
+    ///
+    /// Returns `None`, if the store that holds the object has been dropped.
+    pub fn upgrade(&self) -> Option<Handle<T>> {
+        let store = self.store.upgrade()?;
+
+        Some(Handle {
+            store,
+            index: self.index,
+            ptr: self.ptr,
+        })
+    }
+}
+
+impl<T> Clone for WeakHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            store: self.store.clone(),
+            index: self.index,
+            ptr: self.ptr,
+        }
+    }
+}
+
+impl<T> fmt::Debug for WeakHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = {
+            let type_name = type_name::<T>();
+            match type_name.rsplit_once("::") {
+                Some((_, name)) => name,
+                None => type_name,
+            }
+        };
+        let id = self.id().index;
+
+        write!(f, "weak {name} @ {id:#x}")
+    }
+}
+
+unsafe impl<T> Send for WeakHandle<T> {}
+unsafe impl<T> Sync for WeakHandle<T> {}
+
 /// Represents the ID of an object
 ///
 /// See [`Handle::id`].
+///
+/// # Determinism
+///
+/// Object ids are allocated deterministically. An id consists of the type of
+/// the object, and the position of the object in its [`Store`], which is the
+/// number of objects that were added to the store before it. Performing the
+/// same sequence of operations with a fresh set of stores results in the same
+/// ids, regardless of where in memory the objects end up, or which thread
+/// performs the operations.
+///
+/// This also determines how ids are ordered, which makes it safe to iterate
+/// over collections that are keyed by object id, like a `BTreeMap`, in tests
+/// that compare their output against a snapshot.
+///
+/// Besides that, an id identifies the store that the object lives in, so ids
+/// of objects from different sets of stores, like the ones owned by different
+/// instances of [`Services`], never compare equal. This part of the id is not
+/// deterministic, which is why it only decides the order of ids that are
+/// otherwise the same, and is not part of their debug output.
+///
+/// [`Store`]: super::Store
+/// [`Services`]: crate::services::Services
 #[derive(Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
-pub struct ObjectId(pub(crate) u64);
+pub struct ObjectId {
+    // Objects of the same type are most often compared with each other, so
+    // comparing the position first avoids most of the string comparisons.
+    index: u64,
+    kind: &'static str,
+
+    // The address of the store's shared state. It can't be reused by another
+    // store, as long as a handle that could produce this id is around.
+    store: usize,
+}
 
 impl ObjectId {
-    pub(crate) fn from_ptr<T>(ptr: *const T) -> ObjectId {
-        Self(ptr as u64)
+    pub(super) fn new<T>(index: Index, store: *const ()) -> Self {
+        Self {
+            index: index.position(),
+            kind: type_name::<T>(),
+            store: store as usize,
+        }
     }
 }
 
 impl fmt::Debug for ObjectId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let id = self.0;
-        write!(f, "object id {id:#x}")
+        let Self { index, kind, .. } = self;
+        write!(f, "object id {index:#x} ({kind})")
     }
 }
 
@@ -266,3 +407,69 @@ impl<T> From<Handle<T>> for HandleWrapper<T> {
 
 unsafe impl<T> Send for HandleWrapper<T> {}
 unsafe impl<T> Sync for HandleWrapper<T> {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::storage::{Handle, HandleWrapper, Store};
+
+    #[test]
+    fn same_object() {
+        let mut store = Store::with_block_size(1);
+
+        let a: Handle<i32> = store.reserve();
+        let b = store.reserve();
+        store.insert(a.clone(), 0);
+        store.insert(b.clone(), 0);
+
+        // The objects are equal, but not identical.
+        assert_eq!(a, b);
+        assert!(!a.same_object(&b));
+
+        assert!(a.same_object(&a.clone()));
+    }
+
+    #[test]
+    fn ids_from_different_stores() {
+        let [(a, _store_a), (b, _store_b)] = [(), ()].map(|()| {
+            let mut store = Store::with_block_size(1);
+
+            let handle: Handle<i32> = store.reserve();
+            store.insert(handle.clone(), 0);
+
+            (handle, store)
+        });
+
+        // Both objects are at the same position in their respective stores,
+        // but they are still distinct.
+        assert_eq!(format!("{:?}", a.id()), format!("{:?}", b.id()));
+        assert_ne!(a.id(), b.id());
+
+        let wrappers =
+            HashSet::from([HandleWrapper::from(a), HandleWrapper::from(b)]);
+        assert_eq!(wrappers.len(), 2);
+    }
+
+    #[test]
+    fn weak_handle() {
+        let mut store = Store::with_block_size(1);
+
+        let handle: Handle<i32> = store.reserve();
+        store.insert(handle.clone(), 0);
+
+        let weak = handle.downgrade();
+        assert_eq!(weak.id(), handle.id());
+
+        let upgraded = weak.upgrade().expect("Store is still alive");
+        assert!(upgraded.same_object(&handle));
+        assert_eq!(*upgraded, 0);
+
+        // Once the store and all handles into it are gone, the weak handle can
+        // no longer be upgraded.
+        drop(upgraded);
+        drop(handle);
+        drop(store);
+        assert!(weak.upgrade().is_none());
+    }
+}
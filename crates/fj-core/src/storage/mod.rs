@@ -5,6 +5,6 @@ mod handle;
 mod store;
 
 pub use self::{
-    handle::{Handle, HandleWrapper, ObjectId},
-    store::{Iter, Store},
+    handle::{Handle, HandleWrapper, ObjectId, WeakHandle},
+    store::{Iter, Store, StoreUsage},
 };
@@ -21,7 +21,12 @@
 //!
 //! But in any case, this was fun to write, and not that much work.
 
-use std::{marker::PhantomData, sync::Arc};
+use std::{
+    marker::PhantomData,
+    mem::size_of,
+    ops,
+    sync::{Arc, Weak},
+};
 
 use parking_lot::RwLock;
 
@@ -88,6 +93,19 @@ impl<T> Store<T> {
         inner.blocks.insert(handle.index, object);
     }
 
+    /// Report the memory usage of the store
+    pub fn usage(&self) -> StoreUsage {
+        let inner = self.inner.read();
+
+        let num_objects = inner.blocks.num_reserved();
+
+        StoreUsage {
+            num_objects,
+            size_of_objects: num_objects * size_of::<T>(),
+            size_allocated: inner.blocks.num_slots() * size_of::<Option<T>>(),
+        }
+    }
+
     /// Iterate over all objects in this store
     pub fn iter(&self) -> Iter<T> {
         Iter {
@@ -144,7 +162,42 @@ impl<'a, T: 'a> Iterator for Iter<'a, T> {
     }
 }
 
+/// The memory usage of a [`Store`]
+///
+/// Returned by [`Store::usage`]. Sizes are in bytes, and only include the
+/// objects themselves. Memory that objects allocate separately, like the list
+/// of half-edges of a cycle, is not included.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct StoreUsage {
+    /// The number of objects in the store
+    ///
+    /// This includes slots that have been reserved, but not filled yet.
+    pub num_objects: usize,
+
+    /// The combined size of all objects in the store
+    pub size_of_objects: usize,
+
+    /// The size of the memory that the store has allocated
+    ///
+    /// The store allocates memory in blocks, each of which has room for many
+    /// objects. This is why this is usually larger than the size of objects.
+    pub size_allocated: usize,
+}
+
+impl ops::Add for StoreUsage {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            num_objects: self.num_objects + other.num_objects,
+            size_of_objects: self.size_of_objects + other.size_of_objects,
+            size_allocated: self.size_allocated + other.size_allocated,
+        }
+    }
+}
+
 pub type StoreInner<T> = Arc<RwLock<StoreInnerInner<T>>>;
+pub type WeakStoreInner<T> = Weak<RwLock<StoreInnerInner<T>>>;
 
 #[derive(Debug)]
 pub struct StoreInnerInner<T> {
@@ -153,9 +206,11 @@ pub struct StoreInnerInner<T> {
 
 #[cfg(test)]
 mod tests {
+    use std::mem::size_of;
+
     use crate::storage::Handle;
 
-    use super::Store;
+    use super::{Store, StoreUsage};
 
     #[test]
     fn insert_and_handle() {
@@ -181,4 +236,20 @@ mod tests {
         let objects = store.iter().collect::<Vec<_>>();
         assert_eq!(objects, [a, b]);
     }
+
+    #[test]
+    fn usage() {
+        let mut store = Store::with_block_size(2);
+        assert_eq!(store.usage(), StoreUsage::default());
+
+        for i in 0..3 {
+            let handle: Handle<u64> = store.reserve();
+            store.insert(handle, i);
+        }
+
+        let usage = store.usage();
+        assert_eq!(usage.num_objects, 3);
+        assert_eq!(usage.size_of_objects, 3 * 8);
+        assert_eq!(usage.size_allocated, 4 * size_of::<Option<u64>>());
+    }
 }
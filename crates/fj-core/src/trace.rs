@@ -0,0 +1,155 @@
+//! Optional tracing of operations
+//!
+//! If the `tracing` feature is enabled, operations emit spans using the
+//! [`tracing`] crate. Each span is named after the operation, and records the
+//! number of objects that the operation works on. A subscriber can use these
+//! spans to report what happens while a model is built, and how long each step
+//! takes.
+//!
+//! Without the feature, no spans are emitted, and the instrumentation compiles
+//! to nothing.
+//!
+//! [`tracing`]: https://docs.rs/tracing
+
+/// Enter a span for an operation, until the end of the enclosing scope
+///
+/// Accepts the same arguments as `tracing::debug_span!`. The arguments are not
+/// evaluated, unless the `tracing` feature is enabled.
+///
+/// Prefix the arguments with `let span =`, to bind the entered span to `span`.
+/// This is required to record values for fields that are only known later. The
+/// binding only exists, if the `tracing` feature is enabled.
+macro_rules! operation_span {
+    (let $span:ident = $($args:tt)*) => {
+        #[cfg(feature = "tracing")]
+        let $span = tracing::debug_span!($($args)*).entered();
+    };
+    ($($args:tt)*) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!($($args)*).entered();
+    };
+}
+
+pub(crate) use operation_span;
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use std::sync::Arc;
+
+    use fj_math::{Point, Vector};
+    use parking_lot::Mutex;
+    use tracing::{
+        field::{Field, Visit},
+        span, Event, Metadata, Subscriber,
+    };
+
+    use crate::{
+        algorithms::transform::TransformObject,
+        fixtures,
+        objects::{Cycle, Region, Sketch},
+        operations::{
+            build::{BuildCycle, BuildRegion, BuildSketch},
+            insert::Insert,
+            reverse::Reverse,
+            sweep::SweepSketch,
+            update::{UpdateRegion, UpdateSketch},
+        },
+        services::Services,
+    };
+
+    #[test]
+    fn traced_spacer() -> anyhow::Result<()> {
+        let spans = Spans::default();
+
+        tracing::subscriber::with_default(spans.clone(), || {
+            let mut services = Services::new();
+
+            Sketch::empty()
+                .add_region(
+                    Region::circle(Point::origin(), 1., &mut services)
+                        .add_interiors([Cycle::circle(
+                            Point::origin(),
+                            0.5,
+                            &mut services,
+                        )
+                        .reverse(&mut services)
+                        .insert(&mut services)])
+                        .insert(&mut services),
+                )
+                .sweep_sketch(
+                    services.objects.surfaces.xy_plane(),
+                    Vector::from([0., 0., 1.]),
+                    &mut services,
+                )
+        })?;
+
+        assert_eq!(*spans.names.lock(), ["sweep_sketch", "sweep_face"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn traced_transform() {
+        let mut services = Services::new();
+        let cube = fixtures::cube(&mut services);
+
+        let spans = Spans::default();
+        tracing::subscriber::with_default(spans.clone(), || {
+            cube.translate([1., 0., 0.], &mut services)
+        });
+
+        assert_eq!(*spans.names.lock(), ["transform"]);
+
+        // Among other objects, the 6 faces of the cube have been transformed.
+        let values = spans.values.lock();
+        assert!(matches!(
+            values.as_slice(),
+            [("objects", num_objects)] if *num_objects > 6
+        ));
+    }
+
+    /// A subscriber that records the names of all spans, and the values of
+    /// their fields
+    #[derive(Clone, Default)]
+    struct Spans {
+        names: Arc<Mutex<Vec<&'static str>>>,
+        values: Arc<Mutex<Vec<(&'static str, u64)>>>,
+    }
+
+    impl Subscriber for Spans {
+        fn enabled(&self, _: &Metadata) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &span::Attributes) -> span::Id {
+            span.record(&mut RecordValues(&self.values));
+
+            let mut names = self.names.lock();
+            names.push(span.metadata().name());
+            span::Id::from_u64(names.len() as u64)
+        }
+
+        fn record(&self, _: &span::Id, values: &span::Record) {
+            values.record(&mut RecordValues(&self.values));
+        }
+
+        fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+        fn event(&self, _: &Event) {}
+
+        fn enter(&self, _: &span::Id) {}
+
+        fn exit(&self, _: &span::Id) {}
+    }
+
+    /// Records the values of span fields into [`Spans`]
+    struct RecordValues<'r>(&'r Mutex<Vec<(&'static str, u64)>>);
+
+    impl Visit for RecordValues<'_> {
+        fn record_u64(&mut self, field: &Field, value: u64) {
+            self.0.lock().push((field.name(), value));
+        }
+
+        fn record_debug(&mut self, _: &Field, _: &dyn std::fmt::Debug) {}
+    }
+}
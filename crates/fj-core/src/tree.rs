@@ -0,0 +1,251 @@
+//! Human-readable representation of the object graph
+//!
+//! The derived [`Debug`] implementations of the objects print each object in
+//! full, wherever it is referenced. For anything but the smallest shapes, that
+//! is too verbose to be useful, and it hides which objects are shared.
+//!
+//! [`ObjectTree`] prints a [`Solid`] as an indented tree instead, with one line
+//! per object, down to the vertices and their positions. Each object is labeled
+//! with a number, which is assigned per type of object, in order of first
+//! appearance. An object that is referenced from multiple places shows up with
+//! the same label each time, which makes sharing visible.
+//!
+//! The labels are derived from the object ids of the handles. Unlike those,
+//! they don't change between runs, which makes the output suitable for
+//! comparing against a snapshot in tests.
+
+use std::{collections::BTreeMap, fmt};
+
+use fj_math::{Point, Scalar};
+
+use crate::{
+    geometry::SurfacePath,
+    objects::{Face, HalfEdge, Solid},
+    storage::{Handle, ObjectId},
+};
+
+/// An indented tree representation of a [`Solid`]
+///
+/// See [module-level documentation] for context.
+///
+/// [module-level documentation]: self
+pub struct ObjectTree<'r> {
+    solid: &'r Solid,
+}
+
+impl<'r> ObjectTree<'r> {
+    /// Create the tree representation of a solid
+    pub fn new(solid: &'r Solid) -> Self {
+        Self { solid }
+    }
+}
+
+impl fmt::Display for ObjectTree<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut labels = Labels::default();
+
+        writeln!(f, "Solid")?;
+
+        for shell in self.solid.shells() {
+            let shell_label = label(&mut labels.shells, shell.id());
+            writeln!(f, "  Shell #{shell_label}")?;
+
+            for face in shell.faces() {
+                write_face(f, face, &mut labels)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn write_face(
+    f: &mut fmt::Formatter,
+    face: &Handle<Face>,
+    labels: &mut Labels,
+) -> fmt::Result {
+    let face_label = label(&mut labels.faces, face.id());
+    let surface_label = label(&mut labels.surfaces, face.surface().id());
+    writeln!(f, "    Face #{face_label} (surface #{surface_label})")?;
+
+    let cycles = [(face.region().exterior(), "exterior")].into_iter().chain(
+        face.region()
+            .interiors()
+            .iter()
+            .map(|interior| (interior, "interior")),
+    );
+
+    for (cycle, kind) in cycles {
+        let cycle_label = label(&mut labels.cycles, cycle.id());
+        writeln!(f, "      Cycle #{cycle_label} ({kind})")?;
+
+        for half_edge in cycle.half_edges() {
+            write_half_edge(f, face, half_edge, labels)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_half_edge(
+    f: &mut fmt::Formatter,
+    face: &Face,
+    half_edge: &Handle<HalfEdge>,
+    labels: &mut Labels,
+) -> fmt::Result {
+    let half_edge_label = label(&mut labels.half_edges, half_edge.id());
+    let curve_label = label(&mut labels.curves, half_edge.curve().id());
+    let kind = match half_edge.path() {
+        SurfacePath::Circle(_) => "circle",
+        SurfacePath::Line(_) => "line",
+        SurfacePath::Spiral(_) => "spiral",
+    };
+    let [start, end] = half_edge.boundary().inner.map(|point| point.t);
+    writeln!(
+        f,
+        "        HalfEdge #{half_edge_label} (curve #{curve_label}): \
+        {kind} [{}, {}]",
+        Rounded(start),
+        Rounded(end),
+    )?;
+
+    let vertex_label =
+        label(&mut labels.vertices, half_edge.start_vertex().id());
+    let position = face
+        .surface()
+        .geometry()
+        .point_from_surface_coords(half_edge.start_position());
+    writeln!(
+        f,
+        "          Vertex #{vertex_label} at {}",
+        RoundedPoint(position)
+    )?;
+
+    Ok(())
+}
+
+#[derive(Default)]
+struct Labels {
+    shells: BTreeMap<ObjectId, usize>,
+    faces: BTreeMap<ObjectId, usize>,
+    surfaces: BTreeMap<ObjectId, usize>,
+    cycles: BTreeMap<ObjectId, usize>,
+    half_edges: BTreeMap<ObjectId, usize>,
+    curves: BTreeMap<ObjectId, usize>,
+    vertices: BTreeMap<ObjectId, usize>,
+}
+
+fn label(labels: &mut BTreeMap<ObjectId, usize>, id: ObjectId) -> usize {
+    let next = labels.len();
+    *labels.entry(id).or_insert(next)
+}
+
+/// A number, rounded to a precision that is useful for reading the tree
+///
+/// Numbers that round to zero are printed without sign, so the output doesn't
+/// depend on the direction from which floating-point inaccuracies approached
+/// zero.
+struct Rounded(Scalar);
+
+impl fmt::Display for Rounded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = (self.0.into_f64() * 1000.).round() / 1000.;
+        let value = if value == 0. { 0. } else { value };
+
+        write!(f, "{value:.3}")
+    }
+}
+
+struct RoundedPoint(Point<3>);
+
+impl fmt::Display for RoundedPoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [x, y, z] = self.0.coords.components.map(Rounded);
+        write!(f, "[{x}, {y}, {z}]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Vector};
+
+    use crate::{
+        objects::{Cycle, Region, Sketch},
+        operations::{
+            build::{BuildCycle, BuildRegion, BuildSketch},
+            insert::Insert,
+            reverse::Reverse,
+            sweep::SweepSketch,
+            update::{UpdateRegion, UpdateSketch},
+        },
+        services::Services,
+    };
+
+    use super::ObjectTree;
+
+    #[test]
+    fn spacer() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let spacer = Sketch::empty()
+            .add_region(
+                Region::circle(Point::origin(), 1., &mut services)
+                    .add_interiors([Cycle::circle(
+                        Point::origin(),
+                        0.5,
+                        &mut services,
+                    )
+                    .reverse(&mut services)
+                    .insert(&mut services)])
+                    .insert(&mut services),
+            )
+            .sweep_sketch(
+                services.objects.surfaces.xy_plane(),
+                Vector::from([0., 0., 1.]),
+                &mut services,
+            )?;
+
+        let expected = "\
+Solid
+  Shell #0
+    Face #0 (surface #0)
+      Cycle #0 (exterior)
+        HalfEdge #0 (curve #0): circle [6.283, 0.000]
+          Vertex #0 at [1.000, 0.000, 0.000]
+      Cycle #1 (interior)
+        HalfEdge #1 (curve #1): circle [0.000, 6.283]
+          Vertex #1 at [0.500, 0.000, 0.000]
+    Face #1 (surface #1)
+      Cycle #2 (exterior)
+        HalfEdge #2 (curve #0): line [0.000, 6.283]
+          Vertex #0 at [1.000, 0.000, 0.000]
+        HalfEdge #3 (curve #2): line [0.000, 1.000]
+          Vertex #0 at [1.000, 0.000, 0.000]
+        HalfEdge #4 (curve #3): line [6.283, 0.000]
+          Vertex #2 at [1.000, 0.000, 1.000]
+        HalfEdge #5 (curve #2): line [1.000, 0.000]
+          Vertex #2 at [1.000, 0.000, 1.000]
+    Face #2 (surface #2)
+      Cycle #3 (exterior)
+        HalfEdge #6 (curve #1): line [6.283, 0.000]
+          Vertex #1 at [0.500, 0.000, 0.000]
+        HalfEdge #7 (curve #4): line [0.000, 1.000]
+          Vertex #1 at [0.500, 0.000, 0.000]
+        HalfEdge #8 (curve #5): line [0.000, 6.283]
+          Vertex #3 at [0.500, 0.000, 1.000]
+        HalfEdge #9 (curve #4): line [1.000, 0.000]
+          Vertex #3 at [0.500, 0.000, 1.000]
+    Face #3 (surface #3)
+      Cycle #4 (exterior)
+        HalfEdge #10 (curve #3): circle [0.000, 6.283]
+          Vertex #2 at [1.000, 0.000, 1.000]
+      Cycle #5 (interior)
+        HalfEdge #11 (curve #5): circle [6.283, 0.000]
+          Vertex #3 at [0.500, 0.000, 1.000]
+";
+
+        assert_eq!(ObjectTree::new(&spacer).to_string(), expected);
+
+        Ok(())
+    }
+}
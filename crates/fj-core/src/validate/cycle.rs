@@ -41,6 +41,33 @@ pub enum CycleValidationError {
         /// The edges
         half_edges: [Handle<HalfEdge>; 2],
     },
+
+    /// [`Cycle`]'s last edge doesn't end where its first edge starts
+    ///
+    /// If an open chain of half-edges is intended, it should be represented by
+    /// a [`Wire`] instead.
+    ///
+    /// [`Wire`]: crate::objects::Wire
+    #[error(
+        "`Cycle` is not closed (use a `Wire` for open chains of `HalfEdge`s)\n\
+        - End position of last `HalfEdge`: {end_of_last:?}\n\
+        - Start position of first `HalfEdge`: {start_of_first:?}\n\
+        - Distance between vertices: {distance}\n\
+        - `HalfEdge`s: {half_edges:#?}"
+    )]
+    CycleNotClosed {
+        /// The end position of the last [`HalfEdge`]
+        end_of_last: Point<2>,
+
+        /// The start position of the first [`HalfEdge`]
+        start_of_first: Point<2>,
+
+        /// The distance between the two vertices
+        distance: Scalar,
+
+        /// The last and the first edge
+        half_edges: [Handle<HalfEdge>; 2],
+    },
 }
 
 impl CycleValidationError {
@@ -49,7 +76,9 @@ impl CycleValidationError {
         config: &ValidationConfig,
         errors: &mut Vec<ValidationError>,
     ) {
-        for (first, second) in cycle.half_edges().pairs() {
+        let num_half_edges = cycle.half_edges().len();
+
+        for (i, (first, second)) in cycle.half_edges().pairs().enumerate() {
             let end_of_first = {
                 let [_, end] = first.boundary().inner;
                 first.path().point_from_path_coords(end)
@@ -58,17 +87,30 @@ impl CycleValidationError {
 
             let distance = (end_of_first - start_of_second).magnitude();
 
-            if distance > config.identical_max_distance {
-                errors.push(
-                    Self::HalfEdgesNotConnected {
-                        end_of_first,
-                        start_of_second,
-                        distance,
-                        half_edges: [first.clone(), second.clone()],
-                    }
-                    .into(),
-                );
+            if distance <= config.identical_max_distance {
+                continue;
             }
+
+            // The last pair connects the end of the cycle to its start. If
+            // only that connection is missing, the cycle is most likely an
+            // open chain of half-edges, and we can say so.
+            let error = if i + 1 == num_half_edges {
+                Self::CycleNotClosed {
+                    end_of_last: end_of_first,
+                    start_of_first: start_of_second,
+                    distance,
+                    half_edges: [first.clone(), second.clone()],
+                }
+            } else {
+                Self::HalfEdgesNotConnected {
+                    end_of_first,
+                    start_of_second,
+                    distance,
+                    half_edges: [first.clone(), second.clone()],
+                }
+            };
+
+            errors.push(error.into());
         }
     }
 }
@@ -124,4 +166,38 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn not_closed() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let open = {
+            let edges = [
+                HalfEdge::line_segment(
+                    [[0., 0.], [1., 0.]],
+                    None,
+                    &mut services,
+                ),
+                HalfEdge::line_segment(
+                    [[1., 0.], [1., 1.]],
+                    None,
+                    &mut services,
+                ),
+            ];
+            let edges = edges.map(|edge| edge.insert(&mut services));
+
+            Cycle::empty().add_half_edges(edges)
+        };
+
+        assert_contains_err!(
+            open,
+            ValidationError::Cycle(CycleValidationError::CycleNotClosed { .. })
+        );
+
+        let mut errors = Vec::new();
+        open.validate(&mut errors);
+        assert_eq!(errors.len(), 1);
+
+        Ok(())
+    }
 }
@@ -11,6 +11,7 @@ impl Validate for HalfEdge {
         errors: &mut Vec<ValidationError>,
     ) {
         EdgeValidationError::check_vertex_coincidence(self, config, errors);
+        EdgeValidationError::check_length(self, config, errors);
     }
 }
 
@@ -37,6 +38,25 @@ pub enum EdgeValidationError {
         /// The edge
         half_edge: HalfEdge,
     },
+
+    /// [`HalfEdge`] has no length on its surface
+    ///
+    /// This can happen, if the path of the edge is degenerate, for example a
+    /// line with a zero-length direction, or a circle with zero radius. The
+    /// vertices of such an edge are distinct on the curve, but not on the
+    /// surface.
+    #[error(
+        "`Edge` has no length on its surface\n\
+        - Position of edge on surface: {position:?}\n\
+        - `Edge`: {half_edge:#?}"
+    )]
+    ZeroLength {
+        /// The position of the edge on its surface
+        position: Point<2>,
+
+        /// The edge
+        half_edge: HalfEdge,
+    },
 }
 
 impl EdgeValidationError {
@@ -60,6 +80,39 @@ impl EdgeValidationError {
             );
         }
     }
+
+    fn check_length(
+        edge: &HalfEdge,
+        config: &ValidationConfig,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        let [start, end] = edge.boundary().inner;
+        if (start - end).magnitude() < config.distinct_min_distance {
+            // Coincident vertices are already covered by a different check.
+            return;
+        }
+
+        // The edge might be closed, so its start and end are not enough to
+        // determine its length. If it has any length, its middle is going to
+        // be somewhere else.
+        let middle = start + (end - start) * 0.5;
+        let [start, middle, end] = [start, middle, end]
+            .map(|point| edge.path().point_from_path_coords(point));
+
+        let is_zero_length = [middle, end].into_iter().all(|point| {
+            (point - start).magnitude() < config.distinct_min_distance
+        });
+
+        if is_zero_length {
+            errors.push(
+                Self::ZeroLength {
+                    position: start,
+                    half_edge: edge.clone(),
+                }
+                .into(),
+            );
+        }
+    }
 }
 
 #[cfg(test)]
@@ -101,4 +154,22 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn edge_has_zero_length() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let valid =
+            HalfEdge::line_segment([[0., 0.], [1., 0.]], None, &mut services);
+        let invalid =
+            HalfEdge::line_segment([[1., 0.], [1., 0.]], None, &mut services);
+
+        valid.validate_and_return_first_error()?;
+        assert_contains_err!(
+            invalid,
+            ValidationError::Edge(EdgeValidationError::ZeroLength { .. })
+        );
+
+        Ok(())
+    }
 }
@@ -58,7 +58,12 @@ impl FaceValidationError {
             return;
         }
 
-        let exterior_winding = face.region().exterior().winding();
+        let Some(exterior_winding) = face.region().exterior().try_winding()
+        else {
+            // Can't determine winding, if the cycle has no area. That's
+            // checked when validating the region.
+            return;
+        };
 
         for interior in face.region().interiors() {
             if interior.half_edges().is_empty() {
@@ -66,7 +71,9 @@ impl FaceValidationError {
                 // like a job for a different validation check.
                 continue;
             }
-            let interior_winding = interior.winding();
+            let Some(interior_winding) = interior.try_winding() else {
+                continue;
+            };
 
             if exterior_winding == interior_winding {
                 errors.push(
@@ -45,6 +45,10 @@
 //! handle validation errors in a different way, you don't have to do anything
 //! special to use the validation infrastructure.
 //!
+//! Before exporting a shape, [`validate_all`] can be used to run every
+//! available check on it at once, including those that are too expensive for
+//! background validation, and get a report of all problems that were found.
+//!
 //!
 //! ## Configuration
 //!
@@ -77,16 +81,23 @@ mod cycle;
 mod edge;
 mod face;
 mod region;
+mod report;
 mod shell;
 mod sketch;
 mod solid;
 mod surface;
 mod vertex;
+mod wire;
 
 pub use self::{
-    cycle::CycleValidationError, edge::EdgeValidationError,
-    face::FaceValidationError, shell::ShellValidationError,
+    cycle::CycleValidationError,
+    edge::EdgeValidationError,
+    face::FaceValidationError,
+    region::RegionValidationError,
+    report::{validate_all, validate_all_with_config, ValidationReport},
+    shell::ShellValidationError,
     solid::SolidValidationError,
+    wire::WireValidationError,
 };
 
 use std::{convert::Infallible, fmt};
@@ -183,6 +194,10 @@ pub enum ValidationError {
     #[error("`Face` validation error")]
     Face(#[from] FaceValidationError),
 
+    /// `Region` validation error
+    #[error("`Region` validation error")]
+    Region(#[from] RegionValidationError),
+
     /// `Shell` validation error
     #[error("`Shell` validation error")]
     Shell(#[from] ShellValidationError),
@@ -190,6 +205,10 @@ pub enum ValidationError {
     /// `Solid` validation error
     #[error("`Solid` validation error")]
     Solid(#[from] SolidValidationError),
+
+    /// `Wire` validation error
+    #[error("`Wire` validation error")]
+    Wire(#[from] WireValidationError),
 }
 
 impl From<Infallible> for ValidationError {
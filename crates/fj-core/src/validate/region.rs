@@ -1,4 +1,13 @@
-use crate::objects::Region;
+use fj_math::{Aabb, Point, Scalar};
+
+use crate::{
+    algorithms::{
+        approx::{Approx, Tolerance},
+        bounding_volume::BoundingVolume,
+    },
+    objects::{Cycle, Region},
+    storage::Handle,
+};
 
 use super::{Validate, ValidationConfig, ValidationError};
 
@@ -6,7 +15,231 @@ impl Validate for Region {
     fn validate_with_config(
         &self,
         _: &ValidationConfig,
-        _: &mut Vec<ValidationError>,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        RegionValidationError::check_cycle_area(self, errors);
+        RegionValidationError::check_interiors_within_exterior(self, errors);
+    }
+}
+
+/// [`Region`] validation error
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum RegionValidationError {
+    /// A [`Cycle`] of the [`Region`] doesn't enclose any area
+    ///
+    /// This happens, if the vertices of the cycle collapse onto a line or a
+    /// point. Such a region has no well-defined winding, and anything created
+    /// from it, for example by sweeping it, would be degenerate.
+    #[error(
+        "`Cycle` of `Region` doesn't enclose any area\n\
+        - `Cycle`: {cycle:#?}"
+    )]
+    CycleHasNoArea {
+        /// The cycle that doesn't enclose any area
+        cycle: Handle<Cycle>,
+    },
+
+    /// An interior [`Cycle`] of the [`Region`] is not within its exterior
+    ///
+    /// Interior cycles form holes in the region. A hole that lies partially or
+    /// fully outside of the exterior cycle doesn't make sense.
+    #[error(
+        "Interior `Cycle` of `Region` is not within its exterior\n\
+        - Interior `Cycle`: {interior:#?}"
+    )]
+    InteriorOutsideExterior {
+        /// The interior cycle that is not within the exterior
+        interior: Handle<Cycle>,
+    },
+}
+
+impl RegionValidationError {
+    fn check_cycle_area(region: &Region, errors: &mut Vec<ValidationError>) {
+        for cycle in region.all_cycles() {
+            if cycle.half_edges().is_empty() {
+                // A cycle without edges doesn't enclose any area either, but
+                // that's a job for a different validation check.
+                continue;
+            }
+
+            if cycle.try_winding().is_none() {
+                errors.push(
+                    Self::CycleHasNoArea {
+                        cycle: cycle.clone(),
+                    }
+                    .into(),
+                );
+            }
+        }
+    }
+
+    fn check_interiors_within_exterior(
+        region: &Region,
+        errors: &mut Vec<ValidationError>,
     ) {
+        let Some(aabb) = region.exterior().aabb() else {
+            // A region without exterior has no interior either. Sounds like a
+            // job for a different validation check.
+            return;
+        };
+
+        // The cycles are compared using polygons that approximate them. We
+        // don't have a tolerance value for that, so we derive one from the
+        // size of the exterior.
+        let tolerance = Tolerance::from_aabb(&Aabb {
+            min: aabb.min.to_xyz(),
+            max: aabb.max.to_xyz(),
+        });
+
+        let exterior = approx_cycle(region.exterior(), tolerance);
+
+        for interior in region.interiors() {
+            // Checking all points of the interior covers any hole that is
+            // partially or fully outside of the exterior, unless the cycles
+            // only intersect between those points. That would require the
+            // cycles to intersect each other, which is a problem that
+            // validation doesn't detect yet, regardless.
+            let is_within_exterior =
+                approx_cycle(interior, tolerance).into_iter().all(|point| {
+                    polygon_contains_point(&exterior, point, tolerance)
+                });
+
+            if !is_within_exterior {
+                errors.push(
+                    Self::InteriorOutsideExterior {
+                        interior: interior.clone(),
+                    }
+                    .into(),
+                );
+            }
+        }
+    }
+}
+
+/// Approximate a cycle as a polygon, in surface coordinates
+fn approx_cycle(cycle: &Cycle, tolerance: Tolerance) -> Vec<Point<2>> {
+    let mut points = Vec::new();
+
+    for half_edge in cycle.half_edges() {
+        points.push(half_edge.start_position());
+        points.extend(
+            (&half_edge.path(), half_edge.boundary())
+                .approx(tolerance)
+                .into_iter()
+                .map(|(_, point)| point),
+        );
+    }
+
+    points
+}
+
+/// Determine whether a polygon contains a point, using the even-odd rule
+///
+/// Points that are within the tolerance of the polygon's boundary are
+/// considered to be contained. This allows interior cycles to touch the
+/// exterior.
+fn polygon_contains_point(
+    polygon: &[Point<2>],
+    point: Point<2>,
+    tolerance: Tolerance,
+) -> bool {
+    let mut contains = false;
+
+    for (i, &a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+
+        let ab = b - a;
+        let t = if ab.magnitude() == Scalar::ZERO {
+            Scalar::ZERO
+        } else {
+            ((point - a).dot(&ab) / ab.dot(&ab))
+                .max(Scalar::ZERO)
+                .min(Scalar::ONE)
+        };
+        if (point - (a + ab * t)).magnitude() <= tolerance.inner() {
+            return true;
+        }
+
+        // Cast a ray from the point in positive u-direction, and check whether
+        // it crosses the segment.
+        if (a.v > point.v) != (b.v > point.v) {
+            let u = a.u + (point.v - a.v) / (b.v - a.v) * (b.u - a.u);
+            if point.u < u {
+                contains = !contains;
+            }
+        }
+    }
+
+    contains
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::{
+        assert_contains_err,
+        objects::{Cycle, Region},
+        operations::{
+            build::{BuildCycle, BuildRegion},
+            insert::Insert,
+            reverse::Reverse,
+            update::UpdateRegion,
+        },
+        services::Services,
+        validate::{RegionValidationError, Validate, ValidationError},
+    };
+
+    #[test]
+    fn cycle_has_no_area() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let valid = Region::new(
+            Cycle::polygon([[0., 0.], [1., 0.], [0., 1.]], &mut services)
+                .insert(&mut services),
+            [],
+            None,
+        );
+        let invalid = Region::new(
+            Cycle::polygon([[0., 0.], [1., 0.], [2., 0.]], &mut services)
+                .insert(&mut services),
+            [],
+            None,
+        );
+
+        valid.validate_and_return_first_error()?;
+        assert_contains_err!(
+            invalid,
+            ValidationError::Region(
+                RegionValidationError::CycleHasNoArea { .. }
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn interior_outside_exterior() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        // The profile of the spacer, with its hole in the right place, then
+        // moved outside of the exterior.
+        let [valid, invalid] = [[0., 0.], [3., 0.]].map(|center| {
+            Region::circle(Point::origin(), 1., &mut services).add_interiors([
+                Cycle::circle(center, 0.5, &mut services)
+                    .reverse(&mut services)
+                    .insert(&mut services),
+            ])
+        });
+
+        valid.validate_and_return_first_error()?;
+        assert_contains_err!(
+            invalid,
+            ValidationError::Region(
+                RegionValidationError::InteriorOutsideExterior { .. }
+            )
+        );
+
+        Ok(())
     }
 }
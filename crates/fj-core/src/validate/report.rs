@@ -0,0 +1,269 @@
+use std::{collections::HashSet, fmt};
+
+use fj_math::Aabb;
+
+use crate::{
+    algorithms::{approx::Tolerance, bounding_volume::BoundingVolume},
+    objects::Solid,
+    queries::SelfIntersections,
+    storage::{Handle, ObjectId},
+};
+
+use super::{
+    SolidValidationError, Validate, ValidationConfig, ValidationError,
+    ValidationErrors,
+};
+
+/// Run every available validation check on a [`Solid`]
+///
+/// This validates the solid and every object it references, each of them
+/// once, and collects all validation errors into a [`ValidationReport`]. In
+/// addition, it checks whether any faces of the solid intersect each other,
+/// which is not part of regular validation. See [`SelfIntersections`].
+///
+/// This is intended as a final check, before exporting a shape. Unlike the
+/// background validation, it doesn't stop at the first problem, so all of them
+/// can be presented to the user at once.
+///
+/// Uses the default [`ValidationConfig`], and a tolerance derived from the
+/// bounding box of the solid. See [`validate_all_with_config`].
+pub fn validate_all(solid: &Solid) -> ValidationReport {
+    let aabb = solid.aabb().unwrap_or(Aabb {
+        min: [0.; 3].into(),
+        max: [0.; 3].into(),
+    });
+
+    validate_all_with_config(
+        solid,
+        &ValidationConfig::default(),
+        Tolerance::from_aabb(&aabb),
+    )
+}
+
+/// Run every available validation check on a [`Solid`], using the config
+///
+/// Works like [`validate_all`], but uses the provided configuration for the
+/// validation checks, and the provided tolerance to approximate faces, when
+/// checking for intersections between them.
+pub fn validate_all_with_config(
+    solid: &Solid,
+    config: &ValidationConfig,
+    tolerance: impl Into<Tolerance>,
+) -> ValidationReport {
+    let mut validator = Validator {
+        config,
+        visited: HashSet::new(),
+        errors: Vec::new(),
+    };
+
+    solid.validate_with_config(config, &mut validator.errors);
+
+    for shell in solid.shells() {
+        validator.validate(shell);
+
+        for face in shell.faces() {
+            validator.validate(face);
+            validator.validate(face.surface());
+            validator.validate(face.region());
+
+            for cycle in face.region().all_cycles() {
+                validator.validate(cycle);
+
+                for half_edge in cycle.half_edges() {
+                    validator.validate(half_edge);
+                    validator.validate(half_edge.curve());
+                    validator.validate(half_edge.start_vertex());
+                }
+            }
+        }
+    }
+
+    let mut errors = validator.errors;
+
+    match solid.self_intersections(tolerance) {
+        Ok(intersections) => {
+            errors.extend(intersections.into_iter().map(|[face_a, face_b]| {
+                SolidValidationError::FacesIntersect { face_a, face_b }.into()
+            }));
+        }
+        Err(err) => {
+            errors.push(
+                SolidValidationError::SelfIntersectionsNotChecked(err).into(),
+            );
+        }
+    }
+
+    ValidationReport { errors }
+}
+
+/// The result of [`validate_all`]
+#[derive(Clone, Debug, Default)]
+pub struct ValidationReport {
+    /// All validation errors that were found
+    pub errors: Vec<ValidationError>,
+}
+
+impl ValidationReport {
+    /// Determine whether no validation errors were found
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Convert the report into a `Result`
+    ///
+    /// Returns an error, if the report contains any validation errors.
+    pub fn into_result(self) -> Result<(), ValidationErrors> {
+        if self.is_valid() {
+            return Ok(());
+        }
+
+        Err(ValidationErrors(self.errors))
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_valid() {
+            return writeln!(f, "No validation errors");
+        }
+
+        writeln!(f, "{} validation errors:", self.errors.len())?;
+
+        for err in &self.errors {
+            writeln!(f, "- {err}")?;
+        }
+
+        Ok(())
+    }
+}
+
+struct Validator<'r> {
+    config: &'r ValidationConfig,
+    visited: HashSet<ObjectId>,
+    errors: Vec<ValidationError>,
+}
+
+impl Validator<'_> {
+    fn validate<T: Validate>(&mut self, object: &Handle<T>) {
+        // Objects can be referenced many times, for example half-edges by the
+        // cycles of two faces. Each of them only needs to be validated once.
+        if self.visited.insert(object.id()) {
+            object.validate_with_config(self.config, &mut self.errors);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        objects::{Curve, Shell, Solid},
+        operations::{
+            build::BuildShell,
+            insert::Insert,
+            update::{
+                UpdateCycle, UpdateFace, UpdateHalfEdge, UpdateRegion,
+                UpdateShell,
+            },
+        },
+        services::Services,
+        storage::{Handle, Store},
+        validate::{ShellValidationError, SolidValidationError},
+    };
+
+    use super::{validate_all, ValidationError};
+
+    #[test]
+    fn valid_solid() {
+        let mut services = Services::new();
+
+        let tetrahedron = Shell::tetrahedron(
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]],
+            &mut services,
+        )
+        .shell
+        .insert(&mut services);
+
+        let report = validate_all(&Solid::new([tetrahedron]));
+        assert!(report.is_valid(), "{report}");
+    }
+
+    #[test]
+    fn broken_solid() {
+        let mut services = Services::new();
+
+        // A tetrahedron with a missing face, and a half-edge that no longer
+        // refers to the same curve as its sibling.
+        let tetrahedron = Shell::tetrahedron(
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]],
+            &mut services,
+        );
+        let broken = tetrahedron
+            .shell
+            .remove_face(&tetrahedron.abc.face)
+            .update_face(&tetrahedron.bad.face, |face| {
+                face.update_region(|region| {
+                    region
+                        .update_exterior(|cycle| {
+                            cycle
+                                .update_half_edge(
+                                    cycle.half_edges().nth_circular(1),
+                                    |half_edge| {
+                                        half_edge
+                                            .update_curve(|_| {
+                                                Curve::new()
+                                                    .insert(&mut services)
+                                            })
+                                            .insert(&mut services)
+                                    },
+                                )
+                                .insert(&mut services)
+                        })
+                        .insert(&mut services)
+                })
+                .insert(&mut services)
+            });
+
+        // Another tetrahedron, that overlaps the first one.
+        let overlapping = Shell::tetrahedron(
+            [
+                [0.2, 0.2, 0.2],
+                [0.2, 1.2, 0.2],
+                [1.2, 0.2, 0.2],
+                [0.2, 0.2, 1.2],
+            ],
+            &mut services,
+        )
+        .shell;
+
+        // Inserting the broken shell through `Services` would validate it, and
+        // result in a panic later on. Let's use a separate store instead.
+        let mut store = Store::new();
+        let shells = [broken, overlapping].map(|shell| {
+            let handle: Handle<Shell> = store.reserve();
+            store.insert(handle.clone(), shell);
+            handle
+        });
+
+        let report = validate_all(&Solid::new(shells));
+
+        let found = |matches: fn(&ValidationError) -> bool| {
+            report.errors.iter().any(matches)
+        };
+        assert!(found(|err| matches!(
+            err,
+            ValidationError::Shell(
+                ShellValidationError::HalfEdgeHasNoSibling { .. }
+            )
+        )));
+        assert!(found(|err| matches!(
+            err,
+            ValidationError::Shell(
+                ShellValidationError::CoincidentHalfEdgesAreNotSiblings(..)
+            )
+        )));
+        assert!(found(|err| matches!(
+            err,
+            ValidationError::Solid(SolidValidationError::FacesIntersect { .. })
+        )));
+    }
+}
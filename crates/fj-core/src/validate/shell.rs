@@ -4,9 +4,10 @@ use fj_math::{Point, Scalar};
 
 use crate::{
     geometry::SurfaceGeometry,
-    objects::{HalfEdge, Shell, Surface},
+    objects::{Face, HalfEdge, Shell, Surface},
     queries::{
-        AllHalfEdgesWithSurface, BoundingVerticesOfHalfEdge, SiblingOfHalfEdge,
+        AllHalfEdgesWithSurface, BoundingVerticesOfHalfEdge, CoincidentFaces,
+        SiblingOfHalfEdge,
     },
     storage::{Handle, HandleWrapper},
 };
@@ -20,8 +21,12 @@ impl Validate for Shell {
         errors: &mut Vec<ValidationError>,
     ) {
         ShellValidationError::check_curve_coordinates(self, config, errors);
+        ShellValidationError::check_half_edges_on_surfaces(
+            self, config, errors,
+        );
         ShellValidationError::check_half_edge_pairs(self, errors);
         ShellValidationError::check_half_edge_coincidence(self, config, errors);
+        ShellValidationError::check_face_coincidence(self, config, errors);
     }
 }
 
@@ -36,6 +41,26 @@ pub enum ShellValidationError {
     )]
     CurveCoordinateSystemMismatch(Vec<CurveCoordinateSystemMismatch>),
 
+    /// [`Shell`] contains a half-edge that doesn't lie on the surface of a face
+    /// that shares its curve
+    #[error(
+        "`HalfEdge` doesn't lie on surface of `Face` that shares its curve\n\
+        - Maximum deviation from surface: {max_deviation}\n\
+        - `HalfEdge`: {half_edge:#?}\n\
+        - `Surface`: {surface:#?}"
+    )]
+    HalfEdgeNotOnSurface {
+        /// The half-edge that doesn't lie on the surface
+        half_edge: Handle<HalfEdge>,
+
+        /// The surface of the face that shares the half-edge's curve
+        surface: Handle<Surface>,
+
+        /// The largest distance of a sampled point of the half-edge from the
+        /// surface
+        max_deviation: Scalar,
+    },
+
     /// [`Shell`] contains a half-edge that is not part of a pair
     #[error("Half-edge has no sibling: {half_edge:#?}")]
     HalfEdgeHasNoSibling {
@@ -51,6 +76,14 @@ pub enum ShellValidationError {
         Half-edge 2: {1:#?}"
     )]
     CoincidentHalfEdgesAreNotSiblings(Handle<HalfEdge>, Handle<HalfEdge>),
+
+    /// [`Shell`] contains faces that are coincident
+    #[error(
+        "`Shell` contains coincident `Face`s\n\
+        Face 1: {0:#?}\n\
+        Face 2: {1:#?}"
+    )]
+    CoincidentFaces(Handle<Face>, Handle<Face>),
 }
 
 impl ShellValidationError {
@@ -147,6 +180,59 @@ impl ShellValidationError {
         }
     }
 
+    /// Check that half-edges lie on the surfaces of all faces they bound
+    ///
+    /// Half-edges that refer to the same curve are coincident, so each of them
+    /// must lie on the surfaces of the faces that the others are part of. An
+    /// edge that was constructed on the wrong surface violates that.
+    fn check_half_edges_on_surfaces(
+        shell: &Shell,
+        config: &ValidationConfig,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        let mut edges_and_surfaces = Vec::new();
+        shell.all_half_edges_with_surface(&mut edges_and_surfaces);
+
+        for (half_edge, surface) in &edges_and_surfaces {
+            let points = sample_half_edge(half_edge, surface.geometry());
+
+            for (other, other_surface) in &edges_and_surfaces {
+                // We only care about faces that share the half-edge's curve.
+                if half_edge.curve().id() != other.curve().id() {
+                    continue;
+                }
+
+                // A half-edge lies on its own surface by definition.
+                if surface.id() == other_surface.id() {
+                    continue;
+                }
+
+                if points.iter().all(|&point| {
+                    other_surface
+                        .contains_point(point, config.distinct_min_distance)
+                }) {
+                    continue;
+                }
+
+                let max_deviation = points
+                    .iter()
+                    .map(|&point| {
+                        other_surface.geometry().distance_to_point(point)
+                    })
+                    .fold(Scalar::ZERO, Scalar::max);
+
+                errors.push(
+                    Self::HalfEdgeNotOnSurface {
+                        half_edge: half_edge.clone(),
+                        surface: other_surface.clone(),
+                        max_deviation,
+                    }
+                    .into(),
+                );
+            }
+        }
+    }
+
     /// Check that each half-edge is part of a pair
     fn check_half_edge_pairs(shell: &Shell, errors: &mut Vec<ValidationError>) {
         let mut unmatched_half_edges = BTreeMap::new();
@@ -236,6 +322,19 @@ impl ShellValidationError {
             }
         }
     }
+
+    /// Check that no two faces are coincident
+    fn check_face_coincidence(
+        shell: &Shell,
+        config: &ValidationConfig,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        for [face_a, face_b] in
+            shell.coincident_faces(config.distinct_min_distance)
+        {
+            errors.push(Self::CoincidentFaces(face_a, face_b).into());
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -248,6 +347,30 @@ pub struct CurveCoordinateSystemMismatch {
     pub distance: Scalar,
 }
 
+/// Sample a half-edge at evenly spaced points along its boundary
+///
+/// The half-edge is approximated by these points, which includes its start and
+/// end. Returns the points in global coordinates.
+fn sample_half_edge(
+    half_edge: &HalfEdge,
+    surface: SurfaceGeometry,
+) -> Vec<Point<3>> {
+    // Lines are fully checked by their start and end, and circles by any three
+    // points. Additional samples make the reported deviation more meaningful.
+    let num_segments = 8;
+
+    let [start, end] = half_edge.boundary().inner;
+    (0..=num_segments)
+        .map(|i| {
+            let point_curve =
+                start + (end - start) * (i as f64 / num_segments as f64);
+            let point_surface =
+                half_edge.path().point_from_path_coords(point_curve);
+            surface.point_from_surface_coords(point_surface)
+        })
+        .collect()
+}
+
 /// Sample two edges at various (currently 3) points in 3D along them.
 ///
 /// Returns an [`Iterator`] of the distance at each sample.
@@ -285,8 +408,11 @@ fn distances(
 
 #[cfg(test)]
 mod tests {
+    use fj_math::{Line, Scalar, Vector};
+
     use crate::{
         assert_contains_err,
+        geometry::SurfacePath,
         objects::{Curve, Shell},
         operations::{
             build::BuildShell,
@@ -297,7 +423,10 @@ mod tests {
             },
         },
         services::Services,
-        validate::{shell::ShellValidationError, Validate, ValidationError},
+        validate::{
+            shell::ShellValidationError, Validate, ValidationConfig,
+            ValidationError,
+        },
     };
 
     #[test]
@@ -341,6 +470,75 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn half_edge_not_on_surface() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let valid = Shell::tetrahedron(
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]],
+            &mut services,
+        );
+        let invalid = valid.shell.update_face(&valid.abc.face, |face| {
+            face.update_region(|region| {
+                region
+                    .update_exterior(|cycle| {
+                        cycle
+                            .update_half_edge(
+                                cycle.half_edges().nth_circular(0),
+                                |edge| {
+                                    // Turn the half-edge away from the face it
+                                    // shares its curve with.
+                                    edge.update_path(|path| {
+                                        let SurfacePath::Line(line) = path
+                                        else {
+                                            unreachable!(
+                                                "Tetrahedron has straight edges"
+                                            );
+                                        };
+                                        let d = line.direction();
+                                        SurfacePath::Line(
+                                            Line::from_origin_and_direction(
+                                                line.origin(),
+                                                d + Vector::from([-d.v, d.u])
+                                                    * 0.5,
+                                            ),
+                                        )
+                                    })
+                                    .insert(&mut services)
+                                },
+                            )
+                            .insert(&mut services)
+                    })
+                    .insert(&mut services)
+            })
+            .insert(&mut services)
+        });
+
+        valid.shell.validate_and_return_first_error()?;
+        assert_contains_err!(
+            invalid,
+            ValidationError::Shell(
+                ShellValidationError::HalfEdgeNotOnSurface { .. }
+            )
+        );
+
+        let mut errors = Vec::new();
+        invalid.validate_with_config(&ValidationConfig::default(), &mut errors);
+        for error in errors {
+            if let ValidationError::Shell(
+                ShellValidationError::HalfEdgeNotOnSurface {
+                    max_deviation,
+                    ..
+                },
+            ) = error
+            {
+                assert!(max_deviation > Scalar::from(0.1));
+            }
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn half_edge_has_no_sibling() -> anyhow::Result<()> {
         let mut services = Services::new();
@@ -401,4 +599,24 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn coincident_faces() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let valid = Shell::tetrahedron(
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]],
+            &mut services,
+        );
+        let duplicate = valid.abc.face.clone_object().insert(&mut services);
+        let invalid = valid.shell.add_faces([duplicate]);
+
+        valid.shell.validate_and_return_first_error()?;
+        assert_contains_err!(
+            invalid,
+            ValidationError::Shell(ShellValidationError::CoincidentFaces(..))
+        );
+
+        Ok(())
+    }
 }
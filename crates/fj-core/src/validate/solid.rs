@@ -1,7 +1,8 @@
 use std::iter::repeat;
 
 use crate::{
-    objects::{Solid, Vertex},
+    algorithms::approx::ApproxError,
+    objects::{Face, Solid, Vertex},
     storage::Handle,
 };
 use fj_math::Point;
@@ -60,6 +61,34 @@ pub enum SolidValidationError {
         /// Position of second vertex
         position_b: Point<3>,
     },
+
+    /// [`Solid`] contains faces that intersect each other
+    ///
+    /// This requires approximating the faces, and is only checked by
+    /// [`validate_all`].
+    ///
+    /// [`validate_all`]: super::validate_all
+    #[error(
+        "Solid contains Faces that intersect each other\n
+        Face 1: {face_a:#?}
+        Face 2: {face_b:#?}"
+    )]
+    FacesIntersect {
+        /// The first face
+        face_a: Handle<Face>,
+
+        /// The second face
+        face_b: Handle<Face>,
+    },
+
+    /// Faces of the [`Solid`] could not be checked for intersections
+    ///
+    /// This happens, if the faces can't be approximated. Only returned by
+    /// [`validate_all`].
+    ///
+    /// [`validate_all`]: super::validate_all
+    #[error("Could not check Solid for intersecting Faces")]
+    SelfIntersectionsNotChecked(#[source] ApproxError),
 }
 
 impl SolidValidationError {
@@ -0,0 +1,128 @@
+use fj_math::{Point, Scalar};
+use itertools::Itertools;
+
+use crate::{
+    objects::{HalfEdge, Wire},
+    storage::Handle,
+};
+
+use super::{Validate, ValidationConfig, ValidationError};
+
+impl Validate for Wire {
+    fn validate_with_config(
+        &self,
+        config: &ValidationConfig,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        WireValidationError::check_half_edge_connections(self, config, errors);
+    }
+}
+
+/// [`Wire`] validation failed
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum WireValidationError {
+    /// [`Wire`]'s edges are not connected
+    #[error(
+        "Adjacent `HalfEdge`s are not connected\n\
+        - End position of first `HalfEdge`: {end_of_first:?}\n\
+        - Start position of second `HalfEdge`: {start_of_second:?}\n\
+        - Distance between vertices: {distance}\n\
+        - `HalfEdge`s: {half_edges:#?}"
+    )]
+    HalfEdgesNotConnected {
+        /// The end position of the first [`HalfEdge`]
+        end_of_first: Point<2>,
+
+        /// The start position of the second [`HalfEdge`]
+        start_of_second: Point<2>,
+
+        /// The distance between the two vertices
+        distance: Scalar,
+
+        /// The edges
+        half_edges: [Handle<HalfEdge>; 2],
+    },
+}
+
+impl WireValidationError {
+    fn check_half_edge_connections(
+        wire: &Wire,
+        config: &ValidationConfig,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        // Unlike with a cycle, the last half-edge is not expected to connect
+        // to the first one.
+        for (first, second) in wire.half_edges().iter().tuple_windows() {
+            let end_of_first = {
+                let [_, end] = first.boundary().inner;
+                first.path().point_from_path_coords(end)
+            };
+            let start_of_second = second.start_position();
+
+            let distance = (end_of_first - start_of_second).magnitude();
+
+            if distance > config.identical_max_distance {
+                errors.push(
+                    Self::HalfEdgesNotConnected {
+                        end_of_first,
+                        start_of_second,
+                        distance,
+                        half_edges: [first.clone(), second.clone()],
+                    }
+                    .into(),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        assert_contains_err,
+        objects::{HalfEdge, Wire},
+        operations::{
+            build::{BuildHalfEdge, BuildWire},
+            insert::Insert,
+        },
+        services::Services,
+        validate::{wire::WireValidationError, Validate, ValidationError},
+    };
+
+    #[test]
+    fn edges_connected() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let valid =
+            Wire::polyline([[0., 0.], [1., 0.], [1., 1.]], &mut services);
+
+        valid.validate_and_return_first_error()?;
+
+        let disconnected = {
+            let edges = [
+                HalfEdge::line_segment(
+                    [[0., 0.], [1., 0.]],
+                    None,
+                    &mut services,
+                ),
+                HalfEdge::line_segment(
+                    [[0., 0.], [1., 0.]],
+                    None,
+                    &mut services,
+                ),
+            ];
+            let edges = edges.map(|edge| edge.insert(&mut services));
+
+            Wire::new(edges)
+        };
+
+        assert_contains_err!(
+            disconnected,
+            ValidationError::Wire(
+                WireValidationError::HalfEdgesNotConnected { .. }
+            )
+        );
+
+        Ok(())
+    }
+}
@@ -6,31 +6,77 @@
 //!
 //! This library exports Fornjot models to external file formats.
 //!
+//! ## Units
+//!
+//! Fornjot's internal units are abstract. Unless configured otherwise, via
+//! [`Units`], one unit of the model becomes one millimeter in the exported
+//! file. This matches what slicers assume for STL files, which don't specify
+//! their units.
+//!
 //! [Fornjot]: https://www.fornjot.app/
 
-use std::{fs::File, path::Path};
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
 
 use thiserror::Error;
 
-use fj_interop::mesh::Mesh;
-use fj_math::{Point, Triangle};
+use fj_interop::{
+    drawing::Drawing,
+    mesh::{Color, Mesh},
+};
+use fj_math::{Aabb, Point, Transform, Triangle};
 
 /// Export the provided mesh to the file at the given path.
 ///
 /// This function will create a file if it does not exist, and will truncate it if it does.
 ///
-/// Currently 3MF & STL file types are supported. The case insensitive file extension of
-/// the provided path is used to switch between supported types.
+/// Currently 3MF, STL, OBJ & JSON file types are supported. The case
+/// insensitive file extension of the provided path is used to switch between
+/// supported types. See [`export_mesh_json`] for a description of the JSON
+/// format.
+///
+/// The colors of the triangles are exported to all formats except STL. 3MF
+/// files get one material per color. For OBJ files, the materials are written
+/// to a separate material library, next to the OBJ file, with the same name
+/// and the `.mtl` extension.
+///
+/// Uses the default [`Units`]. See [`export_with_units`].
 pub fn export(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
+    export_with_units(mesh, path, Units::default())
+}
+
+/// Export the provided mesh to the file at the given path, using the units
+///
+/// Works like [`export`], but scales the mesh and annotates the file according
+/// to the provided [`Units`].
+///
+/// 3MF files are always written in millimeters. If the units specify another
+/// unit of length, the mesh is converted to millimeters on export.
+pub fn export_with_units(
+    mesh: &Mesh<Point<3>>,
+    path: &Path,
+    units: Units,
+) -> Result<(), Error> {
     match path.extension() {
         Some(extension) if extension.to_ascii_uppercase() == "3MF" => {
-            export_3mf(mesh, path)
+            let units = units.to_millimeters();
+            export_3mf(&scale_mesh(mesh, units)?, path)
         }
         Some(extension) if extension.to_ascii_uppercase() == "STL" => {
-            export_stl(mesh, path)
+            export_stl(&scale_mesh(mesh, units)?, units.unit, path)
         }
         Some(extension) if extension.to_ascii_uppercase() == "OBJ" => {
-            export_obj(mesh, path)
+            export_obj(&scale_mesh(mesh, units)?, units.unit, path)
+        }
+        Some(extension) if extension.to_ascii_uppercase() == "JSON" => {
+            let mut f = BufWriter::new(File::create(path)?);
+            write_mesh_json(&scale_mesh(mesh, units)?, units.unit, &mut f)?;
+            f.flush()?;
+
+            Ok(())
         }
         Some(extension) => Err(Error::InvalidExtension(
             extension.to_string_lossy().into_owned(),
@@ -40,38 +86,147 @@ pub fn export(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
 }
 
 fn export_3mf(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
-    let vertices = mesh
-        .vertices()
-        .map(|point| threemf::model::Vertex {
-            x: point.x.into_f64(),
-            y: point.y.into_f64(),
-            z: point.z.into_f64(),
-        })
-        .collect();
+    let mut zip = zip::ZipWriter::new(File::create(path)?);
+    let options = zip::write::FileOptions::default();
 
-    let indices: Vec<_> = mesh.indices().collect();
-    let triangles = indices
-        .chunks(3)
-        .map(|triangle| threemf::model::Triangle {
-            v1: triangle[0] as usize,
-            v2: triangle[1] as usize,
-            v3: triangle[2] as usize,
-        })
-        .collect();
+    zip.start_file("[Content_Types].xml", options)?;
+    write!(
+        zip,
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+        <Types xmlns=\"http://schemas.openxmlformats.org/package/2006/\
+        content-types\">\n  \
+        <Default Extension=\"rels\" ContentType=\"application/\
+        vnd.openxmlformats-package.relationships+xml\" />\n  \
+        <Default Extension=\"model\" ContentType=\"application/\
+        vnd.ms-package.3dmanufacturing-3dmodel+xml\" />\n\
+        </Types>\n",
+    )?;
 
-    let mesh = threemf::Mesh {
-        vertices: threemf::model::Vertices { vertex: vertices },
-        triangles: threemf::model::Triangles {
-            triangle: triangles,
-        },
-    };
+    zip.start_file("_rels/.rels", options)?;
+    write!(
+        zip,
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+        <Relationships xmlns=\"http://schemas.openxmlformats.org/package/\
+        2006/relationships\">\n  \
+        <Relationship Target=\"/3D/3dmodel.model\" Id=\"rel0\" \
+        Type=\"http://schemas.microsoft.com/3dmanufacturing/2013/01/\
+        3dmodel\" />\n\
+        </Relationships>\n",
+    )?;
+
+    zip.start_file("3D/3dmodel.model", options)?;
+    write_3mf_model(mesh, &mut zip)?;
 
-    threemf::write(path, mesh)?;
+    zip.finish()?;
 
     Ok(())
 }
 
-fn export_stl(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
+/// Write the model part of a 3MF file
+///
+/// Each distinct color of the mesh becomes a material, and each triangle refers
+/// to the material of its color.
+fn write_3mf_model(
+    mesh: &Mesh<Point<3>>,
+    mut f: impl Write,
+) -> Result<(), Error> {
+    let colors = distinct_colors(mesh);
+
+    writeln!(
+        f,
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+        <model unit=\"millimeter\" xml:lang=\"en-US\" \
+        xmlns=\"http://schemas.microsoft.com/3dmanufacturing/core/\
+        2015/02\">\n  \
+        <resources>\n    \
+        <basematerials id=\"1\">",
+    )?;
+    for color in &colors {
+        let color = color.to_hex();
+        writeln!(
+            f,
+            "      <base name=\"{color}\" displaycolor=\"{color}\" />"
+        )?;
+    }
+    writeln!(
+        f,
+        "    </basematerials>\n    \
+        <object id=\"2\" type=\"model\" pid=\"1\" pindex=\"0\">\n      \
+        <mesh>\n        \
+        <vertices>",
+    )?;
+
+    for point in mesh.vertices() {
+        writeln!(
+            f,
+            "          <vertex x=\"{}\" y=\"{}\" z=\"{}\" />",
+            point.x, point.y, point.z,
+        )?;
+    }
+
+    writeln!(f, "        </vertices>\n        <triangles>")?;
+
+    let indices = mesh.indices().collect::<Vec<_>>();
+    for (triangle, indices) in mesh.triangles().zip(indices.chunks(3)) {
+        let material = colors
+            .binary_search(&triangle.color)
+            .expect("Collected all colors of the mesh");
+
+        writeln!(
+            f,
+            "          <triangle v1=\"{}\" v2=\"{}\" v3=\"{}\" pid=\"1\" \
+            p1=\"{material}\" />",
+            indices[0], indices[1], indices[2],
+        )?;
+    }
+
+    writeln!(
+        f,
+        "        </triangles>\n      \
+        </mesh>\n    \
+        </object>\n  \
+        </resources>\n  \
+        <build>\n    \
+        <item objectid=\"2\" />\n  \
+        </build>\n\
+        </model>",
+    )?;
+
+    Ok(())
+}
+
+/// Collect the distinct colors of the mesh, in order
+fn distinct_colors(mesh: &Mesh<Point<3>>) -> Vec<Color> {
+    let mut colors = mesh
+        .triangles()
+        .map(|triangle| triangle.color)
+        .collect::<Vec<_>>();
+    colors.sort();
+    colors.dedup();
+    colors
+}
+
+/// Scale the mesh according to the units
+fn scale_mesh(
+    mesh: &Mesh<Point<3>>,
+    units: Units,
+) -> Result<Mesh<Point<3>>, Error> {
+    if !(units.scale.is_finite() && units.scale > 0.) {
+        return Err(Error::InvalidScale(units.scale));
+    }
+
+    if units.scale == 1. {
+        return Ok(mesh.clone());
+    }
+
+    Ok(mesh.transform(&Transform::scale(units.scale)))
+}
+
+fn export_stl(
+    mesh: &Mesh<Point<3>>,
+    unit: LengthUnit,
+    path: &Path,
+) -> Result<(), Error> {
     let points = mesh
         .triangles()
         .map(|triangle| triangle.inner.points())
@@ -100,9 +255,15 @@ fn export_stl(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
 
     let mut file = File::create(path)?;
 
+    // STL doesn't have a way to specify units, but the header is free-form
+    // text, so we can at least leave a note there.
+    let mut header = [0u8; 80];
+    let note = format!("Exported by Fornjot; unit: {}", unit.name());
+    header[..note.len()].copy_from_slice(note.as_bytes());
+
     let binary_stl_file = stl::BinaryStlFile {
         header: stl::BinaryStlHeader {
-            header: [0u8; 80],
+            header,
             num_triangles: triangles
                 .len()
                 .try_into()
@@ -116,10 +277,38 @@ fn export_stl(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
     Ok(())
 }
 
-fn export_obj(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
+fn export_obj(
+    mesh: &Mesh<Point<3>>,
+    unit: LengthUnit,
+    path: &Path,
+) -> Result<(), Error> {
+    // OBJ files can't store colors. Those go into a material library, which
+    // is written next to the OBJ file.
+    let mtl_path = path.with_extension("mtl");
+    let mut mtl = BufWriter::new(File::create(&mtl_path)?);
+    for color in distinct_colors(mesh) {
+        let [r, g, b, a] = color.0.map(|channel| f64::from(channel) / 255.);
+        writeln!(mtl, "newmtl {}", material_name(color))?;
+        writeln!(mtl, "Kd {r} {g} {b}")?;
+        writeln!(mtl, "d {a}")?;
+    }
+    mtl.flush()?;
+
     let mut f = File::create(path)?;
 
+    writeln!(f, "# unit: {}", unit.name())?;
+    if let Some(name) = mtl_path.file_name() {
+        writeln!(f, "mtllib {}", name.to_string_lossy())?;
+    }
+
+    let mut current_color = None;
+
     for (cnt, t) in mesh.triangles().enumerate() {
+        if current_color != Some(t.color) {
+            writeln!(f, "usemtl {}", material_name(t.color))?;
+            current_color = Some(t.color);
+        }
+
         // write each point of the triangle
         for v in t.inner.points() {
             wavefront_rs::obj::writer::Writer { auto_newline: true }
@@ -165,6 +354,328 @@ fn export_obj(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
     Ok(())
 }
 
+/// The name of the OBJ material for a color
+fn material_name(color: Color) -> String {
+    let hex = color.to_hex();
+    format!("color_{}", hex.trim_start_matches('#'))
+}
+
+/// Export the provided mesh as JSON to the file at the given path.
+///
+/// This function will create a file if it does not exist, and will truncate it
+/// if it does.
+///
+/// The JSON document is an object with the following fields:
+///
+/// - `vertices`: An array of vertices, each an array of the form `[x, y, z]`.
+/// - `triangles`: An array of triangles, each an array of the form
+///   `[i, j, k]`, where `i`, `j`, and `k` are indices into `vertices`.
+/// - `colors`: An array with one RGBA color per triangle, each an array of the
+///   form `[r, g, b, a]`, with values from 0 to 255.
+/// - `unit`: The unit of length of the coordinates, as a string like
+///   `"millimeter"`. See [`LengthUnit::name`].
+///
+/// The mesh does not record which face a triangle came from, so there is no
+/// per-face grouping.
+///
+/// Uses the default [`Units`]. Use [`export_with_units`] with a path that ends
+/// in `.json`, to specify others.
+pub fn export_mesh_json(
+    mesh: &Mesh<Point<3>>,
+    path: &Path,
+) -> Result<(), Error> {
+    let mut f = BufWriter::new(File::create(path)?);
+    write_mesh_json(mesh, Units::default().unit, &mut f)?;
+    f.flush()?;
+
+    Ok(())
+}
+
+fn write_mesh_json(
+    mesh: &Mesh<Point<3>>,
+    unit: LengthUnit,
+    mut f: impl Write,
+) -> Result<(), Error> {
+    let vertices = mesh
+        .vertices()
+        .map(|point| format!("[{},{},{}]", point.x, point.y, point.z))
+        .collect::<Vec<_>>();
+
+    let indices = mesh.indices().collect::<Vec<_>>();
+    let triangles = indices
+        .chunks(3)
+        .map(|triangle| {
+            format!("[{},{},{}]", triangle[0], triangle[1], triangle[2])
+        })
+        .collect::<Vec<_>>();
+
+    let colors = mesh
+        .triangles()
+        .map(|triangle| {
+            let [r, g, b, a] = triangle.color.0;
+            format!("[{r},{g},{b},{a}]")
+        })
+        .collect::<Vec<_>>();
+
+    writeln!(
+        f,
+        "{{\"vertices\":[{}],\"triangles\":[{}],\"colors\":[{}],\
+        \"unit\":\"{}\"}}",
+        vertices.join(","),
+        triangles.join(","),
+        colors.join(","),
+        unit.name(),
+    )?;
+
+    Ok(())
+}
+
+/// Export the provided 2D drawing to the file at the given path.
+///
+/// This function will create a file if it does not exist, and will truncate it
+/// if it does.
+///
+/// Currently SVG & DXF file types are supported. The case insensitive file
+/// extension of the provided path is used to switch between supported types.
+pub fn export_drawing(drawing: &Drawing, path: &Path) -> Result<(), Error> {
+    match path.extension() {
+        Some(extension) if extension.to_ascii_uppercase() == "SVG" => {
+            export_svg(drawing, path)
+        }
+        Some(extension) if extension.to_ascii_uppercase() == "DXF" => {
+            export_dxf(drawing, path)
+        }
+        Some(extension) => Err(Error::InvalidExtension(
+            extension.to_string_lossy().into_owned(),
+        )),
+        None => Err(Error::NoExtension),
+    }
+}
+
+fn export_svg(drawing: &Drawing, path: &Path) -> Result<(), Error> {
+    let mut f = BufWriter::new(File::create(path)?);
+
+    let points = drawing
+        .segments()
+        .chain(drawing.hidden_segments())
+        .flat_map(|segment| segment.points())
+        .collect::<Vec<_>>();
+    let aabb = if points.is_empty() {
+        Aabb {
+            min: Point::origin(),
+            max: Point::origin(),
+        }
+    } else {
+        Aabb::<2>::from_points(points)
+    };
+    let size = aabb.max - aabb.min;
+
+    // SVG's y-axis points down, so we need to flip the drawing. That also
+    // means the upper edge of the view box is the negated maximum.
+    writeln!(
+        f,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+        viewBox=\"{} {} {} {}\">",
+        aabb.min.x, -aabb.max.y, size.x, size.y,
+    )?;
+
+    // Visible and hidden segments go into separate groups, so they can be
+    // styled (or left out) separately.
+    let layers = [
+        ("visible", "", drawing.segments().collect::<Vec<_>>()),
+        (
+            "hidden",
+            " stroke-dasharray=\"4 2\"",
+            drawing.hidden_segments().collect(),
+        ),
+    ];
+
+    for (id, style, segments) in layers {
+        writeln!(f, "  <g id=\"{id}\" stroke=\"black\" fill=\"none\"{style}>")?;
+
+        for segment in segments {
+            let [a, b] = segment.points();
+
+            writeln!(
+                f,
+                "    <line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" \
+                vector-effect=\"non-scaling-stroke\" />",
+                a.x, -a.y, b.x, -b.y,
+            )?;
+        }
+
+        writeln!(f, "  </g>")?;
+    }
+
+    writeln!(f, "</svg>")?;
+    f.flush()?;
+
+    Ok(())
+}
+
+fn export_dxf(drawing: &Drawing, path: &Path) -> Result<(), Error> {
+    let mut f = BufWriter::new(File::create(path)?);
+    write_dxf(drawing, &mut f)?;
+    f.flush()?;
+
+    Ok(())
+}
+
+fn write_dxf(drawing: &Drawing, mut f: impl Write) -> Result<(), Error> {
+    // DXF files consist of pairs of lines: a group code, then a value. We write
+    // a minimal R12 file, which is the version that the most applications can
+    // read.
+    writeln!(f, "0\nSECTION\n2\nHEADER")?;
+    writeln!(f, "9\n$ACADVER\n1\nAC1009")?;
+    writeln!(f, "0\nENDSEC")?;
+
+    // Line types and layers must be declared, before entities can use them.
+    // Strict readers reject or ignore undeclared ones.
+    writeln!(f, "0\nSECTION\n2\nTABLES")?;
+
+    writeln!(f, "0\nTABLE\n2\nLTYPE\n70\n2")?;
+    writeln!(f, "0\nLTYPE\n2\nCONTINUOUS\n70\n0\n3\nSolid line")?;
+    writeln!(f, "72\n65\n73\n0\n40\n0.0")?;
+    writeln!(f, "0\nLTYPE\n2\nDASHED\n70\n0\n3\nDashed __ __ __")?;
+    writeln!(f, "72\n65\n73\n2\n40\n0.75\n49\n0.5\n49\n-0.25")?;
+    writeln!(f, "0\nENDTAB")?;
+
+    writeln!(f, "0\nTABLE\n2\nLAYER\n70\n2")?;
+    for (layer, line_type) in DXF_LAYERS {
+        writeln!(f, "0\nLAYER\n2\n{layer}\n70\n0\n62\n7\n6\n{line_type}")?;
+    }
+    writeln!(f, "0\nENDTAB")?;
+
+    writeln!(f, "0\nENDSEC")?;
+
+    writeln!(f, "0\nSECTION\n2\nENTITIES")?;
+
+    // Visible and hidden segments go on separate layers. Hidden segments use
+    // the dashed line type.
+    let [visible, hidden] = DXF_LAYERS;
+    let segments = drawing
+        .segments()
+        .map(|segment| (segment, visible))
+        .chain(drawing.hidden_segments().map(|segment| (segment, hidden)));
+
+    for (segment, (layer, line_type)) in segments {
+        let [a, b] = segment.points();
+
+        writeln!(f, "0\nLINE\n8\n{layer}\n6\n{line_type}")?;
+        writeln!(f, "10\n{}\n20\n{}\n30\n0.0", a.x, a.y)?;
+        writeln!(f, "11\n{}\n21\n{}\n31\n0.0", b.x, b.y)?;
+    }
+
+    writeln!(f, "0\nENDSEC\n0\nEOF")?;
+
+    Ok(())
+}
+
+/// The layers of an exported DXF file, and their line types
+const DXF_LAYERS: [(&str, &str); 2] =
+    [("VISIBLE", "CONTINUOUS"), ("HIDDEN", "DASHED")];
+
+/// The units of an exported file
+///
+/// Specifies how coordinates of the model are converted into coordinates of
+/// the exported file, and which unit of length those are in. The default is to
+/// treat one unit of the model as one millimeter.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Units {
+    /// The unit of length of the coordinates in the exported file
+    ///
+    /// File formats that support it are annotated with this unit.
+    pub unit: LengthUnit,
+
+    /// The factor that model coordinates are multiplied with on export
+    ///
+    /// Must be positive.
+    pub scale: f64,
+}
+
+impl Units {
+    /// Treat one unit of the model as one millimeter, scaled by the factor
+    ///
+    /// For example, a factor of `25.4` treats one unit of the model as one
+    /// inch.
+    pub fn scaled(scale: f64) -> Self {
+        Self {
+            unit: LengthUnit::Millimeter,
+            scale,
+        }
+    }
+
+    /// Treat one unit of the model as the provided unit of length
+    ///
+    /// The exported file uses the same unit of length. Formats that can't
+    /// represent it are converted to millimeters.
+    pub fn model_unit(unit: LengthUnit) -> Self {
+        Self { unit, scale: 1. }
+    }
+
+    /// Convert the units into ones that result in millimeters
+    fn to_millimeters(self) -> Self {
+        Self {
+            unit: LengthUnit::Millimeter,
+            scale: self.scale * self.unit.in_millimeters(),
+        }
+    }
+}
+
+impl Default for Units {
+    fn default() -> Self {
+        Self::scaled(1.)
+    }
+}
+
+/// A unit of length
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LengthUnit {
+    /// Micrometers
+    Micron,
+
+    /// Millimeters
+    Millimeter,
+
+    /// Centimeters
+    Centimeter,
+
+    /// Meters
+    Meter,
+
+    /// Inches
+    Inch,
+
+    /// Feet
+    Foot,
+}
+
+impl LengthUnit {
+    /// The name of the unit, as used by 3MF files
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Micron => "micron",
+            Self::Millimeter => "millimeter",
+            Self::Centimeter => "centimeter",
+            Self::Meter => "meter",
+            Self::Inch => "inch",
+            Self::Foot => "foot",
+        }
+    }
+
+    /// The length of the unit, in millimeters
+    pub fn in_millimeters(&self) -> f64 {
+        match self {
+            Self::Micron => 0.001,
+            Self::Millimeter => 1.,
+            Self::Centimeter => 10.,
+            Self::Meter => 1000.,
+            Self::Inch => 25.4,
+            Self::Foot => 304.8,
+        }
+    }
+}
+
 /// An error that can occur while exporting
 #[derive(Debug, Error)]
 pub enum Error {
@@ -184,11 +695,243 @@ pub enum Error {
     #[error("maximum triangle count exceeded")]
     InvalidTriangleCount,
 
-    /// Threemf error whilst exporting to 3MF file
-    #[error("threemf error whilst exporting to 3MF file")]
-    ThreeMF(#[from] threemf::Error),
+    /// Error writing the archive of a 3MF file
+    #[error("zip error whilst exporting to 3MF file")]
+    Zip(#[from] zip::result::ZipError),
 
     /// OBJ exporter error whilst exporting to OBJ file
     #[error("obj error whilst exporting to OBJ file")]
     OBJ,
+
+    /// The scale factor of the units is not positive
+    #[error("invalid scale factor `{0}`; must be positive")]
+    InvalidScale(f64),
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::{
+        drawing::Drawing,
+        mesh::{Color, Mesh},
+    };
+    use fj_math::Point;
+
+    use super::{
+        scale_mesh, write_3mf_model, write_dxf, write_mesh_json, LengthUnit,
+        Units,
+    };
+
+    #[test]
+    fn mesh_json_round_trip() -> anyhow::Result<()> {
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+            Color([255, 0, 0, 255]),
+        );
+        mesh.push_triangle(
+            [[1., 0., 0.], [1., 1., 0.5], [0., 1., 0.]],
+            Color([0, 128, 255, 64]),
+        );
+
+        let mut json = Vec::new();
+        write_mesh_json(&mesh, LengthUnit::Millimeter, &mut json)?;
+        let json: serde_json::Value = serde_json::from_slice(&json)?;
+
+        let vertices =
+            json["vertices"]
+                .as_array()
+                .expect("Expected array of vertices")
+                .iter()
+                .map(|vertex| {
+                    Point::from([0, 1, 2].map(|i| {
+                        vertex[i].as_f64().expect("Expected coordinate")
+                    }))
+                })
+                .collect::<Vec<_>>();
+        let triangles = json["triangles"]
+            .as_array()
+            .expect("Expected array of triangles");
+        let colors =
+            json["colors"].as_array().expect("Expected array of colors");
+
+        let mut parsed = Mesh::new();
+        for (triangle, color) in triangles.iter().zip(colors) {
+            let points = [0, 1, 2].map(|i| {
+                let index = triangle[i].as_u64().expect("Expected index");
+                vertices[index as usize]
+            });
+            let color = [0, 1, 2, 3].map(|i| {
+                color[i]
+                    .as_u64()
+                    .and_then(|value| u8::try_from(value).ok())
+                    .expect("Expected color component")
+            });
+
+            parsed.push_triangle(points, Color(color));
+        }
+
+        assert_eq!(
+            parsed.triangles().collect::<Vec<_>>(),
+            mesh.triangles().collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            parsed.vertices().collect::<Vec<_>>(),
+            mesh.vertices().collect::<Vec<_>>(),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn materials_of_3mf_model() -> anyhow::Result<()> {
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+            Color::BLUE,
+        );
+        mesh.push_triangle(
+            [[1., 0., 0.], [1., 1., 0.], [0., 1., 0.]],
+            Color::YELLOW,
+        );
+        mesh.push_triangle(
+            [[0., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+            Color::BLUE,
+        );
+
+        let mut model = Vec::new();
+        write_3mf_model(&mesh, &mut model)?;
+        let model = String::from_utf8(model)?;
+
+        let attribute = |line: &str, name: &str| {
+            let (_, value) = line
+                .split_once(&format!(" {name}=\""))
+                .expect("Expected attribute");
+            let (value, _) =
+                value.split_once('"').expect("Expected end of attribute");
+            value.to_owned()
+        };
+
+        let materials = model
+            .lines()
+            .filter(|line| line.trim_start().starts_with("<base "))
+            .map(|line| Color::from_hex(&attribute(line, "displaycolor")))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(materials.len(), 2);
+
+        let colors = model
+            .lines()
+            .filter(|line| line.trim_start().starts_with("<triangle "))
+            .map(|line| {
+                let index: usize = attribute(line, "p1").parse()?;
+                Ok(materials[index])
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        assert_eq!(
+            colors,
+            mesh.triangles()
+                .map(|triangle| triangle.color)
+                .collect::<Vec<_>>(),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn export_scaled_cube() -> anyhow::Result<()> {
+        let mut cube = Mesh::new();
+        for axis in 0..3 {
+            for side in [0., 1.] {
+                let [a, b, c, d] = [[0., 0.], [1., 0.], [1., 1.], [0., 1.]]
+                    .map(|[u, v]| {
+                        let mut point = [0.; 3];
+                        point[axis] = side;
+                        point[(axis + 1) % 3] = u;
+                        point[(axis + 2) % 3] = v;
+                        Point::from(point)
+                    });
+
+                cube.push_triangle([a, b, c], Color::default());
+                cube.push_triangle([a, c, d], Color::default());
+            }
+        }
+
+        // Treat one unit of the model as one inch.
+        let units = Units::scaled(25.4);
+
+        let mut json = Vec::new();
+        write_mesh_json(&scale_mesh(&cube, units)?, units.unit, &mut json)?;
+        let json: serde_json::Value = serde_json::from_slice(&json)?;
+
+        assert_eq!(json["unit"], "millimeter");
+
+        let vertices = json["vertices"]
+            .as_array()
+            .expect("Expected array of vertices");
+        assert_eq!(vertices.len(), 8);
+        for vertex in vertices {
+            for i in 0..3 {
+                let coord = vertex[i].as_f64().expect("Expected coordinate");
+                assert!(coord == 0. || (coord - 25.4).abs() < 1e-12);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_scale() {
+        let mesh = Mesh::new();
+        for scale in [0., -1., f64::NAN, f64::INFINITY] {
+            assert!(scale_mesh(&mesh, Units::scaled(scale)).is_err());
+        }
+        assert!(scale_mesh(&mesh, Units::model_unit(LengthUnit::Inch)).is_ok());
+    }
+
+    #[test]
+    fn dxf_declares_line_types_and_layers() -> anyhow::Result<()> {
+        let mut drawing = Drawing::new();
+        drawing.push_segment([[0., 0.], [1., 0.]]);
+        drawing.push_hidden_segment([[0., 1.], [1., 1.]]);
+
+        let mut dxf = Vec::new();
+        write_dxf(&drawing, &mut dxf)?;
+        let dxf = String::from_utf8(dxf)?;
+
+        // The file consists of pairs of group codes and values.
+        let pairs = dxf
+            .lines()
+            .collect::<Vec<_>>()
+            .chunks(2)
+            .map(|pair| (pair[0], pair[1]))
+            .collect::<Vec<_>>();
+        let position = |pair: (&str, &str)| {
+            pairs.iter().position(|&p| p == pair).unwrap_or_else(|| {
+                panic!("Expected {pair:?} in DXF file:\n{dxf}")
+            })
+        };
+
+        let header = position(("2", "HEADER"));
+        let tables = position(("2", "TABLES"));
+        let entities = position(("2", "ENTITIES"));
+        assert!(header < tables && tables < entities);
+
+        for name in ["CONTINUOUS", "DASHED", "VISIBLE", "HIDDEN"] {
+            let declaration = position(("2", name));
+            assert!(tables < declaration && declaration < entities);
+        }
+
+        let hidden_line = pairs
+            .windows(3)
+            .position(|pairs| {
+                pairs
+                    == [("0", "LINE"), ("8", "HIDDEN"), ("6", "DASHED")]
+                        .as_slice()
+            })
+            .expect("Expected hidden line");
+        assert!(hidden_line > entities);
+
+        assert_eq!(pairs.last(), Some(&("0", "EOF")));
+
+        Ok(())
+    }
 }
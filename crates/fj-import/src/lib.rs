@@ -0,0 +1,443 @@
+//! # Fornjot Importer
+//!
+//! [Fornjot] is an early-stage b-rep CAD kernel written in Rust. The kernel is
+//! split into multiple libraries that can be used semi-independently, and this
+//! is one of those.
+//!
+//! This library imports models from external file formats into Fornjot.
+//!
+//! [Fornjot]: https://www.fornjot.app/
+
+use std::{collections::BTreeMap, fs, path::Path};
+
+use thiserror::Error;
+
+use fj_core::{
+    algorithms::approx::Tolerance,
+    objects::Solid,
+    operations::build::{BuildSolid, FromTriangleMeshError, MergedPoints},
+    services::Services,
+};
+use fj_interop::mesh::{Color, Mesh};
+use fj_math::Point;
+
+/// Import the STL file at the given path
+///
+/// Both binary and ASCII STL files are supported. Vertices that are within
+/// `weld_tolerance` of each other are merged into a single vertex.
+///
+/// Problems with the file's content that don't prevent reading it, like holes
+/// in the mesh, are not treated as errors. They are reported in
+/// [`StlImport::issues`] instead.
+pub fn import_stl(
+    path: &Path,
+    weld_tolerance: impl Into<Tolerance>,
+) -> Result<StlImport, Error> {
+    let bytes = fs::read(path)?;
+    let triangles = read_stl(&bytes)?;
+
+    Ok(StlImport::from_triangles(triangles, weld_tolerance.into()))
+}
+
+/// The result of importing an STL file
+///
+/// Returned by [`import_stl`].
+#[derive(Clone, Debug)]
+pub struct StlImport {
+    /// The triangle mesh that was read from the file
+    ///
+    /// Degenerate triangles are not included. See
+    /// [`MeshIssue::DegenerateTriangle`].
+    pub mesh: Mesh<Point<3>>,
+
+    /// The issues that were found in the mesh
+    ///
+    /// If there are any, the mesh can't be converted into a solid.
+    pub issues: Vec<MeshIssue>,
+
+    tolerance: Tolerance,
+}
+
+impl StlImport {
+    fn from_triangles(
+        triangles: Vec<[Point<3>; 3]>,
+        tolerance: Tolerance,
+    ) -> Self {
+        let mut points = MergedPoints::new(tolerance);
+        let mut mesh = Mesh::new();
+        let mut issues = Vec::new();
+
+        // Indices of the directed edges of all triangles, counted per edge.
+        let mut edges = BTreeMap::<_, usize>::new();
+
+        for triangle in triangles {
+            let [a, b, c] = triangle.map(|point| points.index_of(point));
+
+            if points.is_degenerate([a, b, c]) {
+                issues.push(MeshIssue::DegenerateTriangle { triangle });
+                continue;
+            }
+
+            for edge in [[a, b], [b, c], [c, a]] {
+                *edges.entry(edge).or_default() += 1;
+            }
+
+            mesh.push_triangle(
+                [a, b, c].map(|i| points.point(i)),
+                Color::default(),
+            );
+        }
+
+        for (&[a, b], &count) in &edges {
+            // Only look at each edge once, from the direction in which it
+            // exists.
+            let count_reversed = edges.get(&[b, a]).copied().unwrap_or(0);
+            if count_reversed > 0 && b < a {
+                continue;
+            }
+
+            let vertices = [a, b].map(|i| points.point(i));
+            match (count, count_reversed) {
+                (1, 1) => {}
+                (1, 0) => {
+                    issues.push(MeshIssue::OpenEdge { vertices });
+                }
+                _ => {
+                    issues.push(MeshIssue::NonManifoldEdge { vertices });
+                }
+            }
+        }
+
+        Self {
+            mesh,
+            issues,
+            tolerance,
+        }
+    }
+
+    /// Convert the imported mesh into a solid
+    ///
+    /// Coplanar triangles are merged into planar faces. See
+    /// [`BuildShell::from_triangle_mesh`] for details.
+    ///
+    /// Returns an error, if any issues were found in the mesh, or if building
+    /// the solid fails for another reason.
+    ///
+    /// [`BuildShell::from_triangle_mesh`]: fj_core::operations::build::BuildShell::from_triangle_mesh
+    pub fn to_solid(&self, services: &mut Services) -> Result<Solid, Error> {
+        if !self.issues.is_empty() {
+            return Err(Error::InvalidMesh(self.issues.clone()));
+        }
+
+        Ok(Solid::from_triangle_mesh(
+            &self.mesh,
+            self.tolerance,
+            services,
+        )?)
+    }
+}
+
+/// An issue that was found in an imported mesh
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MeshIssue {
+    /// A triangle doesn't span any area, within the weld tolerance
+    ///
+    /// This is the case, if some of its vertices were welded together, or if
+    /// one of them is within the tolerance of the line through the other two.
+    DegenerateTriangle {
+        /// The points of the triangle, before welding
+        triangle: [Point<3>; 3],
+    },
+
+    /// An edge is only part of a single triangle
+    OpenEdge {
+        /// The vertices of the edge
+        vertices: [Point<3>; 2],
+    },
+
+    /// An edge is not shared by exactly two consistently oriented triangles
+    NonManifoldEdge {
+        /// The vertices of the edge
+        vertices: [Point<3>; 2],
+    },
+}
+
+/// An error that can occur while importing
+#[derive(Debug, Error)]
+pub enum Error {
+    /// I/O error whilst importing from file
+    #[error("I/O error whilst importing from file")]
+    Io(#[from] std::io::Error),
+
+    /// The STL file is malformed
+    #[error("malformed STL file: {0}")]
+    MalformedStl(String),
+
+    /// The mesh can't be converted into a solid
+    #[error("mesh has {} issues and can't be converted into a solid", .0.len())]
+    InvalidMesh(Vec<MeshIssue>),
+
+    /// Building a solid from the mesh failed
+    #[error("failed to build solid from mesh")]
+    Solid(#[from] FromTriangleMeshError),
+}
+
+fn read_stl(bytes: &[u8]) -> Result<Vec<[Point<3>; 3]>, Error> {
+    // ASCII files start with "solid", but so do some binary files. The size of
+    // a binary file is determined by its triangle count though, which makes it
+    // possible to tell them apart.
+    let is_binary = match bytes.get(80..84) {
+        Some(&[a, b, c, d]) => {
+            let num_triangles = u32::from_le_bytes([a, b, c, d]) as usize;
+            bytes.len() == 84 + num_triangles * 50
+        }
+        _ => false,
+    };
+
+    if is_binary {
+        read_binary_stl(bytes)
+    } else if bytes.starts_with(b"solid") {
+        read_ascii_stl(bytes)
+    } else {
+        Err(Error::MalformedStl(
+            "size of binary STL doesn't match its triangle count".to_owned(),
+        ))
+    }
+}
+
+fn read_binary_stl(mut bytes: &[u8]) -> Result<Vec<[Point<3>; 3]>, Error> {
+    let file = stl::read_stl(&mut bytes).map_err(|err| {
+        Error::MalformedStl(format!("can't read binary STL: {err}"))
+    })?;
+
+    Ok(file
+        .triangles
+        .into_iter()
+        .map(|triangle| {
+            [triangle.v1, triangle.v2, triangle.v3]
+                .map(|vertex| Point::from(vertex.map(f64::from)))
+        })
+        .collect())
+}
+
+fn read_ascii_stl(bytes: &[u8]) -> Result<Vec<[Point<3>; 3]>, Error> {
+    let text = std::str::from_utf8(bytes).map_err(|_| {
+        Error::MalformedStl("ASCII STL is not valid UTF-8".to_owned())
+    })?;
+
+    let mut triangles = Vec::new();
+    let mut vertices = Vec::new();
+
+    for (i, line) in text.lines().enumerate() {
+        let malformed = |message: &str| {
+            Error::MalformedStl(format!("line {}: {message}", i + 1))
+        };
+
+        let mut tokens = line.split_whitespace();
+        match tokens.next() {
+            Some("vertex") => {
+                let mut coords = [0.; 3];
+                for coord in &mut coords {
+                    *coord = tokens
+                        .next()
+                        .and_then(|token| token.parse().ok())
+                        .ok_or_else(|| malformed("expected 3 coordinates"))?;
+                }
+
+                vertices.push(Point::from(coords));
+            }
+            Some("outer") => {
+                vertices.clear();
+            }
+            Some("endloop") => {
+                let triangle: [Point<3>; 3] = vertices
+                    .as_slice()
+                    .try_into()
+                    .map_err(|_| malformed("expected 3 vertices per facet"))?;
+                triangles.push(triangle);
+            }
+            // Normals are ignored. They can be computed from the vertices,
+            // and are often wrong anyway.
+            Some("solid" | "facet" | "endfacet" | "endsolid") | None => {}
+            Some(token) => {
+                return Err(malformed(&format!("unexpected `{token}`")));
+            }
+        }
+    }
+
+    Ok(triangles)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_core::{algorithms::approx::Tolerance, services::Services};
+    use fj_math::Point;
+
+    use super::{read_stl, MeshIssue, StlImport};
+
+    const TETRAHEDRON: &str = "\
+solid tetrahedron
+  facet normal 0 0 -1
+    outer loop
+      vertex 0 0 0
+      vertex 0 1 0
+      vertex 1 0 0
+    endloop
+  endfacet
+  facet normal -1 0 0
+    outer loop
+      vertex 0 1 0
+      vertex 0 0 0
+      vertex 0 0 1
+    endloop
+  endfacet
+  facet normal 0 -1 0
+    outer loop
+      vertex 0 0 1
+      vertex 0 0 0
+      vertex 1 0 0
+    endloop
+  endfacet
+  facet normal 1 1 1
+    outer loop
+      vertex 1 0 0
+      vertex 0 1 0
+      vertex 0 0 1
+    endloop
+  endfacet
+endsolid tetrahedron
+";
+
+    #[test]
+    fn ascii() -> anyhow::Result<()> {
+        let triangles = read_stl(TETRAHEDRON.as_bytes())?;
+
+        assert_eq!(triangles.len(), 4);
+        assert_eq!(
+            triangles[0],
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.]].map(Point::from),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn binary() -> anyhow::Result<()> {
+        let triangles = read_stl(TETRAHEDRON.as_bytes())?;
+
+        let file = stl::BinaryStlFile {
+            header: stl::BinaryStlHeader {
+                // Binary files may start with "solid" too. This must not
+                // confuse the importer.
+                header: {
+                    let mut header = [0; 80];
+                    header[..5].copy_from_slice(b"solid");
+                    header
+                },
+                num_triangles: triangles.len() as u32,
+            },
+            triangles: triangles
+                .iter()
+                .map(|triangle| {
+                    let [v1, v2, v3] = triangle.map(|point| {
+                        point.coords.components.map(|s| s.into_f32())
+                    });
+                    stl::Triangle {
+                        normal: [0.; 3],
+                        v1,
+                        v2,
+                        v3,
+                        attr_byte_count: 0,
+                    }
+                })
+                .collect(),
+        };
+        let mut bytes = Vec::new();
+        stl::write_stl(&mut bytes, &file)?;
+
+        assert_eq!(read_stl(&bytes)?, triangles);
+
+        Ok(())
+    }
+
+    #[test]
+    fn malformed() {
+        let invalid = TETRAHEDRON.replace("vertex 0 0 1", "vertex 0 0");
+        assert!(read_stl(invalid.as_bytes()).is_err());
+
+        assert!(read_stl(&[0; 100]).is_err());
+    }
+
+    #[test]
+    fn issues() -> anyhow::Result<()> {
+        // Leave out the last triangle, which opens up the mesh.
+        let triangles = read_stl(TETRAHEDRON.as_bytes())?;
+        let import = StlImport::from_triangles(
+            triangles[..3].to_vec(),
+            Tolerance::from_scalar(0.001)?,
+        );
+
+        assert_eq!(import.issues.len(), 3);
+        assert!(import
+            .issues
+            .iter()
+            .all(|issue| matches!(issue, MeshIssue::OpenEdge { .. })));
+
+        let mut services = Services::new();
+        assert!(import.to_solid(&mut services).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn collinear_triangle() -> anyhow::Result<()> {
+        let mut triangles = read_stl(TETRAHEDRON.as_bytes())?;
+
+        // The vertices of this triangle are distinct, but one of them is
+        // within the tolerance of the line through the other two.
+        let sliver =
+            [[0., 0., 0.], [0.5, 1e-6, 0.], [1., 0., 0.]].map(Point::from);
+        triangles.push(sliver);
+
+        let import = StlImport::from_triangles(
+            triangles,
+            Tolerance::from_scalar(0.001)?,
+        );
+        assert_eq!(
+            import.issues,
+            [MeshIssue::DegenerateTriangle { triangle: sliver }],
+        );
+
+        let mut services = Services::new();
+        assert!(import.to_solid(&mut services).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn to_solid() -> anyhow::Result<()> {
+        let mut triangles = read_stl(TETRAHEDRON.as_bytes())?;
+
+        // Vertices that are close enough are welded together.
+        triangles[0][0] = Point::from([1e-6, 0., 0.]);
+
+        let import = StlImport::from_triangles(
+            triangles,
+            Tolerance::from_scalar(0.001)?,
+        );
+        assert!(import.issues.is_empty());
+        assert_eq!(import.mesh.vertices().count(), 4);
+
+        let mut services = Services::new();
+        let solid = import.to_solid(&mut services)?;
+
+        let num_faces = solid
+            .shells()
+            .iter()
+            .map(|shell| shell.faces().len())
+            .sum::<usize>();
+        assert_eq!(num_faces, 4);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+//! A 2D drawing
+
+use fj_math::{Point, Segment};
+
+/// A 2D drawing, made up of line segments
+///
+/// A drawing is the result of projecting a model onto a plane. It can be
+/// exported to 2D file formats, for use in manufacturing documentation.
+///
+/// Segments are either visible, or hidden behind other parts of the model.
+/// Hidden segments are kept separately, so they can be displayed differently
+/// (for example as dashed lines), or left out.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Drawing {
+    segments: Vec<Segment<2>>,
+    hidden_segments: Vec<Segment<2>>,
+}
+
+impl Drawing {
+    /// Construct a new instance of `Drawing`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a visible line segment to the drawing
+    ///
+    /// Segments that are already part of the drawing (in either direction) are
+    /// ignored, as are segments whose points are identical. If the segment has
+    /// previously been added as a hidden segment, it is made visible.
+    pub fn push_segment(&mut self, points: [impl Into<Point<2>>; 2]) {
+        let Some(segment) = segment_from_points(points) else {
+            return;
+        };
+
+        if self.contains_segment(segment) {
+            return;
+        }
+
+        self.hidden_segments
+            .retain(|&s| s != segment && s != segment.reverse());
+        self.segments.push(segment);
+    }
+
+    /// Add a hidden line segment to the drawing
+    ///
+    /// Segments that are already part of the drawing (in either direction),
+    /// whether visible or hidden, are ignored, as are segments whose points are
+    /// identical.
+    pub fn push_hidden_segment(&mut self, points: [impl Into<Point<2>>; 2]) {
+        let Some(segment) = segment_from_points(points) else {
+            return;
+        };
+
+        if self.contains_segment(segment)
+            || self.contains_hidden_segment(segment)
+        {
+            return;
+        }
+
+        self.hidden_segments.push(segment);
+    }
+
+    /// Determine whether the drawing contains the provided visible segment
+    ///
+    /// Returns true, if a visible segment with the same points, in either
+    /// direction, is part of the drawing.
+    pub fn contains_segment(&self, segment: impl Into<Segment<2>>) -> bool {
+        contains(&self.segments, segment.into())
+    }
+
+    /// Determine whether the drawing contains the provided hidden segment
+    ///
+    /// Returns true, if a hidden segment with the same points, in either
+    /// direction, is part of the drawing.
+    pub fn contains_hidden_segment(
+        &self,
+        segment: impl Into<Segment<2>>,
+    ) -> bool {
+        contains(&self.hidden_segments, segment.into())
+    }
+
+    /// Access the visible segments of the drawing
+    pub fn segments(&self) -> impl Iterator<Item = Segment<2>> + '_ {
+        self.segments.iter().copied()
+    }
+
+    /// Access the hidden segments of the drawing
+    pub fn hidden_segments(&self) -> impl Iterator<Item = Segment<2>> + '_ {
+        self.hidden_segments.iter().copied()
+    }
+}
+
+fn segment_from_points(points: [impl Into<Point<2>>; 2]) -> Option<Segment<2>> {
+    let [a, b] = points.map(Into::into);
+
+    if a == b {
+        return None;
+    }
+
+    Some(Segment::from_points([a, b]))
+}
+
+fn contains(segments: &[Segment<2>], segment: Segment<2>) -> bool {
+    segments
+        .iter()
+        .any(|&s| s == segment || s == segment.reverse())
+}
@@ -9,6 +9,10 @@
 //!
 //! [Fornjot]: https://www.fornjot.app/
 
+pub mod drawing;
 pub mod ext;
 pub mod mesh;
 pub mod model;
+pub mod point_cloud;
+
+mod simplify;
@@ -2,7 +2,7 @@
 
 use std::{collections::HashMap, hash::Hash};
 
-use fj_math::Point;
+use fj_math::{Point, Transform};
 
 /// A triangle mesh
 #[derive(Clone, Debug)]
@@ -89,6 +89,55 @@ impl Mesh<Point<3>> {
             color,
         });
     }
+
+    /// Sort the triangles of the mesh into a canonical order
+    ///
+    /// The points of each triangle are rotated, without changing its
+    /// orientation, so that its smallest point comes first. Then the triangles
+    /// are sorted by their points, and the vertices are re-indexed in order of
+    /// their first appearance.
+    ///
+    /// Meshes that contain the same triangles end up identical, regardless of
+    /// the order the triangles were added in. This keeps the output of
+    /// triangulation stable, which matters when comparing it against a stored
+    /// result.
+    pub fn sort_triangles(&mut self) {
+        let mut triangles = self
+            .triangles
+            .iter()
+            .map(|triangle| {
+                let points = triangle.inner.points();
+                let first = (0..3)
+                    .min_by_key(|&i| points[i])
+                    .expect("Triangle has three points");
+                let points = [0, 1, 2].map(|i| points[(first + i) % 3]);
+
+                (points, triangle.color)
+            })
+            .collect::<Vec<_>>();
+        triangles.sort();
+
+        let mut mesh = Self::new();
+        for (points, color) in triangles {
+            mesh.push_triangle(points, color);
+        }
+
+        *self = mesh;
+    }
+
+    /// Create a copy of the mesh, with the transform applied to all triangles
+    pub fn transform(&self, transform: &Transform) -> Self {
+        let mut mesh = Self::new();
+
+        for triangle in self.triangles() {
+            mesh.push_triangle(
+                transform.transform_triangle(&triangle.inner),
+                triangle.color,
+            );
+        }
+
+        mesh
+    }
 }
 
 // This needs to be a manual implementation. Deriving `Default` would require
@@ -120,12 +169,177 @@ pub struct Triangle {
 }
 
 /// RGBA color
+///
+/// The alpha channel determines the opacity of the color, with `255` being
+/// fully opaque and `0` being fully transparent.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct Color(pub [u8; 4]);
 
+impl Color {
+    /// Black
+    pub const BLACK: Self = Self([0, 0, 0, 255]);
+
+    /// White
+    pub const WHITE: Self = Self([255, 255, 255, 255]);
+
+    /// Red
+    pub const RED: Self = Self([255, 0, 0, 255]);
+
+    /// Green
+    pub const GREEN: Self = Self([0, 255, 0, 255]);
+
+    /// Blue
+    pub const BLUE: Self = Self([0, 0, 255, 255]);
+
+    /// Yellow
+    pub const YELLOW: Self = Self([255, 255, 0, 255]);
+
+    /// Orange
+    pub const ORANGE: Self = Self([255, 128, 0, 255]);
+
+    /// Gray
+    pub const GRAY: Self = Self([128, 128, 128, 255]);
+
+    /// The blueish gray of steel
+    pub const STEEL: Self = Self([113, 121, 126, 255]);
+
+    /// The light gray of aluminum
+    pub const ALUMINUM: Self = Self([200, 202, 205, 255]);
+
+    /// The yellowish color of brass
+    pub const BRASS: Self = Self([181, 166, 66, 255]);
+
+    /// Parse a color from a hex string
+    ///
+    /// Accepts strings of the form `#rrggbb`, which result in an opaque color,
+    /// or `#rrggbbaa`, which include an alpha channel. The leading `#` is
+    /// optional.
+    pub fn from_hex(hex: &str) -> Result<Self, InvalidHexColor> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+
+        let invalid = || InvalidHexColor(hex.to_owned());
+
+        if !(digits.len() == 6 || digits.len() == 8) || !digits.is_ascii() {
+            return Err(invalid());
+        }
+
+        let mut color = Self::BLACK;
+        for (channel, i) in color.0.iter_mut().zip((0..digits.len()).step_by(2))
+        {
+            *channel = u8::from_str_radix(&digits[i..i + 2], 16)
+                .map_err(|_| invalid())?;
+        }
+
+        Ok(color)
+    }
+
+    /// Convert the color into a hex string of the form `#rrggbbaa`
+    ///
+    /// The alpha channel is left out, if the color is opaque. The result can be
+    /// parsed by [`Color::from_hex`].
+    pub fn to_hex(&self) -> String {
+        let [r, g, b, a] = self.0;
+
+        if self.is_opaque() {
+            format!("#{r:02x}{g:02x}{b:02x}")
+        } else {
+            format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+        }
+    }
+
+    /// Return a copy of the color with the provided alpha value
+    pub fn with_alpha(self, alpha: u8) -> Self {
+        let [r, g, b, _] = self.0;
+        Self([r, g, b, alpha])
+    }
+
+    /// Determine whether the color is fully opaque
+    pub fn is_opaque(&self) -> bool {
+        self.0[3] == 255
+    }
+}
+
 impl Default for Color {
     fn default() -> Self {
         // The default color is red. This is an arbitrary choice.
-        Self([255, 0, 0, 255])
+        Self::RED
+    }
+}
+
+/// Error parsing a [`Color`] from a hex string
+///
+/// Returned by [`Color::from_hex`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[error("Invalid hex color `{0}`; expected `#rrggbb` or `#rrggbbaa`")]
+pub struct InvalidHexColor(pub String);
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use super::{Color, Mesh};
+
+    #[test]
+    fn hex_round_trip() {
+        for color in [
+            Color::STEEL,
+            Color::BLACK,
+            Color::WHITE,
+            Color([1, 35, 69, 103]),
+            Color::BRASS.with_alpha(0),
+        ] {
+            assert_eq!(Color::from_hex(&color.to_hex()), Ok(color));
+        }
+
+        assert_eq!(Color::from_hex("#ff8000"), Ok(Color::ORANGE));
+        assert_eq!(
+            Color::from_hex("FF800080"),
+            Ok(Color::ORANGE.with_alpha(128))
+        );
+        assert_eq!(Color::STEEL.to_hex(), "#71797e");
+    }
+
+    #[test]
+    fn invalid_hex() {
+        for hex in ["", "#", "#fff", "#ff800", "#ff80000", "#gg8000", "#ff80é"]
+        {
+            assert!(Color::from_hex(hex).is_err());
+        }
+    }
+
+    #[test]
+    fn sort_triangles() {
+        let [a, b, c, d] =
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]
+                .map(Point::from);
+
+        let mut mesh = Mesh::new();
+        mesh.push_triangle([a, c, b], Color::RED);
+        mesh.push_triangle([d, a, b], Color::GREEN);
+
+        let mut other = Mesh::new();
+        other.push_triangle([b, d, a], Color::GREEN);
+        other.push_triangle([b, a, c], Color::RED);
+
+        mesh.sort_triangles();
+        other.sort_triangles();
+
+        assert_eq!(
+            mesh.triangles().collect::<Vec<_>>(),
+            other.triangles().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            mesh.vertices().collect::<Vec<_>>(),
+            other.vertices().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            mesh.indices().collect::<Vec<_>>(),
+            other.indices().collect::<Vec<_>>()
+        );
+
+        // The orientation of the triangles is preserved.
+        assert!(mesh
+            .triangles()
+            .any(|triangle| triangle.inner.points() == [a, c, b]));
     }
 }
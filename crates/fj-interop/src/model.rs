@@ -10,6 +10,12 @@ pub struct Model {
     /// The triangle mesh that approximates the model
     pub mesh: Mesh<Point<3>>,
 
+    /// A coarser approximation of the model, if available
+    ///
+    /// This is cheaper to render than [`Model::mesh`], and can be displayed
+    /// instead, while the user is interacting with the model.
+    pub preview_mesh: Option<Mesh<Point<3>>>,
+
     /// The axis-aligned bounding box of the model
     pub aabb: Aabb<3>,
 }
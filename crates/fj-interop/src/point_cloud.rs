@@ -0,0 +1,174 @@
+//! A point cloud
+
+use fj_math::{Point, Triangle};
+
+use crate::mesh::{Color, Index, Mesh};
+
+/// A set of points, without any connectivity between them
+///
+/// Can be extracted from a [`Mesh`], which drops the triangles and keeps only
+/// the positions of the vertices, or be built from any set of points, for
+/// example by sampling surfaces.
+///
+/// Converting a point cloud back into a mesh requires triangles that connect
+/// its points. See the [`TryFrom`] implementation of [`Mesh`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PointCloud {
+    /// The points of the point cloud
+    pub points: Vec<Point<3>>,
+}
+
+impl PointCloud {
+    /// Construct an empty instance of `PointCloud`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Access the number of points
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Determine whether the point cloud is empty
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+}
+
+impl From<&Mesh<Point<3>>> for PointCloud {
+    fn from(mesh: &Mesh<Point<3>>) -> Self {
+        // The vertices of a mesh are already deduplicated, so each position
+        // ends up in the point cloud once.
+        Self {
+            points: mesh.vertices().collect(),
+        }
+    }
+}
+
+impl From<Vec<Point<3>>> for PointCloud {
+    fn from(points: Vec<Point<3>>) -> Self {
+        Self { points }
+    }
+}
+
+impl From<PointCloud> for Vec<Point<3>> {
+    fn from(point_cloud: PointCloud) -> Self {
+        point_cloud.points
+    }
+}
+
+/// Build a mesh from a point cloud and the triangles that connect its points
+///
+/// Each triangle refers to three points of the point cloud, by their index. All
+/// triangles get the default color.
+impl TryFrom<(&PointCloud, &[[Index; 3]])> for Mesh<Point<3>> {
+    type Error = TriangulationError;
+
+    fn try_from(
+        (point_cloud, triangles): (&PointCloud, &[[Index; 3]]),
+    ) -> Result<Self, Self::Error> {
+        let mut mesh = Mesh::new();
+
+        for &indices in triangles {
+            let mut points = [Point::origin(); 3];
+            for (point, index) in points.iter_mut().zip(indices) {
+                *point =
+                    point_cloud.points.get(index as usize).copied().ok_or(
+                        TriangulationError::IndexOutOfBounds {
+                            index,
+                            num_points: point_cloud.len(),
+                        },
+                    )?;
+            }
+
+            let triangle = Triangle::from_points(points).map_err(|_| {
+                TriangulationError::DegenerateTriangle { indices }
+            })?;
+
+            mesh.push_triangle(triangle, Color::default());
+        }
+
+        Ok(mesh)
+    }
+}
+
+/// Error converting a [`PointCloud`] into a [`Mesh`]
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum TriangulationError {
+    /// A triangle refers to a point that is not part of the point cloud
+    #[error(
+        "Triangle refers to point {index}, but point cloud only has \
+        {num_points} points"
+    )]
+    IndexOutOfBounds {
+        /// The index that is out of bounds
+        index: Index,
+
+        /// The number of points in the point cloud
+        num_points: usize,
+    },
+
+    /// The points of a triangle don't span any area
+    #[error("Points {indices:?} don't form a triangle")]
+    DegenerateTriangle {
+        /// The indices of the points
+        indices: [Index; 3],
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::mesh::{Color, Mesh};
+
+    use super::{PointCloud, TriangulationError};
+
+    #[test]
+    fn mesh_to_point_cloud() {
+        let [a, b, c, d] =
+            [[0., 0., 0.], [1., 0., 0.], [1., 1., 0.], [0., 1., 0.]]
+                .map(Point::<3>::from);
+
+        // A square, made up of two triangles that share two of their vertices.
+        let mut mesh = Mesh::new();
+        mesh.push_triangle([a, b, c], Color::default());
+        mesh.push_triangle([a, c, d], Color::default());
+
+        let point_cloud = PointCloud::from(&mesh);
+        assert_eq!(point_cloud.len(), 4);
+        assert_eq!(point_cloud.len(), mesh.vertices().count());
+        assert_eq!(point_cloud.points, [a, b, c, d]);
+
+        let triangles = [[0, 1, 2], [0, 2, 3]];
+        let rebuilt =
+            Mesh::<Point<3>>::try_from((&point_cloud, triangles.as_slice()))
+                .unwrap();
+        assert!(rebuilt.contains_triangle([a, b, c]));
+        assert!(rebuilt.contains_triangle([a, c, d]));
+        assert_eq!(PointCloud::from(&rebuilt), point_cloud);
+    }
+
+    #[test]
+    fn invalid_triangles() {
+        let point_cloud = PointCloud::from(
+            [[0., 0., 0.], [1., 0., 0.], [2., 0., 0.]]
+                .map(Point::<3>::from)
+                .to_vec(),
+        );
+
+        assert_eq!(
+            Mesh::<Point<3>>::try_from((&point_cloud, [[0, 1, 3]].as_slice()))
+                .err(),
+            Some(TriangulationError::IndexOutOfBounds {
+                index: 3,
+                num_points: 3
+            })
+        );
+        assert_eq!(
+            Mesh::<Point<3>>::try_from((&point_cloud, [[0, 1, 2]].as_slice()))
+                .err(),
+            Some(TriangulationError::DegenerateTriangle { indices: [0, 1, 2] })
+        );
+    }
+}
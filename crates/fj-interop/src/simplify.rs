@@ -0,0 +1,515 @@
+//! Mesh simplification
+//!
+//! See [`Mesh::simplify`].
+
+use std::{
+    cmp::Reverse,
+    collections::{BTreeSet, BinaryHeap, HashMap},
+    ops,
+};
+
+use fj_math::{Point, Scalar};
+
+use crate::mesh::{Color, Mesh};
+
+impl Mesh<Point<3>> {
+    /// Simplify the mesh, reducing its number of triangles
+    ///
+    /// Edges of the mesh are collapsed one by one, cheapest first, until the
+    /// mesh has no more than `target` triangles, or until no edge can be
+    /// collapsed without deviating from the original mesh by more than
+    /// `tolerance`. The cost of collapsing an edge is measured using quadric
+    /// error metrics, as the sum of squared distances of the merged vertex to
+    /// the planes of the original triangles it replaces.
+    ///
+    /// Vertices on boundary edges (edges that belong to only one triangle) and
+    /// on edges between triangles of different colors are never moved. Sharp
+    /// features are preserved within the tolerance, as moving a vertex away
+    /// from a sharp edge moves it away from the planes that meet there.
+    ///
+    /// Collapses that would flip the orientation of a triangle, or make the
+    /// mesh non-manifold, are skipped.
+    pub fn simplify(
+        &self,
+        target: usize,
+        tolerance: impl Into<Scalar>,
+    ) -> Self {
+        let tolerance = tolerance.into();
+        let max_error = tolerance * tolerance;
+
+        let mut simplifier = Simplifier::new(self);
+        let mut queue = simplifier
+            .edges()
+            .into_iter()
+            .filter_map(|[a, b]| simplifier.collapse(a, b))
+            .map(Reverse)
+            .collect::<BinaryHeap<_>>();
+
+        while simplifier.num_triangles > target {
+            let Some(Reverse(collapse)) = queue.pop() else {
+                break;
+            };
+
+            // The queue is ordered by error, so if this collapse is too
+            // expensive, all remaining ones are too.
+            if collapse.error > max_error {
+                break;
+            }
+
+            if !simplifier.is_valid(&collapse) {
+                continue;
+            }
+
+            let keep = simplifier.perform(&collapse);
+            for neighbor in simplifier.neighbors(keep) {
+                if let Some(collapse) = simplifier.collapse(keep, neighbor) {
+                    queue.push(Reverse(collapse));
+                }
+            }
+        }
+
+        simplifier.into_mesh()
+    }
+}
+
+/// The state of a mesh that is being simplified
+struct Simplifier {
+    positions: Vec<Point<3>>,
+    quadrics: Vec<Quadric>,
+    versions: Vec<u32>,
+    is_locked: Vec<bool>,
+    is_removed: Vec<bool>,
+
+    triangles: Vec<Option<([usize; 3], Color)>>,
+    triangles_by_vertex: Vec<BTreeSet<usize>>,
+    num_triangles: usize,
+}
+
+impl Simplifier {
+    fn new(mesh: &Mesh<Point<3>>) -> Self {
+        let mut indices = HashMap::new();
+        let mut positions = Vec::new();
+        let mut triangles = Vec::new();
+
+        for triangle in mesh.triangles() {
+            let vertices = triangle.inner.points().map(|point| {
+                *indices.entry(point).or_insert_with(|| {
+                    positions.push(point);
+                    positions.len() - 1
+                })
+            });
+
+            triangles.push(Some((vertices, triangle.color)));
+        }
+
+        let mut quadrics = vec![Quadric::default(); positions.len()];
+        let mut triangles_by_vertex = vec![BTreeSet::new(); positions.len()];
+        let mut colors_by_edge = HashMap::<[usize; 2], Vec<Color>>::new();
+
+        for (i, (vertices, color)) in triangles.iter().flatten().enumerate() {
+            let [a, b, c] = vertices.map(|vertex| positions[vertex]);
+            let quadric = Quadric::from_triangle(a, b, c);
+
+            for (j, &vertex) in vertices.iter().enumerate() {
+                quadrics[vertex] = quadrics[vertex] + quadric;
+                triangles_by_vertex[vertex].insert(i);
+
+                let next = vertices[(j + 1) % 3];
+                colors_by_edge
+                    .entry(edge(vertex, next))
+                    .or_default()
+                    .push(*color);
+            }
+        }
+
+        // Vertices on boundary edges, or on edges between different colors,
+        // must stay where they are. Edges that belong to more than two
+        // triangles are treated the same, as collapsing them is bound to make
+        // things worse.
+        let mut is_locked = vec![false; positions.len()];
+        for (vertices, colors) in colors_by_edge {
+            let is_feature = colors.len() != 2 || colors[0] != colors[1];
+
+            if is_feature {
+                for vertex in vertices {
+                    is_locked[vertex] = true;
+                }
+            }
+        }
+
+        let num_triangles = triangles.len();
+
+        Self {
+            versions: vec![0; positions.len()],
+            is_removed: vec![false; positions.len()],
+            positions,
+            quadrics,
+            is_locked,
+            triangles,
+            triangles_by_vertex,
+            num_triangles,
+        }
+    }
+
+    fn edges(&self) -> BTreeSet<[usize; 2]> {
+        self.triangles
+            .iter()
+            .flatten()
+            .flat_map(|([a, b, c], _)| {
+                [edge(*a, *b), edge(*b, *c), edge(*c, *a)]
+            })
+            .collect()
+    }
+
+    fn neighbors(&self, vertex: usize) -> BTreeSet<usize> {
+        self.triangles_by_vertex[vertex]
+            .iter()
+            .filter_map(|&triangle| self.triangles[triangle])
+            .flat_map(|(vertices, _)| vertices)
+            .filter(|&other| other != vertex)
+            .collect()
+    }
+
+    /// Compute the collapse of the edge between two vertices
+    ///
+    /// Returns `None`, if both vertices are locked.
+    fn collapse(&self, a: usize, b: usize) -> Option<Collapse> {
+        let (keep, remove) = match (self.is_locked[a], self.is_locked[b]) {
+            (true, true) => return None,
+            (false, true) => (b, a),
+            _ => (a, b),
+        };
+
+        let quadric = self.quadrics[keep] + self.quadrics[remove];
+
+        let position = if self.is_locked[keep] {
+            self.positions[keep]
+        } else {
+            let [p, q] = [keep, remove].map(|vertex| self.positions[vertex]);
+            let midpoint = p + (q - p) / 2.;
+
+            [p, q, midpoint]
+                .into_iter()
+                .chain(quadric.minimizer())
+                .min_by_key(|&position| quadric.error(position))
+                .unwrap_or(midpoint)
+        };
+
+        Some(Collapse {
+            error: quadric.error(position),
+            keep,
+            remove,
+            versions: [self.versions[keep], self.versions[remove]],
+            position,
+        })
+    }
+
+    fn is_valid(&self, collapse: &Collapse) -> bool {
+        let Collapse {
+            keep,
+            remove,
+            versions,
+            position,
+            ..
+        } = *collapse;
+
+        // The collapse might have been computed before one of its vertices was
+        // changed by another collapse.
+        if self.is_removed[keep]
+            || self.is_removed[remove]
+            || versions != [self.versions[keep], self.versions[remove]]
+        {
+            return false;
+        }
+
+        let shared = self.triangles_by_vertex[keep]
+            .intersection(&self.triangles_by_vertex[remove])
+            .collect::<BTreeSet<_>>();
+        if shared.is_empty() {
+            return false;
+        }
+
+        // If the vertices have neighbors in common, other than those of the
+        // triangles that are removed along with the edge, the collapse would
+        // fold the mesh onto itself.
+        let num_common_neighbors = self
+            .neighbors(keep)
+            .intersection(&self.neighbors(remove))
+            .count();
+        if num_common_neighbors != shared.len() {
+            return false;
+        }
+
+        // None of the remaining triangles must flip over, or degenerate.
+        self.triangles_by_vertex[keep]
+            .iter()
+            .chain(&self.triangles_by_vertex[remove])
+            .filter(|triangle| !shared.contains(triangle))
+            .filter_map(|&triangle| self.triangles[triangle])
+            .all(|(vertices, _)| {
+                let before = vertices.map(|vertex| self.positions[vertex]);
+                let after = vertices.map(|vertex| {
+                    if vertex == keep || vertex == remove {
+                        position
+                    } else {
+                        self.positions[vertex]
+                    }
+                });
+
+                let [normal_before, normal_after] =
+                    [before, after].map(|[a, b, c]| (b - a).cross(&(c - a)));
+
+                normal_after.magnitude() > Scalar::default_epsilon()
+                    && normal_before.dot(&normal_after) > Scalar::ZERO
+            })
+    }
+
+    /// Perform the collapse, returning the vertex that remains
+    fn perform(&mut self, collapse: &Collapse) -> usize {
+        let Collapse {
+            keep,
+            remove,
+            position,
+            ..
+        } = *collapse;
+
+        self.positions[keep] = position;
+        self.quadrics[keep] = self.quadrics[keep] + self.quadrics[remove];
+        self.versions[keep] += 1;
+        self.is_removed[remove] = true;
+
+        for triangle in std::mem::take(&mut self.triangles_by_vertex[remove]) {
+            let Some((vertices, color)) = self.triangles[triangle] else {
+                continue;
+            };
+
+            if vertices.contains(&keep) {
+                self.triangles[triangle] = None;
+                self.num_triangles -= 1;
+
+                for vertex in vertices {
+                    self.triangles_by_vertex[vertex].remove(&triangle);
+                }
+            } else {
+                let vertices =
+                    vertices.map(
+                        |vertex| if vertex == remove { keep } else { vertex },
+                    );
+
+                self.triangles[triangle] = Some((vertices, color));
+                self.triangles_by_vertex[keep].insert(triangle);
+            }
+        }
+
+        keep
+    }
+
+    fn into_mesh(self) -> Mesh<Point<3>> {
+        let mut mesh = Mesh::new();
+
+        for (vertices, color) in self.triangles.into_iter().flatten() {
+            let points = vertices.map(|vertex| self.positions[vertex]);
+            mesh.push_triangle(points, color);
+        }
+
+        mesh
+    }
+}
+
+/// The collapse of an edge into a single vertex
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+struct Collapse {
+    /// The error of the collapse, as computed from the quadrics
+    ///
+    /// This field must come first, so collapses are ordered by it.
+    error: Scalar,
+
+    /// The vertex that remains, moved to `position`
+    keep: usize,
+
+    /// The vertex that is removed
+    remove: usize,
+
+    /// The versions of both vertices when the collapse was computed
+    versions: [u32; 2],
+
+    /// The position of the remaining vertex after the collapse
+    position: Point<3>,
+}
+
+/// A quadric that measures the squared distance of a point to a set of planes
+///
+/// Stored as the symmetric 4x4 matrix that is the sum of `p * p^T`, for each
+/// plane `p = [a, b, c, d]`, with `ax + by + cz + d = 0`.
+#[derive(Clone, Copy, Debug, Default)]
+struct Quadric([[f64; 4]; 4]);
+
+impl Quadric {
+    fn from_triangle(a: Point<3>, b: Point<3>, c: Point<3>) -> Self {
+        let normal = (b - a).cross(&(c - a));
+        if normal.magnitude() == Scalar::ZERO {
+            return Self::default();
+        }
+
+        let normal = normal.normalize();
+        let d = -normal.dot(&a.coords);
+
+        let [x, y, z] = normal.components.map(Scalar::into_f64);
+        let plane = [x, y, z, d.into_f64()];
+
+        Self(plane.map(|p| plane.map(|q| p * q)))
+    }
+
+    fn error(&self, point: Point<3>) -> Scalar {
+        let [x, y, z] = point.coords.components.map(Scalar::into_f64);
+        let v = [x, y, z, 1.];
+
+        let mut error = 0.;
+        for (i, row) in self.0.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                error += v[i] * value * v[j];
+            }
+        }
+
+        Scalar::from(error)
+    }
+
+    /// Compute the point that minimizes the error, if there is a unique one
+    fn minimizer(&self) -> Option<Point<3>> {
+        let m = self.0;
+
+        // Solve the linear system that results from setting the gradient of
+        // the error to zero, using Cramer's rule.
+        let columns = [0, 1, 2].map(|j| [m[0][j], m[1][j], m[2][j]]);
+        let rhs = [-m[0][3], -m[1][3], -m[2][3]];
+
+        let det = determinant(columns);
+        if det.abs() < 1e-12 {
+            return None;
+        }
+
+        let solution = [0, 1, 2].map(|i| {
+            let mut columns = columns;
+            columns[i] = rhs;
+            determinant(columns) / det
+        });
+
+        Some(Point::from(solution))
+    }
+}
+
+impl ops::Add for Quadric {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(
+            [0, 1, 2, 3]
+                .map(|i| [0, 1, 2, 3].map(|j| self.0[i][j] + other.0[i][j])),
+        )
+    }
+}
+
+fn determinant([a, b, c]: [[f64; 3]; 3]) -> f64 {
+    a[0] * (b[1] * c[2] - b[2] * c[1]) - b[0] * (a[1] * c[2] - a[2] * c[1])
+        + c[0] * (a[1] * b[2] - a[2] * b[1])
+}
+
+fn edge(a: usize, b: usize) -> [usize; 2] {
+    if a < b {
+        [a, b]
+    } else {
+        [b, a]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{PI, TAU};
+
+    use fj_math::{Point, Scalar};
+
+    use crate::mesh::{Color, Mesh};
+
+    #[test]
+    fn simplify_sphere() {
+        let mesh = sphere(64, 32);
+        let num_triangles = mesh.triangles().count();
+
+        let tolerance = 0.05;
+        let simplified = mesh.simplify(num_triangles / 2, tolerance);
+
+        assert!(simplified.triangles().count() <= num_triangles / 2);
+        for point in simplified.vertices() {
+            let radius = point.coords.magnitude();
+            assert!((radius - Scalar::ONE).abs() < Scalar::from(tolerance));
+        }
+    }
+
+    #[test]
+    fn simplify_preserves_boundary() {
+        // A hemisphere, whose boundary is the equator.
+        let is_on_equator =
+            |point: &Point<3>| point.z.abs() < Scalar::from(1e-9);
+        let mut hemisphere = Mesh::new();
+        for triangle in sphere(32, 16).triangles() {
+            let is_in_upper_half =
+                triangle.inner.points().iter().all(|point| {
+                    point.z > Scalar::ZERO || is_on_equator(point)
+                });
+
+            if is_in_upper_half {
+                hemisphere.push_triangle(triangle.inner, triangle.color);
+            }
+        }
+
+        let simplified = hemisphere.simplify(0, 0.05);
+        assert!(
+            simplified.triangles().count() < hemisphere.triangles().count()
+        );
+
+        for point in hemisphere.vertices().filter(is_on_equator) {
+            assert!(simplified.vertices().any(|p| p == point));
+        }
+    }
+
+    /// Create a mesh that approximates the unit sphere
+    fn sphere(longitudes: usize, latitudes: usize) -> Mesh<Point<3>> {
+        let point = |i: usize, j: usize| {
+            if i == 0 {
+                return Point::from([0., 0., 1.]);
+            }
+            if i == latitudes {
+                return Point::from([0., 0., -1.]);
+            }
+
+            let theta = PI * i as f64 / latitudes as f64;
+            let phi = TAU * (j % longitudes) as f64 / longitudes as f64;
+
+            Point::from([
+                theta.sin() * phi.cos(),
+                theta.sin() * phi.sin(),
+                theta.cos(),
+            ])
+        };
+
+        let mut mesh = Mesh::new();
+
+        for i in 0..latitudes {
+            for j in 0..longitudes {
+                let a = point(i, j);
+                let b = point(i + 1, j);
+                let c = point(i + 1, j + 1);
+                let d = point(i, j + 1);
+
+                if i != latitudes - 1 {
+                    mesh.push_triangle([a, b, c], Color::default());
+                }
+                if i != 0 {
+                    mesh.push_triangle([a, c, d], Color::default());
+                }
+            }
+        }
+
+        mesh
+    }
+}
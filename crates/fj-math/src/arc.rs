@@ -81,13 +81,190 @@ impl Arc {
             end_angle,
         }
     }
+
+    /// Constructs an [`Arc`] that starts at `p0`, passes through `p1`, and ends
+    /// at `p2`
+    ///
+    /// The arc is counter-clockwise, if the points are in counter-clockwise
+    /// order, and clockwise otherwise.
+    ///
+    /// Returns an error, if the points are collinear (which includes the case
+    /// of coincident points). No circle passes through such points.
+    pub fn from_three_points(
+        p0: impl Into<Point<2>>,
+        p1: impl Into<Point<2>>,
+        p2: impl Into<Point<2>>,
+    ) -> Result<Self, InvalidArc> {
+        let p0 = p0.into();
+        let p1 = p1.into();
+        let p2 = p2.into();
+
+        let b = p1 - p0;
+        let c = p2 - p0;
+
+        // Twice the signed area of the triangle formed by the points. Positive,
+        // if the points are in counter-clockwise order.
+        let d = b.cross2d(&c);
+        if d == Scalar::ZERO {
+            return Err(InvalidArc::CollinearPoints);
+        }
+
+        // The center of the circle is the circumcenter of the triangle. See
+        // https://en.wikipedia.org/wiki/Circumcircle#Cartesian_coordinates_2
+        let center = {
+            let bb = b.dot(&b);
+            let cc = c.dot(&c);
+
+            p0 + Vector::from([
+                (c.v * bb - b.v * cc) / (d * 2.),
+                (b.u * cc - c.u * bb) / (d * 2.),
+            ])
+        };
+        let radius = (p0 - center).magnitude();
+
+        let [start_angle, end_angle] =
+            angles_of_endpoints(center, [p0, p2], d > Scalar::ZERO);
+
+        Ok(Self {
+            center,
+            radius,
+            start_angle,
+            end_angle,
+        })
+    }
+
+    /// Constructs an [`Arc`] that starts at `start` in the direction of
+    /// `tangent`, and ends at `end`
+    ///
+    /// The arc is counter-clockwise, if `end` is to the left of the tangent,
+    /// and clockwise otherwise.
+    ///
+    /// Returns an error, if `start` and `end` are coincident, or if `end` lies
+    /// on the line defined by `start` and `tangent` (which would require an
+    /// infinite radius).
+    pub fn from_start_tangent_and_end(
+        start: impl Into<Point<2>>,
+        tangent: impl Into<Vector<2>>,
+        end: impl Into<Point<2>>,
+    ) -> Result<Self, InvalidArc> {
+        let start = start.into();
+        let tangent = tangent.into();
+        let end = end.into();
+
+        let start_to_end = end - start;
+        if start_to_end.magnitude() == Scalar::ZERO {
+            return Err(InvalidArc::CoincidentEndPoints);
+        }
+
+        // The center lies on the normal of the tangent that passes through
+        // `start`. This normal points to the left of the tangent.
+        let normal = Vector::from([-tangent.v, tangent.u]);
+        let normal_dot_start_to_end = normal.dot(&start_to_end);
+        if normal_dot_start_to_end == Scalar::ZERO {
+            return Err(InvalidArc::CollinearPoints);
+        }
+
+        // Solving `|center - start| == |center - end|` for the position of
+        // the center along the normal.
+        let s =
+            start_to_end.dot(&start_to_end) / (normal_dot_start_to_end * 2.);
+        let center = start + normal * s;
+        let radius = (start - center).magnitude();
+
+        let [start_angle, end_angle] =
+            angles_of_endpoints(center, [start, end], s > Scalar::ZERO);
+
+        Ok(Self {
+            center,
+            radius,
+            start_angle,
+            end_angle,
+        })
+    }
+
+    /// Constructs a counter-clockwise [`Arc`] from two endpoints and a radius
+    ///
+    /// There are two counter-clockwise arcs with the given radius that connect
+    /// the endpoints: The minor arc (less than a half turn), and the major arc
+    /// (more than a half turn). The sign of the radius selects between them: A
+    /// positive radius selects the minor arc, a negative one the major arc. To
+    /// get a clockwise arc, swap the endpoints.
+    ///
+    /// Returns an error, if the endpoints are coincident, or if the radius is
+    /// too small to connect them.
+    pub fn from_endpoints_and_radius(
+        p0: impl Into<Point<2>>,
+        p1: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+    ) -> Result<Self, InvalidArc> {
+        let p0 = p0.into();
+        let p1 = p1.into();
+        let radius = radius.into();
+
+        let distance_between_endpoints = (p1 - p0).magnitude();
+        if distance_between_endpoints == Scalar::ZERO {
+            return Err(InvalidArc::CoincidentEndPoints);
+        }
+        if radius.abs() * 2. < distance_between_endpoints {
+            return Err(InvalidArc::RadiusTooSmall);
+        }
+
+        let minor_angle =
+            (distance_between_endpoints / (radius.abs() * 2.)).asin() * 2.;
+        let angle = if radius > Scalar::ZERO {
+            minor_angle
+        } else {
+            Scalar::TAU - minor_angle
+        };
+
+        Ok(Self::from_endpoints_and_angle(p0, p1, angle))
+    }
+}
+
+fn angles_of_endpoints(
+    center: Point<2>,
+    [start, end]: [Point<2>; 2],
+    counter_clockwise: bool,
+) -> [Scalar; 2] {
+    let angle_of = |point: Point<2>| {
+        let from_center = point - center;
+        from_center.v.atan2(from_center.u)
+    };
+
+    let start_angle = angle_of(start);
+    let mut end_angle = angle_of(end);
+
+    if counter_clockwise {
+        while end_angle <= start_angle {
+            end_angle += Scalar::TAU;
+        }
+    } else {
+        while end_angle >= start_angle {
+            end_angle -= Scalar::TAU;
+        }
+    }
+
+    [start_angle, end_angle]
+}
+
+/// Returned by [`Arc`] constructors, if the arguments don't define an arc
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum InvalidArc {
+    /// The points are collinear, which would require an infinite radius
+    CollinearPoints,
+
+    /// The end points of the arc are coincident
+    CoincidentEndPoints,
+
+    /// The radius is too small to connect the end points
+    RadiusTooSmall,
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{Point, Scalar, Vector};
 
-    use super::Arc;
+    use super::{Arc, InvalidArc};
 
     use approx::{assert_abs_diff_eq, AbsDiffEq};
 
@@ -125,6 +302,111 @@ mod tests {
         );
     }
 
+    #[test]
+    fn arc_from_three_points() {
+        let arc = Arc::from_three_points([1., 0.], [0., 1.], [-1., 0.])
+            .expect("Points are not collinear");
+        let epsilon = Scalar::default_epsilon() * 10.;
+
+        assert_abs_diff_eq!(arc.center, Point::origin(), epsilon = epsilon);
+        assert_abs_diff_eq!(arc.radius, Scalar::ONE, epsilon = epsilon);
+        assert_abs_diff_eq!(arc.start_angle, Scalar::ZERO, epsilon = epsilon);
+        assert_abs_diff_eq!(arc.end_angle, Scalar::PI, epsilon = epsilon);
+
+        // Same points, but clockwise.
+        let arc = Arc::from_three_points([1., 0.], [0., -1.], [-1., 0.])
+            .expect("Points are not collinear");
+
+        assert_abs_diff_eq!(arc.center, Point::origin(), epsilon = epsilon);
+        assert_abs_diff_eq!(arc.start_angle, Scalar::ZERO, epsilon = epsilon);
+        assert_abs_diff_eq!(arc.end_angle, -Scalar::PI, epsilon = epsilon);
+    }
+
+    #[test]
+    fn arc_from_collinear_points() {
+        assert_eq!(
+            Arc::from_three_points([0., 0.], [1., 1.], [2., 2.]).err(),
+            Some(InvalidArc::CollinearPoints),
+        );
+        assert_eq!(
+            Arc::from_three_points([0., 0.], [1., 1.], [0., 0.]).err(),
+            Some(InvalidArc::CollinearPoints),
+        );
+    }
+
+    #[test]
+    fn arc_from_start_tangent_and_end() {
+        let epsilon = Scalar::default_epsilon() * 10.;
+
+        let arc = Arc::from_start_tangent_and_end([1., 0.], [1., 0.], [2., 1.])
+            .expect("End point is not on tangent");
+        assert_abs_diff_eq!(
+            arc.center,
+            Point::from([1., 1.]),
+            epsilon = epsilon
+        );
+        assert_abs_diff_eq!(
+            arc.end_angle - arc.start_angle,
+            Scalar::PI / 2.,
+            epsilon = epsilon
+        );
+
+        let arc =
+            Arc::from_start_tangent_and_end([1., 0.], [1., 0.], [2., -1.])
+                .expect("End point is not on tangent");
+        assert_abs_diff_eq!(
+            arc.center,
+            Point::from([1., -1.]),
+            epsilon = epsilon
+        );
+        assert_abs_diff_eq!(
+            arc.end_angle - arc.start_angle,
+            -Scalar::PI / 2.,
+            epsilon = epsilon
+        );
+
+        assert_eq!(
+            Arc::from_start_tangent_and_end([0., 0.], [1., 0.], [2., 0.]).err(),
+            Some(InvalidArc::CollinearPoints),
+        );
+    }
+
+    #[test]
+    fn arc_from_endpoints_and_radius() {
+        let epsilon = Scalar::default_epsilon() * 10.;
+
+        let minor = Arc::from_endpoints_and_radius([1., 0.], [0., 1.], 1.)
+            .expect("Radius is large enough");
+        assert_abs_diff_eq!(minor.center, Point::origin(), epsilon = epsilon);
+        assert_abs_diff_eq!(
+            minor.end_angle - minor.start_angle,
+            Scalar::PI / 2.,
+            epsilon = epsilon
+        );
+
+        let major = Arc::from_endpoints_and_radius([1., 0.], [0., 1.], -1.)
+            .expect("Radius is large enough");
+        assert_abs_diff_eq!(
+            major.center,
+            Point::from([1., 1.]),
+            epsilon = epsilon
+        );
+        assert_abs_diff_eq!(
+            major.end_angle - major.start_angle,
+            Scalar::PI * 1.5,
+            epsilon = epsilon
+        );
+
+        assert_eq!(
+            Arc::from_endpoints_and_radius([0., 0.], [3., 0.], 1.).err(),
+            Some(InvalidArc::RadiusTooSmall),
+        );
+        assert_eq!(
+            Arc::from_endpoints_and_radius([1., 1.], [1., 1.], 1.).err(),
+            Some(InvalidArc::CoincidentEndPoints),
+        );
+    }
+
     fn check_arc_calculation(
         center: impl Into<Point<2>>,
         radius: f64,
@@ -41,13 +41,14 @@ mod point;
 mod poly_chain;
 mod scalar;
 mod segment;
+mod spiral;
 mod transform;
 mod triangle;
 mod vector;
 
 pub use self::{
     aabb::Aabb,
-    arc::Arc,
+    arc::{Arc, InvalidArc},
     circle::Circle,
     coordinates::{Uv, Xyz, T},
     line::Line,
@@ -56,6 +57,7 @@ pub use self::{
     poly_chain::PolyChain,
     scalar::{Scalar, Sign},
     segment::Segment,
+    spiral::{InvalidSpiral, Spiral},
     transform::Transform,
     triangle::{Triangle, Winding},
     vector::Vector,
@@ -0,0 +1,242 @@
+use crate::{Point, Scalar, Vector};
+
+/// An n-dimensional Archimedean spiral
+///
+/// The dimensionality of the spiral is defined by the const generic `D`
+/// parameter.
+///
+/// Spiral coordinates are angles, in radians, just like circle coordinates. The
+/// distance of a point from the center of the spiral grows linearly with its
+/// angle: `r(t) = radius + growth * t`. Unlike a circle, a spiral doesn't
+/// connect to itself after a full turn.
+///
+/// Where `r(t)` is negative, the point ends up on the opposite side of the
+/// center.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Spiral<const D: usize> {
+    center: Point<D>,
+    a: Vector<D>,
+    b: Vector<D>,
+    radius: Scalar,
+    growth: Scalar,
+}
+
+impl<const D: usize> Spiral<D> {
+    /// Construct a spiral
+    ///
+    /// `a` and `b` define the plane of the spiral, like they do for a circle.
+    /// Only their direction is used; they are normalized.
+    ///
+    /// `radius` is the distance from the center at spiral coordinate `0.`, and
+    /// `growth` is how much that distance grows per radian.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the arguments don't define a spiral. See [`Spiral::try_new`]
+    /// for the requirements.
+    pub fn new(
+        center: impl Into<Point<D>>,
+        a: impl Into<Vector<D>>,
+        b: impl Into<Vector<D>>,
+        radius: impl Into<Scalar>,
+        growth: impl Into<Scalar>,
+    ) -> Self {
+        match Self::try_new(center, a, b, radius, growth) {
+            Ok(spiral) => spiral,
+            Err(err) => panic!("Invalid spiral: {err:?}"),
+        }
+    }
+
+    /// Construct a spiral, if the arguments define one
+    ///
+    /// Like [`Spiral::new`], but returns an error, if any of the following
+    /// requirements are not met:
+    ///
+    /// - `a` and `b` must not be zero.
+    /// - `a` and `b` must be perpendicular to each other.
+    /// - `growth` must not be zero. Use a circle instead.
+    pub fn try_new(
+        center: impl Into<Point<D>>,
+        a: impl Into<Vector<D>>,
+        b: impl Into<Vector<D>>,
+        radius: impl Into<Scalar>,
+        growth: impl Into<Scalar>,
+    ) -> Result<Self, InvalidSpiral> {
+        let a = a.into();
+        let b = b.into();
+        let growth = growth.into();
+
+        if a.magnitude() == Scalar::ZERO || b.magnitude() == Scalar::ZERO {
+            return Err(InvalidSpiral::ZeroAxis);
+        }
+        if a.normalize().dot(&b.normalize()).abs() >= Scalar::from(1e-12) {
+            return Err(InvalidSpiral::NonPerpendicularAxes);
+        }
+        if growth == Scalar::ZERO {
+            return Err(InvalidSpiral::ZeroGrowth);
+        }
+
+        Ok(Self {
+            center: center.into(),
+            a: a.normalize(),
+            b: b.normalize(),
+            radius: radius.into(),
+            growth,
+        })
+    }
+
+    /// Access the center point of the spiral
+    pub fn center(&self) -> Point<D> {
+        self.center
+    }
+
+    /// Access the unit vector that points towards spiral coordinate `0.`
+    pub fn a(&self) -> Vector<D> {
+        self.a
+    }
+
+    /// Access the unit vector that defines the direction of the spiral
+    ///
+    /// Spiral coordinates increase from [`Self::a`] towards this vector.
+    pub fn b(&self) -> Vector<D> {
+        self.b
+    }
+
+    /// Access the distance from the center at spiral coordinate `0.`
+    pub fn radius(&self) -> Scalar {
+        self.radius
+    }
+
+    /// Access how much the distance from the center grows per radian
+    pub fn growth(&self) -> Scalar {
+        self.growth
+    }
+
+    /// Compute the distance from the center at the given spiral coordinate
+    ///
+    /// The result is negative, where the point is on the opposite side of the
+    /// center.
+    pub fn radius_at(&self, point: impl Into<Point<1>>) -> Scalar {
+        self.radius + self.growth * point.into().t
+    }
+
+    /// Create a new instance that is reversed
+    ///
+    /// The reversed spiral has the same points, but its coordinates run the
+    /// other way: coordinate `t` of the reversed spiral is coordinate `-t` of
+    /// the original one.
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        self.b = -self.b;
+        self.growth = -self.growth;
+        self
+    }
+
+    /// Convert a point in spiral coordinates into a `D`-dimensional point
+    pub fn point_from_spiral_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        let t = point.into().t;
+        let (sin, cos) = t.sin_cos();
+
+        self.center + (self.a * cos + self.b * sin) * self.radius_at([t])
+    }
+
+    /// Compute the tangent of the spiral at the given spiral coordinate
+    ///
+    /// The tangent points in the direction of increasing spiral coordinates.
+    /// Its magnitude is the rate of change of the point per radian.
+    pub fn tangent_at(&self, point: impl Into<Point<1>>) -> Vector<D> {
+        let t = point.into().t;
+        let (sin, cos) = t.sin_cos();
+
+        let outward = self.a * cos + self.b * sin;
+        let around = self.b * cos - self.a * sin;
+
+        outward * self.growth + around * self.radius_at([t])
+    }
+}
+
+/// Returned by [`Spiral::try_new`], if the arguments don't define a spiral
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum InvalidSpiral {
+    /// `a` or `b` is zero
+    ZeroAxis,
+
+    /// `a` and `b` are not perpendicular to each other
+    NonPerpendicularAxes,
+
+    /// The growth is zero, which makes the spiral a circle
+    ZeroGrowth,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+    use crate::{Point, Scalar, Vector};
+
+    use super::{InvalidSpiral, Spiral};
+
+    #[test]
+    fn radius_grows_linearly() {
+        let spiral = Spiral::new([1., 2.], [1., 0.], [0., 1.], 0.5, 0.25);
+
+        for t in [0., FRAC_PI_2, PI, TAU, TAU * 2.] {
+            let expected = Scalar::from(0.5 + 0.25 * t);
+
+            let point = spiral.point_from_spiral_coords([t]);
+            let distance = point.distance_to(&spiral.center());
+
+            assert!((distance - expected).abs() < Scalar::from(1e-12));
+            assert!(
+                (spiral.radius_at([t]) - expected).abs() < Scalar::from(1e-12)
+            );
+        }
+
+        let point = spiral.point_from_spiral_coords([FRAC_PI_2]);
+        let expected = Point::from([1., 2. + 0.5 + 0.25 * FRAC_PI_2]);
+        assert!(point.distance_to(&expected) < Scalar::from(1e-12));
+    }
+
+    #[test]
+    fn reverse() {
+        let spiral = Spiral::new([0., 0.], [1., 0.], [0., 1.], 1., 0.5);
+        let reversed = spiral.reverse();
+
+        for t in [0., 1., 2., 5.] {
+            let a = spiral.point_from_spiral_coords([t]);
+            let b = reversed.point_from_spiral_coords([-t]);
+            assert!(a.distance_to(&b) < Scalar::from(1e-12));
+
+            let a = spiral.tangent_at([t]);
+            let b = reversed.tangent_at([-t]);
+            assert!((a + b).magnitude() < Scalar::from(1e-12));
+        }
+
+        assert_eq!(
+            spiral.tangent_at([0.]),
+            Vector::from([0.5, 1.]),
+            "Tangent should combine growth and rotation"
+        );
+    }
+
+    #[test]
+    fn try_new() {
+        let new = |a: [f64; 2], b: [f64; 2], growth: f64| {
+            Spiral::try_new([0., 0.], a, b, 1., growth).err()
+        };
+
+        assert_eq!(new([1., 0.], [0., 1.], 0.5), None);
+        assert_eq!(new([0., 0.], [0., 1.], 0.5), Some(InvalidSpiral::ZeroAxis));
+        assert_eq!(
+            new([1., 0.], [1., 1.], 0.5),
+            Some(InvalidSpiral::NonPerpendicularAxes)
+        );
+        assert_eq!(
+            new([1., 0.], [0., 1.], 0.),
+            Some(InvalidSpiral::ZeroGrowth)
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use parry3d_f64::query::{Ray, RayCast as _};
+use parry3d_f64::query::{PointQuery as _, Ray, RayCast as _};
 
 use crate::Vector;
 
@@ -102,6 +102,13 @@ impl Triangle<3> {
             .map(Into::into)
     }
 
+    /// Compute the distance between the triangle and a point
+    pub fn distance_to_point(&self, point: impl Into<Point<3>>) -> Scalar {
+        self.to_parry()
+            .distance_to_local_point(&point.into().to_na(), true)
+            .into()
+    }
+
     /// Compute the triangle's normal
     pub fn normal(&self) -> Vector<3> {
         self.to_parry()
@@ -151,7 +158,7 @@ impl Winding {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Point, Vector};
+    use crate::{Point, Scalar, Vector};
 
     use super::Triangle;
 
@@ -189,6 +196,15 @@ mod tests {
         let _triangle = Triangle::from([a, b, c]);
     }
 
+    #[test]
+    fn distance_to_point() {
+        let triangle =
+            Triangle::from([[0.0, 0.0, 0.0], [2.0, 0.0, 0.0], [0.0, 2.0, 0.0]]);
+        assert_eq!(triangle.distance_to_point([0.5, 0.5, 1.0]), Scalar::ONE);
+        assert_eq!(triangle.distance_to_point([0.5, 0.5, 0.0]), Scalar::ZERO);
+        assert_eq!(triangle.distance_to_point([-1.0, 0.0, 0.0]), Scalar::ONE);
+    }
+
     #[test]
     fn normal() {
         let triangle =
@@ -0,0 +1,209 @@
+//! Smooth transitions between camera views
+
+use std::time::Duration;
+
+use fj_math::{Point, Transform, Vector};
+use nalgebra::{Matrix3, Rotation3, UnitQuaternion};
+
+use crate::camera::{Camera, Orbit};
+
+/// An animated transition of a [`Camera`] to a new view
+///
+/// The orientation of the camera is interpolated along the shortest arc
+/// between the start and end orientation. Its distance and target are
+/// interpolated linearly.
+///
+/// The animation doesn't keep track of time by itself. Call
+/// [`CameraAnimation::update`] once per frame, with the time that has passed
+/// since the previous frame.
+#[derive(Clone, Debug)]
+pub struct CameraAnimation {
+    start: View,
+    end: View,
+    duration: Duration,
+    elapsed: Duration,
+    easing: Easing,
+}
+
+impl CameraAnimation {
+    /// Create an animation from the current view of the camera to a new one
+    ///
+    /// Uses the default [`Easing`]. See [`CameraAnimation::with_easing`].
+    pub fn new(camera: &Camera, end: Orbit, duration: Duration) -> Self {
+        let start = camera.orbit();
+
+        Self {
+            start: View {
+                rotation: quaternion_from_transform(&camera.rotation),
+                target: start.target,
+                distance: start.distance,
+            },
+            end: View {
+                rotation: quaternion_from_transform(&end.rotation()),
+                target: end.target,
+                distance: end.distance,
+            },
+            duration,
+            elapsed: Duration::ZERO,
+            easing: Easing::default(),
+        }
+    }
+
+    /// Use the provided easing function for the animation
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Indicate whether the camera has reached the end of the animation
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Advance the animation, and move the camera accordingly
+    ///
+    /// `delta_t` is the time that has passed since the last update. The
+    /// animation never moves the camera past its end.
+    pub fn update(&mut self, delta_t: Duration, camera: &mut Camera) {
+        self.elapsed = (self.elapsed + delta_t).min(self.duration);
+
+        let progress = if self.duration.is_zero() {
+            1.
+        } else {
+            self.elapsed.as_secs_f64() / self.duration.as_secs_f64()
+        };
+        let t = self.easing.apply(progress);
+
+        // There is no shortest arc between opposite orientations. Not much we
+        // can do in that case, except to jump halfway through.
+        let rotation = self
+            .start
+            .rotation
+            .try_slerp(&self.end.rotation, t, f64::EPSILON)
+            .unwrap_or(if t < 0.5 {
+                self.start.rotation
+            } else {
+                self.end.rotation
+            });
+        let target =
+            self.start.target + (self.end.target - self.start.target) * t;
+        let distance =
+            self.start.distance + (self.end.distance - self.start.distance) * t;
+
+        camera.set_view(transform_from_quaternion(rotation), target, distance);
+    }
+
+    /// Skip the rest of the animation, moving the camera to its end
+    pub fn skip(&mut self, camera: &mut Camera) {
+        self.update(self.duration, camera);
+    }
+}
+
+/// The progression of a [`CameraAnimation`] over time
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Easing {
+    /// Move at constant speed
+    Linear,
+
+    /// Accelerate at the start of the animation, and decelerate at its end
+    #[default]
+    EaseInOut,
+}
+
+impl Easing {
+    /// Map the elapsed fraction of the animation to the interpolated fraction
+    ///
+    /// Both range from `0.` to `1.`.
+    pub fn apply(self, t: f64) -> f64 {
+        match self {
+            Self::Linear => t,
+            Self::EaseInOut => t * t * (3. - 2. * t),
+        }
+    }
+}
+
+/// The state of a camera, in the form that is interpolated
+#[derive(Clone, Copy, Debug)]
+struct View {
+    rotation: UnitQuaternion<f64>,
+    target: Point<3>,
+    distance: f64,
+}
+
+fn quaternion_from_transform(transform: &Transform) -> UnitQuaternion<f64> {
+    // The data is in column-major order, but `Matrix3::new` expects its
+    // arguments in row-major order.
+    let d = transform.data();
+    let matrix =
+        Matrix3::new(d[0], d[4], d[8], d[1], d[5], d[9], d[2], d[6], d[10]);
+
+    UnitQuaternion::from_rotation_matrix(&Rotation3::from_matrix_unchecked(
+        matrix,
+    ))
+}
+
+fn transform_from_quaternion(rotation: UnitQuaternion<f64>) -> Transform {
+    let axis_angle = rotation.scaled_axis();
+    Transform::rotation(Vector::from([
+        axis_angle.x,
+        axis_angle.y,
+        axis_angle.z,
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use fj_math::Point;
+
+    use crate::camera::{Camera, Orbit};
+
+    use super::CameraAnimation;
+
+    #[test]
+    fn reaches_end_after_duration() {
+        let mut camera = Camera::new();
+        camera.set_orbit(Orbit {
+            azimuth: 0.,
+            elevation: 0.,
+            distance: 2.,
+            target: Point::origin(),
+        });
+
+        let end = Orbit {
+            azimuth: 1.,
+            elevation: 0.5,
+            distance: 4.,
+            target: Point::from([1., 2., 3.]),
+        };
+        let mut animation =
+            CameraAnimation::new(&camera, end, Duration::from_secs(1));
+
+        for _ in 0..5 {
+            animation.update(Duration::from_millis(100), &mut camera);
+        }
+        assert!(!animation.is_finished());
+        assert!(camera.distance() > 2. && camera.distance() < 4.);
+
+        for _ in 0..5 {
+            animation.update(Duration::from_millis(100), &mut camera);
+        }
+        assert!(animation.is_finished());
+
+        let mut expected = Camera::new();
+        expected.set_orbit(end);
+        for (a, b) in camera
+            .camera_to_model()
+            .data()
+            .iter()
+            .zip(expected.camera_to_model().data())
+        {
+            assert!((a - b).abs() < 1e-9, "{a} != {b}");
+        }
+
+        // Further updates don't move the camera past the end.
+        animation.update(Duration::from_secs(1), &mut camera);
+        assert!((camera.distance() - end.distance).abs() < 1e-9);
+    }
+}
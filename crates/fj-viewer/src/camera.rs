@@ -4,7 +4,7 @@ use std::f64::consts::FRAC_PI_2;
 use fj_interop::{mesh::Mesh, model::Model};
 use fj_math::{Aabb, Point, Scalar, Transform, Vector};
 
-use crate::screen::NormalizedScreenPosition;
+use crate::screen::{NormalizedScreenPosition, ScreenSize};
 
 /// The camera abstraction
 ///
@@ -25,6 +25,12 @@ pub struct Camera {
 
     /// The locational part of the transform
     pub translation: Transform,
+
+    /// The point that the camera orbits around
+    ///
+    /// Input handling only updates `rotation` and `translation`, so this point
+    /// is not necessarily in front of the camera. See [`Camera::orbit`].
+    target: Point<3>,
 }
 
 impl Camera {
@@ -41,6 +47,8 @@ impl Camera {
 
             rotation: Transform::identity(),
             translation: Transform::identity(),
+
+            target: Point::origin(),
         }
     }
 
@@ -65,6 +73,121 @@ impl Camera {
             .inverse_transform_point(&Point::<3>::origin())
     }
 
+    /// Returns the orbit parameters of the camera
+    ///
+    /// The target is the point in front of the camera, that is at the same
+    /// depth as the last target that was set. This way, the target follows the
+    /// camera, when it is moved by user input.
+    ///
+    /// Any roll of the camera around its view direction is ignored.
+    pub fn orbit(&self) -> Orbit {
+        let distance = -self
+            .camera_to_model()
+            .transform_point(&self.target)
+            .z
+            .into_f64();
+        let target = self
+            .camera_to_model()
+            .inverse_transform_point(&Point::from([0., 0., -distance]));
+
+        // The axes of the camera, in model space.
+        let camera_rotation = self.rotation.inverse();
+        let right =
+            camera_rotation.transform_vector(&Vector::from([1., 0., 0.]));
+        let backward =
+            camera_rotation.transform_vector(&Vector::from([0., 0., 1.]));
+
+        let azimuth = f64::atan2(-right.x.into_f64(), right.y.into_f64());
+        let elevation = backward.z.into_f64().clamp(-1., 1.).asin();
+
+        Orbit {
+            azimuth,
+            elevation,
+            distance,
+            target,
+        }
+    }
+
+    /// Move the camera according to the provided orbit parameters
+    ///
+    /// This replaces the rotation and translation of the camera.
+    pub fn set_orbit(&mut self, orbit: Orbit) {
+        self.set_view(orbit.rotation(), orbit.target, orbit.distance);
+    }
+
+    /// Look at the target from the provided distance, with the given rotation
+    pub(crate) fn set_view(
+        &mut self,
+        rotation: Transform,
+        target: Point<3>,
+        distance: f64,
+    ) {
+        let rotated_target = rotation.transform_point(&target);
+
+        self.rotation = rotation;
+        self.translation = Transform::translation([
+            -rotated_target.x,
+            -rotated_target.y,
+            -rotated_target.z - Scalar::from(distance),
+        ]);
+        self.target = target;
+    }
+
+    /// Returns the azimuth of the camera. See [`Orbit::azimuth`].
+    pub fn azimuth(&self) -> f64 {
+        self.orbit().azimuth
+    }
+
+    /// Set the azimuth of the camera, leaving the other parameters as they are
+    pub fn set_azimuth(&mut self, azimuth: f64) {
+        self.set_orbit(Orbit {
+            azimuth,
+            ..self.orbit()
+        });
+    }
+
+    /// Returns the elevation of the camera. See [`Orbit::elevation`].
+    pub fn elevation(&self) -> f64 {
+        self.orbit().elevation
+    }
+
+    /// Set the elevation of the camera, leaving the other parameters as they
+    /// are
+    pub fn set_elevation(&mut self, elevation: f64) {
+        self.set_orbit(Orbit {
+            elevation,
+            ..self.orbit()
+        });
+    }
+
+    /// Returns the distance of the camera from its target
+    pub fn distance(&self) -> f64 {
+        self.orbit().distance
+    }
+
+    /// Set the distance of the camera from its target, leaving the other
+    /// parameters as they are
+    pub fn set_distance(&mut self, distance: f64) {
+        self.set_orbit(Orbit {
+            distance,
+            ..self.orbit()
+        });
+    }
+
+    /// Returns the point that the camera orbits around
+    pub fn target(&self) -> Point<3> {
+        self.orbit().target
+    }
+
+    /// Set the point that the camera orbits around, leaving the other
+    /// parameters as they are
+    pub fn set_target(&mut self, target: impl Into<Point<3>>) {
+        self.set_orbit(Orbit {
+            target: target.into(),
+            ..self.orbit()
+        });
+    }
+
     /// Transform a normalized cursor position on the near plane to model space.
     pub fn cursor_to_model_space(
         &self,
@@ -174,6 +297,61 @@ impl Camera {
         ]);
 
         self.translation = translation;
+        self.target =
+            Point::from([-initial_offset.x, -initial_offset.y, Scalar::ZERO]);
+    }
+
+    /// Position the camera, so the bounding box fills the screen
+    ///
+    /// The rotation of the camera is left as it is. The camera is moved, so it
+    /// looks at the center of the bounding box, from a distance at which the
+    /// bounding box just fits on the screen.
+    ///
+    /// `margin` is the fraction of the screen, from its center to each edge,
+    /// that is left empty. It must be at least `0.` and less than `1.`.
+    pub fn fit(
+        &mut self,
+        aabb: &Aabb<3>,
+        screen_size: ScreenSize,
+        margin: f64,
+    ) {
+        let [width, height] = screen_size.as_f64();
+        let aspect_ratio = width / height;
+
+        // The slopes of the frustum's sides, reduced by the margin. A point at
+        // a given depth in front of the camera is visible, if its distance to
+        // the view direction doesn't exceed its depth times the slope.
+        let slope_x = (self.field_of_view_in_x() / 2.).tan() * (1. - margin);
+        let slope_y = slope_x / aspect_ratio;
+
+        let vertices = aabb
+            .vertices()
+            .map(|vertex| self.rotation.transform_point(&vertex));
+        let center = self.rotation.transform_point(&aabb.center());
+
+        // The distance of the camera from the plane through the center, that
+        // is parallel to the screen. It needs to be large enough to fit each
+        // vertex into the frustum.
+        let distance = vertices
+            .into_iter()
+            .map(|vertex| {
+                let offset = vertex - center;
+                let [x, y, z] =
+                    offset.components.map(|component| component.into_f64());
+
+                let required_x = x.abs() / slope_x;
+                let required_y = y.abs() / slope_y;
+
+                z + f64::max(required_x, required_y)
+            })
+            .fold(0., f64::max);
+
+        self.translation = Transform::translation([
+            -center.x,
+            -center.y,
+            -center.z - Scalar::from(distance),
+        ]);
+        self.target = aabb.center();
     }
 
     /// Update the max and minimum rendering distance for this camera.
@@ -228,9 +406,151 @@ impl Default for Camera {
     }
 }
 
+/// The orbit parameters of a [`Camera`]
+///
+/// These describe the camera as orbiting around a target point, with the
+/// z-axis of the model pointing up.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Orbit {
+    /// The angle of the camera around the target, in radians
+    ///
+    /// Measured counter-clockwise around the z-axis, starting from the positive
+    /// x-axis. At an azimuth of `0.`, the camera is on the positive x side of
+    /// the target, looking towards the negative x-axis.
+    pub azimuth: f64,
+
+    /// The angle of the camera above the xy-plane of the target, in radians
+    ///
+    /// Ranges from `-FRAC_PI_2`, looking straight up at the target, to
+    /// `FRAC_PI_2`, looking straight down.
+    pub elevation: f64,
+
+    /// The distance of the camera from the target
+    pub distance: f64,
+
+    /// The point that the camera looks at
+    pub target: Point<3>,
+}
+
+impl Orbit {
+    /// Compute the rotation of a camera with these orbit parameters
+    pub(crate) fn rotation(&self) -> Transform {
+        Transform::rotation([self.elevation - FRAC_PI_2, 0., 0.])
+            * Transform::rotation([0., 0., -FRAC_PI_2 - self.azimuth])
+    }
+}
+
 /// The point around which camera movement happens.
 ///
 /// This will be the point on the model that the cursor is currently pointing at if such a point exists,
 /// falling back to the center point of the model's bounding volume otherwise.
 #[derive(Clone, Copy)]
 pub struct FocusPoint(pub Point<3>);
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use fj_math::{Aabb, Point, Transform, Vector};
+
+    use crate::screen::ScreenSize;
+
+    use super::{Camera, Orbit};
+
+    #[test]
+    fn fit() {
+        let aabb = Aabb {
+            min: [0., 0., 0.].into(),
+            max: [1., 1., 1.].into(),
+        };
+        let screen_size = ScreenSize {
+            width: 800,
+            height: 600,
+        };
+
+        let mut camera = Camera::new();
+        camera.rotation = Transform::rotation([0.5, 0.25, 0.]);
+        camera.fit(&aabb, screen_size, 0.1);
+
+        let [width, height] = screen_size.as_f64();
+        let slope_x = (camera.field_of_view_in_x() / 2.).tan();
+        let slope_y = slope_x / (width / height);
+
+        for vertex in aabb.vertices() {
+            let vertex = camera.camera_to_model().transform_point(&vertex);
+            let [x, y, z] = vertex.coords.components.map(|c| c.into_f64());
+
+            // The camera looks along the negative z-axis.
+            let depth = -z;
+            assert!(depth > 0.);
+            assert!(x.abs() <= depth * slope_x);
+            assert!(y.abs() <= depth * slope_y);
+        }
+    }
+
+    #[test]
+    fn orbit() {
+        let orbit = Orbit {
+            azimuth: 0.5,
+            elevation: 0.25,
+            distance: 3.,
+            target: Point::from([1., 2., 3.]),
+        };
+
+        let mut camera = Camera::new();
+        camera.set_orbit(orbit);
+        assert_orbit_eq(camera.orbit(), orbit);
+
+        // The target is straight in front of the camera, which is positioned
+        // according to the azimuth and elevation.
+        let target = camera.camera_to_model().transform_point(&orbit.target);
+        assert!(
+            target.distance_to(&Point::from([0., 0., -3.])).into_f64() < 1e-9
+        );
+
+        let direction = Vector::from([
+            orbit.elevation.cos() * orbit.azimuth.cos(),
+            orbit.elevation.cos() * orbit.azimuth.sin(),
+            orbit.elevation.sin(),
+        ]);
+        let position = orbit.target + direction * orbit.distance;
+        assert!(camera.position().distance_to(&position).into_f64() < 1e-9);
+
+        // Setting one parameter leaves the others as they are.
+        camera.set_distance(5.);
+        camera.set_azimuth(-1.);
+        assert_orbit_eq(
+            camera.orbit(),
+            Orbit {
+                azimuth: -1.,
+                distance: 5.,
+                ..orbit
+            },
+        );
+
+        // Looking straight down, without rotation around the z-axis, is the
+        // default orientation of the camera.
+        camera.set_orbit(Orbit {
+            azimuth: -FRAC_PI_2,
+            elevation: FRAC_PI_2,
+            distance: 2.,
+            target: Point::from([1., 2., 3.]),
+        });
+        let expected = Transform::translation([-1., -2., -5.]);
+        for (a, b) in
+            camera.camera_to_model().data().iter().zip(expected.data())
+        {
+            assert!((a - b).abs() < 1e-9, "{a} != {b}");
+        }
+    }
+
+    fn assert_orbit_eq(a: Orbit, b: Orbit) {
+        assert!((a.azimuth - b.azimuth).abs() < 1e-9, "{a:?} != {b:?}");
+        assert!((a.elevation - b.elevation).abs() < 1e-9, "{a:?} != {b:?}");
+        assert!((a.distance - b.distance).abs() < 1e-9, "{a:?} != {b:?}");
+        assert!(
+            a.target.distance_to(&b.target).into_f64() < 1e-9,
+            "{a:?} != {b:?}"
+        );
+    }
+}
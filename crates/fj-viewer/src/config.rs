@@ -0,0 +1,119 @@
+use fj_interop::mesh::Color;
+
+/// Configuration of the viewer
+///
+/// Passed to [`Viewer::with_config`]. The default configuration results in the
+/// viewer's regular look.
+///
+/// [`Viewer::with_config`]: crate::Viewer::with_config
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ViewerConfig {
+    /// The theme of the viewer
+    pub theme: Theme,
+
+    /// The background behind the model
+    ///
+    /// Overrides the background of the theme, if set.
+    pub background: Option<Background>,
+}
+
+impl ViewerConfig {
+    /// Access the background that is used, according to the configuration
+    pub fn background(&self) -> Background {
+        self.background.unwrap_or_else(|| self.theme.background())
+    }
+}
+
+/// The theme of the viewer
+///
+/// The theme determines the background, unless one is configured explicitly,
+/// and the colors of the overlays, like the navigation cube and the axes.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Theme {
+    /// Dark elements on a light background
+    #[default]
+    Light,
+
+    /// Light elements on a dark background
+    Dark,
+}
+
+impl Theme {
+    /// Access the background of the theme
+    pub fn background(&self) -> Background {
+        match self {
+            Self::Light => Background::Solid(Color::WHITE),
+            Self::Dark => Background::Gradient {
+                top: Color([58, 62, 68, 255]),
+                bottom: Color([24, 26, 29, 255]),
+            },
+        }
+    }
+
+    /// Access the tint of the overlays
+    ///
+    /// The colors of the overlays are blended towards the RGB part of the tint,
+    /// by the amount given in its alpha channel. The light theme leaves them
+    /// unchanged, the dark theme lightens them, so they stand out against the
+    /// dark background.
+    pub(crate) fn overlay_tint(&self) -> [f32; 4] {
+        match self {
+            Self::Light => [0., 0., 0., 0.],
+            Self::Dark => [1., 1., 1., 0.3],
+        }
+    }
+
+    /// Return the other theme
+    pub fn toggle(&self) -> Self {
+        match self {
+            Self::Light => Self::Dark,
+            Self::Dark => Self::Light,
+        }
+    }
+}
+
+/// The background behind the model
+///
+/// The alpha channel of the colors is ignored. The background is always
+/// opaque.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Background {
+    /// A single color
+    Solid(Color),
+
+    /// A vertical gradient between two colors
+    ///
+    /// In the split screen mode, each view has its own gradient.
+    Gradient {
+        /// The color at the top of the view
+        top: Color,
+
+        /// The color at the bottom of the view
+        bottom: Color,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::mesh::Color;
+
+    use super::{Background, Theme, ViewerConfig};
+
+    #[test]
+    fn background() {
+        let config = ViewerConfig::default();
+        assert_eq!(config.background(), Background::Solid(Color::WHITE));
+
+        let config = ViewerConfig {
+            theme: Theme::Dark,
+            ..config
+        };
+        assert_eq!(config.background(), Theme::Dark.background());
+
+        let config = ViewerConfig {
+            background: Some(Background::Solid(Color::GRAY)),
+            ..config
+        };
+        assert_eq!(config.background(), Background::Solid(Color::GRAY));
+    }
+}
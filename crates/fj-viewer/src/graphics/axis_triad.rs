@@ -0,0 +1,229 @@
+use bytemuck::{Pod, Zeroable};
+use fj_math::Transform;
+use wgpu::util::DeviceExt;
+
+use crate::screen::Viewport;
+
+use super::{
+    navigation_cube::{overlay_mvp_matrix, NO_TINT},
+    renderer::set_viewport,
+    transform,
+};
+
+/// Renders the coordinate axes into a corner of each view
+///
+/// The axes are rotated along with the camera, so they show the orientation of
+/// the model. The x-, y-, and z-axis are drawn in red, green, and blue, tinted
+/// according to the theme.
+#[derive(Debug)]
+pub struct AxisTriadRenderer {
+    vertex_buffer: wgpu::Buffer,
+    render_pipeline: wgpu::RenderPipeline,
+    mvp_matrix_bind_group: wgpu::BindGroup,
+    mvp_matrix_buffer: wgpu::Buffer,
+    tint_buffer: wgpu::Buffer,
+}
+
+const SCALE_FACTOR: f64 = 0.15;
+const TRIAD_TRANSLATION: [f64; 3] = [-0.8, -0.7, 0.0];
+
+const VERTICES: [TriadVertex; 6] = [
+    TriadVertex::new([0., 0., 0.], [1., 0., 0.]),
+    TriadVertex::new([1., 0., 0.], [1., 0., 0.]),
+    TriadVertex::new([0., 0., 0.], [0., 1., 0.]),
+    TriadVertex::new([0., 1., 0.], [0., 1., 0.]),
+    TriadVertex::new([0., 0., 0.], [0., 0., 1.]),
+    TriadVertex::new([0., 0., 1.], [0., 0., 1.]),
+];
+
+impl AxisTriadRenderer {
+    pub fn new(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+    ) -> Self {
+        let vertex_buffer =
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Axis Triad Vertex Buffer"),
+                contents: bytemuck::cast_slice(&VERTICES),
+                usage: wgpu::BufferUsages::VERTEX,
+            });
+
+        let mvp_matrix_buffer =
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Axis Triad Matrix Buffer"),
+                contents: bytemuck::cast_slice(&[
+                    transform::Transform::identity(),
+                ]),
+                usage: wgpu::BufferUsages::UNIFORM
+                    | wgpu::BufferUsages::COPY_DST,
+            });
+        let tint_buffer =
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Axis Triad Tint Buffer"),
+                contents: bytemuck::cast_slice(&[NO_TINT]),
+                usage: wgpu::BufferUsages::UNIFORM
+                    | wgpu::BufferUsages::COPY_DST,
+            });
+        let mvp_matrix_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::VERTEX,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+                label: Some("axis_triad_matrix_group_layout"),
+            });
+        let mvp_matrix_bind_group =
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                layout: &mvp_matrix_bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: mvp_matrix_buffer.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: tint_buffer.as_entire_binding(),
+                    },
+                ],
+                label: Some("axis_triad_matrix_bind_group"),
+            });
+
+        let shader =
+            device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("Axis Triad Shader"),
+                source: wgpu::ShaderSource::Wgsl(
+                    include_str!("axis_triad.wgsl").into(),
+                ),
+            });
+
+        let render_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Axis Triad Pipeline Layout"),
+                bind_group_layouts: &[&mvp_matrix_bind_group_layout],
+                push_constant_ranges: &[],
+            });
+
+        let render_pipeline =
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("Axis Triad Renderer"),
+                layout: Some(&render_pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vertex",
+                    buffers: &[wgpu::VertexBufferLayout {
+                        array_stride: std::mem::size_of::<TriadVertex>()
+                            as wgpu::BufferAddress,
+                        step_mode: wgpu::VertexStepMode::Vertex,
+                        attributes: &wgpu::vertex_attr_array![
+                            0 => Float32x3,
+                            1 => Float32x3,
+                        ],
+                    }],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fragment",
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format: config.format,
+                        blend: Some(wgpu::BlendState::REPLACE),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::LineList,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState {
+                    count: 1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+                multiview: None,
+            });
+
+        Self {
+            vertex_buffer,
+            render_pipeline,
+            mvp_matrix_bind_group,
+            mvp_matrix_buffer,
+            tint_buffer,
+        }
+    }
+
+    pub fn draw(
+        &mut self,
+        view: &wgpu::TextureView,
+        encoder: &mut wgpu::CommandEncoder,
+        queue: &wgpu::Queue,
+        viewport: &Viewport,
+        rotation: Transform,
+        tint: [f32; 4],
+    ) {
+        queue.write_buffer(&self.tint_buffer, 0, bytemuck::cast_slice(&[tint]));
+
+        let [width, height] = viewport.size.as_f64();
+        let aspect_ratio = width / height;
+
+        let mvp_matrix = overlay_mvp_matrix(
+            rotation,
+            aspect_ratio,
+            SCALE_FACTOR,
+            TRIAD_TRANSLATION,
+        );
+        queue.write_buffer(
+            &self.mvp_matrix_buffer,
+            0,
+            bytemuck::cast_slice(&[mvp_matrix]),
+        );
+
+        let mut render_pass =
+            encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                ..Default::default()
+            });
+        set_viewport(&mut render_pass, viewport);
+        render_pass.set_pipeline(&self.render_pipeline);
+        render_pass.set_bind_group(0, &self.mvp_matrix_bind_group, &[]);
+        render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        render_pass.draw(0..VERTICES.len() as u32, 0..1);
+    }
+}
+
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+struct TriadVertex {
+    position: [f32; 3],
+    color: [f32; 3],
+}
+
+impl TriadVertex {
+    const fn new(position: [f32; 3], color: [f32; 3]) -> Self {
+        Self { position, color }
+    }
+}
@@ -0,0 +1,27 @@
+@group(0) @binding(0)
+var<uniform> mvp_matrix: mat4x4<f32>;
+@group(0) @binding(1)
+var<uniform> tint: vec4<f32>;
+
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) color: vec3<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec3<f32>,
+}
+
+@vertex
+fn vertex(in: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.clip_position = mvp_matrix * vec4<f32>(in.position, 1.0);
+    out.color = in.color;
+    return out;
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(mix(in.color, tint.rgb, tint.a), 1.0);
+}
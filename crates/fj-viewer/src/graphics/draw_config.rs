@@ -1,3 +1,7 @@
+use crate::{Background, Theme};
+
+use super::Lighting;
+
 /// High level configuration for rendering the active model
 #[derive(Debug)]
 pub struct DrawConfig {
@@ -6,6 +10,21 @@ pub struct DrawConfig {
 
     /// Toggle for displaying the wireframe model
     pub draw_mesh: bool,
+
+    /// Toggle for displaying the shaded model semi-transparently
+    ///
+    /// This makes internal features of the model visible. It only has an
+    /// effect, if the shaded model is displayed.
+    pub draw_x_ray: bool,
+
+    /// The lighting of the shaded model
+    pub lighting: Lighting,
+
+    /// The background behind the model
+    pub background: Background,
+
+    /// The theme, which determines the look of the overlays
+    pub theme: Theme,
 }
 
 impl Default for DrawConfig {
@@ -13,6 +32,10 @@ impl Default for DrawConfig {
         Self {
             draw_model: true,
             draw_mesh: false,
+            draw_x_ray: false,
+            lighting: Lighting::default(),
+            background: Theme::default().background(),
+            theme: Theme::default(),
         }
     }
 }
@@ -5,29 +5,56 @@ use super::{
 
 pub struct Drawables<'r> {
     pub model: Drawable<'r>,
-    pub mesh: Option<Drawable<'r>>,
+    pub transparent_model: Drawable<'r>,
+    pub x_ray: Drawable<'r>,
+    pub mesh: Option<[Drawable<'r>; 2]>,
 }
 
 impl<'r> Drawables<'r> {
-    pub fn new(geometries: &'r Geometries, pipelines: &'r Pipelines) -> Self {
-        let model = Drawable::new(&geometries.mesh, &pipelines.model);
-        let mesh = pipelines
-            .mesh
-            .as_ref()
-            .map(|pipeline| Drawable::new(&geometries.mesh, pipeline));
+    /// Create the drawables for the view with the provided index
+    pub fn new(
+        geometries: &'r Geometries,
+        pipelines: &'r Pipelines,
+        view: usize,
+    ) -> Self {
+        let model = Drawable::new(&geometries.mesh, &pipelines.model, view);
+        let transparent_model = Drawable::new(
+            &geometries.transparent,
+            &pipelines.transparent_model,
+            view,
+        );
+        let x_ray = Drawable::new(&geometries.all, &pipelines.x_ray, view);
+        let mesh = pipelines.mesh.as_ref().map(|pipeline| {
+            [&geometries.mesh, &geometries.transparent]
+                .map(|geometry| Drawable::new(geometry, pipeline, view))
+        });
 
-        Self { model, mesh }
+        Self {
+            model,
+            transparent_model,
+            x_ray,
+            mesh,
+        }
     }
 }
 
 pub struct Drawable<'a> {
     pub geometry: &'a Geometry,
     pub pipeline: &'a Pipeline,
+    pub view: usize,
 }
 
 impl<'a> Drawable<'a> {
-    fn new(geometry: &'a Geometry, pipeline: &'a Pipeline) -> Self {
-        Self { geometry, pipeline }
+    fn new(
+        geometry: &'a Geometry,
+        pipeline: &'a Pipeline,
+        view: usize,
+    ) -> Self {
+        Self {
+            geometry,
+            pipeline,
+            view,
+        }
     }
 
     pub fn draw<'b>(&self, render_pass: &mut wgpu::RenderPass<'b>)
@@ -37,7 +64,9 @@ impl<'a> Drawable<'a> {
         render_pass.set_pipeline(&self.pipeline.0);
         render_pass.set_vertex_buffer(0, self.geometry.vertex_buffer.slice(..));
         render_pass.set_index_buffer(
-            self.geometry.index_buffer.slice(..),
+            self.geometry
+                .index_buffer
+                .slice(self.geometry.index_offset(self.view)..),
             wgpu::IndexFormat::Uint32,
         );
 
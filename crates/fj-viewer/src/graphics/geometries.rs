@@ -2,18 +2,39 @@ use std::convert::TryInto;
 
 use wgpu::util::DeviceExt;
 
-use super::vertices::{Vertex, Vertices};
+use super::{
+    vertices::{Vertex, Vertices},
+    MAX_VIEWS,
+};
 
 #[derive(Debug)]
 pub struct Geometries {
     pub mesh: Geometry,
+    pub transparent: Geometry,
+    pub all: Geometry,
 }
 
 impl Geometries {
     pub fn new(device: &wgpu::Device, mesh: &Vertices) -> Self {
-        let mesh = Geometry::new(device, mesh.vertices(), mesh.indices());
+        let transparent = Geometry::new(
+            device,
+            mesh.vertices(),
+            mesh.transparent_indices(),
+            MAX_VIEWS,
+        );
+        let all = Geometry::new(
+            device,
+            mesh.vertices(),
+            mesh.all_indices(),
+            MAX_VIEWS,
+        );
+        let mesh = Geometry::new(device, mesh.vertices(), mesh.indices(), 1);
 
-        Self { mesh }
+        Self {
+            mesh,
+            transparent,
+            all,
+        }
     }
 }
 
@@ -22,6 +43,12 @@ pub struct Geometry {
     pub vertex_buffer: wgpu::Buffer,
     pub index_buffer: wgpu::Buffer,
     pub num_indices: u32,
+
+    /// The number of copies of the indices in the index buffer
+    ///
+    /// Triangles that are sorted before drawing them need a copy of their
+    /// indices for each view, as each view sorts them differently.
+    pub num_copies: usize,
 }
 
 impl Geometry {
@@ -29,7 +56,10 @@ impl Geometry {
         device: &wgpu::Device,
         vertices: &[Vertex],
         indices: &[u32],
+        num_copies: usize,
     ) -> Self {
+        let indices = indices.repeat(num_copies);
+
         Self {
             vertex_buffer: device.create_buffer_init(
                 &wgpu::util::BufferInitDescriptor {
@@ -41,14 +71,27 @@ impl Geometry {
             index_buffer: device.create_buffer_init(
                 &wgpu::util::BufferInitDescriptor {
                     label: None,
-                    contents: bytemuck::cast_slice(indices),
-                    usage: wgpu::BufferUsages::INDEX,
+                    contents: bytemuck::cast_slice(&indices),
+                    // Needs to be writable, so transparent triangles can be
+                    // re-sorted before drawing them.
+                    usage: wgpu::BufferUsages::INDEX
+                        | wgpu::BufferUsages::COPY_DST,
                 },
             ),
-            num_indices: indices
-                .len()
+            num_indices: (indices.len() / num_copies.max(1))
                 .try_into()
                 .expect("`usize` couldn't be cast to `u32`"),
+            num_copies,
         }
     }
+
+    /// The offset of the indices for the provided view, in bytes
+    ///
+    /// Views beyond the number of copies share the last copy.
+    pub fn index_offset(&self, view: usize) -> wgpu::BufferAddress {
+        let copy = view.min(self.num_copies.saturating_sub(1));
+        let size = self.num_indices as usize * std::mem::size_of::<u32>();
+
+        (copy * size) as wgpu::BufferAddress
+    }
 }
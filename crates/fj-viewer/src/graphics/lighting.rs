@@ -0,0 +1,154 @@
+use fj_math::{Scalar, Vector};
+
+/// The lighting of the shaded model
+///
+/// The model is lit by up to three directional lights, and an ambient light
+/// that lights all surfaces equally. The contributions of all lights are added
+/// up, and the result is capped, so surfaces can't become brighter than their
+/// color.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lighting {
+    /// The main light, which determines most of the shading
+    pub key: Light,
+
+    /// A weaker light, which brightens the shadows of the key light
+    pub fill: Light,
+
+    /// A light from behind, which highlights the silhouette of the model
+    pub back: Light,
+
+    /// The intensity of the ambient light
+    pub ambient: f64,
+}
+
+impl Default for Lighting {
+    fn default() -> Self {
+        LightingPreset::default().lighting()
+    }
+}
+
+/// A directional light
+///
+/// Lights are defined relative to the camera, so they move with it, and the
+/// model is lit the same way, regardless of which side it's viewed from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Light {
+    /// The direction that the light shines in, in camera space
+    ///
+    /// The camera looks along the negative z-axis, with the positive y-axis
+    /// pointing up. The direction doesn't need to be normalized.
+    pub direction: Vector<3>,
+
+    /// The intensity of the light
+    ///
+    /// A light of intensity `1.` fully lights a surface that is facing it. Set
+    /// this to `0.` to disable the light.
+    pub intensity: f64,
+}
+
+impl Light {
+    /// A light that is disabled
+    pub const NONE: Self = Self {
+        direction: Vector {
+            components: [Scalar::ZERO; 3],
+        },
+        intensity: 0.,
+    };
+
+    fn new(direction: [f64; 3], intensity: f64) -> Self {
+        Self {
+            direction: Vector::from(direction),
+            intensity,
+        }
+    }
+}
+
+/// A predefined [`Lighting`] configuration
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum LightingPreset {
+    /// A single light that shines from the camera onto the model
+    #[default]
+    Headlight,
+
+    /// A soft key light from the upper left, a fill light, and some ambient
+    /// light
+    Studio,
+
+    /// A light that shines down from above the camera, and some ambient light
+    Top,
+
+    /// The classic arrangement of key, fill, and back light
+    ThreePoint,
+}
+
+impl LightingPreset {
+    /// Access the lighting configuration of the preset
+    pub fn lighting(&self) -> Lighting {
+        match self {
+            Self::Headlight => Lighting {
+                key: Light::new([0., 0., -1.], 1.),
+                fill: Light::NONE,
+                back: Light::NONE,
+                ambient: 0.,
+            },
+            Self::Studio => Lighting {
+                key: Light::new([0.5, -0.5, -1.], 0.75),
+                fill: Light::new([-0.5, -0.2, -1.], 0.35),
+                back: Light::NONE,
+                ambient: 0.2,
+            },
+            Self::Top => Lighting {
+                key: Light::new([0., -1., -0.3], 0.85),
+                fill: Light::NONE,
+                back: Light::NONE,
+                ambient: 0.25,
+            },
+            Self::ThreePoint => Lighting {
+                key: Light::new([1., -1., -1.], 0.8),
+                fill: Light::new([-1., 0., -1.], 0.35),
+                back: Light::new([0., -1., 1.], 0.5),
+                ambient: 0.1,
+            },
+        }
+    }
+
+    /// Return the preset that comes after this one, when cycling through them
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Headlight => Self::Studio,
+            Self::Studio => Self::Top,
+            Self::Top => Self::ThreePoint,
+            Self::ThreePoint => Self::Headlight,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LightingPreset;
+
+    #[test]
+    fn cycle_presets() {
+        let mut preset = LightingPreset::default();
+        let mut visited = Vec::new();
+
+        loop {
+            visited.push(preset);
+            preset = preset.next();
+
+            if preset == LightingPreset::default() {
+                break;
+            }
+        }
+
+        assert_eq!(
+            visited,
+            [
+                LightingPreset::Headlight,
+                LightingPreset::Studio,
+                LightingPreset::Top,
+                LightingPreset::ThreePoint,
+            ]
+        );
+    }
+}
@@ -1,9 +1,11 @@
 //! Rendering primitives, routines, and structures.
 
+mod axis_triad;
 mod device;
 mod draw_config;
 mod drawables;
 mod geometries;
+mod lighting;
 mod model;
 mod navigation_cube;
 mod pipelines;
@@ -17,8 +19,12 @@ mod vertices;
 pub use self::{
     device::DeviceError,
     draw_config::DrawConfig,
+    lighting::{Light, Lighting, LightingPreset},
     renderer::{DrawError, Renderer, RendererInitError},
 };
 
 pub const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
 pub const SAMPLE_COUNT: u32 = 4;
+
+/// The maximum number of views that the model is drawn into at once
+pub const MAX_VIEWS: usize = 4;
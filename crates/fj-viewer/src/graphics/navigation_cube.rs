@@ -1,8 +1,11 @@
 use fj_math::Transform;
 use wgpu::util::DeviceExt;
 
+use crate::screen::Viewport;
+
 use super::{
     model::{self, load_model, DrawModel, Model},
+    renderer::set_viewport,
     transform,
 };
 
@@ -12,11 +15,20 @@ pub struct NavigationCubeRenderer {
     render_pipeline: wgpu::RenderPipeline,
     mvp_matrix_bind_group: wgpu::BindGroup,
     mvp_matrix_buffer: wgpu::Buffer,
+    tint_buffer: wgpu::Buffer,
 }
 
 const SCALE_FACTOR: f64 = 0.13;
 const CUBE_TRANSLATION: [f64; 3] = [0.8, 0.7, 0.0];
 
+/// The tint of an overlay that leaves its colors unchanged
+///
+/// A tint is a color, with the alpha channel defining how much the colors of
+/// the overlay are blended towards it. See [`Theme::overlay_tint`].
+///
+/// [`Theme::overlay_tint`]: crate::Theme::overlay_tint
+pub const NO_TINT: [f32; 4] = [0., 0., 0., 0.];
+
 impl NavigationCubeRenderer {
     pub fn new(
         device: &wgpu::Device,
@@ -59,27 +71,52 @@ impl NavigationCubeRenderer {
                 usage: wgpu::BufferUsages::UNIFORM
                     | wgpu::BufferUsages::COPY_DST,
             });
+        let tint_buffer =
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Navigation Cube Tint Buffer"),
+                contents: bytemuck::cast_slice(&[NO_TINT]),
+                usage: wgpu::BufferUsages::UNIFORM
+                    | wgpu::BufferUsages::COPY_DST,
+            });
         let mvp_matrix_bind_group_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
-                entries: &[wgpu::BindGroupLayoutEntry {
-                    binding: 0,
-                    visibility: wgpu::ShaderStages::VERTEX,
-                    ty: wgpu::BindingType::Buffer {
-                        ty: wgpu::BufferBindingType::Uniform,
-                        has_dynamic_offset: false,
-                        min_binding_size: None,
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::VERTEX,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
                     },
-                    count: None,
-                }],
+                ],
                 label: Some("mvp_matrix_group_layout"),
             });
         let mvp_matrix_bind_group =
             device.create_bind_group(&wgpu::BindGroupDescriptor {
                 layout: &mvp_matrix_bind_group_layout,
-                entries: &[wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: mvp_matrix_buffer.as_entire_binding(),
-                }],
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: mvp_matrix_buffer.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: tint_buffer.as_entire_binding(),
+                    },
+                ],
                 label: Some("mvp_matrix_bind_group"),
             });
 
@@ -153,6 +190,7 @@ impl NavigationCubeRenderer {
             render_pipeline,
             mvp_matrix_bind_group,
             mvp_matrix_buffer,
+            tint_buffer,
         }
     }
 
@@ -161,10 +199,21 @@ impl NavigationCubeRenderer {
         view: &wgpu::TextureView,
         encoder: &mut wgpu::CommandEncoder,
         queue: &wgpu::Queue,
-        aspect_ratio: f64,
+        viewport: &Viewport,
         rotation: Transform,
+        tint: [f32; 4],
     ) {
-        let mvp_matrix = Self::get_mvp_matrix(rotation, aspect_ratio);
+        queue.write_buffer(&self.tint_buffer, 0, bytemuck::cast_slice(&[tint]));
+
+        let [width, height] = viewport.size.as_f64();
+        let aspect_ratio = width / height;
+
+        let mvp_matrix = overlay_mvp_matrix(
+            rotation,
+            aspect_ratio,
+            SCALE_FACTOR,
+            CUBE_TRANSLATION,
+        );
         queue.write_buffer(
             &self.mvp_matrix_buffer,
             0,
@@ -183,38 +232,48 @@ impl NavigationCubeRenderer {
                 })],
                 ..Default::default()
             });
+        set_viewport(&mut render_pass, viewport);
         render_pass.set_pipeline(&self.render_pipeline);
         render_pass.set_bind_group(1, &self.mvp_matrix_bind_group, &[]);
         render_pass.draw_model(&self.cube_model);
     }
+}
 
-    fn get_mvp_matrix(rotation: Transform, aspect_ratio: f64) -> [f32; 16] {
-        let scale = Transform::scale(SCALE_FACTOR);
-        let world_translation = Transform::translation([0.0, 0.0, -1.0]);
+/// Compute the transform for an overlay that is drawn into a corner of a view
+///
+/// The overlay is rotated along with the camera, scaled by `scale_factor`, and
+/// then moved to the corner by `screen_translation`, which is defined in
+/// normalized device coordinates.
+pub fn overlay_mvp_matrix(
+    rotation: Transform,
+    aspect_ratio: f64,
+    scale_factor: f64,
+    screen_translation: [f64; 3],
+) -> [f32; 16] {
+    let scale = Transform::scale(scale_factor);
+    let world_translation = Transform::translation([0.0, 0.0, -1.0]);
 
-        let mut model_matrix = Transform::identity();
-        model_matrix = model_matrix * world_translation;
-        model_matrix = model_matrix * rotation;
-        model_matrix = model_matrix * scale;
+    let mut model_matrix = Transform::identity();
+    model_matrix = model_matrix * world_translation;
+    model_matrix = model_matrix * rotation;
+    model_matrix = model_matrix * scale;
 
-        let perspective =
-            nalgebra::Perspective3::new(aspect_ratio, 30.0, 0.1, 2.0);
+    let perspective = nalgebra::Perspective3::new(aspect_ratio, 30.0, 0.1, 2.0);
 
-        let view_matrix = nalgebra::Matrix4::look_at_lh(
-            &nalgebra::Point3::new(0.0, 0.0, 0.0),
-            &nalgebra::Point3::new(0.0, 0.0, 1.0),
-            &nalgebra::Vector3::new(0.0, -1.0, 0.0),
-        );
+    let view_matrix = nalgebra::Matrix4::look_at_lh(
+        &nalgebra::Point3::new(0.0, 0.0, 0.0),
+        &nalgebra::Point3::new(0.0, 0.0, 1.0),
+        &nalgebra::Vector3::new(0.0, -1.0, 0.0),
+    );
 
-        let screen_translation = Transform::translation(CUBE_TRANSLATION);
+    let screen_translation = Transform::translation(screen_translation);
 
-        let matrix = screen_translation.get_inner().matrix()
-            * *perspective.to_projective().matrix()
-            * view_matrix
-            * model_matrix.get_inner().matrix();
+    let matrix = screen_translation.get_inner().matrix()
+        * *perspective.to_projective().matrix()
+        * view_matrix
+        * model_matrix.get_inner().matrix();
 
-        let mut mat = [0.; 16];
-        mat.copy_from_slice(matrix.as_slice());
-        mat.map(|x| x as f32)
-    }
+    let mut mat = [0.; 16];
+    mat.copy_from_slice(matrix.as_slice());
+    mat.map(|x| x as f32)
 }
@@ -2,6 +2,8 @@
 
 @group(1) @binding(0)
 var<uniform> mvp_matrix: mat4x4<f32>;
+@group(1) @binding(1)
+var<uniform> tint: vec4<f32>;
 
 struct VertexInput {
     @location(0) position: vec3<f32>,
@@ -32,5 +34,6 @@ var s_diffuse: sampler;
 
 @fragment
 fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
-    return textureSample(t_diffuse, s_diffuse, in.tex_coords);
+    let color = textureSample(t_diffuse, s_diffuse, in.tex_coords);
+    return vec4<f32>(mix(color.rgb, tint.rgb, tint.a), color.a);
 }
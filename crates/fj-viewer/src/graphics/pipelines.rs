@@ -8,7 +8,10 @@ use super::{
 
 #[derive(Debug)]
 pub struct Pipelines {
+    pub background: Pipeline,
     pub model: Pipeline,
+    pub transparent_model: Pipeline,
+    pub x_ray: Pipeline,
     pub mesh: Option<Pipeline>,
 }
 
@@ -28,6 +31,13 @@ impl Pipelines {
 
         let shaders = Shaders::new(device);
 
+        let background = Pipeline::background(
+            device,
+            &pipeline_layout,
+            shaders.background(),
+            color_format,
+        );
+
         let model = Pipeline::new(
             device,
             &pipeline_layout,
@@ -35,6 +45,31 @@ impl Pipelines {
             wgpu::PrimitiveTopology::TriangleList,
             wgpu::PolygonMode::Fill,
             color_format,
+            true,
+        );
+
+        // Transparent triangles are drawn after all opaque ones, back to
+        // front. They must not write to the depth buffer, or they would hide
+        // the transparent triangles behind them.
+        let transparent_model = Pipeline::new(
+            device,
+            &pipeline_layout,
+            shaders.model(),
+            wgpu::PrimitiveTopology::TriangleList,
+            wgpu::PolygonMode::Fill,
+            color_format,
+            false,
+        );
+
+        // In x-ray mode, all triangles are transparent, so the same applies.
+        let x_ray = Pipeline::new(
+            device,
+            &pipeline_layout,
+            shaders.x_ray(),
+            wgpu::PrimitiveTopology::TriangleList,
+            wgpu::PolygonMode::Fill,
+            color_format,
+            false,
         );
 
         let mesh = if features.contains(wgpu::Features::POLYGON_MODE_LINE) {
@@ -48,12 +83,19 @@ impl Pipelines {
                 wgpu::PrimitiveTopology::TriangleList,
                 wgpu::PolygonMode::Line,
                 color_format,
+                true,
             ))
         } else {
             None
         };
 
-        Self { model, mesh }
+        Self {
+            background,
+            model,
+            transparent_model,
+            x_ray,
+            mesh,
+        }
     }
 }
 
@@ -68,6 +110,7 @@ impl Pipeline {
         topology: wgpu::PrimitiveTopology,
         polygon_mode: wgpu::PolygonMode,
         color_format: wgpu::TextureFormat,
+        depth_write_enabled: bool,
     ) -> Self {
         let pipeline =
             device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
@@ -97,7 +140,7 @@ impl Pipeline {
                 },
                 depth_stencil: Some(wgpu::DepthStencilState {
                     format: DEPTH_FORMAT,
-                    depth_write_enabled: true,
+                    depth_write_enabled,
                     depth_compare: wgpu::CompareFunction::LessEqual,
                     stencil: wgpu::StencilState {
                         front: wgpu::StencilFaceState::IGNORE,
@@ -128,4 +171,53 @@ impl Pipeline {
 
         Self(pipeline)
     }
+
+    /// Create the pipeline that draws the background
+    ///
+    /// The background is a single triangle that covers the viewport. Its
+    /// vertices are generated in the vertex shader, so it doesn't need any
+    /// vertex buffers. It's drawn first, and doesn't write to the depth
+    /// buffer, so anything that's drawn later ends up in front of it.
+    fn background(
+        device: &wgpu::Device,
+        pipeline_layout: &wgpu::PipelineLayout,
+        shader: Shader,
+        color_format: wgpu::TextureFormat,
+    ) -> Self {
+        let pipeline =
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: None,
+                layout: Some(pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: shader.module,
+                    entry_point: "vertex_background",
+                    buffers: &[],
+                },
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: Some(wgpu::DepthStencilState {
+                    format: DEPTH_FORMAT,
+                    depth_write_enabled: false,
+                    depth_compare: wgpu::CompareFunction::Always,
+                    stencil: wgpu::StencilState::default(),
+                    bias: wgpu::DepthBiasState::default(),
+                }),
+                multisample: wgpu::MultisampleState {
+                    count: SAMPLE_COUNT,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: shader.module,
+                    entry_point: shader.frag_entry,
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format: color_format,
+                        blend: None,
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                }),
+                multiview: None,
+            });
+
+        Self(pipeline)
+    }
 }
@@ -1,4 +1,4 @@
-use std::{io, mem::size_of, vec};
+use std::{io, mem, vec};
 
 use thiserror::Error;
 use tracing::{debug, error, trace};
@@ -6,14 +6,22 @@ use wgpu::util::DeviceExt as _;
 
 use crate::{
     camera::Camera,
-    screen::{Screen, ScreenSize},
+    screen::{Screen, ScreenSize, Viewport},
+    Background,
 };
 
 use super::{
-    device::Device, draw_config::DrawConfig, drawables::Drawables,
-    geometries::Geometries, navigation_cube::NavigationCubeRenderer,
-    pipelines::Pipelines, transform::Transform, uniforms::Uniforms,
-    vertices::Vertices, DeviceError, DEPTH_FORMAT, SAMPLE_COUNT,
+    axis_triad::AxisTriadRenderer,
+    device::Device,
+    draw_config::DrawConfig,
+    drawables::Drawables,
+    geometries::Geometries,
+    navigation_cube::NavigationCubeRenderer,
+    pipelines::Pipelines,
+    transform::Transform,
+    uniforms::{encode_opaque_color, Uniforms},
+    vertices::{SortedTriangles, Vertices},
+    DeviceError, DEPTH_FORMAT, MAX_VIEWS, SAMPLE_COUNT,
 };
 
 /// Graphics rendering state and target abstraction
@@ -29,10 +37,21 @@ pub struct Renderer {
     uniform_buffer: wgpu::Buffer,
     bind_group: wgpu::BindGroup,
 
+    vertices: Vertices,
     geometries: Geometries,
     pipelines: Pipelines,
 
+    // The sort order of the transparent triangles, and of all triangles in
+    // x-ray mode, for each view.
+    sorted_transparent: [SortedTriangles; MAX_VIEWS],
+    sorted_all: [SortedTriangles; MAX_VIEWS],
+
+    // Geometry that has been uploaded, but is not drawn right now. See
+    // `Renderer::swap_geometry`.
+    inactive_geometry: Option<InactiveGeometry>,
+
     navigation_cube_renderer: NavigationCubeRenderer,
+    axis_triad_renderer: AxisTriadRenderer,
 }
 
 impl Renderer {
@@ -142,11 +161,9 @@ impl Renderer {
                     ty: wgpu::BindingType::Buffer {
                         ty: wgpu::BufferBindingType::Uniform,
                         has_dynamic_offset: false,
-                        min_binding_size: wgpu::BufferSize::new(size_of::<
-                            Uniforms,
-                        >(
-                        )
-                            as u64),
+                        min_binding_size: wgpu::BufferSize::new(
+                            mem::size_of::<Uniforms>() as u64,
+                        ),
                     },
                     count: None,
                 }],
@@ -169,7 +186,8 @@ impl Renderer {
                 label: None,
             });
 
-        let geometries = Geometries::new(&device.device, &Vertices::empty());
+        let vertices = Vertices::empty();
+        let geometries = Geometries::new(&device.device, &vertices);
         let pipelines = Pipelines::new(
             &device.device,
             &bind_group_layout,
@@ -182,6 +200,8 @@ impl Renderer {
             &device.queue,
             &surface_config,
         );
+        let axis_triad_renderer =
+            AxisTriadRenderer::new(&device.device, &surface_config);
 
         Ok(Self {
             surface,
@@ -194,16 +214,76 @@ impl Renderer {
             uniform_buffer,
             bind_group,
 
+            vertices,
             geometries,
             pipelines,
 
+            sorted_transparent: Default::default(),
+            sorted_all: Default::default(),
+
+            inactive_geometry: None,
+
             navigation_cube_renderer,
+            axis_triad_renderer,
         })
     }
 
     /// Updates the geometry of the model being rendered.
-    pub fn update_geometry(&mut self, mesh: Vertices) {
+    ///
+    /// The alternative geometry, if provided, is uploaded too, but not drawn
+    /// until [`Renderer::swap_geometry`] is called.
+    pub fn update_geometry(
+        &mut self,
+        mesh: Vertices,
+        alternative: Option<Vertices>,
+    ) {
         self.geometries = Geometries::new(&self.device.device, &mesh);
+        self.vertices = mesh;
+        self.reset_sort_order();
+
+        self.inactive_geometry = alternative.map(|vertices| InactiveGeometry {
+            geometries: Geometries::new(&self.device.device, &vertices),
+            vertices,
+            sorted_transparent: Default::default(),
+            sorted_all: Default::default(),
+        });
+    }
+
+    /// Switch between the geometry that is drawn and the alternative one
+    ///
+    /// Both geometries stay uploaded, so this is cheap enough to do in
+    /// response to user input. Each geometry keeps the sort order of its
+    /// triangles, too.
+    ///
+    /// Returns `false`, if there is no alternative geometry to switch to.
+    pub fn swap_geometry(&mut self) -> bool {
+        let Some(inactive) = &mut self.inactive_geometry else {
+            return false;
+        };
+
+        mem::swap(&mut self.vertices, &mut inactive.vertices);
+        mem::swap(&mut self.geometries, &mut inactive.geometries);
+        mem::swap(
+            &mut self.sorted_transparent,
+            &mut inactive.sorted_transparent,
+        );
+        mem::swap(&mut self.sorted_all, &mut inactive.sorted_all);
+
+        true
+    }
+
+    /// Make sure that triangles are sorted again, before they are drawn next
+    ///
+    /// The new geometry's index buffers contain the triangles in their
+    /// original order, regardless of where the cameras are.
+    fn reset_sort_order(&mut self) {
+        for sorted in self
+            .sorted_transparent
+            .iter_mut()
+            .chain(&mut self.sorted_all)
+        {
+            sorted.reset();
+        }
     }
 
     /// Resizes the render surface.
@@ -228,23 +308,27 @@ impl Renderer {
     }
 
     /// Draws the renderer, camera, and config state to the window.
+    ///
+    /// The model is drawn once for each of the provided views, each time from
+    /// the point of view of that view's camera, into that view's viewport.
     pub fn draw(
         &mut self,
-        camera: &Camera,
+        views: &[(&Camera, Viewport)],
         config: &DrawConfig,
     ) -> Result<(), DrawError> {
-        let aspect_ratio = f64::from(self.surface_config.width)
-            / f64::from(self.surface_config.height);
-        let uniforms = Uniforms {
-            transform: Transform::for_vertices(camera, aspect_ratio),
-            transform_normals: Transform::for_normals(camera),
+        // Each view keeps its own sort order of the transparent triangles, so
+        // views need to keep their index, even if some of them are skipped.
+        let visible_views = || {
+            views
+                .iter()
+                .take(MAX_VIEWS)
+                .enumerate()
+                .filter(|(_, (_, viewport))| !viewport.is_empty())
         };
-
-        self.device.queue.write_buffer(
-            &self.uniform_buffer,
-            0,
-            bytemuck::cast_slice(&[uniforms]),
-        );
+        let num_views = visible_views().count();
+        if num_views == 0 {
+            return Ok(());
+        }
 
         let surface_texture = match self.surface.get_current_texture() {
             Ok(surface_texture) => surface_texture,
@@ -264,10 +348,126 @@ impl Renderer {
             .texture
             .create_view(&wgpu::TextureViewDescriptor::default());
 
+        for (i, (view, (camera, viewport))) in visible_views().enumerate() {
+            let is_first = i == 0;
+            let is_last = i == num_views - 1;
+
+            self.draw_view(
+                view,
+                camera,
+                viewport,
+                &color_view,
+                is_first,
+                is_last,
+                config,
+            );
+        }
+
+        // The frame buffer is only resolved into the surface once, after the
+        // last view has been drawn. Overlays are drawn into the surface
+        // directly, so they need to come after that, or the resolve would
+        // overwrite them.
+        for (_, (camera, viewport)) in visible_views() {
+            self.draw_overlays(camera, viewport, &color_view, config);
+        }
+
+        trace!("Presenting...");
+        surface_texture.present();
+
+        trace!("Finished drawing.");
+        Ok(())
+    }
+
+    /// Draws the model into a single viewport
+    ///
+    /// Each view needs its own uniforms and its own sort order of transparent
+    /// triangles. Buffer writes only take effect on the next submission, so
+    /// each view is submitted separately.
+    ///
+    /// The sorted triangles of each view are kept in a separate part of the
+    /// index buffer. They are only sorted and written again, if the camera of
+    /// the view has moved since the last time.
+    ///
+    /// All views are drawn into the multisampled frame buffer, which is
+    /// resolved into the surface by the pass of the last view.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_view(
+        &mut self,
+        view: usize,
+        camera: &Camera,
+        viewport: &Viewport,
+        color_view: &wgpu::TextureView,
+        is_first: bool,
+        is_last: bool,
+        config: &DrawConfig,
+    ) {
+        let [width, height] = viewport.size.as_f64();
+        let aspect_ratio = width / height;
+        let uniforms = Uniforms::new(
+            Transform::for_vertices(camera, aspect_ratio),
+            Transform::for_normals(camera),
+            config,
+        );
+
+        self.device.queue.write_buffer(
+            &self.uniform_buffer,
+            0,
+            bytemuck::cast_slice(&[uniforms]),
+        );
+
+        let (sorted, indices, geometry) = if config.draw_x_ray {
+            (
+                &mut self.sorted_all[view],
+                self.vertices.all_indices(),
+                &self.geometries.all,
+            )
+        } else {
+            (
+                &mut self.sorted_transparent[view],
+                self.vertices.transparent_indices(),
+                &self.geometries.transparent,
+            )
+        };
+        if !indices.is_empty() {
+            if let Some(indices) = sorted.sort(
+                self.vertices.vertices(),
+                indices,
+                camera.position(),
+            ) {
+                self.device.queue.write_buffer(
+                    &geometry.index_buffer,
+                    geometry.index_offset(view),
+                    bytemuck::cast_slice(indices),
+                );
+            }
+        }
+
         let mut encoder = self.device.device.create_command_encoder(
             &wgpu::CommandEncoderDescriptor { label: None },
         );
 
+        // Only the first view clears the screen, or it would erase the views
+        // drawn before it. For the same reason, the multisampled frame buffer
+        // must be kept, until the last view has resolved it.
+        let load = if is_first {
+            // A gradient is drawn separately, for each view. The clear color
+            // only matters, if the views don't cover the whole screen.
+            let color = match config.background {
+                Background::Solid(color) => color,
+                Background::Gradient { bottom, .. } => bottom,
+            };
+            let [r, g, b, a] = encode_opaque_color(color).map(f64::from);
+
+            wgpu::LoadOp::Clear(wgpu::Color { r, g, b, a })
+        } else {
+            wgpu::LoadOp::Load
+        };
+        let (store, resolve_target) = if is_last {
+            (wgpu::StoreOp::Discard, Some(color_view))
+        } else {
+            (wgpu::StoreOp::Store, None)
+        };
+
         // Need this block here, as a render pass only takes effect once it's
         // dropped.
         {
@@ -276,12 +476,8 @@ impl Renderer {
                     color_attachments: &[Some(
                         wgpu::RenderPassColorAttachment {
                             view: &self.frame_buffer,
-                            resolve_target: Some(&color_view),
-                            ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Clear(wgpu::Color::WHITE),
-                                // Not necessary, due to MSAA being enabled.
-                                store: wgpu::StoreOp::Discard,
-                            },
+                            resolve_target,
+                            ops: wgpu::Operations { load, store },
                         },
                     )],
                     depth_stencil_attachment: Some(
@@ -296,37 +492,77 @@ impl Renderer {
                     ),
                     ..Default::default()
                 });
+            set_viewport(&mut render_pass, viewport);
             render_pass.set_bind_group(0, &self.bind_group, &[]);
 
-            let drawables = Drawables::new(&self.geometries, &self.pipelines);
+            if let Background::Gradient { .. } = config.background {
+                render_pass.set_pipeline(&self.pipelines.background.0);
+                render_pass.draw(0..3, 0..1);
+            }
+
+            let drawables =
+                Drawables::new(&self.geometries, &self.pipelines, view);
 
             if config.draw_model {
-                drawables.model.draw(&mut render_pass);
+                if config.draw_x_ray {
+                    drawables.x_ray.draw(&mut render_pass);
+                } else {
+                    // Transparent triangles must come last, so that everything
+                    // behind them has already been drawn.
+                    drawables.model.draw(&mut render_pass);
+                    drawables.transparent_model.draw(&mut render_pass);
+                }
             }
 
-            if let Some(drawable) = drawables.mesh {
+            if let Some(drawables) = drawables.mesh {
                 if config.draw_mesh {
-                    drawable.draw(&mut render_pass);
+                    for drawable in drawables {
+                        drawable.draw(&mut render_pass);
+                    }
                 }
             }
         }
 
+        let command_buffer = encoder.finish();
+        self.device.queue.submit(Some(command_buffer));
+    }
+
+    /// Draws the overlays of a single view into its viewport
+    ///
+    /// Like [`Renderer::draw_view`], this writes buffers that are shared
+    /// between views, so each view is submitted separately.
+    fn draw_overlays(
+        &mut self,
+        camera: &Camera,
+        viewport: &Viewport,
+        color_view: &wgpu::TextureView,
+        config: &DrawConfig,
+    ) {
+        let tint = config.theme.overlay_tint();
+
+        let mut encoder = self.device.device.create_command_encoder(
+            &wgpu::CommandEncoderDescriptor { label: None },
+        );
+
         self.navigation_cube_renderer.draw(
-            &color_view,
+            color_view,
             &mut encoder,
             &self.device.queue,
-            aspect_ratio,
+            viewport,
             camera.rotation,
+            tint,
+        );
+        self.axis_triad_renderer.draw(
+            color_view,
+            &mut encoder,
+            &self.device.queue,
+            viewport,
+            camera.rotation,
+            tint,
         );
 
         let command_buffer = encoder.finish();
         self.device.queue.submit(Some(command_buffer));
-
-        trace!("Presenting...");
-        surface_texture.present();
-
-        trace!("Finished drawing.");
-        Ok(())
     }
 
     fn create_frame_buffer(
@@ -373,6 +609,25 @@ impl Renderer {
     }
 }
 
+/// Geometry that is uploaded, but not drawn right now
+///
+/// See [`Renderer::swap_geometry`].
+#[derive(Debug)]
+struct InactiveGeometry {
+    vertices: Vertices,
+    geometries: Geometries,
+    sorted_transparent: [SortedTriangles; MAX_VIEWS],
+    sorted_all: [SortedTriangles; MAX_VIEWS],
+}
+
+/// Restrict drawing in the render pass to the viewport
+pub fn set_viewport(render_pass: &mut wgpu::RenderPass, viewport: &Viewport) {
+    let [x, y] = viewport.position.map(|coord| coord as f32);
+    let ScreenSize { width, height } = viewport.size;
+
+    render_pass.set_viewport(x, y, width as f32, height as f32, 0., 1.);
+}
+
 /// Error describing the set of render surface initialization errors
 #[derive(Error, Debug)]
 pub enum RendererInitError {
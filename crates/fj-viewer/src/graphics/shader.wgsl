@@ -1,6 +1,13 @@
 struct Uniforms {
     transform: mat4x4<f32>,
     transform_normals: mat4x4<f32>,
+    // Key, fill, and back light. Each one consists of its normalized
+    // direction, followed by its intensity.
+    lights: array<vec4<f32>, 3>,
+    // Only `x` is used. The rest is padding.
+    ambient: vec4<f32>,
+    background_top: vec4<f32>,
+    background_bottom: vec4<f32>,
 };
 
 @group(0) @binding(0)
@@ -37,22 +44,81 @@ const pi: f32 = 3.14159265359;
 
 @fragment
 fn frag_model(in: VertexOutput) -> FragmentOutput {
-    let light = vec3<f32>(0.0, 0.0, -1.0);
+    var out: FragmentOutput;
+    out.color = shade(in);
 
-    let angle = acos(dot(light, -in.normal));
-    let f_angle = angle / (pi * 0.75);
+    return out;
+}
 
-    let f_normal = max(1.0 - f_angle, 0.0);
+// The opacity of the model in x-ray mode, relative to its regular opacity
+const x_ray_opacity: f32 = 0.25;
 
+@fragment
+fn frag_x_ray(in: VertexOutput) -> FragmentOutput {
     var out: FragmentOutput;
-    out.color = vec4<f32>(in.color.rgb * f_normal, in.color.a);
+    // The color is premultiplied, so all of its components need to be scaled,
+    // to change its opacity.
+    out.color = shade(in) * x_ray_opacity;
 
     return out;
 }
 
+fn shade(in: VertexOutput) -> vec4<f32> {
+    var f_light = uniforms.ambient.x;
+    for (var i = 0; i < 3; i++) {
+        f_light += light_contribution(uniforms.lights[i], in.normal);
+    }
+
+    return vec4<f32>(in.color.rgb * min(f_light, 1.0), in.color.a);
+}
+
+fn light_contribution(light: vec4<f32>, normal: vec3<f32>) -> f32 {
+    // Disabled lights have an intensity of zero.
+    if light.w == 0.0 {
+        return 0.0;
+    }
+
+    let angle = acos(clamp(dot(light.xyz, -normal), -1.0, 1.0));
+    let f_angle = angle / (pi * 0.75);
+
+    return light.w * max(1.0 - f_angle, 0.0);
+}
+
 @fragment
 fn frag_mesh(in: VertexOutput) -> FragmentOutput {
     var out: FragmentOutput;
     out.color = vec4<f32>(1.0 - in.color.rgb, in.color.a);
     return out;
 }
+
+struct BackgroundOutput {
+    @builtin(position) position: vec4<f32>,
+    // 0 at the bottom of the viewport, 1 at the top.
+    @location(0) height: f32,
+};
+
+@vertex
+fn vertex_background(@builtin(vertex_index) index: u32) -> BackgroundOutput {
+    // A single triangle that covers the whole viewport, drawn at the far plane,
+    // behind everything else.
+    let x = f32(index & 1u) * 4.0 - 1.0;
+    let y = f32(index >> 1u) * 4.0 - 1.0;
+
+    var out: BackgroundOutput;
+    out.position = vec4<f32>(x, y, 1.0, 1.0);
+    out.height = (y + 1.0) / 2.0;
+
+    return out;
+}
+
+@fragment
+fn frag_background(in: BackgroundOutput) -> FragmentOutput {
+    var out: FragmentOutput;
+    out.color = mix(
+        uniforms.background_bottom,
+        uniforms.background_top,
+        clamp(in.height, 0.0, 1.0),
+    );
+
+    return out;
+}
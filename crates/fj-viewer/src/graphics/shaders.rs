@@ -22,12 +22,26 @@ impl Shaders {
         }
     }
 
+    pub fn x_ray(&self) -> Shader {
+        Shader {
+            module: &self.0,
+            frag_entry: "frag_x_ray",
+        }
+    }
+
     pub fn mesh(&self) -> Shader {
         Shader {
             module: &self.0,
             frag_entry: "frag_mesh",
         }
     }
+
+    pub fn background(&self) -> Shader {
+        Shader {
+            module: &self.0,
+            frag_entry: "frag_background",
+        }
+    }
 }
 
 #[derive(Clone, Copy)]
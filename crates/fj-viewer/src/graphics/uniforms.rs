@@ -1,19 +1,93 @@
 use bytemuck::{Pod, Zeroable};
+use fj_interop::mesh::Color;
 
-use super::transform::Transform;
+use crate::Background;
+
+use super::{
+    draw_config::DrawConfig,
+    lighting::{Light, Lighting},
+    transform::Transform,
+};
 
 #[derive(Clone, Copy, Pod, Zeroable)]
 #[repr(C)]
 pub struct Uniforms {
     pub transform: Transform,
     pub transform_normals: Transform,
+
+    /// The key, fill, and back light
+    ///
+    /// Each light is stored as its normalized direction, followed by its
+    /// intensity.
+    pub lights: [[f32; 4]; 3],
+
+    /// The intensity of the ambient light
+    ///
+    /// Only the first component is used. The others are padding, as required
+    /// by the alignment rules for uniform buffers.
+    pub ambient: [f32; 4],
+
+    /// The colors at the top and bottom of the background
+    ///
+    /// Both are the same, if the background is a single color.
+    pub background: [[f32; 4]; 2],
 }
 
-impl Default for Uniforms {
-    fn default() -> Self {
+impl Uniforms {
+    pub fn new(
+        transform: Transform,
+        transform_normals: Transform,
+        config: &DrawConfig,
+    ) -> Self {
+        let Lighting {
+            key,
+            fill,
+            back,
+            ambient,
+        } = &config.lighting;
+
+        let background = match config.background {
+            Background::Solid(color) => [color; 2],
+            Background::Gradient { top, bottom } => [top, bottom],
+        };
+
         Self {
-            transform: Transform::identity(),
-            transform_normals: Transform::identity(),
+            transform,
+            transform_normals,
+            lights: [key, fill, back].map(encode_light),
+            ambient: [*ambient as f32, 0., 0., 0.],
+            background: background.map(encode_opaque_color),
         }
     }
 }
+
+impl Default for Uniforms {
+    fn default() -> Self {
+        Self::new(
+            Transform::identity(),
+            Transform::identity(),
+            &DrawConfig::default(),
+        )
+    }
+}
+
+fn encode_light(light: &Light) -> [f32; 4] {
+    // A disabled light might not have a direction that can be normalized, so
+    // it's encoded as all zeros, which the shader skips.
+    if light.intensity == 0. || light.direction.magnitude().is_zero() {
+        return [0.; 4];
+    }
+
+    let [x, y, z] = light
+        .direction
+        .normalize()
+        .components
+        .map(|component| component.into_f32());
+
+    [x, y, z, light.intensity as f32]
+}
+
+pub fn encode_opaque_color(color: Color) -> [f32; 4] {
+    let [r, g, b, _] = color.0.map(|channel| f32::from(channel) / 255.);
+    [r, g, b, 1.]
+}
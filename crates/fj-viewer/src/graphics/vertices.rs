@@ -1,10 +1,13 @@
 use bytemuck::{Pod, Zeroable};
 use fj_interop::mesh::{Index, Mesh};
+use fj_math::Point;
 
 #[derive(Debug)]
 pub struct Vertices {
     vertices: Vec<Vertex>,
     indices: Vec<Index>,
+    transparent_indices: Vec<Index>,
+    all_indices: Vec<Index>,
 }
 
 impl Vertices {
@@ -12,6 +15,8 @@ impl Vertices {
         Self {
             vertices: Vec::new(),
             indices: Vec::new(),
+            transparent_indices: Vec::new(),
+            all_indices: Vec::new(),
         }
     }
 
@@ -19,9 +24,20 @@ impl Vertices {
         self.vertices.as_slice()
     }
 
+    /// Access the indices of the opaque triangles
     pub fn indices(&self) -> &[Index] {
         self.indices.as_slice()
     }
+
+    /// Access the indices of the transparent triangles
+    pub fn transparent_indices(&self) -> &[Index] {
+        self.transparent_indices.as_slice()
+    }
+
+    /// Access the indices of all triangles, opaque and transparent
+    pub fn all_indices(&self) -> &[Index] {
+        self.all_indices.as_slice()
+    }
 }
 
 impl From<&Mesh<fj_math::Point<3>>> for Vertices {
@@ -48,9 +64,98 @@ impl From<&Mesh<fj_math::Point<3>>> for Vertices {
             })
             .collect();
 
-        let indices = m.indices().collect();
+        // Transparent triangles are kept separate, as they need to be sorted
+        // before drawing them.
+        let all_indices = m.indices().collect::<Vec<_>>();
+        let (transparent_indices, indices) = all_indices
+            .chunks(3)
+            .zip(mesh.triangles())
+            .partition::<Vec<_>, _>(|(_, triangle)| {
+                !triangle.color.is_opaque()
+            });
+        let [indices, transparent_indices] = [indices, transparent_indices]
+            .map(|triangles| {
+                triangles
+                    .into_iter()
+                    .flat_map(|(indices, _)| indices.iter().copied())
+                    .collect()
+            });
+
+        Self {
+            vertices,
+            indices,
+            transparent_indices,
+            all_indices,
+        }
+    }
+}
+
+/// Triangles, sorted back to front for a single view
+///
+/// Transparent triangles need to be drawn in that order, for blending to
+/// produce the correct result. Sorting is only repeated, if the eye position
+/// has changed since the last time, and the buffers are reused between sorts.
+#[derive(Debug, Default)]
+pub struct SortedTriangles {
+    eye: Option<Point<3>>,
+    distances: Vec<f32>,
+    order: Vec<u32>,
+    indices: Vec<Index>,
+}
+
+impl SortedTriangles {
+    /// Sort the triangles back to front, as seen from `eye`
+    ///
+    /// `indices` are the unsorted indices of the triangles, three per
+    /// triangle. Returns the sorted indices, or `None`, if the eye position
+    /// hasn't changed since the last sort.
+    ///
+    /// Triangles are sorted by the distance of their centers, which is only an
+    /// approximation, but good enough in most cases.
+    pub fn sort(
+        &mut self,
+        vertices: &[Vertex],
+        indices: &[Index],
+        eye: Point<3>,
+    ) -> Option<&[Index]> {
+        if self.eye == Some(eye) {
+            return None;
+        }
+        self.eye = Some(eye);
+
+        let eye = eye.coords.components.map(|s| s.into_f32());
+
+        self.distances.clear();
+        self.distances.extend(indices.chunks(3).map(|triangle| {
+            let center = triangle.iter().fold([0.; 3], |center, &index| {
+                let position = vertices[index as usize].position;
+                [0, 1, 2].map(|i| center[i] + position[i] / 3.)
+            });
 
-        Self { vertices, indices }
+            (0..3).map(|i| (center[i] - eye[i]).powi(2)).sum::<f32>()
+        }));
+
+        let distances = &self.distances;
+        self.order.clear();
+        self.order.extend(0..distances.len() as u32);
+        self.order.sort_unstable_by(|&a, &b| {
+            distances[b as usize].total_cmp(&distances[a as usize])
+        });
+
+        self.indices.clear();
+        self.indices.extend(self.order.iter().flat_map(|&triangle| {
+            let i = triangle as usize * 3;
+            [indices[i], indices[i + 1], indices[i + 2]]
+        }));
+
+        Some(&self.indices)
+    }
+
+    /// Forget the last sort, so the next one isn't skipped
+    ///
+    /// This needs to be called, if the triangles have changed.
+    pub fn reset(&mut self) {
+        self.eye = None;
     }
 }
 
@@ -61,3 +166,45 @@ pub struct Vertex {
     pub normal: [f32; 3],
     pub color: [f32; 4],
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use super::{SortedTriangles, Vertex};
+
+    #[test]
+    fn sort_back_to_front() {
+        let vertices = [0., 1., 2.]
+            .into_iter()
+            .flat_map(|z| {
+                [[0., 0., z], [1., 0., z], [0., 1., z]].map(|position| Vertex {
+                    position,
+                    normal: [0., 0., 1.],
+                    color: [1.; 4],
+                })
+            })
+            .collect::<Vec<_>>();
+        let indices = (0..9).collect::<Vec<_>>();
+
+        let mut sorted = SortedTriangles::default();
+
+        let from_above = Point::from([0., 0., 5.]);
+        assert_eq!(
+            sorted.sort(&vertices, &indices, from_above),
+            Some([0, 1, 2, 3, 4, 5, 6, 7, 8].as_slice())
+        );
+
+        // The eye hasn't moved, so there's no need to sort again.
+        assert_eq!(sorted.sort(&vertices, &indices, from_above), None);
+
+        let from_below = Point::from([0., 0., -5.]);
+        assert_eq!(
+            sorted.sort(&vertices, &indices, from_below),
+            Some([6, 7, 8, 3, 4, 5, 0, 1, 2].as_slice())
+        );
+
+        sorted.reset();
+        assert!(sorted.sort(&vertices, &indices, from_below).is_some());
+    }
+}
@@ -8,16 +8,25 @@
 //!
 //! [Fornjot]: https://www.fornjot.app/
 
+mod animation;
 mod assets;
 mod camera;
+mod config;
 mod graphics;
 mod input;
 mod screen;
 mod viewer;
+mod viewpoints;
 
 pub use self::{
-    graphics::{DeviceError, RendererInitError},
+    animation::{CameraAnimation, Easing},
+    camera::{Camera, Orbit},
+    config::{Background, Theme, ViewerConfig},
+    graphics::{
+        DeviceError, Light, Lighting, LightingPreset, RendererInitError,
+    },
     input::InputEvent,
     screen::{NormalizedScreenPosition, Screen, ScreenSize},
     viewer::Viewer,
+    viewpoints::{Viewpoints, ViewpointsError},
 };
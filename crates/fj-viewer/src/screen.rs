@@ -42,4 +42,150 @@ impl ScreenSize {
     pub fn as_f64(&self) -> [f64; 2] {
         [self.width, self.height].map(Into::into)
     }
+
+    /// Convert a position on the screen, in pixels, to normalized coordinates
+    ///
+    /// The pixel position is measured from the top-left corner of the screen,
+    /// with the y-axis pointing down. See [`NormalizedScreenPosition`] for the
+    /// coordinate system it is converted into.
+    pub fn normalize(&self, position: [f64; 2]) -> NormalizedScreenPosition {
+        let [width, height] = self.as_f64();
+        let aspect_ratio = width / height;
+        let [x, y] = position;
+
+        NormalizedScreenPosition {
+            x: x / width * 2. - 1.,
+            y: -(y / height * 2. - 1.) / aspect_ratio,
+        }
+    }
+
+    /// Convert a normalized position to a position on the screen, in pixels
+    ///
+    /// This is the inverse of [`ScreenSize::normalize`].
+    pub fn denormalize(&self, position: NormalizedScreenPosition) -> [f64; 2] {
+        let [width, height] = self.as_f64();
+        let aspect_ratio = width / height;
+
+        [
+            (position.x + 1.) / 2. * width,
+            (1. - position.y * aspect_ratio) / 2. * height,
+        ]
+    }
+}
+
+/// A rectangular area of the screen that a view of the model is drawn into
+#[derive(Clone, Copy, Debug)]
+pub struct Viewport {
+    /// The position of the viewport's top-left corner on the screen, in pixels
+    pub position: [u32; 2],
+
+    /// The size of the viewport
+    pub size: ScreenSize,
+}
+
+impl Viewport {
+    /// Create a viewport that covers the whole screen
+    pub fn full(size: ScreenSize) -> Self {
+        Self {
+            position: [0, 0],
+            size,
+        }
+    }
+
+    /// Split the screen into a 2x2 grid of viewports
+    ///
+    /// The viewports are returned in reading order, top-left first.
+    pub fn grid(size: ScreenSize) -> [Self; 4] {
+        let half = ScreenSize {
+            width: size.width / 2,
+            height: size.height / 2,
+        };
+
+        [
+            [0, 0],
+            [half.width, 0],
+            [0, half.height],
+            [half.width, half.height],
+        ]
+        .map(|position| Self {
+            position,
+            size: half,
+        })
+    }
+
+    /// Indicate whether the viewport has an area of zero
+    pub fn is_empty(&self) -> bool {
+        self.size.width == 0 || self.size.height == 0
+    }
+
+    /// Indicate whether the viewport contains a position on the screen
+    ///
+    /// The position is expected in pixels, as described in
+    /// [`ScreenSize::normalize`].
+    pub fn contains(&self, position: [f64; 2]) -> bool {
+        let [x, y] = position;
+        let [min_x, min_y] = self.position.map(f64::from);
+        let [width, height] = self.size.as_f64();
+
+        x >= min_x && x < min_x + width && y >= min_y && y < min_y + height
+    }
+
+    /// Convert a position on the screen, in pixels, to normalized coordinates
+    ///
+    /// Unlike [`ScreenSize::normalize`], the normalized coordinates are
+    /// relative to this viewport, not the whole screen.
+    pub fn normalize(&self, position: [f64; 2]) -> NormalizedScreenPosition {
+        let [x, y] = position;
+        let [min_x, min_y] = self.position.map(f64::from);
+
+        self.size.normalize([x - min_x, y - min_y])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ScreenSize, Viewport};
+
+    #[test]
+    fn normalize() {
+        let size = ScreenSize {
+            width: 800,
+            height: 400,
+        };
+
+        let center = size.normalize([400., 200.]);
+        assert_eq!([center.x, center.y], [0., 0.]);
+
+        // The aspect ratio is 2, so the y-coordinate only goes to 0.5.
+        let top_left = size.normalize([0., 0.]);
+        assert_eq!([top_left.x, top_left.y], [-1., 0.5]);
+        let bottom_right = size.normalize([800., 400.]);
+        assert_eq!([bottom_right.x, bottom_right.y], [1., -0.5]);
+
+        for position in [[0., 0.], [400., 200.], [800., 400.], [200., 300.]] {
+            assert_eq!(size.denormalize(size.normalize(position)), position);
+        }
+    }
+
+    #[test]
+    fn viewport_grid() {
+        let size = ScreenSize {
+            width: 800,
+            height: 400,
+        };
+        let viewports = Viewport::grid(size);
+
+        // Each pixel is contained in exactly one of the viewports.
+        for position in [[0., 0.], [399., 199.], [400., 200.], [799., 399.]] {
+            let num_containing = viewports
+                .iter()
+                .filter(|viewport| viewport.contains(position))
+                .count();
+            assert_eq!(num_containing, 1);
+        }
+
+        // The center of the bottom-right viewport.
+        let center = viewports[3].normalize([600., 300.]);
+        assert_eq!([center.x, center.y], [0., 0.]);
+    }
 }
@@ -1,36 +1,73 @@
+use std::{
+    array,
+    f64::consts::{FRAC_PI_2, FRAC_PI_4},
+    time::Duration,
+};
+
 use fj_interop::model::Model;
+use fj_math::Transform;
 use tracing::warn;
 
 use crate::{
-    camera::{Camera, FocusPoint},
-    graphics::{DrawConfig, Renderer},
+    animation::CameraAnimation,
+    camera::{Camera, FocusPoint, Orbit},
+    graphics::{DrawConfig, Lighting, LightingPreset, Renderer, MAX_VIEWS},
     input::InputHandler,
+    screen::Viewport,
     InputEvent, NormalizedScreenPosition, RendererInitError, Screen,
-    ScreenSize,
+    ScreenSize, ViewerConfig,
 };
 
 /// The Fornjot model viewer
 pub struct Viewer {
-    camera: Camera,
+    cameras: [Camera; MAX_VIEWS],
+    camera_animation: Option<CameraAnimation>,
+    active_camera: usize,
+    split_screen: bool,
+    screen_size: ScreenSize,
     cursor: Option<NormalizedScreenPosition>,
+    config: ViewerConfig,
     draw_config: DrawConfig,
+    lighting_preset: Option<LightingPreset>,
     focus_point: Option<FocusPoint>,
     renderer: Renderer,
     model: Option<Model>,
+    preview: Preview,
 }
 
 impl Viewer {
     /// Construct a new instance of `Viewer`
+    ///
+    /// Uses the default configuration. See [`Viewer::with_config`].
     pub async fn new(screen: &impl Screen) -> Result<Self, RendererInitError> {
+        Self::with_config(screen, ViewerConfig::default()).await
+    }
+
+    /// Construct a new instance of `Viewer`, using the provided configuration
+    pub async fn with_config(
+        screen: &impl Screen,
+        config: ViewerConfig,
+    ) -> Result<Self, RendererInitError> {
         let renderer = Renderer::new(screen).await?;
 
         Ok(Self {
-            camera: Camera::default(),
+            cameras: Default::default(),
+            camera_animation: None,
+            active_camera: 0,
+            split_screen: false,
+            screen_size: screen.size(),
             cursor: None,
-            draw_config: DrawConfig::default(),
+            config,
+            draw_config: DrawConfig {
+                background: config.background(),
+                theme: config.theme,
+                ..DrawConfig::default()
+            },
+            lighting_preset: Some(LightingPreset::default()),
             focus_point: None,
             renderer,
             model: None,
+            preview: Preview::default(),
         })
     }
 
@@ -49,34 +86,203 @@ impl Viewer {
         self.draw_config.draw_mesh = !self.draw_config.draw_mesh;
     }
 
+    /// Toggle the "draw x-ray" setting
+    pub fn toggle_draw_x_ray(&mut self) {
+        self.draw_config.draw_x_ray = !self.draw_config.draw_x_ray;
+    }
+
+    /// Switch to the next lighting preset
+    ///
+    /// If custom lighting has been set via [`Viewer::set_lighting`], this
+    /// switches back to the default preset.
+    pub fn cycle_lighting_preset(&mut self) {
+        let preset = match self.lighting_preset {
+            Some(preset) => preset.next(),
+            None => LightingPreset::default(),
+        };
+
+        self.lighting_preset = Some(preset);
+        self.draw_config.lighting = preset.lighting();
+    }
+
+    /// Set custom lighting
+    pub fn set_lighting(&mut self, lighting: Lighting) {
+        self.lighting_preset = None;
+        self.draw_config.lighting = lighting;
+    }
+
+    /// Toggle between the light and the dark theme
+    ///
+    /// This also replaces a background that was set explicitly in the
+    /// configuration with the background of the new theme.
+    pub fn toggle_theme(&mut self) {
+        self.config = ViewerConfig {
+            theme: self.config.theme.toggle(),
+            background: None,
+        };
+        self.draw_config.background = self.config.background();
+        self.draw_config.theme = self.config.theme;
+    }
+
+    /// Access the camera of the main view
+    ///
+    /// In the 2x2 grid of views, this is the camera of the top-left one.
+    pub fn camera(&self) -> &Camera {
+        &self.cameras[0]
+    }
+
+    /// Access the camera of the main view mutably
+    ///
+    /// See [`Viewer::camera`].
+    pub fn camera_mut(&mut self) -> &mut Camera {
+        &mut self.cameras[0]
+    }
+
+    /// Move the camera of the main view smoothly to a new view
+    ///
+    /// The animation progresses with each call to [`Viewer::update`]. It
+    /// replaces any animation that is still running, and is cancelled by user
+    /// input.
+    pub fn animate_camera(&mut self, orbit: Orbit, duration: Duration) {
+        self.camera_animation =
+            Some(CameraAnimation::new(&self.cameras[0], orbit, duration));
+    }
+
+    /// Move the camera to the end of the running animation, if any
+    pub fn skip_camera_animation(&mut self) {
+        if let Some(mut animation) = self.camera_animation.take() {
+            animation.skip(&mut self.cameras[0]);
+        }
+    }
+
+    /// Stop the running animation, if any, leaving the camera where it is
+    pub fn cancel_camera_animation(&mut self) {
+        self.camera_animation = None;
+    }
+
+    /// Advance time-based state, like camera animations
+    ///
+    /// Call this once per frame, before [`Viewer::draw`], with the time that
+    /// has passed since the previous frame.
+    pub fn update(&mut self, delta_t: Duration) {
+        if let Some(animation) = &mut self.camera_animation {
+            animation.update(delta_t, &mut self.cameras[0]);
+
+            if animation.is_finished() {
+                self.camera_animation = None;
+            }
+        }
+
+        if self.preview.is_active {
+            self.preview.time_since_interaction += delta_t;
+            if self.preview.time_since_interaction >= PREVIEW_IDLE_TIME {
+                self.preview.swap_geometry(&mut self.renderer);
+            }
+        }
+    }
+
+    /// Toggle between a single view and a 2x2 grid of views
+    ///
+    /// Each view of the grid has its own camera. The top-left one shows the
+    /// same view as the single view does.
+    pub fn toggle_split_screen(&mut self) {
+        self.split_screen = !self.split_screen;
+        self.active_camera = 0;
+        self.focus_point = None;
+    }
+
     /// Handle the model being updated
     pub fn handle_model_update(&mut self, model: Model) {
-        self.renderer.update_geometry((&model.mesh).into());
+        self.renderer.update_geometry(
+            (&model.mesh).into(),
+            model.preview_mesh.as_ref().map(Into::into),
+        );
+        self.preview = Preview::default();
 
         let aabb = model.aabb;
         if self.model.replace(model).is_none() {
-            self.camera.init_planes(&aabb);
+            let [main, others @ ..] = &mut self.cameras;
+            main.init_planes(&aabb);
+
+            // The other cameras are only used in the grid of views. Set them
+            // up to show the model from the front, the right, and at an angle.
+            let front = Transform::rotation([-FRAC_PI_2, 0., 0.]);
+            let right = Transform::rotation([0., -FRAC_PI_2, 0.]) * front;
+            let iso = Transform::rotation([(1. / 3_f64.sqrt()).asin(), 0., 0.])
+                * Transform::rotation([0., -FRAC_PI_4, 0.])
+                * front;
+
+            let viewport = Viewport::grid(self.screen_size)[0];
+            for (camera, rotation) in others.iter_mut().zip([front, right, iso])
+            {
+                camera.rotation = rotation;
+                if !viewport.is_empty() {
+                    camera.fit(&aabb, viewport.size, 0.2);
+                }
+            }
         }
     }
 
     /// Handle an input event
+    ///
+    /// The event applies to the camera of the view that the cursor was in,
+    /// when the focus point was added.
     pub fn handle_input_event(&mut self, event: InputEvent) {
         if let Some(focus_point) = self.focus_point {
-            InputHandler::handle_event(event, focus_point, &mut self.camera);
+            let event = match event {
+                InputEvent::Translation { previous, current } => {
+                    InputEvent::Translation {
+                        previous: self.to_active_viewport(previous),
+                        current: self.to_active_viewport(current),
+                    }
+                }
+                event => event,
+            };
+
+            // The user takes over from any running animation.
+            self.camera_animation = None;
+
+            let camera = &mut self.cameras[self.active_camera];
+            InputHandler::handle_event(event, focus_point, camera);
+
+            self.preview.time_since_interaction = Duration::ZERO;
+            if !self.preview.is_active {
+                self.preview.swap_geometry(&mut self.renderer);
+            }
         }
     }
 
     /// Handle the screen being resized
     pub fn handle_screen_resize(&mut self, screen_size: ScreenSize) {
+        self.screen_size = screen_size;
         self.renderer.handle_resize(screen_size);
     }
 
     /// Compute and store a focus point, unless one is already stored
+    ///
+    /// This also selects the camera that subsequent input events apply to.
     pub fn add_focus_point(&mut self) {
         if let Some(model) = &self.model {
             if self.focus_point.is_none() {
-                self.focus_point =
-                    Some(self.camera.focus_point(self.cursor, model));
+                let mut cursor = self.cursor;
+
+                if let Some(position) = self.cursor {
+                    let position = self.screen_size.denormalize(position);
+                    let (viewports, num_views) = self.viewports();
+                    let viewport = viewports
+                        .into_iter()
+                        .take(num_views)
+                        .enumerate()
+                        .find(|(_, viewport)| viewport.contains(position));
+
+                    if let Some((i, viewport)) = viewport {
+                        self.active_camera = i;
+                        cursor = Some(viewport.normalize(position));
+                    }
+                }
+
+                let camera = &self.cameras[self.active_camera];
+                self.focus_point = Some(camera.focus_point(cursor, model));
             }
         }
     }
@@ -94,10 +300,64 @@ impl Viewer {
             .map(|shape| shape.aabb)
             .unwrap_or_default();
 
-        self.camera.update_planes(&aabb);
+        let (viewports, num_views) = self.viewports();
+        for camera in &mut self.cameras[..num_views] {
+            camera.update_planes(&aabb);
+        }
 
-        if let Err(err) = self.renderer.draw(&self.camera, &self.draw_config) {
+        let views: [_; MAX_VIEWS] =
+            array::from_fn(|i| (&self.cameras[i], viewports[i]));
+        if let Err(err) =
+            self.renderer.draw(&views[..num_views], &self.draw_config)
+        {
             warn!("Draw error: {}", err);
         }
     }
+
+    /// Compute the viewports of the views that are shown
+    ///
+    /// Returns the viewports, and the number of views. Only that many of the
+    /// viewports are used. Returning a fixed-size array avoids allocating on
+    /// every frame.
+    fn viewports(&self) -> ([Viewport; MAX_VIEWS], usize) {
+        if self.split_screen {
+            (Viewport::grid(self.screen_size), MAX_VIEWS)
+        } else {
+            ([Viewport::full(self.screen_size); MAX_VIEWS], 1)
+        }
+    }
+
+    /// Convert a cursor position to be relative to the active viewport
+    fn to_active_viewport(
+        &self,
+        position: NormalizedScreenPosition,
+    ) -> NormalizedScreenPosition {
+        let (viewports, _) = self.viewports();
+        let viewport = viewports[self.active_camera];
+        viewport.normalize(self.screen_size.denormalize(position))
+    }
+}
+
+/// Displays a coarser mesh while the user is interacting with the model
+///
+/// Both meshes are uploaded to the renderer, which switches between them. See
+/// [`Renderer::swap_geometry`].
+#[derive(Default)]
+struct Preview {
+    is_active: bool,
+    time_since_interaction: Duration,
+}
+
+impl Preview {
+    fn swap_geometry(&mut self, renderer: &mut Renderer) {
+        if renderer.swap_geometry() {
+            self.is_active = !self.is_active;
+        }
+    }
 }
+
+/// The time without interaction, before the preview ends
+///
+/// This is measured using the time passed to [`Viewer::update`], so it doesn't
+/// depend on a clock, which isn't available on all platforms.
+const PREVIEW_IDLE_TIME: Duration = Duration::from_millis(500);
@@ -0,0 +1,204 @@
+//! Named camera views, that can be saved to a file
+
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+use fj_math::Point;
+use serde::{Deserialize, Serialize};
+
+use crate::camera::{Camera, Orbit};
+
+/// A list of named camera views
+///
+/// This allows users to bookmark views, and return to them later, for example
+/// to review the same angles across iterations of a model. Views are stored as
+/// [`Orbit`] parameters, so any roll of the camera is not preserved.
+///
+/// A saved view can be restored immediately, using [`Viewpoints::restore`], or
+/// used as the end of a [`CameraAnimation`].
+///
+/// [`CameraAnimation`]: crate::CameraAnimation
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Viewpoints {
+    views: Vec<(String, Orbit)>,
+}
+
+impl Viewpoints {
+    /// Create an empty list of viewpoints
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Save the current view of the camera under the provided name
+    ///
+    /// Replaces any view that was previously saved under the same name.
+    pub fn save(&mut self, name: impl Into<String>, camera: &Camera) {
+        let name = name.into();
+        let orbit = camera.orbit();
+
+        match self.views.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, view)) => *view = orbit,
+            None => self.views.push((name, orbit)),
+        }
+    }
+
+    /// Access the view with the provided name
+    pub fn get(&self, name: &str) -> Option<Orbit> {
+        self.views
+            .iter()
+            .find_map(|(n, orbit)| (n == name).then_some(*orbit))
+    }
+
+    /// Move the camera to the view with the provided name
+    ///
+    /// Returns `false`, if there is no view with that name. The camera is left
+    /// as it is, in that case.
+    pub fn restore(&self, name: &str, camera: &mut Camera) -> bool {
+        let Some(orbit) = self.get(name) else {
+            return false;
+        };
+
+        camera.set_orbit(orbit);
+        true
+    }
+
+    /// Remove the view with the provided name
+    pub fn remove(&mut self, name: &str) -> Option<Orbit> {
+        let i = self.views.iter().position(|(n, _)| n == name)?;
+        Some(self.views.remove(i).1)
+    }
+
+    /// Iterate over the names of all views, in the order they were saved
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.views.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Read a list of viewpoints, as written by [`Viewpoints::write`]
+    pub fn read(reader: impl Read) -> Result<Self, ViewpointsError> {
+        let saved: Vec<SavedViewpoint> = serde_json::from_reader(reader)?;
+
+        let views = saved
+            .into_iter()
+            .map(|view| {
+                let orbit = Orbit {
+                    azimuth: view.azimuth,
+                    elevation: view.elevation,
+                    distance: view.distance,
+                    target: Point::from(view.target),
+                };
+
+                (view.name, orbit)
+            })
+            .collect();
+
+        Ok(Self { views })
+    }
+
+    /// Write the list of viewpoints
+    ///
+    /// The viewpoints are written as a JSON array, with one object per view.
+    pub fn write(&self, writer: impl Write) -> Result<(), ViewpointsError> {
+        let saved = self
+            .views
+            .iter()
+            .map(|(name, orbit)| SavedViewpoint {
+                name: name.clone(),
+                azimuth: orbit.azimuth,
+                elevation: orbit.elevation,
+                distance: orbit.distance,
+                target: orbit.target.coords.components.map(|c| c.into_f64()),
+            })
+            .collect::<Vec<_>>();
+
+        serde_json::to_writer_pretty(writer, &saved)?;
+
+        Ok(())
+    }
+
+    /// Load a list of viewpoints from a file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ViewpointsError> {
+        let file = File::open(path)?;
+        Self::read(BufReader::new(file))
+    }
+
+    /// Store the list of viewpoints in a file
+    ///
+    /// Overwrites the file, if it already exists.
+    pub fn store(&self, path: impl AsRef<Path>) -> Result<(), ViewpointsError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write(&mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+}
+
+/// Error reading or writing [`Viewpoints`]
+#[derive(Debug, thiserror::Error)]
+pub enum ViewpointsError {
+    /// I/O error
+    #[error("I/O error")]
+    Io(#[from] io::Error),
+
+    /// Error (de-)serializing the viewpoints
+    #[error("Error (de-)serializing viewpoints")]
+    Json(#[from] serde_json::Error),
+}
+
+#[derive(Serialize, Deserialize)]
+struct SavedViewpoint {
+    name: String,
+    azimuth: f64,
+    elevation: f64,
+    distance: f64,
+    target: [f64; 3],
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::camera::{Camera, Orbit};
+
+    use super::{Viewpoints, ViewpointsError};
+
+    #[test]
+    fn save_and_restore() -> Result<(), ViewpointsError> {
+        let mut camera = Camera::new();
+        camera.set_orbit(Orbit {
+            azimuth: 0.5,
+            elevation: 0.25,
+            distance: 3.,
+            target: Point::from([1., 2., 3.]),
+        });
+        let original = camera.camera_to_model();
+
+        let mut viewpoints = Viewpoints::new();
+        viewpoints.save("front", &camera);
+
+        let mut file = Vec::new();
+        viewpoints.write(&mut file)?;
+        let viewpoints = Viewpoints::read(file.as_slice())?;
+        assert_eq!(viewpoints.names().collect::<Vec<_>>(), ["front"]);
+
+        camera.set_orbit(Orbit {
+            azimuth: -1.,
+            elevation: -0.5,
+            distance: 10.,
+            target: Point::origin(),
+        });
+        assert!(viewpoints.restore("front", &mut camera));
+        assert!(!viewpoints.restore("back", &mut camera));
+
+        for (a, b) in
+            camera.camera_to_model().data().iter().zip(original.data())
+        {
+            assert!((a - b).abs() < 1e-9, "{a} != {b}");
+        }
+
+        Ok(())
+    }
+}
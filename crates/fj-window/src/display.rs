@@ -1,3 +1,5 @@
+use std::time::Instant;
+
 use fj_interop::model::Model;
 use fj_viewer::{
     InputEvent, NormalizedScreenPosition, RendererInitError, Screen,
@@ -28,6 +30,7 @@ pub fn display(model: Model, invert_zoom: bool) -> Result<(), Error> {
     let mut held_mouse_button = None;
     let mut new_size = None;
     let mut stop_drawing = false;
+    let mut last_frame = Instant::now();
 
     event_loop.run(move |event, event_loop_window_target| {
         let input_event = input_event(
@@ -70,6 +73,18 @@ pub fn display(model: Model, invert_zoom: bool) -> Result<(), Error> {
                 Key::Character("2") => {
                     viewer.toggle_draw_mesh();
                 }
+                Key::Character("3") => {
+                    viewer.toggle_draw_x_ray();
+                }
+                Key::Character("4") => {
+                    viewer.toggle_split_screen();
+                }
+                Key::Character("5") => {
+                    viewer.cycle_lighting_preset();
+                }
+                Key::Character("6") => {
+                    viewer.toggle_theme();
+                }
                 _ => {}
             },
             Event::WindowEvent {
@@ -114,6 +129,10 @@ pub fn display(model: Model, invert_zoom: bool) -> Result<(), Error> {
                     }
                 }
 
+                let now = Instant::now();
+                viewer.update(now - last_frame);
+                last_frame = now;
+
                 if !stop_drawing {
                     viewer.draw();
                 }
@@ -153,15 +172,7 @@ fn input_event<T>(
             event: WindowEvent::CursorMoved { position, .. },
             ..
         } => {
-            let [width, height] = window.size().as_f64();
-            let aspect_ratio = width / height;
-
-            // Cursor position in normalized coordinates (-1 to +1) with
-            // aspect ratio taken into account.
-            let current = NormalizedScreenPosition {
-                x: position.x / width * 2. - 1.,
-                y: -(position.y / height * 2. - 1.) / aspect_ratio,
-            };
+            let current = window.size().normalize([position.x, position.y]);
             let event = match (*previous_cursor, held_mouse_button) {
                 (Some(previous), Some(button)) => match button {
                     MouseButton::Left => {
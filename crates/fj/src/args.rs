@@ -14,19 +14,53 @@ use fj_math::Scalar;
 /// more high-level and convenient interface.
 ///
 /// [`handle_model`]: crate::handle_model()
-#[derive(clap::Parser)]
+#[derive(Default, clap::Parser)]
 pub struct Args {
     /// Export model to this path
     #[arg(short, long, value_name = "PATH")]
     pub export: Option<PathBuf>,
 
+    /// Scale the exported model by this factor
+    ///
+    /// One unit of the model is one millimeter in the exported file, before
+    /// scaling. Use `25.4`, for example, for models that are modeled in inches.
+    ///
+    /// Overrides the units declared in the model's manifest, if any. Defaults
+    /// to `1`.
+    #[arg(long, value_name = "FACTOR")]
+    pub export_scale: Option<f64>,
+
     /// How much the export can deviate from the original model
+    ///
+    /// Overrides the tolerance declared in the model's manifest, if any.
     #[arg(short, long, value_parser = parse_tolerance)]
     pub tolerance: Option<Tolerance>,
 
+    /// Limit the number of triangles of the displayed model
+    ///
+    /// Relaxes the tolerance of the displayed model, until it fits into this
+    /// budget. This keeps the viewer responsive on weak hardware, at the cost
+    /// of accuracy. Doesn't affect the export.
+    #[arg(long, value_name = "COUNT")]
+    pub max_triangles: Option<usize>,
+
     /// Ignore validation errors
     #[arg(short, long)]
     pub ignore_validation: bool,
+
+    /// Set a parameter of the model, overriding its default value
+    ///
+    /// Can be specified multiple times. Only parameters that are declared in
+    /// the model's manifest can be set. See [`Manifest`].
+    ///
+    /// [`Manifest`]: crate::Manifest
+    #[arg(
+        short,
+        long = "parameter",
+        value_name = "NAME=VALUE",
+        value_parser = parse_parameter
+    )]
+    pub parameters: Vec<(String, f64)>,
 }
 
 impl Args {
@@ -47,11 +81,23 @@ fn parse_tolerance(input: &str) -> Result<Tolerance, ArgsError> {
     Ok(tolerance)
 }
 
+fn parse_parameter(input: &str) -> Result<(String, f64), ArgsError> {
+    let (name, value) = input
+        .split_once('=')
+        .ok_or_else(|| ArgsError::InvalidParameter(input.to_owned()))?;
+    let value = f64::from_str(value)?;
+
+    Ok((name.to_owned(), value))
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum ArgsError {
     #[error("Error parsing tolerance")]
     ParseTolerance(#[from] ParseFloatError),
 
+    #[error("Invalid parameter `{0}`; expected `NAME=VALUE`")]
+    InvalidParameter(String),
+
     #[error(transparent)]
     InvalidTolerance(#[from] InvalidTolerance),
 }
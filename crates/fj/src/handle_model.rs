@@ -1,19 +1,21 @@
-use std::{error::Error as _, fmt, mem, ops::Deref};
+use std::{
+    error::Error as _, f64::consts::FRAC_PI_4, fmt, mem, ops::Deref, sync::Once,
+};
 
 use fj_core::{
     algorithms::{
-        approx::{InvalidTolerance, Tolerance},
+        approx::{ApproxError, InvalidTolerance, Tolerance},
         bounding_volume::BoundingVolume,
-        triangulate::Triangulate,
+        triangulate::{FacetBudget, Triangulate, TriangulateWithinBudget},
     },
     services::Services,
     validate::ValidationErrors,
 };
 use fj_interop::model::Model;
 use fj_math::{Aabb, Point, Scalar};
-use tracing_subscriber::prelude::*;
+use tracing_subscriber::{fmt::format::FmtSpan, prelude::*};
 
-use crate::Args;
+use crate::{manifest::UnknownParameter, Args, Manifest};
 
 /// Export or display a model, according to CLI arguments
 ///
@@ -23,20 +25,43 @@ use crate::Args;
 ///
 /// This function is used by Fornjot's own testing infrastructure, but is useful
 /// beyond that, when using Fornjot directly to define a model.
+///
+/// Use [`handle_model_with_manifest`], if the model declares a [`Manifest`].
 pub fn handle_model<M>(
     model: impl Deref<Target = M>,
     services: Services,
 ) -> Result
 where
-    for<'r> (&'r M, Tolerance): Triangulate,
+    for<'r> (&'r M, Tolerance): Triangulate + TriangulateWithinBudget,
+    M: BoundingVolume<3>,
+{
+    handle_model_with_manifest(|_, _| model, Manifest::default(), services)
+}
+
+/// Export or display a model, according to its manifest and CLI arguments
+///
+/// Like [`handle_model`], but uses the tolerance and units declared in the
+/// manifest, unless they are overridden via the command line.
+///
+/// The model is built by calling `build`, after the parameters from the command
+/// line have been applied to the manifest. `build` can then read them via
+/// [`Manifest::parameter`].
+pub fn handle_model_with_manifest<M, R>(
+    build: impl FnOnce(&Manifest, &mut Services) -> R,
+    mut manifest: Manifest,
+    mut services: Services,
+) -> Result
+where
+    for<'r> (&'r M, Tolerance): Triangulate + TriangulateWithinBudget,
     M: BoundingVolume<3>,
+    R: Deref<Target = M>,
 {
-    tracing_subscriber::registry()
-        .with(tracing_subscriber::fmt::layer())
-        .with(tracing_subscriber::EnvFilter::from_default_env())
-        .init();
+    init_tracing();
 
     let args = Args::parse();
+    manifest.apply_args(&args)?;
+
+    let model = build(&manifest, &mut services);
 
     if args.ignore_validation {
         mem::forget(services);
@@ -49,43 +74,108 @@ where
         max: Point::origin(),
     });
 
-    let tolerance = match args.tolerance {
-        None => {
-            // Compute a reasonable default for the tolerance value. To do
-            // this, we just look at the smallest non-zero extent of the
-            // bounding box and divide that by some value.
-
-            let mut min_extent = Scalar::MAX;
-            for extent in aabb.size().components {
-                if extent > Scalar::ZERO && extent < min_extent {
-                    min_extent = extent;
-                }
-            }
-
-            let tolerance = min_extent / Scalar::from_f64(1000.);
-            Tolerance::from_scalar(tolerance)?
-        }
-        Some(user_defined_tolerance) => user_defined_tolerance,
-    };
+    let tolerance = manifest.resolve_tolerance(&args, &aabb);
 
-    let mesh = (model.deref(), tolerance).triangulate();
+    let mesh = (model.deref(), tolerance).triangulate()?;
 
     if let Some(path) = args.export {
-        crate::export::export(&mesh, &path)?;
+        let units = manifest.resolve_units(&args);
+        crate::export::export_with_units(&mesh, &path, units)?;
         return Ok(());
     }
 
-    let model = Model { mesh, aabb };
+    let (mesh, tolerance) = match args.max_triangles {
+        Some(max_triangles) => {
+            let budgeted = (model.deref(), tolerance)
+                .triangulate_within_budget(FacetBudget::new(max_triangles))?;
+            (budgeted.mesh, budgeted.tolerance)
+        }
+        None => (mesh, tolerance),
+    };
+
+    // Use a coarser approximation while the user is interacting with the
+    // model, to keep the viewer responsive.
+    let preview_tolerance = preview_tolerance(tolerance, &aabb)?;
+    let preview_mesh = Some((model.deref(), preview_tolerance).triangulate()?);
+
+    let model = Model {
+        mesh,
+        preview_mesh,
+        aabb,
+    };
 
     crate::window::display(model, false)?;
 
     Ok(())
 }
 
+/// Set up logging, unless that has already happened
+///
+/// [`handle_model`] calls this, but only after the model has been built. Call
+/// it before building the model, to also see the operations that build it.
+/// Those are only traced, if the `tracing` feature is enabled.
+///
+/// Which messages are logged is controlled by the `RUST_LOG` environment
+/// variable. When a span closes, it is logged with the time spent in it.
+pub fn init_tracing() {
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| {
+        tracing_subscriber::registry()
+            .with(
+                tracing_subscriber::fmt::layer()
+                    .with_span_events(FmtSpan::CLOSE),
+            )
+            .with(tracing_subscriber::EnvFilter::from_default_env())
+            .init();
+    });
+}
+
+/// Compute the tolerance of the preview mesh
+///
+/// The preview is coarser than the full mesh, but not so coarse that curves
+/// lose their shape: Its tolerance is capped, so that a curve with a radius of
+/// half the smallest extent of the model is approximated with segments that
+/// span no more than [`MAX_PREVIEW_ANGLE`].
+fn preview_tolerance(
+    tolerance: Tolerance,
+    aabb: &Aabb<3>,
+) -> std::result::Result<Tolerance, InvalidTolerance> {
+    let coarse = tolerance.inner() * PREVIEW_TOLERANCE_FACTOR;
+
+    let min_extent = aabb
+        .size()
+        .components
+        .into_iter()
+        .filter(|extent| *extent > Scalar::ZERO)
+        .min();
+    let capped = match min_extent {
+        Some(min_extent) => {
+            let radius = min_extent / 2.;
+            let angle = Scalar::from(MAX_PREVIEW_ANGLE);
+            let max = radius * (Scalar::ONE - (angle / 2.).cos());
+
+            coarse.min(max)
+        }
+        None => coarse,
+    };
+
+    // The preview must not be finer than the full mesh.
+    Tolerance::from_scalar(capped.max(tolerance.inner()))
+}
+
+/// The factor by which the tolerance of the preview mesh is larger
+const PREVIEW_TOLERANCE_FACTOR: f64 = 10.;
+
+/// The largest angle that a segment of a curve spans in the preview mesh
+///
+/// See [`preview_tolerance`].
+const MAX_PREVIEW_ANGLE: f64 = FRAC_PI_4;
+
 /// Return value of [`handle_model`]
 pub type Result = std::result::Result<(), Error>;
 
-/// Error returned by [`handle_model`]
+/// Error returned by [`handle_model`] and [`handle_model_with_manifest`]
 #[derive(thiserror::Error)]
 pub enum Error {
     /// Failed to set up logger
@@ -104,9 +194,17 @@ pub enum Error {
     #[error(transparent)]
     Tolerance(#[from] InvalidTolerance),
 
+    /// Model can't be approximated
+    #[error(transparent)]
+    Approx(#[from] ApproxError),
+
     /// Unhandled validation errors
     #[error(transparent)]
     Validation(#[from] ValidationErrors),
+
+    /// Parameter that the model doesn't declare
+    #[error(transparent)]
+    Parameter(#[from] UnknownParameter),
 }
 
 impl fmt::Debug for Error {
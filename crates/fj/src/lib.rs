@@ -11,14 +11,21 @@
 
 mod args;
 mod handle_model;
+mod manifest;
 
 pub use self::{
     args::Args,
-    handle_model::{handle_model, Error, Result},
+    handle_model::{
+        handle_model, handle_model_with_manifest, init_tracing, Error, Result,
+    },
+    manifest::{Manifest, UnknownParameter},
 };
 
+pub use fj_core::prelude;
+
 pub use fj_core as core;
 pub use fj_export as export;
+pub use fj_import as import;
 pub use fj_interop as interop;
 pub use fj_math as math;
 pub use fj_viewer as viewer;
@@ -0,0 +1,169 @@
+use std::collections::BTreeMap;
+
+use fj_core::algorithms::approx::Tolerance;
+use fj_export::Units;
+use fj_math::Aabb;
+
+use crate::Args;
+
+/// Information that a model declares about itself
+///
+/// A manifest allows a model to configure the host that displays or exports
+/// it, without the host needing code that is specific to the model. Pass it to
+/// [`handle_model_with_manifest`].
+///
+/// Anything that the manifest declares, can be overridden via the command
+/// line. See [`Args`].
+///
+/// [`handle_model_with_manifest`]: crate::handle_model_with_manifest()
+#[derive(Clone, Debug, Default)]
+pub struct Manifest {
+    /// The tolerance that the model should be approximated with
+    ///
+    /// If this is `None`, the tolerance is derived from the size of the model.
+    pub tolerance: Option<Tolerance>,
+
+    /// The units that the model should be exported in
+    ///
+    /// If this is `None`, the default [`Units`] are used.
+    pub units: Option<Units>,
+
+    /// The parameters of the model, and their values
+    ///
+    /// The model declares each parameter with a default value. The values can
+    /// be overridden via the command line, before the model is built.
+    pub parameters: BTreeMap<String, f64>,
+}
+
+impl Manifest {
+    /// Create an empty manifest
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declare the tolerance that the model should be approximated with
+    pub fn with_tolerance(mut self, tolerance: Tolerance) -> Self {
+        self.tolerance = Some(tolerance);
+        self
+    }
+
+    /// Declare the units that the model should be exported in
+    pub fn with_units(mut self, units: Units) -> Self {
+        self.units = Some(units);
+        self
+    }
+
+    /// Declare a parameter of the model, with its default value
+    pub fn with_parameter(
+        mut self,
+        name: impl Into<String>,
+        value: f64,
+    ) -> Self {
+        self.parameters.insert(name.into(), value);
+        self
+    }
+
+    /// Access the value of a parameter
+    ///
+    /// Returns `None`, if the model hasn't declared the parameter.
+    pub fn parameter(&self, name: &str) -> Option<f64> {
+        self.parameters.get(name).copied()
+    }
+
+    /// Apply the parameter values from the command-line arguments
+    ///
+    /// Call this before building the model, so it can use the values that the
+    /// user provided.
+    ///
+    /// Returns an error, if a parameter from the arguments hasn't been declared
+    /// by the model.
+    pub fn apply_args(&mut self, args: &Args) -> Result<(), UnknownParameter> {
+        for (name, value) in &args.parameters {
+            let Some(parameter) = self.parameters.get_mut(name) else {
+                return Err(UnknownParameter(name.clone()));
+            };
+
+            *parameter = *value;
+        }
+
+        Ok(())
+    }
+
+    /// Determine the tolerance to use for a model
+    ///
+    /// A tolerance from the command-line arguments takes precedence over one
+    /// from the manifest. If neither specifies a tolerance, it is derived from
+    /// the bounding box of the model.
+    pub fn resolve_tolerance(&self, args: &Args, aabb: &Aabb<3>) -> Tolerance {
+        args.tolerance
+            .or(self.tolerance)
+            .unwrap_or_else(|| Tolerance::from_aabb(aabb))
+    }
+
+    /// Determine the units to use for exporting a model
+    ///
+    /// A scale factor from the command-line arguments takes precedence over
+    /// the units from the manifest. If neither specifies units, the default
+    /// ones are used.
+    pub fn resolve_units(&self, args: &Args) -> Units {
+        args.export_scale
+            .map(Units::scaled)
+            .or(self.units)
+            .unwrap_or_default()
+    }
+}
+
+/// A parameter was provided, that the model hasn't declared
+///
+/// Returned by [`Manifest::apply_args`].
+#[derive(Debug, thiserror::Error)]
+#[error("Model has no parameter `{0}`")]
+pub struct UnknownParameter(pub String);
+
+#[cfg(test)]
+mod tests {
+    use fj_core::algorithms::approx::Tolerance;
+    use fj_math::{Aabb, Point};
+
+    use crate::Args;
+
+    use super::Manifest;
+
+    #[test]
+    fn manifest_tolerance_overrides_default() {
+        let aabb = Aabb {
+            min: Point::from([0., 0., 0.]),
+            max: Point::from([1., 1., 1.]),
+        };
+        let manifest_tolerance = Tolerance::from_scalar(0.5).unwrap();
+        let user_tolerance = Tolerance::from_scalar(0.25).unwrap();
+
+        let mut args = Args::default();
+
+        let default = Manifest::new().resolve_tolerance(&args, &aabb);
+        assert_eq!(default, Tolerance::from_aabb(&aabb));
+
+        let manifest = Manifest::new().with_tolerance(manifest_tolerance);
+        assert_eq!(
+            manifest.resolve_tolerance(&args, &aabb),
+            manifest_tolerance
+        );
+
+        // The user can still override the tolerance via the command line.
+        args.tolerance = Some(user_tolerance);
+        assert_eq!(manifest.resolve_tolerance(&args, &aabb), user_tolerance);
+    }
+
+    #[test]
+    fn apply_parameters_from_args() {
+        let mut manifest = Manifest::new().with_parameter("width", 3.);
+
+        let mut args = Args::default();
+        args.parameters.push(("width".to_string(), 5.));
+        manifest.apply_args(&args).unwrap();
+        assert_eq!(manifest.parameter("width"), Some(5.));
+
+        args.parameters.push(("height".to_string(), 1.));
+        assert!(manifest.apply_args(&args).is_err());
+    }
+}
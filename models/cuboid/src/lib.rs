@@ -36,5 +36,6 @@ pub fn model(
             .insert(services),
         )
         .sweep_sketch(bottom_surface, sweep_path, services)
+        .expect("Cuboid needs positive edge lengths")
         .insert(services)
 }
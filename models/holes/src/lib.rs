@@ -27,15 +27,17 @@ pub fn model(
             let offset = size / 2.;
             let depth = size / 2.;
 
-            let shell = shell.add_blind_hole(
-                HoleLocation {
-                    face: bottom_face,
-                    position: [-offset, Scalar::ZERO].into(),
-                },
-                radius,
-                [Scalar::ZERO, Scalar::ZERO, depth],
-                services,
-            );
+            let shell = shell
+                .add_blind_hole(
+                    HoleLocation {
+                        face: bottom_face,
+                        position: [-offset, Scalar::ZERO].into(),
+                    },
+                    radius,
+                    [Scalar::ZERO, Scalar::ZERO, depth],
+                    services,
+                )
+                .expect("Hole is bounded by a circle");
 
             let bottom_face = shell.faces().first();
             let top_face = shell
@@ -58,6 +60,7 @@ pub fn model(
                     radius,
                     services,
                 )
+                .expect("Hole is bounded by a circle")
                 .insert(services)
         })
         .insert(services)
@@ -1,18 +1,4 @@
-use fj::{
-    core::{
-        objects::{Cycle, Region, Sketch, Solid},
-        operations::{
-            build::{BuildCycle, BuildRegion, BuildSketch},
-            insert::Insert,
-            reverse::Reverse,
-            sweep::SweepSketch,
-            update::{UpdateRegion, UpdateSketch},
-        },
-        services::Services,
-        storage::Handle,
-    },
-    math::{Point, Vector},
-};
+use fj::prelude::*;
 
 pub fn model(
     outer: f64,
@@ -36,5 +22,6 @@ pub fn model(
                 .insert(services),
         )
         .sweep_sketch(bottom_surface, sweep_path, services)
+        .expect("Spacer needs an outer radius")
         .insert(services)
 }
@@ -1,6 +1,11 @@
 use fj::{core::services::Services, handle_model};
 
 fn main() -> fj::Result {
+    // Set up logging before building the model, so the operations that build
+    // it can be traced. Run with `--features tracing` and
+    // `RUST_LOG=fj_core=debug` to see them.
+    fj::init_tracing();
+
     let mut services = Services::new();
     let model = spacer::model(1., 0.5, 1., &mut services);
     handle_model(model, services)?;
@@ -29,6 +29,7 @@ pub fn model(
 
             shell
                 .sweep_face_of_shell(face, [0., 0., -size / 2.], services)
+                .expect("Faces of the cuboid are bounded by lines")
                 .insert(services)
         })
         .insert(services)
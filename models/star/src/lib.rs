@@ -55,5 +55,6 @@ pub fn model(
                 .insert(services),
         )
         .sweep_sketch(bottom_surface, sweep_path, services)
+        .expect("Star encloses an area")
         .insert(services)
 }